@@ -16,6 +16,11 @@
 //! Define the commands supported by the CLI related to Accounts.
 
 use super::*;
+use serde::Serialize;
+use sp_runtime::traits::Header as _;
+
+/// Number of blocks scanned by `account history` if `--from-block` is not given.
+const DEFAULT_HISTORY_BLOCKS: BlockNumber = 100;
 
 /// Account related commands
 #[derive(StructOpt, Clone)]
@@ -24,6 +29,8 @@ pub enum Command {
     Show(Show),
     /// Transfer funds from the author to a recipient account.
     Transfer(Transfer),
+    /// List the transactions and balance changes of an account in a range of blocks.
+    History(History),
 }
 
 #[async_trait::async_trait]
@@ -32,6 +39,7 @@ impl CommandT for Command {
         match self {
             Command::Show(cmd) => cmd.run().await,
             Command::Transfer(cmd) => cmd.run().await,
+            Command::History(cmd) => cmd.run().await,
         }
     }
 }
@@ -53,9 +61,22 @@ pub struct Show {
 impl CommandT for Show {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
-        let balance = client.free_balance(&self.account_id).await?;
+        let account_info = client.account_info(&self.account_id).await?;
         println!("ss58 address: {}", self.account_id.to_ss58check());
-        println!("balance: {} μRAD", balance);
+        println!("balance: {} μRAD", account_info.free);
+        if account_info.reserved > 0 {
+            println!("reserved: {} μRAD", account_info.reserved);
+        }
+        if account_info.locked > 0 {
+            println!("locked: {} μRAD", account_info.locked);
+        }
+        if let Some(vesting) = account_info.vesting {
+            let best_number = client.block_header_best_chain().await?.number;
+            println!("vesting:");
+            println!("  starting block: {}", vesting.starting_block);
+            println!("  released per block: {} μRAD", vesting.per_block);
+            println!("  still vesting: {} μRAD", vesting.locked_at(best_number));
+        }
         Ok(())
     }
 }
@@ -89,7 +110,7 @@ impl CommandT for Transfer {
                     recipient: self.recipient,
                     amount: self.amount,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Transferring funds...");
@@ -103,3 +124,184 @@ impl CommandT for Transfer {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct History {
+    /// The account's SS58 address or the name of a local key pair.
+    #[structopt(
+        value_name = "address_or_name",
+        parse(try_from_str = parse_account_id),
+    )]
+    account_id: AccountId,
+
+    /// First block to scan. Defaults to 100 blocks before the last block.
+    #[structopt(long, value_name = "number")]
+    from_block: Option<BlockNumber>,
+
+    /// Last block to scan. Defaults to the tip of the best chain.
+    #[structopt(long, value_name = "number")]
+    to_block: Option<BlockNumber>,
+
+    /// Print the history as a JSON array.
+    #[structopt(long)]
+    json: bool,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+/// Entry of the account history.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HistoryEntry {
+    block_number: BlockNumber,
+    block_hash: BlockHash,
+    #[serde(flatten)]
+    kind: HistoryEntryKind,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum HistoryEntryKind {
+    /// The account authored a transaction.
+    #[serde(rename_all = "camelCase")]
+    Transaction {
+        tx_hash: TxHash,
+        call: String,
+        fee: Balance,
+        success: bool,
+    },
+    TransferIn {
+        from: String,
+        amount: Balance,
+    },
+    TransferOut {
+        to: String,
+        amount: Balance,
+    },
+    FeePaid {
+        amount: Balance,
+    },
+    RewardReceived {
+        amount: Balance,
+    },
+}
+
+impl From<AccountStatementEntry> for HistoryEntry {
+    fn from(entry: AccountStatementEntry) -> Self {
+        let kind = match entry.kind {
+            AccountStatementEntryKind::TransferIn { from, amount } => {
+                HistoryEntryKind::TransferIn {
+                    from: from.to_ss58check(),
+                    amount,
+                }
+            }
+            AccountStatementEntryKind::TransferOut { to, amount } => {
+                HistoryEntryKind::TransferOut {
+                    to: to.to_ss58check(),
+                    amount,
+                }
+            }
+            AccountStatementEntryKind::FeePaid { amount } => HistoryEntryKind::FeePaid { amount },
+            AccountStatementEntryKind::RewardReceived { amount } => {
+                HistoryEntryKind::RewardReceived { amount }
+            }
+        };
+        HistoryEntry {
+            block_number: entry.block_number,
+            block_hash: entry.block_hash,
+            kind,
+        }
+    }
+}
+
+impl std::fmt::Display for HistoryEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "#{} ", self.block_number)?;
+        match &self.kind {
+            HistoryEntryKind::Transaction {
+                tx_hash,
+                call,
+                fee,
+                success,
+            } => {
+                write!(f, "transaction {} {} (fee {} μRAD)", tx_hash, call, fee)?;
+                if !success {
+                    write!(f, " failed")?;
+                }
+                Ok(())
+            }
+            HistoryEntryKind::TransferIn { from, amount } => {
+                write!(f, "received {} μRAD from {}", amount, from)
+            }
+            HistoryEntryKind::TransferOut { to, amount } => {
+                write!(f, "sent {} μRAD to {}", amount, to)
+            }
+            HistoryEntryKind::FeePaid { amount } => write!(f, "paid fee of {} μRAD", amount),
+            HistoryEntryKind::RewardReceived { amount } => {
+                write!(f, "received block reward of {} μRAD", amount)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandT for History {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let tip = client.block_header_best_chain().await?;
+        let to_block = self.to_block.unwrap_or(tip.number).min(tip.number);
+        let from_block = self
+            .from_block
+            .unwrap_or_else(|| to_block.saturating_sub(DEFAULT_HISTORY_BLOCKS - 1));
+
+        let mut entries = Vec::new();
+        let mut maybe_header = Some(tip);
+        while let Some(header) = maybe_header {
+            if header.number < from_block {
+                break;
+            }
+            if header.number <= to_block {
+                let block_hash = header.hash();
+                let transactions = client.block_body(block_hash).await?.unwrap_or_default();
+                let block_entries = transactions
+                    .into_iter()
+                    .filter(|tx| tx.author == self.account_id)
+                    .map(|tx| HistoryEntry {
+                        block_number: header.number,
+                        block_hash,
+                        kind: HistoryEntryKind::Transaction {
+                            tx_hash: tx.tx_hash,
+                            call: tx.call_name(),
+                            fee: tx.fee,
+                            success: tx.result.is_ok(),
+                        },
+                    })
+                    .collect::<Vec<_>>();
+                entries.splice(0..0, block_entries);
+            }
+            if header.number == 0 {
+                break;
+            }
+            maybe_header = client.block_header(header.parent_hash).await?;
+        }
+        let statement = client
+            .account_statement(&self.account_id, from_block, to_block)
+            .await?;
+        entries.extend(statement.into_iter().map(HistoryEntry::from));
+        // The sort is stable so transactions stay before the balance changes of their block.
+        entries.sort_by_key(|entry| entry.block_number);
+
+        if self.json {
+            let json = serde_json::to_string_pretty(&entries).expect("History is serializable");
+            println!("{}", json);
+        } else if entries.is_empty() {
+            println!("No activity in blocks {} to {}", from_block, to_block);
+        } else {
+            for entry in entries {
+                println!("{}", entry);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Define the commands supported by the CLI related to org and user ids.
+
+use super::*;
+
+/// Org and user id related commands
+#[derive(StructOpt, Clone)]
+pub enum Command {
+    /// Show whether an id is available, taken, retired or reserved.
+    Status(Status),
+    /// List all reserved ids and the accounts holding the reservations.
+    ListReservations(ListReservations),
+    /// Reserve an id for an account. Only that account can register the id.
+    /// The author must be the chain's sudo key.
+    Reserve(Reserve),
+    /// Cancel the reservation of an id.
+    /// The author must be the chain's sudo key.
+    CancelReservation(CancelReservation),
+}
+
+#[async_trait::async_trait]
+impl CommandT for Command {
+    async fn run(self) -> Result<(), CommandError> {
+        match self {
+            Command::Status(cmd) => cmd.run().await,
+            Command::ListReservations(cmd) => cmd.run().await,
+            Command::Reserve(cmd) => cmd.run().await,
+            Command::CancelReservation(cmd) => cmd.run().await,
+        }
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Status {
+    /// The org or user id
+    id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Status {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let status = client.get_id_status(&self.id).await?;
+        println!("{}: {:?}", self.id, status);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct ListReservations {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ListReservations {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let reservations = client.list_id_reservations().await?;
+        println!("RESERVED IDS ({})", reservations.len());
+        for (id, holder) in reservations {
+            println!("{}: reserved for {}", id, holder.to_ss58check());
+        }
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Reserve {
+    /// The org or user id to reserve.
+    id: Id,
+
+    /// The account that may register the id.
+    /// SS58 address or name of a local key pair.
+    #[structopt(parse(try_from_str = parse_account_id))]
+    holder: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Reserve {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let reserve_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::ReserveId {
+                    id: self.id.clone(),
+                    holder: self.holder,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Reserving id...");
+
+        let reserved = reserve_fut.await?;
+        reserved.result?;
+        println!(
+            "✓ Id {} reserved for {} in block {}",
+            self.id,
+            self.holder.to_ss58check(),
+            reserved.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct CancelReservation {
+    /// The reserved org or user id.
+    id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for CancelReservation {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let cancel_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::CancelIdReservation {
+                    id: self.id.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Cancelling id reservation...");
+
+        let cancelled = cancel_fut.await?;
+        cancelled.result?;
+        println!(
+            "✓ Reservation of id {} cancelled in block {}",
+            self.id, cancelled.block,
+        );
+        Ok(())
+    }
+}
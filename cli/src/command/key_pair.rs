@@ -15,8 +15,9 @@
 
 //! Define the commands supported by the CLI related to key-pairs.
 
+use std::path::PathBuf;
+
 use super::*;
-use crate::key_pair_storage;
 
 /// Key-pair related commands
 #[derive(StructOpt, Clone)]
@@ -27,6 +28,11 @@ pub enum Command {
     Generate(Generate),
     /// List all the local key pairs.
     List(List),
+    /// Write the encrypted key pair identified by `name` to a file.
+    Export(Export),
+    /// Add a key pair from a file written by `key-pair export`.
+    /// The key pair keeps the password it was exported with.
+    Import(Import),
 }
 
 #[async_trait::async_trait]
@@ -35,6 +41,8 @@ impl CommandT for Command {
         match self {
             Command::Generate(cmd) => cmd.run().await,
             Command::List(cmd) => cmd.run().await,
+            Command::Export(cmd) => cmd.run().await,
+            Command::Import(cmd) => cmd.run().await,
         }
     }
 }
@@ -48,8 +56,9 @@ pub struct Generate {
 #[async_trait::async_trait]
 impl CommandT for Generate {
     async fn run(self) -> Result<(), CommandError> {
+        let password = new_key_pair_password(&self.name).map_err(CommandError::Input)?;
         let (key_pair, seed) = ed25519::Pair::generate();
-        key_pair_storage::add(self.name, key_pair_storage::KeyPairData { seed })?;
+        key_pair_storage::add(self.name, seed, &password)?;
         println!("✓ Key pair generated successfully");
         println!("ⓘ SS58 address: {}", key_pair.public().to_ss58check());
         Ok(())
@@ -64,13 +73,72 @@ impl CommandT for List {
     async fn run(self) -> Result<(), CommandError> {
         let key_pairs = key_pair_storage::list()?;
         println!("Key pairs ({})\n", key_pairs.len());
-        for (name, data) in key_pairs {
+        for (name, key_pair) in key_pairs {
             println!("  '{}'", name);
-            println!(
-                "  ss58 address: {}\n",
-                ed25519::Pair::from_seed(&data.seed).public().to_ss58check()
-            );
+            println!("  ss58 address: {}\n", key_pair.public().to_ss58check());
         }
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct Export {
+    /// The name of the key pair to export.
+    name: String,
+
+    /// File to write the encrypted key pair to.
+    #[structopt(value_name = "file")]
+    output: PathBuf,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Export {
+    async fn run(self) -> Result<(), CommandError> {
+        let password = key_pair_password(&self.name).map_err(CommandError::Input)?;
+        let key_pair = key_pair_storage::export(&self.name, &password)?;
+        let content =
+            serde_json::to_string_pretty(&key_pair).expect("EncryptedKeyPair is serializable");
+        std::fs::write(&self.output, content).map_err(|error| {
+            CommandError::InvalidKeyPairFile {
+                path: self.output.clone(),
+                reason: error.to_string(),
+            }
+        })?;
+        println!(
+            "✓ Key pair '{}' written to {}",
+            self.name,
+            self.output.display()
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Import {
+    /// The name that uniquely identifies the key pair locally.
+    name: String,
+
+    /// File written by `key-pair export`.
+    #[structopt(value_name = "file")]
+    path: PathBuf,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Import {
+    async fn run(self) -> Result<(), CommandError> {
+        let invalid_key_pair_file = |reason: String| CommandError::InvalidKeyPairFile {
+            path: self.path.clone(),
+            reason,
+        };
+        let content = std::fs::read_to_string(&self.path)
+            .map_err(|error| invalid_key_pair_file(error.to_string()))?;
+        let key_pair: key_pair_storage::EncryptedKeyPair = serde_json::from_str(&content)
+            .map_err(|error| invalid_key_pair_file(error.to_string()))?;
+        let public = key_pair.public;
+        let password = key_pair_password(&self.name).map_err(CommandError::Input)?;
+        key_pair_storage::import(self.name, key_pair, &password)?;
+        println!("✓ Key pair imported successfully");
+        println!("ⓘ SS58 address: {}", public.to_ss58check());
+        Ok(())
+    }
+}
@@ -15,7 +15,10 @@
 
 //! Define the commands supported by the CLI.
 
-use crate::{lookup_key_pair, CommandError, CommandT, NetworkOptions, TxOptions};
+use crate::{
+    key_pair_password, key_pair_storage, lookup_signer, new_key_pair_password, CommandError,
+    CommandT, NetworkOptions, TxOptions,
+};
 use itertools::Itertools;
 use radicle_registry_client::*;
 
@@ -23,32 +26,61 @@ use sp_core::crypto::Ss58Codec;
 use structopt::StructOpt;
 
 pub mod account;
+pub mod id;
 pub mod key_pair;
+pub mod onboard;
 pub mod org;
 pub mod other;
 pub mod project;
 pub mod runtime;
+pub mod treasury;
+pub mod tx;
 pub mod user;
+pub mod watch;
 
 fn parse_account_id(data: &str) -> Result<AccountId, String> {
     Ss58Codec::from_ss58check(data)
         .map_err(|err| format!("{:?}", err))
         .or_else(|address_error| {
-            lookup_key_pair(data)
-                .map(|key_pair| key_pair.public())
-                .map_err(|key_pair_error| {
-                    format!(
-                        "
+            key_pair_storage::public(data).map_err(|key_pair_error| {
+                format!(
+                    "
     ! Could not parse an ss58 address nor find a local key pair with the given name.
     ⓘ Error parsing SS58 address: {}
     ⓘ Error looking up key pair: {}
     ",
-                        address_error, key_pair_error
-                    )
-                })
+                    address_error, key_pair_error
+                )
+            })
         })
 }
 
+/// Parse org or user metadata from the UTF-8 bytes of `data`.
+fn parse_metadata(data: &str) -> Result<Bytes128, String> {
+    Bytes128::from_vec(data.as_bytes().to_vec())
+        .map_err(|_| "metadata must not be longer than 128 bytes".to_string())
+}
+
+/// Print the metadata of an org or user. Metadata that is not valid UTF-8 is printed as bytes.
+fn print_metadata(metadata: Option<&Bytes128>) {
+    match metadata.cloned().map(Vec::from) {
+        Some(bytes) => match String::from_utf8(bytes) {
+            Ok(text) => println!("metadata: {}", text),
+            Err(err) => println!("metadata: {:?}", err.into_bytes()),
+        },
+        None => println!("metadata: none"),
+    }
+}
+
+/// Print the block in which an org, user, or project was registered. Entities registered before
+/// the block number was recorded show as unknown.
+fn print_registered_at(registered_at: Option<u32>) {
+    match registered_at {
+        Some(block_number) => println!("registered at block: {}", block_number),
+        None => println!("registered at block: unknown"),
+    }
+}
+
 fn announce_tx(msg: &str) {
     println!("{}", msg);
     println!("⏳ Transactions might take a while to be processed. Please wait...");
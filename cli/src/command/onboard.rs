@@ -0,0 +1,221 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Guided first-run setup of a key pair and a user.
+
+use std::io::{self, BufRead as _, Write as _};
+use std::time::Duration;
+
+use super::*;
+
+/// Guide through creating a key pair, funding its account and registering a user.
+///
+/// Options that are not given on the command line are prompted for.
+#[derive(StructOpt, Clone)]
+pub struct Onboard {
+    /// The name of the local key pair to use. The key pair is generated if it does not exist.
+    #[structopt(long, value_name = "key_pair_name")]
+    key_pair: Option<String>,
+
+    /// Id of the user to register. The valid charset is: 'a-z0-9-' and can't begin or end with
+    /// a '-', must also not contain more than two '-' in a row.
+    #[structopt(long, value_name = "user_id")]
+    user_id: Option<Id>,
+
+    /// Seconds to wait between checks of the account balance while waiting for funds.
+    #[structopt(long, default_value = "5", value_name = "seconds")]
+    poll_interval: u64,
+
+    /// Fee that will be charged to submit transactions.
+    /// Defaults to the minimum transaction fee of the chain.
+    #[structopt(long, env = "RAD_FEE", value_name = "fee")]
+    fee: Option<Balance>,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Onboard {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let key_pair = self.key_pair()?;
+        let account_id = key_pair.public();
+        println!("ⓘ SS58 address: {}", account_id.to_ss58check());
+
+        if let Some((user_id, _)) = client.get_user_by_account(&account_id).await? {
+            println!("✓ The key pair is already associated with user {}", user_id);
+            return Ok(());
+        }
+
+        let user_id = self.user_id(&client, &account_id).await?;
+        let message = message::RegisterUser {
+            user_id: user_id.clone(),
+            idempotency_key: None,
+        };
+
+        let mut estimate = client.estimate_fee(&account_id, message.clone()).await?;
+        if let Some(fee) = self.fee {
+            estimate.tx_fee = fee;
+        }
+        self.wait_for_funds(&client, &account_id, estimate.required_balance())
+            .await?;
+
+        let register_user_fut = client
+            .sign_and_submit_message(&key_pair, message, estimate.tx_fee)
+            .await?;
+        announce_tx("Registering user...");
+        register_user_fut.await?.result?;
+        println!("✓ User {} is now registered.", user_id);
+        Ok(())
+    }
+}
+
+impl Onboard {
+    /// Look up the key pair with the given or prompted name. Generate and store a new key pair if
+    /// none with the name exists.
+    fn key_pair(&self) -> Result<ed25519::Pair, CommandError> {
+        let name = match &self.key_pair {
+            Some(name) => name.clone(),
+            None => prompt("Name of the key pair to use or create")?,
+        };
+        match key_pair_storage::public(&name) {
+            Ok(_) => {
+                let password = key_pair_password(&name).map_err(CommandError::Input)?;
+                let key_pair = key_pair_storage::get(&name, &password)?;
+                println!("✓ Using existing key pair '{}'", name);
+                Ok(key_pair)
+            }
+            Err(key_pair_storage::Error::NotFound()) => {
+                let password = new_key_pair_password(&name).map_err(CommandError::Input)?;
+                let (key_pair, seed) = ed25519::Pair::generate();
+                key_pair_storage::add(name.clone(), seed, &password)?;
+                println!("✓ Key pair '{}' generated successfully", name);
+                Ok(key_pair)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Return the given user id or prompt for user ids until an id that can be claimed by
+    /// `account_id` is entered.
+    async fn user_id(&self, client: &Client, account_id: &AccountId) -> Result<Id, CommandError> {
+        if let Some(user_id) = &self.user_id {
+            return match id_unavailable_reason(client, account_id, user_id).await? {
+                None => Ok(user_id.clone()),
+                Some(reason) => Err(CommandError::IdUnavailable {
+                    id: user_id.clone(),
+                    reason,
+                }),
+            };
+        }
+
+        loop {
+            let input = prompt("Id of the user to register")?;
+            let user_id = match input.parse::<Id>() {
+                Ok(user_id) => user_id,
+                Err(error) => {
+                    println!("! Invalid user id: {}", error);
+                    continue;
+                }
+            };
+            match id_unavailable_reason(client, account_id, &user_id).await? {
+                None => return Ok(user_id),
+                Some(reason) => println!("! The id {} {}", user_id, reason),
+            }
+        }
+    }
+
+    /// Poll the balance of `account_id` until it holds at least `required_balance`.
+    async fn wait_for_funds(
+        &self,
+        client: &Client,
+        account_id: &AccountId,
+        required_balance: Balance,
+    ) -> Result<(), CommandError> {
+        let mut balance = client.free_balance(account_id).await?;
+        if balance >= required_balance {
+            return Ok(());
+        }
+
+        println!(
+            "ⓘ Registering a user requires a balance of {} μRAD. The account has {} μRAD.",
+            required_balance, balance
+        );
+        println!(
+            "ⓘ Ask the faucet of the network or another account holder to transfer funds to {}.",
+            account_id.to_ss58check()
+        );
+        println!("  See https://registry.radicle.xyz/docs/getting-started for details.");
+        println!("⏳ Waiting for funds. Press Ctrl-C to abort.");
+        while balance < required_balance {
+            async_std::task::sleep(Duration::from_secs(self.poll_interval)).await;
+            let new_balance = client.free_balance(account_id).await?;
+            if new_balance != balance {
+                println!("ⓘ Balance: {} μRAD", new_balance);
+                balance = new_balance;
+            }
+        }
+        println!("✓ The account is funded");
+        Ok(())
+    }
+}
+
+/// Explain why `id` cannot be claimed by `account_id`. Returns `None` if it can be claimed.
+async fn id_unavailable_reason(
+    client: &Client,
+    account_id: &AccountId,
+    id: &Id,
+) -> Result<Option<&'static str>, CommandError> {
+    let reason = match client.get_id_status(id).await? {
+        IdStatus::Available => None,
+        IdStatus::Taken => Some("is already taken"),
+        IdStatus::Retired => Some("is retired and cannot be claimed again"),
+        IdStatus::Reserved => {
+            let reservations = client.list_id_reservations().await?;
+            let holder = reservations
+                .into_iter()
+                .find(|(reserved_id, _)| reserved_id == id)
+                .map(|(_, holder)| holder);
+            if holder.as_ref() == Some(account_id) {
+                None
+            } else {
+                Some("is reserved for another account")
+            }
+        }
+    };
+    Ok(reason)
+}
+
+/// Print `question` and read a non-empty answer from stdin.
+fn prompt(question: &str) -> Result<String, CommandError> {
+    loop {
+        print!("{}: ", question);
+        io::stdout().flush().map_err(CommandError::Input)?;
+        let mut answer = String::new();
+        let read = io::stdin()
+            .lock()
+            .read_line(&mut answer)
+            .map_err(CommandError::Input)?;
+        if read == 0 {
+            return Err(CommandError::Input(io::ErrorKind::UnexpectedEof.into()));
+        }
+        let answer = answer.trim();
+        if !answer.is_empty() {
+            return Ok(answer.to_string());
+        }
+    }
+}
@@ -15,6 +15,8 @@
 
 //! Define the commands supported by the CLI related to Orgs.
 
+use futures::TryStreamExt as _;
+
 use super::*;
 
 /// Org related commands
@@ -31,8 +33,28 @@ pub enum Command {
     Register(Register),
     /// Unregister an org.
     Unregister(Unregister),
+    /// Transfer all remaining funds of an org to a recipient and unregister the org.
+    /// The author needs to be the only member of the org.
+    Dissolve(Dissolve),
     /// Register a new member under an org.
     RegisterMember(RegisterMember),
+    /// Set the metadata of an org.
+    /// The author needs to be a member of the org.
+    Update(Update),
+    /// Hand over the membership of the author's user to another user.
+    TransferMembership(TransferMembership),
+    /// Transfer funds from the author to an org.
+    Fund(Fund),
+    /// Print the balance of an org whenever it changes and warn when it
+    /// drops below a threshold.
+    WatchBalance(WatchBalance),
+    /// Freeze an org. Funds cannot be transferred from a frozen org and no
+    /// projects can be registered under it.
+    /// The author must be the chain's sudo key.
+    Freeze(Freeze),
+    /// Unfreeze a frozen org.
+    /// The author must be the chain's sudo key.
+    Unfreeze(Unfreeze),
 }
 
 #[async_trait::async_trait]
@@ -43,8 +65,15 @@ impl CommandT for Command {
             Command::List(cmd) => cmd.run().await,
             Command::Register(cmd) => cmd.run().await,
             Command::Unregister(cmd) => cmd.run().await,
+            Command::Dissolve(cmd) => cmd.run().await,
             Command::Transfer(cmd) => cmd.run().await,
             Command::RegisterMember(cmd) => cmd.run().await,
+            Command::Update(cmd) => cmd.run().await,
+            Command::TransferMembership(cmd) => cmd.run().await,
+            Command::Fund(cmd) => cmd.run().await,
+            Command::WatchBalance(cmd) => cmd.run().await,
+            Command::Freeze(cmd) => cmd.run().await,
+            Command::Unfreeze(cmd) => cmd.run().await,
         }
     }
 }
@@ -94,6 +123,11 @@ impl CommandT for Show {
         println!("balance: {} μRAD", balance);
         println!("member ids: [{}]", org.members().iter().format(", "));
         println!("projects: [{}]", org.projects().iter().format(", "));
+        print_registered_at(org.registered_at());
+        print_metadata(org.metadata());
+        if org.frozen() {
+            println!("frozen: true");
+        }
         Ok(())
     }
 }
@@ -120,8 +154,9 @@ impl CommandT for Register {
                 &self.tx_options.author,
                 message::RegisterOrg {
                     org_id: self.org_id.clone(),
+                    idempotency_key: None,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Registering org...");
@@ -155,7 +190,7 @@ impl CommandT for Unregister {
                 message::UnregisterOrg {
                     org_id: self.org_id.clone(),
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Unregistering org...");
@@ -166,6 +201,49 @@ impl CommandT for Unregister {
     }
 }
 
+#[derive(StructOpt, Clone)]
+pub struct Dissolve {
+    /// Id of the org to dissolve.
+    org_id: Id,
+
+    /// The account receiving the remaining org funds.
+    /// SS58 address or name of a local key pair.
+    #[structopt(parse(try_from_str = parse_account_id))]
+    recipient: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Dissolve {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let dissolve_org_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::DissolveOrg {
+                    org_id: self.org_id.clone(),
+                    recipient: self.recipient,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Dissolving org...");
+
+        dissolve_org_fut.await?.result?;
+        println!(
+            "✓ Org {} is now dissolved. Remaining funds were transferred to {}.",
+            self.org_id, self.recipient
+        );
+        Ok(())
+    }
+}
+
 #[derive(StructOpt, Clone)]
 pub struct Transfer {
     /// Id of the org.
@@ -180,6 +258,10 @@ pub struct Transfer {
     #[structopt(parse(try_from_str = parse_account_id))]
     recipient: AccountId,
 
+    /// Allow the transfer to drain the org account below the existential deposit.
+    #[structopt(long)]
+    allow_death: bool,
+
     #[structopt(flatten)]
     network_options: NetworkOptions,
 
@@ -198,8 +280,9 @@ impl CommandT for Transfer {
                     org_id: self.org_id.clone(),
                     recipient: self.recipient,
                     amount: self.amount,
+                    allow_death: self.allow_death,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Transferring funds...");
@@ -241,7 +324,7 @@ impl CommandT for RegisterMember {
                     org_id: self.org_id.clone(),
                     user_id: self.user_id.clone(),
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Registering member...");
@@ -254,3 +337,244 @@ impl CommandT for RegisterMember {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct Update {
+    /// Id of the org to update.
+    org_id: Id,
+
+    /// The new metadata of the org, for example a display name or the hash of a profile.
+    /// At most 128 bytes.
+    #[structopt(parse(try_from_str = parse_metadata))]
+    metadata: Bytes128,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Update {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let update_org_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::UpdateOrg {
+                    org_id: self.org_id.clone(),
+                    metadata: self.metadata,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Updating org...");
+
+        update_org_fut.await?.result?;
+        println!("✓ Org {} updated.", self.org_id);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct TransferMembership {
+    /// Id of the org in which the membership is transferred.
+    org_id: Id,
+
+    /// Id of the user that takes over the membership.
+    recipient: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for TransferMembership {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let transfer_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::TransferOrgMembership {
+                    org_id: self.org_id.clone(),
+                    recipient: self.recipient.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Transferring membership...");
+
+        transfer_fut.await?.result?;
+        println!(
+            "✓ User {} took over your membership in the Org {}.",
+            self.recipient, self.org_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Fund {
+    /// Id of the org to fund.
+    #[structopt(value_name = "org")]
+    org_id: Id,
+
+    /// The amount to transfer from the author to the org.
+    amount: Balance,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Fund {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let fund_fut = client
+            .fund_org(
+                &self.tx_options.author,
+                self.org_id.clone(),
+                self.amount,
+                self.tx_options.fee(&client).await?,
+            )
+            .await
+            .map_err(|error| match error {
+                Error::OrgMissing { org_id } => CommandError::OrgNotFound { org_id },
+                error => CommandError::from(error),
+            })?;
+        announce_tx("Transferring funds...");
+
+        let funded = fund_fut.await?;
+        funded.result?;
+        println!(
+            "✓ Transferred {} μRAD to Org {} in block {}",
+            self.amount, self.org_id, funded.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Freeze {
+    /// Id of the org to freeze.
+    org_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Freeze {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let freeze_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::FreezeOrg {
+                    org_id: self.org_id.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Freezing org...");
+
+        let frozen = freeze_fut.await?;
+        frozen.result?;
+        println!("✓ Org {} frozen in block {}", self.org_id, frozen.block);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Unfreeze {
+    /// Id of the org to unfreeze.
+    org_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Unfreeze {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let unfreeze_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::UnfreezeOrg {
+                    org_id: self.org_id.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Unfreezing org...");
+
+        let unfrozen = unfreeze_fut.await?;
+        unfrozen.result?;
+        println!("✓ Org {} unfrozen in block {}", self.org_id, unfrozen.block);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct WatchBalance {
+    /// Id of the org to watch.
+    #[structopt(value_name = "org")]
+    org_id: Id,
+
+    /// Warn when the balance of the org drops below this amount of μRAD.
+    #[structopt(long, value_name = "amount", default_value = "0")]
+    low_water_mark: Balance,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for WatchBalance {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let mut updates = client
+            .watch_org_treasury(self.org_id.clone(), self.low_water_mark)
+            .await
+            .map_err(|error| match error {
+                Error::OrgMissing { org_id } => CommandError::OrgNotFound { org_id },
+                error => CommandError::from(error),
+            })?;
+        while let Some(update) = updates.try_next().await? {
+            println!(
+                "block #{}: best {} μRAD, finalized {} μRAD",
+                update.block_number, update.best_balance, update.finalized_balance
+            );
+            match update.alert {
+                Some(OrgBalanceAlert::Low) => println!(
+                    "⚠ Balance of Org {} is below {} μRAD",
+                    self.org_id, self.low_water_mark
+                ),
+                Some(OrgBalanceAlert::Recovered) => println!(
+                    "✓ Balance of Org {} is back above {} μRAD",
+                    self.org_id, self.low_water_mark
+                ),
+                None => {}
+            }
+        }
+        Err(Error::BlockSubscriptionTerminated.into())
+    }
+}
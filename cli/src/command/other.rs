@@ -23,6 +23,8 @@ use super::*;
 pub enum Command {
     /// Show the genesis hash the node uses
     GenesisHash(ShowGenesisHash),
+    /// Show the account that controls privileged operations like runtime updates
+    SudoKey(ShowSudoKey),
 }
 
 #[async_trait::async_trait]
@@ -30,6 +32,7 @@ impl CommandT for Command {
     async fn run(self) -> Result<(), CommandError> {
         match self {
             Command::GenesisHash(cmd) => cmd.run().await,
+            Command::SudoKey(cmd) => cmd.run().await,
         }
     }
 }
@@ -49,3 +52,21 @@ impl CommandT for ShowGenesisHash {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct ShowSudoKey {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ShowSudoKey {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        match client.sudo_key().await? {
+            Some(sudo_key) => println!("Sudo key: {}", sudo_key.to_ss58check()),
+            None => println!("The chain has no sudo key"),
+        }
+        Ok(())
+    }
+}
@@ -25,6 +25,27 @@ pub enum Command {
     List(List),
     /// Register a project with the given name under the given org.
     Register(Register),
+    /// Show information for a registered project.
+    Show(Show),
+    /// Unregister a project. The project cannot be registered again.
+    Unregister(Unregister),
+    /// Offer a project registered under a user to another user.
+    Transfer(Transfer),
+    /// Accept a project transfer offered to the user of the author.
+    AcceptTransfer(AcceptTransfer),
+    /// Move a project to another org or user. The author must be authorized in both domains.
+    Move(Move),
+    /// Rename a project. The project stays in its domain.
+    Rename(Rename),
+    /// List all reserved project names and the orgs or users they are reserved for.
+    ListNameReservations(ListNameReservations),
+    /// Reserve a project name for an org or user. Projects with the name can only be added to
+    /// that org or user.
+    /// The author must be the chain's sudo key.
+    ReserveName(ReserveName),
+    /// Cancel the reservation of a project name.
+    /// The author must be the chain's sudo key.
+    CancelNameReservation(CancelNameReservation),
 }
 
 #[async_trait::async_trait]
@@ -33,6 +54,15 @@ impl CommandT for Command {
         match self {
             Command::List(cmd) => cmd.run().await,
             Command::Register(cmd) => cmd.run().await,
+            Command::Show(cmd) => cmd.run().await,
+            Command::Unregister(cmd) => cmd.run().await,
+            Command::Transfer(cmd) => cmd.run().await,
+            Command::AcceptTransfer(cmd) => cmd.run().await,
+            Command::Move(cmd) => cmd.run().await,
+            Command::Rename(cmd) => cmd.run().await,
+            Command::ListNameReservations(cmd) => cmd.run().await,
+            Command::ReserveName(cmd) => cmd.run().await,
+            Command::CancelNameReservation(cmd) => cmd.run().await,
         }
     }
 }
@@ -56,6 +86,57 @@ impl CommandT for List {
     }
 }
 
+#[derive(StructOpt, Clone)]
+pub struct Show {
+    /// Name of the project to show.
+    project_name: ProjectName,
+
+    /// The type of domain under which the project is registered.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    domain_type: DomainType,
+
+    /// The id of the domain under which the project is registered.
+    domain_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Show {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let project_domain = match self.domain_type {
+            DomainType::Org => ProjectDomain::Org(self.domain_id),
+            DomainType::User => ProjectDomain::User(self.domain_id),
+        };
+        let project = client
+            .get_project(self.project_name.clone(), project_domain.clone())
+            .await?
+            .ok_or(CommandError::ProjectNotFound {
+                project_name: self.project_name.clone(),
+                project_domain: project_domain.clone(),
+            })?;
+
+        println!("name: {}", self.project_name);
+        println!("domain: {:?}", project_domain);
+        println!("metadata: {:?}", project.metadata());
+        print_registered_at(project.registered_at());
+        if let ProjectDomain::User(user_id) = project_domain {
+            let pending_transfer = client
+                .get_pending_project_transfer(self.project_name, user_id)
+                .await?;
+            if let Some(recipient) = pending_transfer {
+                println!("pending transfer to: {}", recipient);
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(StructOpt, Clone)]
 pub struct Register {
     /// Name of the project to register.
@@ -96,8 +177,9 @@ impl CommandT for Register {
                     project_name: self.project_name.clone(),
                     project_domain: project_domain.clone(),
                     metadata: Bytes128::random(),
+                    idempotency_key: None,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Registering project...");
@@ -112,6 +194,268 @@ impl CommandT for Register {
     }
 }
 
+#[derive(StructOpt, Clone)]
+pub struct Unregister {
+    /// Name of the project to unregister.
+    project_name: ProjectName,
+
+    /// The type of domain under which the project is registered.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    domain_type: DomainType,
+
+    /// The id of the domain under which the project is registered.
+    domain_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Unregister {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let project_domain = match self.domain_type {
+            DomainType::Org => ProjectDomain::Org(self.domain_id),
+            DomainType::User => ProjectDomain::User(self.domain_id),
+        };
+        let unregister_project_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::UnregisterProject {
+                    project_name: self.project_name.clone(),
+                    project_domain: project_domain.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Unregistering project...");
+
+        unregister_project_fut.await?.result?;
+        println!(
+            "✓ Project {}.{:?} is now unregistered.",
+            self.project_name, project_domain
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Transfer {
+    /// Name of the project to transfer.
+    project_name: ProjectName,
+
+    /// The id of the user that owns the project.
+    user_id: Id,
+
+    /// The id of the user to transfer the project to.
+    recipient: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Transfer {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let transfer_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::TransferProjectOwnership {
+                    project_name: self.project_name.clone(),
+                    user_id: self.user_id.clone(),
+                    recipient: self.recipient.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Offering project...");
+
+        transfer_fut.await?.result?;
+        println!(
+            "✓ Project {}.{:?} offered to user {}. The transfer completes once {} accepts it.",
+            self.project_name,
+            ProjectDomain::User(self.user_id),
+            self.recipient,
+            self.recipient,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct AcceptTransfer {
+    /// Name of the project to accept.
+    project_name: ProjectName,
+
+    /// The id of the user that currently owns the project.
+    user_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for AcceptTransfer {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let accept_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::AcceptProjectOwnership {
+                    project_name: self.project_name.clone(),
+                    user_id: self.user_id.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Accepting project transfer...");
+
+        let tx_included = accept_fut.await?;
+        tx_included.result?;
+        println!(
+            "✓ Project {} of user {} transferred in block {}",
+            self.project_name, self.user_id, tx_included.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Move {
+    /// Name of the project to move.
+    project_name: ProjectName,
+
+    /// The type of domain under which the project is registered.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    from_domain_type: DomainType,
+
+    /// The id of the domain under which the project is registered.
+    from_domain_id: Id,
+
+    /// The type of domain to move the project to.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    to_domain_type: DomainType,
+
+    /// The id of the domain to move the project to.
+    to_domain_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Move {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let from = match self.from_domain_type {
+            DomainType::Org => ProjectDomain::Org(self.from_domain_id),
+            DomainType::User => ProjectDomain::User(self.from_domain_id),
+        };
+        let to = match self.to_domain_type {
+            DomainType::Org => ProjectDomain::Org(self.to_domain_id),
+            DomainType::User => ProjectDomain::User(self.to_domain_id),
+        };
+        let move_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::TransferProject {
+                    project_name: self.project_name.clone(),
+                    from: from.clone(),
+                    to: to.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Moving project...");
+
+        let tx_included = move_fut.await?;
+        tx_included.result?;
+        println!(
+            "✓ Project {} moved from {:?} to {:?} in block {}",
+            self.project_name, from, to, tx_included.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Rename {
+    /// Current name of the project.
+    old_name: ProjectName,
+
+    /// New name of the project.
+    new_name: ProjectName,
+
+    /// The type of domain under which the project is registered.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    domain_type: DomainType,
+
+    /// The id of the domain under which the project is registered.
+    domain_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Rename {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let domain = match self.domain_type {
+            DomainType::Org => ProjectDomain::Org(self.domain_id),
+            DomainType::User => ProjectDomain::User(self.domain_id),
+        };
+        let rename_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::RenameProject {
+                    old_name: self.old_name.clone(),
+                    new_name: self.new_name.clone(),
+                    domain: domain.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Renaming project...");
+
+        let tx_included = rename_fut.await?;
+        tx_included.result?;
+        println!(
+            "✓ Project {}.{:?} renamed to {} in block {}",
+            self.old_name, domain, self.new_name, tx_included.block,
+        );
+        Ok(())
+    }
+}
+
 arg_enum! {
     #[derive(Clone, Eq, PartialEq, Debug)]
     enum DomainType {
@@ -141,3 +485,111 @@ mod test {
         }
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct ListNameReservations {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ListNameReservations {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let reservations = client.list_project_name_reservations().await?;
+        println!("RESERVED PROJECT NAMES ({})", reservations.len());
+        for (project_name, domain) in reservations {
+            println!("{}: reserved for {:?}", project_name, domain);
+        }
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct ReserveName {
+    /// The project name to reserve.
+    project_name: ProjectName,
+
+    /// The type of domain to reserve the name for.
+    #[structopt(
+        possible_values = &DomainType::variants(),
+        case_insensitive = true,
+    )]
+    domain_type: DomainType,
+
+    /// The id of the org or user to reserve the name for.
+    domain_id: Id,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ReserveName {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let domain = match self.domain_type {
+            DomainType::Org => ProjectDomain::Org(self.domain_id),
+            DomainType::User => ProjectDomain::User(self.domain_id),
+        };
+        let reserve_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::ReserveProjectName {
+                    project_name: self.project_name.clone(),
+                    domain: domain.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Reserving project name...");
+
+        let reserved = reserve_fut.await?;
+        reserved.result?;
+        println!(
+            "✓ Project name {} reserved for {:?} in block {}",
+            self.project_name, domain, reserved.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct CancelNameReservation {
+    /// The reserved project name.
+    project_name: ProjectName,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for CancelNameReservation {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let cancel_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::CancelProjectNameReservation {
+                    project_name: self.project_name.clone(),
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Cancelling project name reservation...");
+
+        let cancelled = cancel_fut.await?;
+        cancelled.result?;
+        println!(
+            "✓ Reservation of project name {} cancelled in block {}",
+            self.project_name, cancelled.block,
+        );
+        Ok(())
+    }
+}
@@ -16,32 +16,47 @@
 //! Define the commands supported by the CLI related to the on-chain runtime.
 
 use super::*;
+use parity_scale_codec::Decode as _;
+use sp_wasm_interface::HostFunctions as _;
 
 /// Runtime related commands
 #[derive(StructOpt, Clone)]
 pub enum Command {
-    /// Submit a transaction to update the on-chain runtime.
-    Update(Update),
+    /// Upgrade the on-chain runtime with a sudo transaction.
+    ///
+    /// The new runtime must have the same spec name and a higher spec version than the on-chain
+    /// runtime. The author must be the sudo key of the chain.
+    #[structopt(alias = "update")]
+    Upgrade(Upgrade),
 
     /// Show the version of the on-chain runtime.
     Version(ShowVersion),
+
+    /// Show the constants of the on-chain runtime like the block reward and the fees.
+    Constants(ShowConstants),
 }
 
 #[async_trait::async_trait]
 impl CommandT for Command {
     async fn run(self) -> Result<(), CommandError> {
         match self {
-            Command::Update(cmd) => cmd.run().await,
+            Command::Upgrade(cmd) => cmd.run().await,
             Command::Version(cmd) => cmd.run().await,
+            Command::Constants(cmd) => cmd.run().await,
         }
     }
 }
 
 #[derive(StructOpt, Clone)]
-pub struct Update {
-    /// The path to the (wasm) runtime code to submit
+pub struct Upgrade {
+    /// The path to the wasm runtime code to submit
     path: std::path::PathBuf,
 
+    /// Confirm that the runtime should be upgraded after reviewing the summary of the changes.
+    /// Without this flag only the summary is shown.
+    #[structopt(long = "i-know-what-i-am-doing")]
+    confirmed: bool,
+
     #[structopt(flatten)]
     network_options: NetworkOptions,
 
@@ -50,11 +65,81 @@ pub struct Update {
 }
 
 #[async_trait::async_trait]
-impl CommandT for Update {
+impl CommandT for Upgrade {
     async fn run(self) -> Result<(), CommandError> {
         let client = self.network_options.client().await?;
-        let new_runtime_code =
-            std::fs::read(self.path).expect("Invalid path or couldn't read the wasm file");
+        let new_runtime_code = std::fs::read(&self.path).map_err(CommandError::Input)?;
+        let new_version = wasm_runtime_version(&new_runtime_code).map_err(|reason| {
+            CommandError::RuntimeUpgrade {
+                reason: format!("cannot read the version of {:?}: {}", self.path, reason),
+            }
+        })?;
+        let current_version = client.runtime_version().await?;
+
+        println!("Runtime upgrade:");
+        println!(
+            "  spec_name: {} -> {}",
+            current_version.spec_name, new_version.spec_name
+        );
+        println!(
+            "  spec_version: {} -> {}",
+            current_version.spec_version, new_version.spec_version
+        );
+        println!(
+            "  impl_version: {} -> {}",
+            current_version.impl_version, new_version.impl_version
+        );
+        println!(
+            "  transaction_version: {} -> {}",
+            current_version.transaction_version, new_version.transaction_version
+        );
+        for (api_id, new_api_version) in new_version.apis.iter() {
+            match current_version.api_version(api_id) {
+                Some(current_api_version) if current_api_version == *new_api_version => {}
+                Some(current_api_version) => println!(
+                    "  api 0x{}: version {} -> {}",
+                    hex::encode(api_id),
+                    current_api_version,
+                    new_api_version
+                ),
+                None => println!(
+                    "  api 0x{}: added with version {}",
+                    hex::encode(api_id),
+                    new_api_version
+                ),
+            }
+        }
+        for (api_id, _) in current_version.apis.iter() {
+            if !new_version.has_api_with(api_id, |_| true) {
+                println!("  api 0x{}: removed", hex::encode(api_id));
+            }
+        }
+        println!("  code size: {} bytes", new_runtime_code.len());
+
+        if new_version.spec_name != current_version.spec_name {
+            return Err(CommandError::RuntimeUpgrade {
+                reason: "the spec name of the new runtime differs from the on-chain runtime"
+                    .to_string(),
+            });
+        }
+        if new_version.spec_version <= current_version.spec_version {
+            return Err(CommandError::RuntimeUpgrade {
+                reason: "the spec version of the new runtime is not higher than the on-chain \
+                         runtime"
+                    .to_string(),
+            });
+        }
+        let author = self.tx_options.author.account_id();
+        if client.sudo_key().await? != Some(author) {
+            return Err(CommandError::RuntimeUpgrade {
+                reason: format!("the author {} is not the sudo key", author.to_ss58check()),
+            });
+        }
+        if !self.confirmed {
+            return Err(CommandError::RuntimeUpgrade {
+                reason: "pass --i-know-what-i-am-doing to submit the upgrade".to_string(),
+            });
+        }
 
         let update_runtime_fut = client
             .sign_and_submit_message(
@@ -62,7 +147,7 @@ impl CommandT for Update {
                 message::UpdateRuntime {
                     code: new_runtime_code,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Submitting the new on-chain runtime...");
@@ -73,6 +158,27 @@ impl CommandT for Update {
     }
 }
 
+/// Read the version of a wasm runtime by executing its `Core_version` function.
+fn wasm_runtime_version(code: &[u8]) -> Result<RuntimeVersion, String> {
+    let executor = sc_executor::WasmExecutor::new(
+        sc_executor::WasmExecutionMethod::Interpreted,
+        None,
+        sp_io::SubstrateHostFunctions::host_functions(),
+        1,
+    );
+    let mut ext = sp_state_machine::BasicExternalities::new_empty();
+    let encoded_version = sp_core::traits::CallInWasm::call_in_wasm(
+        &executor,
+        code,
+        None,
+        "Core_version",
+        &[],
+        &mut ext,
+        sp_core::traits::MissingHostFunctions::Allow,
+    )?;
+    RuntimeVersion::decode(&mut &encoded_version[..]).map_err(|error| error.to_string())
+}
+
 #[derive(StructOpt, Clone)]
 pub struct ShowVersion {
     #[structopt(flatten)]
@@ -90,3 +196,31 @@ impl CommandT for ShowVersion {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct ShowConstants {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ShowConstants {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let constants = client.chain_constants().await?;
+        println!("On-chain runtime constants:");
+        println!(
+            "  target_block_time: {}s",
+            constants.target_block_time.as_secs()
+        );
+        println!("  block_reward: {} μRAD", constants.block_reward);
+        println!("  registration_fee: {} μRAD", constants.registration_fee);
+        println!("  minimum_tx_fee: {} μRAD", constants.minimum_tx_fee);
+        println!("  fee_burn_share: {:?}", constants.fee_burn_share);
+        println!(
+            "  existential_deposit: {} μRAD",
+            constants.existential_deposit
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Define the commands supported by the CLI related to the treasury.
+
+use super::*;
+
+/// Treasury related commands
+#[derive(StructOpt, Clone)]
+pub enum Command {
+    /// Show the treasury account and its balance.
+    Show(Show),
+    /// List all open treasury spend proposals.
+    ListProposals(ListProposals),
+    /// Propose to spend funds from the treasury.
+    Propose(Propose),
+    /// Approve a treasury spend proposal and pay it out.
+    /// The author must be the chain's sudo key.
+    Approve(Approve),
+    /// Reject a treasury spend proposal.
+    /// The author must be the chain's sudo key.
+    Reject(Reject),
+    /// Withdraw a treasury spend proposal.
+    /// The author must be the proposer.
+    Cancel(Cancel),
+}
+
+#[async_trait::async_trait]
+impl CommandT for Command {
+    async fn run(self) -> Result<(), CommandError> {
+        match self {
+            Command::Show(cmd) => cmd.run().await,
+            Command::ListProposals(cmd) => cmd.run().await,
+            Command::Propose(cmd) => cmd.run().await,
+            Command::Approve(cmd) => cmd.run().await,
+            Command::Reject(cmd) => cmd.run().await,
+            Command::Cancel(cmd) => cmd.run().await,
+        }
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Show {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Show {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let balance = client.treasury_balance().await?;
+        println!("ss58 address: {}", treasury_account().to_ss58check());
+        println!("balance: {} μRAD", balance);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct ListProposals {
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for ListProposals {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let proposal_ids = client.list_treasury_proposals().await?;
+        println!("TREASURY PROPOSALS ({})", proposal_ids.len());
+        for proposal_id in proposal_ids {
+            if let Some(proposal) = client.get_treasury_proposal(proposal_id).await? {
+                println!(
+                    "{}: {} μRAD to {} proposed by {}",
+                    proposal_id,
+                    proposal.amount(),
+                    proposal.recipient().to_ss58check(),
+                    proposal.proposer().to_ss58check(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Propose {
+    /// The amount to spend.
+    amount: Balance,
+
+    /// The recipient account.
+    /// SS58 address or name of a local key pair.
+    #[structopt(parse(try_from_str = parse_account_id))]
+    recipient: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Propose {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let propose_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::ProposeTreasurySpend {
+                    recipient: self.recipient,
+                    amount: self.amount,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Proposing treasury spend...");
+
+        let proposed = propose_fut.await?;
+        proposed.result?;
+        println!(
+            "✓ Proposed to spend {} μRAD for {} in block {}",
+            self.amount, self.recipient, proposed.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Approve {
+    /// The id of the proposal to approve.
+    proposal_id: TreasuryProposalId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Approve {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let approve_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::ApproveTreasurySpend {
+                    proposal_id: self.proposal_id,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Approving treasury spend...");
+
+        let approved = approve_fut.await?;
+        approved.result?;
+        println!(
+            "✓ Treasury spend proposal {} approved and paid out in block {}",
+            self.proposal_id, approved.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Reject {
+    /// The id of the proposal to reject.
+    proposal_id: TreasuryProposalId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Reject {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let reject_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::RejectTreasurySpend {
+                    proposal_id: self.proposal_id,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Rejecting treasury spend...");
+
+        let rejected = reject_fut.await?;
+        rejected.result?;
+        println!(
+            "✓ Treasury spend proposal {} rejected in block {}",
+            self.proposal_id, rejected.block,
+        );
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Cancel {
+    /// The id of the proposal to withdraw.
+    proposal_id: TreasuryProposalId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Cancel {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let cancel_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::CancelTreasurySpend {
+                    proposal_id: self.proposal_id,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Withdrawing treasury spend...");
+
+        let cancelled = cancel_fut.await?;
+        cancelled.result?;
+        println!(
+            "✓ Treasury spend proposal {} withdrawn in block {}",
+            self.proposal_id, cancelled.block,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Define the commands to sign transactions offline and submit them later.
+
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Sign transactions offline and submit them later
+#[derive(StructOpt, Clone)]
+pub enum Command {
+    /// Show the parameters required to sign a transaction of an account offline.
+    Params(Params),
+    /// Sign a transaction without connecting to a node and write it to a file.
+    Sign(Sign),
+    /// Submit a transaction from a file written by `tx sign`.
+    Submit(Submit),
+}
+
+#[async_trait::async_trait]
+impl CommandT for Command {
+    async fn run(self) -> Result<(), CommandError> {
+        match self {
+            Command::Params(cmd) => cmd.run().await,
+            Command::Sign(cmd) => cmd.run().await,
+            Command::Submit(cmd) => cmd.run().await,
+        }
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Params {
+    /// The account's SS58 address or the name of a local key pair.
+    #[structopt(
+        value_name = "address_or_name",
+        parse(try_from_str = parse_account_id),
+    )]
+    account_id: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Params {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let nonce = client.account_nonce(&self.account_id).await?;
+        let runtime_version = client.runtime_version().await?;
+        println!("nonce: {}", nonce);
+        println!("genesis hash: {}", client.genesis_hash());
+        println!("tx version: {}", runtime_version.transaction_version);
+        println!("minimum fee: {} μRAD", client.minimum_tx_fee().await?);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Sign {
+    /// File to write the signed transaction to.
+    #[structopt(long, short, value_name = "file")]
+    output: PathBuf,
+
+    /// The name of the local key pair to sign the transaction with.
+    #[structopt(
+        long,
+        env = "RAD_AUTHOR",
+        value_name = "key_pair_name",
+        parse(try_from_str = lookup_signer)
+    )]
+    author: Arc<dyn Signer>,
+
+    /// Nonce of the author account. See `tx params`.
+    #[structopt(long)]
+    nonce: u32,
+
+    /// Genesis hash of the chain the transaction is valid for. See `tx params`.
+    #[structopt(long, value_name = "hash")]
+    genesis_hash: Hash,
+
+    /// Transaction version of the chain runtime. See `tx params`.
+    #[structopt(long, value_name = "version")]
+    tx_version: u32,
+
+    /// Fee that will be charged to submit the transaction.
+    #[structopt(long, env = "RAD_FEE", value_name = "fee")]
+    fee: Balance,
+
+    #[structopt(subcommand)]
+    message: SignMessage,
+}
+
+/// Messages that can be signed offline.
+#[derive(StructOpt, Clone)]
+enum SignMessage {
+    /// Transfer funds from the author to a recipient account.
+    Transfer {
+        // The amount to transfer.
+        amount: Balance,
+
+        /// The recipient account.
+        /// SS58 address or name of a local key pair.
+        #[structopt(parse(try_from_str = parse_account_id))]
+        recipient: AccountId,
+    },
+
+    /// Transfer funds from an org to a recipient.
+    TransferFromOrg {
+        /// Id of the org.
+        #[structopt(value_name = "org")]
+        org_id: Id,
+
+        // The amount to transfer from the org to the recipient.
+        amount: Balance,
+
+        /// The recipient account.
+        /// SS58 address or name of a local key pair.
+        #[structopt(parse(try_from_str = parse_account_id))]
+        recipient: AccountId,
+
+        /// Allow the transfer to drain the org account below the existential deposit.
+        #[structopt(long)]
+        allow_death: bool,
+    },
+}
+
+#[async_trait::async_trait]
+impl CommandT for Sign {
+    async fn run(self) -> Result<(), CommandError> {
+        let extra = TransactionExtra {
+            nonce: self.nonce,
+            genesis_hash: self.genesis_hash,
+            fee: self.fee,
+            runtime_transaction_version: self.tx_version,
+        };
+        let (message_kind, extrinsic) = match self.message {
+            SignMessage::Transfer { amount, recipient } => {
+                let message = message::Transfer { recipient, amount };
+                let transaction = Transaction::new_signed(&*self.author, message, extra).await?;
+                (MessageKind::Transfer, transaction.encode())
+            }
+            SignMessage::TransferFromOrg {
+                org_id,
+                amount,
+                recipient,
+                allow_death,
+            } => {
+                let message = message::TransferFromOrg {
+                    org_id,
+                    recipient,
+                    amount,
+                    allow_death,
+                };
+                let transaction = Transaction::new_signed(&*self.author, message, extra).await?;
+                (MessageKind::TransferFromOrg, transaction.encode())
+            }
+        };
+
+        let tx_file = TxFile {
+            message: message_kind,
+            extrinsic: hex::encode(extrinsic),
+        };
+        let content = serde_json::to_string_pretty(&tx_file).expect("TxFile is serializable");
+        std::fs::write(&self.output, content).map_err(|error| CommandError::InvalidTxFile {
+            path: self.output.clone(),
+            reason: error.to_string(),
+        })?;
+        println!("✓ Signed transaction written to {}", self.output.display());
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct Submit {
+    /// File written by `tx sign`.
+    #[structopt(value_name = "file")]
+    path: PathBuf,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Submit {
+    async fn run(self) -> Result<(), CommandError> {
+        let invalid_tx_file = |reason: String| CommandError::InvalidTxFile {
+            path: self.path.clone(),
+            reason,
+        };
+        let content = std::fs::read_to_string(&self.path)
+            .map_err(|error| invalid_tx_file(error.to_string()))?;
+        let tx_file: TxFile =
+            serde_json::from_str(&content).map_err(|error| invalid_tx_file(error.to_string()))?;
+        let extrinsic =
+            hex::decode(&tx_file.extrinsic).map_err(|error| invalid_tx_file(error.to_string()))?;
+
+        let client = self.network_options.client().await?;
+        let tx_included_fut = match tx_file.message {
+            MessageKind::Transfer => {
+                let transaction = Transaction::<message::Transfer>::decode(&extrinsic)
+                    .map_err(|error| invalid_tx_file(error.to_string()))?;
+                client.submit_transaction(transaction).await?
+            }
+            MessageKind::TransferFromOrg => {
+                let transaction = Transaction::<message::TransferFromOrg>::decode(&extrinsic)
+                    .map_err(|error| invalid_tx_file(error.to_string()))?;
+                client.submit_transaction(transaction).await?
+            }
+        };
+        announce_tx("Submitting transaction...");
+
+        let tx_included = tx_included_fut.await?;
+        tx_included.result?;
+        println!(
+            "✓ Transaction {} included in block {}",
+            tx_included.tx_hash, tx_included.block
+        );
+        Ok(())
+    }
+}
+
+/// Content of the file written by `tx sign`.
+#[derive(Serialize, Deserialize)]
+struct TxFile {
+    /// Message of the transaction. Determines how the transaction result is extracted.
+    message: MessageKind,
+
+    /// Hex encoded signed extrinsic. See [Transaction::encode].
+    extrinsic: String,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum MessageKind {
+    Transfer,
+    TransferFromOrg,
+}
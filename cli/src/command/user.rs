@@ -28,6 +28,12 @@ pub enum Command {
     Show(Show),
     /// List all users in the registry.
     List(List),
+    /// Set the metadata of a user.
+    /// The author needs to be associated with the user.
+    Update(Update),
+    /// Associate the user of the author with another account.
+    /// Use this to rotate the key that controls the user.
+    TransferId(TransferId),
 }
 
 #[async_trait::async_trait]
@@ -38,6 +44,8 @@ impl CommandT for Command {
             user::Command::Unregister(cmd) => cmd.run().await,
             user::Command::Show(cmd) => cmd.run().await,
             user::Command::List(cmd) => cmd.run().await,
+            user::Command::Update(cmd) => cmd.run().await,
+            user::Command::TransferId(cmd) => cmd.run().await,
         }
     }
 }
@@ -64,8 +72,9 @@ impl CommandT for Register {
                 &self.tx_options.author,
                 message::RegisterUser {
                     user_id: self.user_id.clone(),
+                    idempotency_key: None,
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Registering user...");
@@ -98,7 +107,7 @@ impl CommandT for Unregister {
                 message::UnregisterUser {
                     user_id: self.user_id.clone(),
                 },
-                self.tx_options.fee,
+                self.tx_options.fee(&client).await?,
             )
             .await?;
         announce_tx("Unregistering user...");
@@ -135,6 +144,8 @@ impl CommandT for Show {
         println!("account id: {}", user.account_id());
         println!("balance: {} μRAD", balance);
         println!("projects: [{}]", user.projects().iter().format(", "));
+        print_registered_at(user.registered_at());
+        print_metadata(user.metadata());
         Ok(())
     }
 }
@@ -157,3 +168,82 @@ impl CommandT for List {
         Ok(())
     }
 }
+
+#[derive(StructOpt, Clone)]
+pub struct Update {
+    /// Id of the user to update.
+    user_id: Id,
+
+    /// The new metadata of the user, for example a display name, an avatar URL or the hash of
+    /// a profile. At most 128 bytes.
+    #[structopt(parse(try_from_str = parse_metadata))]
+    metadata: Bytes128,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Update {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let update_user_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::UpdateUser {
+                    user_id: self.user_id.clone(),
+                    metadata: self.metadata,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Updating user...");
+
+        update_user_fut.await?.result?;
+        println!("✓ User {} updated.", self.user_id);
+        Ok(())
+    }
+}
+
+#[derive(StructOpt, Clone)]
+pub struct TransferId {
+    /// The account that controls the user after the transfer. SS58 address or name of a local
+    /// key pair. Funds are not moved to the new account.
+    #[structopt(parse(try_from_str = parse_account_id))]
+    new_account_id: AccountId,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+
+    #[structopt(flatten)]
+    tx_options: TxOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for TransferId {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+
+        let transfer_fut = client
+            .sign_and_submit_message(
+                &self.tx_options.author,
+                message::TransferUserId {
+                    new_account_id: self.new_account_id,
+                },
+                self.tx_options.fee(&client).await?,
+            )
+            .await?;
+        announce_tx("Transferring user...");
+
+        transfer_fut.await?.result?;
+        println!(
+            "✓ User is now controlled by account {}.",
+            self.new_account_id
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Define the `watch` command that prints registry events of finalized blocks.
+
+use super::*;
+use futures::TryStreamExt as _;
+use sp_runtime::traits::Header as _;
+use std::str::FromStr;
+
+/// Print the registry events of new blocks as they are finalized.
+///
+/// If `--org` or `--project` is given only the events concerning the org or the project are
+/// printed.
+#[derive(StructOpt, Clone)]
+pub struct Watch {
+    /// Only print events concerning the org with this id.
+    #[structopt(long, value_name = "id")]
+    org: Option<Id>,
+
+    /// Only print events concerning this project. The project is given as `<name>.<domain_id>`
+    /// where the domain id is the id of the org or user the project is registered under.
+    #[structopt(long, value_name = "name.domain")]
+    project: Option<ProjectFilter>,
+
+    #[structopt(flatten)]
+    network_options: NetworkOptions,
+}
+
+#[async_trait::async_trait]
+impl CommandT for Watch {
+    async fn run(self) -> Result<(), CommandError> {
+        let client = self.network_options.client().await?;
+        let mut finalized_blocks = client.subscribe_finalized_blocks().await?;
+        println!("Watching registry events of finalized blocks...");
+        while let Some(header) = finalized_blocks.try_next().await? {
+            let records = client.block_event_records(header.hash()).await?;
+            for registry_event in records.iter().filter_map(event::registry_event) {
+                if self.is_selected(registry_event) {
+                    println!("#{} {}", header.number, describe(registry_event));
+                }
+            }
+        }
+        Err(Error::BlockSubscriptionTerminated.into())
+    }
+}
+
+impl Watch {
+    /// Return true if the event should be printed. Without filters all events are selected. With
+    /// filters an event is selected if it matches any of them.
+    fn is_selected(&self, registry_event: &event::Registry) -> bool {
+        if self.org.is_none() && self.project.is_none() {
+            return true;
+        }
+        let org_matches = self
+            .org
+            .as_ref()
+            .map_or(false, |org_id| concerns_org(registry_event, org_id));
+        let project_matches = self
+            .project
+            .as_ref()
+            .map_or(false, |project| project.matches(registry_event));
+        org_matches || project_matches
+    }
+}
+
+/// A project given as `<name>.<domain_id>` on the command line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectFilter {
+    name: ProjectName,
+    domain_id: Id,
+}
+
+impl FromStr for ProjectFilter {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parts = input.splitn(2, '.');
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(domain_id)) => Ok(ProjectFilter {
+                name: ProjectName::from_str(name).map_err(|error| error.to_string())?,
+                domain_id: Id::from_str(domain_id).map_err(|error| error.to_string())?,
+            }),
+            _ => Err(format!(
+                "\"{}\" is not of the form <name>.<domain_id>",
+                input
+            )),
+        }
+    }
+}
+
+impl ProjectFilter {
+    /// Return true if the event concerns the project. Project transfers concern both the previous
+    /// and the new owner as the domain. Renames concern both the previous and the new name.
+    fn matches(&self, registry_event: &event::Registry) -> bool {
+        match registry_event {
+            event::Registry::ProjectUnregistered(name, ProjectDomain::Org(domain_id))
+            | event::Registry::ProjectUnregistered(name, ProjectDomain::User(domain_id)) => {
+                *name == self.name && *domain_id == self.domain_id
+            }
+            event::Registry::ProjectTransferProposed(name, owner, recipient)
+            | event::Registry::ProjectTransferred(name, owner, recipient) => {
+                *name == self.name && (*owner == self.domain_id || *recipient == self.domain_id)
+            }
+            event::Registry::ProjectMoved(name, from, to) => {
+                *name == self.name
+                    && (domain_id(from) == &self.domain_id || domain_id(to) == &self.domain_id)
+            }
+            event::Registry::ProjectRenamed(old_name, new_name, domain) => {
+                (*old_name == self.name || *new_name == self.name)
+                    && domain_id(domain) == &self.domain_id
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Return true if the event concerns the org or a project registered under the org.
+fn concerns_org(registry_event: &event::Registry, org_id: &Id) -> bool {
+    match registry_event {
+        event::Registry::OrgDissolved(id, _, _) | event::Registry::OrgTransferred(id, _, _, _) => {
+            id == org_id
+        }
+        event::Registry::ProjectUnregistered(_, ProjectDomain::Org(id)) => id == org_id,
+        event::Registry::ProjectMoved(_, from, to) => {
+            *from == ProjectDomain::Org(org_id.clone()) || *to == ProjectDomain::Org(org_id.clone())
+        }
+        event::Registry::ProjectRenamed(_, _, ProjectDomain::Org(id)) => id == org_id,
+        event::Registry::ProjectNameReserved(_, ProjectDomain::Org(id)) => id == org_id,
+        event::Registry::OrgUpdated(id)
+        | event::Registry::OrgMembershipTransferred(id, _, _)
+        | event::Registry::OrgFrozen(id)
+        | event::Registry::OrgUnfrozen(id) => id == org_id,
+        _ => false,
+    }
+}
+
+/// The id of the org or user of a project domain.
+fn domain_id(domain: &ProjectDomain) -> &Id {
+    match domain {
+        ProjectDomain::Org(id) | ProjectDomain::User(id) => id,
+    }
+}
+
+/// Human readable description of a registry event.
+fn describe(registry_event: &event::Registry) -> String {
+    match registry_event {
+        event::Registry::OrgDissolved(org_id, recipient, amount) => format!(
+            "org {} dissolved, {} μRAD transferred to {}",
+            org_id,
+            amount,
+            recipient.to_ss58check()
+        ),
+        event::Registry::BlockRewardCredited(account_id, amount) => format!(
+            "block reward of {} μRAD credited to {}",
+            amount,
+            account_id.to_ss58check()
+        ),
+        event::Registry::FeePaid(payer, amount) => {
+            format!("fee of {} μRAD paid by {}", amount, payer.to_ss58check())
+        }
+        event::Registry::TreasurySpendProposed(proposal_id, recipient, amount) => format!(
+            "treasury spend {} of {} μRAD to {} proposed",
+            proposal_id,
+            amount,
+            recipient.to_ss58check()
+        ),
+        event::Registry::TreasurySpendApproved(proposal_id, recipient, amount) => format!(
+            "treasury spend {} of {} μRAD to {} approved",
+            proposal_id,
+            amount,
+            recipient.to_ss58check()
+        ),
+        event::Registry::MigrationExecuted(from_version, to_version, items_migrated) => format!(
+            "storage migrated from version {} to {}, {} items migrated",
+            from_version, to_version, items_migrated
+        ),
+        event::Registry::ProjectUnregistered(project_name, project_domain) => {
+            format!("project {}.{:?} unregistered", project_name, project_domain)
+        }
+        event::Registry::OrgTransferred(org_id, recipient, amount, remaining_balance) => format!(
+            "{} μRAD transferred from org {} to {}, {} μRAD remaining",
+            amount,
+            org_id,
+            recipient.to_ss58check(),
+            remaining_balance
+        ),
+        event::Registry::IdReserved(id, holder) => {
+            format!("id {} reserved for {}", id, holder.to_ss58check())
+        }
+        event::Registry::IdReservationCancelled(id) => {
+            format!("reservation of id {} cancelled", id)
+        }
+        event::Registry::ProjectTransferProposed(project_name, owner, recipient) => format!(
+            "user {} offered project {} to user {}",
+            owner, project_name, recipient
+        ),
+        event::Registry::ProjectTransferred(project_name, previous_owner, new_owner) => format!(
+            "project {} transferred from user {} to user {}",
+            project_name, previous_owner, new_owner
+        ),
+        event::Registry::OrgUpdated(org_id) => format!("metadata of org {} updated", org_id),
+        event::Registry::UserUpdated(user_id) => format!("metadata of user {} updated", user_id),
+        event::Registry::UserIdTransferred(user_id, previous_account, new_account) => format!(
+            "user {} transferred from {} to {}",
+            user_id,
+            previous_account.to_ss58check(),
+            new_account.to_ss58check()
+        ),
+        event::Registry::OrgMembershipTransferred(org_id, previous_member, new_member) => format!(
+            "membership of user {} in org {} transferred to user {}",
+            previous_member, org_id, new_member
+        ),
+        event::Registry::ProjectMoved(project_name, from, to) => {
+            format!("project {} moved from {:?} to {:?}", project_name, from, to)
+        }
+        event::Registry::ProjectRenamed(old_name, new_name, domain) => format!(
+            "project {} renamed to {} in {:?}",
+            old_name, new_name, domain
+        ),
+        event::Registry::OrgFrozen(org_id) => format!("org {} frozen", org_id),
+        event::Registry::OrgUnfrozen(org_id) => format!("org {} unfrozen", org_id),
+        event::Registry::TreasurySpendRejected(proposal_id) => {
+            format!("treasury spend {} rejected", proposal_id)
+        }
+        event::Registry::TreasurySpendCancelled(proposal_id) => {
+            format!("treasury spend {} cancelled", proposal_id)
+        }
+        event::Registry::ProjectNameReserved(project_name, domain) => {
+            format!("project name {} reserved for {:?}", project_name, domain)
+        }
+        event::Registry::ProjectNameReservationCancelled(project_name) => {
+            format!("reservation of project name {} cancelled", project_name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn id(id: &str) -> Id {
+        Id::try_from(id).unwrap()
+    }
+
+    fn project_name(name: &str) -> ProjectName {
+        ProjectName::try_from(name).unwrap()
+    }
+
+    #[test]
+    fn parse_project_filter() {
+        assert_eq!(
+            ProjectFilter::from_str("radicle.monadic"),
+            Ok(ProjectFilter {
+                name: project_name("radicle"),
+                domain_id: id("monadic"),
+            })
+        );
+        assert!(ProjectFilter::from_str("radicle").is_err());
+        assert!(ProjectFilter::from_str("radicle.").is_err());
+    }
+
+    #[test]
+    fn filter_events() {
+        let watch = Watch {
+            org: Some(id("monadic")),
+            project: Some(ProjectFilter::from_str("radicle.alice").unwrap()),
+            network_options: NetworkOptions {
+                node_host: url::Host::Domain("localhost".to_string()),
+                node_port: DEFAULT_RPC_PORT,
+                node_tls: false,
+            },
+        };
+        let org_project_unregistered = event::Registry::ProjectUnregistered(
+            project_name("upstream"),
+            ProjectDomain::Org(id("monadic")),
+        );
+        assert!(watch.is_selected(&org_project_unregistered));
+        let project_transferred =
+            event::Registry::ProjectTransferred(project_name("radicle"), id("alice"), id("bob"));
+        assert!(watch.is_selected(&project_transferred));
+        let other_project_transferred =
+            event::Registry::ProjectTransferred(project_name("radicle"), id("carol"), id("bob"));
+        assert!(!watch.is_selected(&other_project_transferred));
+        let project_moved = event::Registry::ProjectMoved(
+            project_name("other"),
+            ProjectDomain::User(id("carol")),
+            ProjectDomain::Org(id("monadic")),
+        );
+        assert!(watch.is_selected(&project_moved));
+        let project_renamed = event::Registry::ProjectRenamed(
+            project_name("radicle"),
+            project_name("registry"),
+            ProjectDomain::User(id("alice")),
+        );
+        assert!(watch.is_selected(&project_renamed));
+        assert!(!watch.is_selected(&event::Registry::IdReservationCancelled(id("monadic"))));
+    }
+}
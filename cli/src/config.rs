@@ -0,0 +1,163 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Optional configuration file for the CLI.
+//!
+//! The file is read from `radicle-registry/config.toml` in the user configuration directory,
+//! for example `~/.config/radicle-registry/config.toml` on Linux.
+//!
+//! ```toml
+//! node-host = "127.0.0.1"
+//! author = "alice"
+//! fee = 10
+//! ```
+//!
+//! Options given as command line flags take precedence over environment variables, which take
+//! precedence over the configuration file. We implement this by exporting the values from the
+//! file as the environment variables the options read if these variables are not already set.
+
+use directories::BaseDirs;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use thiserror::Error as ThisError;
+
+use radicle_registry_client::Balance;
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    /// Default for `--node-host` and `RAD_NODE_HOST`.
+    pub node_host: Option<String>,
+
+    /// Default for `--author` and `RAD_AUTHOR`.
+    pub author: Option<String>,
+
+    /// Default for `--fee` and `RAD_FEE`.
+    pub fee: Option<Balance>,
+}
+
+#[derive(Debug, ThisError)]
+pub enum Error {
+    /// Cannot read the configuration file
+    #[error("Cannot read configuration file '{1}'")]
+    Read(#[source] std::io::Error, PathBuf),
+
+    /// Cannot parse the configuration file
+    #[error("Cannot parse configuration file '{1}'")]
+    Parse(#[source] toml::de::Error, PathBuf),
+}
+
+impl Config {
+    /// Read the configuration file at [path]. Returns the empty configuration if the file does
+    /// not exist.
+    pub fn load() -> Result<Self, Error> {
+        Self::load_from(&path())
+    }
+
+    fn load_from(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content, path),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(Error::Read(error, path.to_path_buf())),
+        }
+    }
+
+    fn parse(content: &str, path: &Path) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|error| Error::Parse(error, path.to_path_buf()))
+    }
+
+    /// Set the environment variables read by the CLI options to the configured values unless
+    /// the variables are already set.
+    ///
+    /// Must be called before the command line is parsed.
+    pub fn export_to_env(&self) {
+        for (name, value) in self.env_vars() {
+            if std::env::var_os(name).is_none() {
+                std::env::set_var(name, value);
+            }
+        }
+    }
+
+    /// Environment variables for all configured values.
+    fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut vars = Vec::new();
+        if let Some(node_host) = &self.node_host {
+            vars.push(("RAD_NODE_HOST", node_host.clone()));
+        }
+        if let Some(author) = &self.author {
+            vars.push(("RAD_AUTHOR", author.clone()));
+        }
+        if let Some(fee) = self.fee {
+            vars.push(("RAD_FEE", fee.to_string()));
+        }
+        vars
+    }
+}
+
+/// Location of the configuration file.
+pub fn path() -> PathBuf {
+    BaseDirs::new()
+        .unwrap()
+        .config_dir()
+        .join("radicle-registry")
+        .join("config.toml")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let content = r#"
+            node-host = "127.0.0.1"
+            author = "alice"
+            fee = 10
+        "#;
+        let config = Config::parse(content, Path::new("config.toml")).unwrap();
+        assert_eq!(
+            config.env_vars(),
+            vec![
+                ("RAD_NODE_HOST", "127.0.0.1".to_string()),
+                ("RAD_AUTHOR", "alice".to_string()),
+                ("RAD_FEE", "10".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_partial_config() {
+        let config = Config::parse("author = \"alice\"", Path::new("config.toml")).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                author: Some("alice".to_string()),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unknown_field() {
+        let result = Config::parse("node = \"127.0.0.1\"", Path::new("config.toml"));
+        assert!(matches!(result, Err(Error::Parse(..))));
+    }
+
+    #[test]
+    fn missing_config_file() {
+        let config = Config::load_from(Path::new("/does/not/exist/config.toml")).unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
@@ -15,8 +15,13 @@
 
 //! Manages key pairs stored in the filesystem,
 //! providing ways to store and retrieve them.
+//!
+//! Seeds are stored encrypted with a password, see [encryption]. Files written by previous
+//! versions contain plain seeds. These are encrypted with the first password that is given to
+//! [add], [get], [export] or [import].
 
 use directories::BaseDirs;
+use radicle_registry_client::{ed25519, CryptoPair as _};
 use sp_core::serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
@@ -26,6 +31,10 @@ use lazy_static::lazy_static;
 use std::io::Error as IOError;
 use std::path::{Path, PathBuf};
 
+mod encryption;
+
+pub use encryption::{EncryptedSeed, KdfParams};
+
 lazy_static! {
     /// The file where the key pairs are stored.
     static ref FILE: PathBuf = build_path("key-pairs.json");
@@ -50,19 +59,53 @@ enum VersionedFile {
     V1 {
         key_pairs: HashMap<String, KeyPairData>,
     },
+
+    /// Seeds are encrypted with a password.
+    #[serde(rename = "2")]
+    V2 {
+        key_pairs: HashMap<String, EncryptedKeyPair>,
+    },
 }
 
-/// The data that is stored in the filesystem relative
-/// to a key pair. The name of the key pair is used as
-/// the key to this value, therefore not included here.
+/// The data that previous versions stored in the filesystem
+/// relative to a key pair. The name of the key pair is used
+/// as the key to this value, therefore not included here.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct KeyPairData {
     pub seed: Seed,
 }
 
+/// A key pair with an encrypted seed as it is stored in the
+/// filesystem and written by `key-pair export`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EncryptedKeyPair {
+    /// The public key is not encrypted so that key pairs can be
+    /// listed and used as recipients without a password.
+    pub public: ed25519::Public,
+    pub seed: EncryptedSeed,
+}
+
+/// A key pair read from the storage.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoredKeyPair {
+    Encrypted(EncryptedKeyPair),
+
+    /// A key pair stored by a previous version with a plain seed.
+    Plain(KeyPairData),
+}
+
+impl StoredKeyPair {
+    pub fn public(&self) -> ed25519::Public {
+        match self {
+            StoredKeyPair::Encrypted(key_pair) => key_pair.public,
+            StoredKeyPair::Plain(data) => ed25519::Pair::from_seed(&data.seed).public(),
+        }
+    }
+}
+
 /// The seed from which a key pair
 /// can be deterministically generated.
-type Seed = [u8; 32];
+pub type Seed = [u8; 32];
 
 #[derive(Debug, ThisError)]
 pub enum Error {
@@ -89,6 +132,18 @@ pub enum Error {
     /// Could not find a key pair with the given name
     #[error("Could not find a key pair with the given name")]
     NotFound(),
+
+    /// The password does not decrypt the key pair
+    #[error("Wrong password for the key pair")]
+    WrongPassword(),
+
+    /// The public key of an imported key pair does not match its seed
+    #[error("The public key does not match the seed of the key pair")]
+    PublicKeyMismatch(),
+
+    /// Failed to derive the encryption key or to encrypt a seed
+    #[error("Failed to encrypt the key pair")]
+    Encryption(),
 }
 
 fn io_error_message(action: &str) -> String {
@@ -123,40 +178,123 @@ pub enum ReadingError {
 ///
 /// Preemptively [init()]s the storage on disk and checks permissions.
 /// It can fail from IO errors or Serde Json errors.
-pub fn list() -> Result<HashMap<String, KeyPairData>, Error> {
+pub fn list() -> Result<HashMap<String, StoredKeyPair>, Error> {
     use {KeyStorageFile::*, VersionedFile::*};
 
     init()?;
+    let plain = |key_pairs: HashMap<String, KeyPairData>| {
+        key_pairs
+            .into_iter()
+            .map(|(name, data)| (name, StoredKeyPair::Plain(data)))
+            .collect()
+    };
     match parse_file()? {
-        Unversioned(key_pairs) => Ok(key_pairs),
-        Versioned(V1 { key_pairs }) => Ok(key_pairs),
+        Unversioned(key_pairs) => Ok(plain(key_pairs)),
+        Versioned(V1 { key_pairs }) => Ok(plain(key_pairs)),
+        Versioned(V2 { key_pairs }) => Ok(key_pairs
+            .into_iter()
+            .map(|(name, key_pair)| (name, StoredKeyPair::Encrypted(key_pair)))
+            .collect()),
     }
 }
 
-/// Add a key pair to the storage.
+/// Get the public key of a key pair by name. Does not require
+/// the password.
+pub fn public(name: &str) -> Result<ed25519::Public, Error> {
+    list()?
+        .get(name)
+        .map(StoredKeyPair::public)
+        .ok_or(Error::NotFound())
+}
+
+/// Add a key pair with the given `seed` to the storage and
+/// encrypt it with `password`.
 ///
 /// Fails if a key pair with the given `name` already exists.
 /// It can also fail from IO and Serde Json errors.
-pub fn add(name: String, data: KeyPairData) -> Result<(), Error> {
-    let mut key_pairs = list()?;
+pub fn add(name: String, seed: Seed, password: &str) -> Result<(), Error> {
+    let encrypted = encryption::encrypt(&seed, password, encryption::DEFAULT_KDF_PARAMS)?;
+    let key_pair = EncryptedKeyPair {
+        public: ed25519::Pair::from_seed(&seed).public(),
+        seed: encrypted,
+    };
+    insert(name, key_pair, password)
+}
+
+/// Get a key pair by name and decrypt it with `password`.
+///
+/// It can fail from IO and Serde Json errors, if no such
+/// key pair is found or if the password is wrong.
+pub fn get(name: &str, password: &str) -> Result<ed25519::Pair, Error> {
+    let key_pair = unlock(password)?.remove(name).ok_or(Error::NotFound())?;
+    let seed = encryption::decrypt(&key_pair.seed, password)?;
+    Ok(ed25519::Pair::from_seed(&seed))
+}
+
+/// Get the encrypted key pair by name for exporting it.
+///
+/// Fails with [Error::WrongPassword] if `password` does not
+/// decrypt the key pair.
+pub fn export(name: &str, password: &str) -> Result<EncryptedKeyPair, Error> {
+    let key_pair = unlock(password)?.remove(name).ok_or(Error::NotFound())?;
+    encryption::decrypt(&key_pair.seed, password)?;
+    Ok(key_pair)
+}
+
+/// Add an exported key pair to the storage.
+///
+/// The key pair keeps its encryption. Fails if `password` does
+/// not decrypt the key pair, if its public key does not match
+/// the seed or if a key pair with the given `name` already exists.
+pub fn import(name: String, key_pair: EncryptedKeyPair, password: &str) -> Result<(), Error> {
+    let seed = encryption::decrypt(&key_pair.seed, password)?;
+    if ed25519::Pair::from_seed(&seed).public() != key_pair.public {
+        return Err(Error::PublicKeyMismatch());
+    }
+    insert(name, key_pair, password)
+}
+
+fn insert(name: String, key_pair: EncryptedKeyPair, password: &str) -> Result<(), Error> {
+    let mut key_pairs = unlock(password)?;
     if key_pairs.contains_key(&name) {
         return Err(Error::AlreadyExists());
     }
 
-    key_pairs.insert(name, data);
+    key_pairs.insert(name, key_pair);
     update(key_pairs)
 }
 
-/// Get a key pair by name.
-///
-/// It can fail from IO and Serde Json errors, or if no such
-/// key pair is found.
-pub fn get(name: &str) -> Result<KeyPairData, Error> {
-    list()?.get(name).map(Clone::clone).ok_or(Error::NotFound())
+/// Read all key pairs and encrypt the plain seeds stored by
+/// previous versions with `password`. The encrypted seeds are
+/// written to the storage right away.
+fn unlock(password: &str) -> Result<HashMap<String, EncryptedKeyPair>, Error> {
+    let mut migrated = false;
+    let mut key_pairs = HashMap::new();
+    for (name, key_pair) in list()? {
+        let key_pair = match key_pair {
+            StoredKeyPair::Encrypted(key_pair) => key_pair,
+            StoredKeyPair::Plain(data) => {
+                migrated = true;
+                EncryptedKeyPair {
+                    public: ed25519::Pair::from_seed(&data.seed).public(),
+                    seed: encryption::encrypt(
+                        &data.seed,
+                        password,
+                        encryption::DEFAULT_KDF_PARAMS,
+                    )?,
+                }
+            }
+        };
+        key_pairs.insert(name, key_pair);
+    }
+    if migrated {
+        update(key_pairs.clone())?;
+    }
+    Ok(key_pairs)
 }
 
-fn update(key_pairs: HashMap<String, KeyPairData>) -> Result<(), Error> {
-    let data = VersionedFile::V1 { key_pairs };
+fn update(key_pairs: HashMap<String, EncryptedKeyPair>) -> Result<(), Error> {
+    let data = VersionedFile::V2 { key_pairs };
     let new_content = serde_json::to_string_pretty(&data).map_err(WritingError::Serialization)?;
     std::fs::write(FILE.as_path(), new_content.as_bytes()).map_err(WritingError::IO)?;
     Ok(())
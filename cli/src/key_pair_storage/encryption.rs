@@ -0,0 +1,127 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Password based encryption of key pair seeds.
+//!
+//! The encryption key is derived from the password with scrypt. The seed is encrypted and
+//! authenticated with XSalsa20-Poly1305 so that decrypting with a wrong password fails.
+
+use serde::{Deserialize, Serialize};
+use xsalsa20poly1305::aead::{generic_array::GenericArray, Aead, NewAead};
+use xsalsa20poly1305::XSalsa20Poly1305;
+
+use super::{Error, Seed};
+
+/// scrypt parameters used for newly encrypted seeds.
+///
+/// These are the parameters recommended for interactive logins.
+pub const DEFAULT_KDF_PARAMS: KdfParams = KdfParams {
+    log_n: 15,
+    r: 8,
+    p: 1,
+};
+
+/// Parameters of the scrypt key derivation.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+/// A seed encrypted with a password together with everything but the password that is required
+/// to decrypt it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EncryptedSeed {
+    pub kdf: KdfParams,
+    pub salt: [u8; 32],
+    pub nonce: [u8; 24],
+    pub ciphertext: Vec<u8>,
+}
+
+/// Encrypt `seed` with a key derived from `password` with a random salt and nonce.
+pub fn encrypt(seed: &Seed, password: &str, kdf: KdfParams) -> Result<EncryptedSeed, Error> {
+    let salt: [u8; 32] = rand::random();
+    let nonce: [u8; 24] = rand::random();
+    let cipher = cipher(password, &salt, kdf)?;
+    let ciphertext = cipher
+        .encrypt(GenericArray::from_slice(&nonce), &seed[..])
+        .map_err(|_| Error::Encryption())?;
+    Ok(EncryptedSeed {
+        kdf,
+        salt,
+        nonce,
+        ciphertext,
+    })
+}
+
+/// Decrypt `encrypted` with `password`.
+///
+/// Fails with [Error::WrongPassword] if `password` is not the password the seed was encrypted
+/// with.
+pub fn decrypt(encrypted: &EncryptedSeed, password: &str) -> Result<Seed, Error> {
+    let cipher = cipher(password, &encrypted.salt, encrypted.kdf)?;
+    let plaintext = cipher
+        .decrypt(
+            GenericArray::from_slice(&encrypted.nonce),
+            &encrypted.ciphertext[..],
+        )
+        .map_err(|_| Error::WrongPassword())?;
+    let mut seed = Seed::default();
+    if plaintext.len() != seed.len() {
+        return Err(Error::WrongPassword());
+    }
+    seed.copy_from_slice(&plaintext);
+    Ok(seed)
+}
+
+fn cipher(password: &str, salt: &[u8], kdf: KdfParams) -> Result<XSalsa20Poly1305, Error> {
+    let params =
+        scrypt::ScryptParams::new(kdf.log_n, kdf.r, kdf.p).map_err(|_| Error::Encryption())?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| Error::Encryption())?;
+    Ok(XSalsa20Poly1305::new(GenericArray::from_slice(&key)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Cheap parameters to keep the tests fast.
+    const TEST_KDF_PARAMS: KdfParams = KdfParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn encrypt_decrypt() {
+        let seed: Seed = rand::random();
+        let encrypted = encrypt(&seed, "password", TEST_KDF_PARAMS).unwrap();
+        assert_ne!(&encrypted.ciphertext[..seed.len()], &seed[..]);
+        assert_eq!(decrypt(&encrypted, "password").unwrap(), seed);
+    }
+
+    #[test]
+    fn decrypt_wrong_password() {
+        let seed: Seed = rand::random();
+        let encrypted = encrypt(&seed, "password", TEST_KDF_PARAMS).unwrap();
+        match decrypt(&encrypted, "wrong password") {
+            Err(Error::WrongPassword()) => {}
+            result => panic!("Expected Error::WrongPassword, got {:?}", result),
+        }
+    }
+}
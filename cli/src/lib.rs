@@ -17,15 +17,18 @@
 
 #![allow(clippy::large_enum_variant)]
 
-use lazy_static::lazy_static;
 use radicle_registry_client::*;
+use std::sync::Arc;
 use structopt::StructOpt;
 use thiserror::Error as ThisError;
 
+pub mod config;
 pub mod key_pair_storage;
 
 mod command;
-use command::{account, key_pair, org, other, project, runtime, user};
+use command::{
+    account, id, key_pair, onboard, org, other, project, runtime, treasury, tx, user, watch,
+};
 
 /// The type that captures the command line.
 #[derive(StructOpt, Clone)]
@@ -55,6 +58,14 @@ pub struct NetworkOptions {
         parse(try_from_str = Self::parse_node_host),
     )]
     pub node_host: url::Host,
+
+    /// Port of the RPC API
+    #[structopt(long, default_value = "9944", env = "RAD_NODE_PORT")]
+    pub node_port: u16,
+
+    /// Connect to the RPC API with TLS
+    #[structopt(long)]
+    pub node_tls: bool,
 }
 
 impl NetworkOptions {
@@ -70,7 +81,12 @@ impl NetworkOptions {
     }
 
     pub async fn client(&self) -> Result<Client, Error> {
-        Client::create_with_executor(self.node_host.clone()).await
+        let config = ClientConfig {
+            port: self.node_port,
+            use_tls: self.node_tls,
+            ..ClientConfig::new(self.node_host.clone())
+        };
+        Client::create_with_executor(config).await
     }
 }
 
@@ -82,24 +98,67 @@ pub struct TxOptions {
         long,
         env = "RAD_AUTHOR",
         value_name = "key_pair_name",
-        parse(try_from_str = lookup_key_pair)
+        parse(try_from_str = lookup_signer)
     )]
-    pub author: ed25519::Pair,
+    pub author: Arc<dyn Signer>,
 
     /// Fee that will be charged to submit transactions.
     /// The higher the fee, the higher the priority of a transaction.
-    #[structopt(long, default_value = &FEE_DEFAULT, env = "RAD_FEE", value_name = "fee")]
-    pub fee: Balance,
+    /// Defaults to the minimum transaction fee of the chain.
+    #[structopt(long, env = "RAD_FEE", value_name = "fee")]
+    pub fee: Option<Balance>,
+}
+
+impl TxOptions {
+    /// The fee given by the user or the minimum transaction fee of the chain `client` is
+    /// connected to.
+    pub async fn fee(&self, client: &Client) -> Result<Balance, Error> {
+        match self.fee {
+            Some(fee) => Ok(fee),
+            None => client.minimum_tx_fee().await,
+        }
+    }
+}
+
+fn lookup_signer(name: &str) -> Result<Arc<dyn Signer>, String> {
+    let password = key_pair_password(name).map_err(|e| format!("{}", e))?;
+    let key_pair = key_pair_storage::get(name, &password).map_err(|e| format!("{}", e))?;
+    Ok(Arc::new(key_pair))
 }
 
-lazy_static! {
-    static ref FEE_DEFAULT: String = MINIMUM_TX_FEE.to_string();
+/// Environment variable that provides the password of key pairs
+/// instead of prompting for it.
+const KEY_PAIR_PASSWORD_ENV: &str = "RAD_KEY_PAIR_PASSWORD";
+
+/// Read the password of the key pair `name` from [KEY_PAIR_PASSWORD_ENV]
+/// or prompt for it.
+fn key_pair_password(name: &str) -> Result<String, std::io::Error> {
+    match std::env::var(KEY_PAIR_PASSWORD_ENV) {
+        Ok(password) => Ok(password),
+        Err(_) => {
+            rpassword::read_password_from_tty(Some(&format!("Password for key pair '{}': ", name)))
+        }
+    }
 }
 
-fn lookup_key_pair(name: &str) -> Result<ed25519::Pair, String> {
-    key_pair_storage::get(name)
-        .map(|data| ed25519::Pair::from_seed(&data.seed))
-        .map_err(|e| format!("{}", e))
+/// Read the password for the new key pair `name` from
+/// [KEY_PAIR_PASSWORD_ENV] or prompt for it twice until both
+/// entries match.
+fn new_key_pair_password(name: &str) -> Result<String, std::io::Error> {
+    if let Ok(password) = std::env::var(KEY_PAIR_PASSWORD_ENV) {
+        return Ok(password);
+    }
+    loop {
+        let password = rpassword::read_password_from_tty(Some(&format!(
+            "New password for key pair '{}': ",
+            name
+        )))?;
+        let confirmation = rpassword::read_password_from_tty(Some("Repeat the password: "))?;
+        if password == confirmation {
+            return Ok(password);
+        }
+        println!("! The passwords do not match");
+    }
 }
 
 /// The supported [CommandLine] commands.
@@ -107,12 +166,21 @@ fn lookup_key_pair(name: &str) -> Result<ed25519::Pair, String> {
 #[derive(StructOpt, Clone)]
 pub enum Command {
     Account(account::Command),
+    Id(id::Command),
     KeyPair(key_pair::Command),
     Org(org::Command),
     Project(project::Command),
     Runtime(runtime::Command),
+    Treasury(treasury::Command),
+    Tx(tx::Command),
     User(user::Command),
 
+    /// Create a key pair, wait for funds and register a user in one guided flow.
+    Onboard(onboard::Onboard),
+
+    /// Print registry events of new blocks as they are finalized.
+    Watch(watch::Watch),
+
     #[structopt(flatten)]
     Other(other::Command),
 }
@@ -122,11 +190,16 @@ impl CommandT for Command {
     async fn run(self) -> Result<(), CommandError> {
         match self.clone() {
             Command::Account(cmd) => cmd.run().await,
+            Command::Id(cmd) => cmd.run().await,
             Command::KeyPair(cmd) => cmd.run().await,
             Command::Org(cmd) => cmd.run().await,
             Command::Project(cmd) => cmd.run().await,
             Command::User(cmd) => cmd.run().await,
             Command::Runtime(cmd) => cmd.run().await,
+            Command::Treasury(cmd) => cmd.run().await,
+            Command::Tx(cmd) => cmd.run().await,
+            Command::Onboard(cmd) => cmd.run().await,
+            Command::Watch(cmd) => cmd.run().await,
             Command::Other(cmd) => cmd.run().await,
         }
     }
@@ -163,4 +236,25 @@ pub enum CommandError {
 
     #[error(transparent)]
     KeyPairStorageError(#[from] key_pair_storage::Error),
+
+    #[error("the id {id} {reason}")]
+    IdUnavailable { id: Id, reason: &'static str },
+
+    #[error("failed to read input")]
+    Input(#[source] std::io::Error),
+
+    #[error("invalid transaction file {path:?}: {reason}")]
+    InvalidTxFile {
+        path: std::path::PathBuf,
+        reason: String,
+    },
+
+    #[error("cannot upgrade the runtime: {reason}")]
+    RuntimeUpgrade { reason: String },
+
+    #[error("invalid key pair file {path:?}: {reason}")]
+    InvalidKeyPairFile {
+        path: std::path::PathBuf,
+        reason: String,
+    },
 }
@@ -15,13 +15,20 @@
 
 //! The executable entry point for the Radicle Registry CLI.
 
-use radicle_registry_cli::CommandLine;
+use radicle_registry_cli::{config::Config, CommandLine};
 use std::error::Error;
 use structopt::StructOpt;
 
 #[async_std::main]
 async fn main() {
     pretty_env_logger::init();
+    match Config::load() {
+        Ok(config) => config.export_to_env(),
+        Err(error) => {
+            print_error(&error);
+            std::process::exit(1);
+        }
+    }
     let cmd_line = CommandLine::from_args();
     let result = cmd_line.run().await;
 
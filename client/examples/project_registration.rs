@@ -37,6 +37,7 @@ async fn main() -> Result<(), Error> {
                 project_name: project_name.clone(),
                 project_domain: ProjectDomain::Org(org_id.clone()),
                 metadata: Bytes128::random(),
+                idempotency_key: None,
             },
             567,
         )
@@ -15,16 +15,21 @@
 
 //! Provides [Emulator] backend to run the registry ledger in memory.
 
+use futures::channel::mpsc;
 use futures::future::BoxFuture;
-use std::collections::HashMap;
+use futures::stream::{BoxStream, StreamExt as _};
+use parity_scale_codec::Encode as _;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
-use sp_runtime::{traits::Block as _, traits::Hash as _, BuildStorage as _, Digest};
+use frame_support::storage::StorageMap as _;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_runtime::{traits::Block as _, traits::Hash as _, BuildStorage as _, Digest, DigestItem};
 use sp_state_machine::backend::Backend as _;
 
 use radicle_registry_runtime::{
     event,
-    genesis::{BalancesConfig, GenesisConfig},
+    genesis::{BalancesConfig, GenesisConfig, SudoConfig},
     registry, runtime_api, AccountId, Block, Hash, Hashing, Header, Runtime, RuntimeVersion,
 };
 
@@ -41,9 +46,17 @@ use crate::interface::*;
 /// * Every [backend::Backend::submit] call creates a new block that only contains the submited
 ///   transaction.
 ///
-/// * The responses returned from the client never result in an [Error].
+/// * The responses returned from the client never result in an [Error] unless state is fetched at
+///   an unknown block.
 ///
 /// * The block author is fixed to [BLOCK_AUTHOR].
+///
+/// * Every block is final as soon as it has been added. The emulator keeps a snapshot of the state
+///   for every block so that state can be fetched at any block.
+///
+/// * [EmulatorControl::reorg] may replace finalized blocks, which a real chain never does. The
+///   finalized head moves back to the common ancestor together with the tip and the new blocks
+///   are finalized as they are added.
 #[derive(Clone)]
 pub struct Emulator {
     genesis_hash: Hash,
@@ -78,6 +91,77 @@ impl EmulatorControl {
             self.0.add_block(vec![]);
         }
     }
+
+    /// Replace the last `depth` blocks of the chain with `new_blocks` empty blocks.
+    ///
+    /// The state is rolled back to the common ancestor and the new blocks are built on top of it.
+    /// The new blocks are always different from the replaced blocks. Subscribers of
+    /// [EmulatorControl::block_notifications] first receive [BlockNotification::Retracted] for
+    /// every replaced block starting at the old tip and then [BlockNotification::Imported] for
+    /// every new block.
+    ///
+    /// Since every block of the emulator is final the replaced blocks were finalized. The reorg
+    /// moves the finalized head back with the tip, so transactions that
+    /// [crate::TransactionIncluded::wait_finalized] reported as final may be reverted and
+    /// [crate::Client::subscribe_finalized_blocks] does not emit the new blocks with the numbers
+    /// of replaced blocks. Use reorgs only to test code that handles blocks of the best chain.
+    ///
+    /// Panics if `depth` reaches beyond the first block of the chain.
+    ///
+    /// ```
+    /// # #[async_std::main]
+    /// # async fn main () {
+    /// # use radicle_registry_client::{Client, ClientT};
+    /// let (client, emulator) = Client::new_emulator();
+    /// emulator.add_blocks(3);
+    /// let old_tip = client.block_header_best_chain().await.unwrap();
+    /// emulator.reorg(2, 3);
+    /// let new_tip = client.block_header_best_chain().await.unwrap();
+    /// assert_eq!(new_tip.number, old_tip.number + 1);
+    /// # }
+    /// ```
+    pub fn reorg(&self, depth: u32, new_blocks: u32) {
+        self.0.rollback(depth);
+        self.add_blocks(new_blocks);
+    }
+
+    /// Return a stream of [BlockNotification]s for all blocks that are added to or removed from
+    /// the chain after this call.
+    pub fn block_notifications(&self) -> mpsc::UnboundedReceiver<BlockNotification> {
+        self.0.block_notifications()
+    }
+
+    /// Run `f` with access to the runtime storage of the chain tip and return its result.
+    ///
+    /// Storage changes are applied to the state of the tip without adding a block. Use this to set
+    /// up state that cannot be created with transactions, for example storage in the format of an
+    /// older runtime to test migrations.
+    pub fn execute_with<R>(&self, f: impl FnOnce() -> R) -> R {
+        let mut state = self.0.state.lock().unwrap();
+        let result = state.test_ext.execute_with(f);
+        let snapshot = state.snapshot();
+        let tip_hash = state.tip_header.hash();
+        state.snapshots.insert(tip_hash, snapshot);
+        result
+    }
+
+    /// Enact a runtime upgrade: run the `on_runtime_upgrade` hook of the registry module, which
+    /// executes all pending storage migrations, and add a block.
+    ///
+    /// The added block contains the [registry::Event::MigrationExecuted] events.
+    pub fn upgrade_runtime(&self) {
+        self.execute_with(<registry::Module<Runtime> as OnRuntimeUpgrade>::on_runtime_upgrade);
+        self.add_blocks(1);
+    }
+}
+
+/// Change of the emulated chain. See [EmulatorControl::block_notifications].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockNotification {
+    /// The block was added to the tip of the chain.
+    Imported(Header),
+    /// The block was removed from the chain by [EmulatorControl::reorg].
+    Retracted(Header),
 }
 
 /// Mutable state of the emulator.
@@ -85,6 +169,37 @@ struct EmulatorState {
     test_ext: sp_io::TestExternalities,
     tip_header: Header,
     headers: HashMap<BlockHash, Header>,
+    /// Extrinsics of every block except the genesis block, which has no extrinsics.
+    extrinsics: HashMap<BlockHash, Vec<backend::UncheckedExtrinsic>>,
+    /// Storage snapshot of the state after every block.
+    snapshots: HashMap<BlockHash, StateSnapshot>,
+    /// Number of reorgs. Included in the header digest of new blocks so that blocks of different
+    /// forks have different hashes.
+    forks: u32,
+    subscribers: Vec<mpsc::UnboundedSender<BlockNotification>>,
+}
+
+/// Top-level storage key-value pairs of the runtime state.
+type StateSnapshot = BTreeMap<Vec<u8>, Vec<u8>>;
+
+impl EmulatorState {
+    /// Snapshot of the current state.
+    fn snapshot(&self) -> StateSnapshot {
+        self.test_ext.commit_all().pairs().into_iter().collect()
+    }
+
+    /// Return the state snapshot at `block_hash`. Fails if the block is not known.
+    fn snapshot_at(&self, block_hash: BlockHash) -> Result<&StateSnapshot, Error> {
+        self.snapshots
+            .get(&block_hash)
+            .ok_or_else(|| Error::BlockMissing { block_hash })
+    }
+
+    /// Send the notification to all subscribers and drop subscribers that are gone.
+    fn notify(&mut self, notification: BlockNotification) {
+        self.subscribers
+            .retain(|subscriber| subscriber.unbounded_send(notification.clone()).is_ok());
+    }
 }
 
 /// Block author account used when the emulator creates blocks.
@@ -120,14 +235,22 @@ impl Emulator {
         let mut headers = HashMap::new();
         headers.insert(tip_header.hash(), tip_header.clone());
 
+        let mut state = EmulatorState {
+            test_ext,
+            tip_header,
+            headers,
+            extrinsics: HashMap::new(),
+            snapshots: HashMap::new(),
+            forks: 0,
+            subscribers: Vec::new(),
+        };
+        let snapshot = state.snapshot();
+        state.snapshots.insert(state.tip_header.hash(), snapshot);
+
         Emulator {
             genesis_hash,
             inherent_data_providers,
-            state: Arc::new(Mutex::new(EmulatorState {
-                test_ext,
-                tip_header,
-                headers,
-            })),
+            state: Arc::new(Mutex::new(state)),
         }
     }
 
@@ -135,6 +258,12 @@ impl Emulator {
         EmulatorControl(self.clone())
     }
 
+    fn block_notifications(&self) -> mpsc::UnboundedReceiver<BlockNotification> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.state.lock().unwrap().subscribers.push(sender);
+        receiver
+    }
+
     /// Add a block with `extrinsics` to the chain. Returns the added block and a list of events
     /// recorded during the execution of the block.
     fn add_block(
@@ -143,9 +272,17 @@ impl Emulator {
     ) -> (Block, Vec<event::Record>) {
         let mut state = self.state.lock().unwrap();
 
+        let digest = if state.forks == 0 {
+            Digest::default()
+        } else {
+            Digest {
+                logs: vec![DigestItem::Other(state.forks.encode())],
+            }
+        };
         let new_tip_header_init = Header {
             parent_hash: state.tip_header.hash(),
             number: state.tip_header.number + 1,
+            digest,
             ..state.tip_header.clone()
         };
 
@@ -168,9 +305,53 @@ impl Emulator {
 
         state.tip_header = block.header.clone();
         state.headers.insert(block.hash(), block.header.clone());
+        state
+            .extrinsics
+            .insert(block.hash(), block.extrinsics.clone());
+        let snapshot = state.snapshot();
+        state.snapshots.insert(block.hash(), snapshot);
+        state.notify(BlockNotification::Imported(block.header.clone()));
 
         (block, event_records)
     }
+
+    /// Number of blocks between the tip and its ancestor `block_hash`. Returns `None` if the block
+    /// is not part of the current chain.
+    fn depth_of(&self, block_hash: BlockHash) -> Option<u32> {
+        let state = self.state.lock().unwrap();
+        let mut header = &state.tip_header;
+        let mut depth = 0;
+        while header.hash() != block_hash {
+            header = state.headers.get(&header.parent_hash)?;
+            depth += 1;
+        }
+        Some(depth)
+    }
+
+    /// Remove the last `depth` blocks from the chain and restore the state of the new tip.
+    ///
+    /// The headers and state snapshots of the removed blocks are kept so that they can still be
+    /// queried by hash.
+    fn rollback(&self, depth: u32) {
+        let mut state = self.state.lock().unwrap();
+        for _ in 0..depth {
+            let retracted = state.tip_header.clone();
+            let parent = state
+                .headers
+                .get(&retracted.parent_hash)
+                .cloned()
+                .expect("Cannot roll back beyond the first block");
+            state.tip_header = parent;
+            state.notify(BlockNotification::Retracted(retracted));
+        }
+
+        let storage = sp_core::storage::Storage {
+            top: state.snapshot_at(state.tip_header.hash()).unwrap().clone(),
+            children_default: Default::default(),
+        };
+        state.test_ext = sp_io::TestExternalities::new(storage);
+        state.forks += 1;
+    }
 }
 
 #[async_trait::async_trait]
@@ -192,6 +373,7 @@ impl backend::Backend for Emulator {
                 tx_hash,
                 block: block.hash(),
                 events,
+                finalized: Box::pin(futures::future::ready(Ok(block.hash()))),
             },
         ))))
     }
@@ -201,13 +383,35 @@ impl backend::Backend for Emulator {
         key: &[u8],
         block_hash: Option<BlockHash>,
     ) -> Result<Option<Vec<u8>>, Error> {
-        if block_hash.is_some() {
-            panic!("Passing a block hash 'fetch' for the client emulator is not supported")
+        let mut state = self.state.lock().unwrap();
+        match block_hash {
+            Some(block_hash) => Ok(state.snapshot_at(block_hash)?.get(key).cloned()),
+            None => Ok(state.test_ext.execute_with(|| sp_io::storage::get(key))),
         }
+    }
 
-        let mut state = self.state.lock().unwrap();
-        let maybe_data = state.test_ext.execute_with(|| sp_io::storage::get(key));
-        Ok(maybe_data)
+    async fn read_proof(&self, key: &[u8], block_hash: BlockHash) -> Result<Vec<Vec<u8>>, Error> {
+        let state = self.state.lock().unwrap();
+        let storage = sp_core::storage::Storage {
+            top: state.snapshot_at(block_hash)?.clone(),
+            children_default: Default::default(),
+        };
+        let trie_backend = sp_state_machine::InMemoryBackend::<Hashing>::from(storage);
+        let proof = sp_state_machine::prove_read(trie_backend, std::iter::once(key))
+            .expect("Reading from the in-memory backend cannot fail");
+        Ok(proof.iter_nodes().collect())
+    }
+
+    async fn fetch_many(
+        &self,
+        keys: &[Vec<u8>],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let mut values = Vec::with_capacity(keys.len());
+        for key in keys {
+            values.push(self.fetch(key, block_hash).await?);
+        }
+        Ok(values)
     }
 
     async fn fetch_keys(
@@ -215,11 +419,18 @@ impl backend::Backend for Emulator {
         prefix: &[u8],
         block_hash: Option<BlockHash>,
     ) -> Result<Vec<Vec<u8>>, Error> {
-        if block_hash.is_some() {
-            panic!("Passing a block hash 'fetch_keys' for the client emulator is not supported")
+        let state = self.state.lock().unwrap();
+        if let Some(block_hash) = block_hash {
+            let keys = state
+                .snapshot_at(block_hash)?
+                .range(prefix.to_vec()..)
+                .map(|(key, _)| key)
+                .take_while(|key| key.starts_with(prefix))
+                .cloned()
+                .collect();
+            return Ok(keys);
         }
 
-        let state = self.state.lock().unwrap();
         let backend = state.test_ext.commit_all();
 
         let mut keys = Vec::new();
@@ -227,6 +438,22 @@ impl backend::Backend for Emulator {
         Ok(keys)
     }
 
+    async fn fetch_keys_paged(
+        &self,
+        prefix: &[u8],
+        start_key: Option<&[u8]>,
+        limit: u32,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let mut keys = self.fetch_keys(prefix, block_hash).await?;
+        keys.sort();
+        Ok(keys
+            .into_iter()
+            .filter(|key| start_key.map_or(true, |start_key| key.as_slice() > start_key))
+            .take(limit as usize)
+            .collect())
+    }
+
     async fn block_header(
         &self,
         block_hash_opt: Option<BlockHash>,
@@ -239,6 +466,45 @@ impl backend::Backend for Emulator {
         Ok(state.headers.get(&block_hash).cloned())
     }
 
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error> {
+        let state = self.state.lock().unwrap();
+        Ok(state.headers.get(&block_hash).map(|header| Block {
+            header: header.clone(),
+            extrinsics: state
+                .extrinsics
+                .get(&block_hash)
+                .cloned()
+                .unwrap_or_default(),
+        }))
+    }
+
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<Header, Error>>, Error> {
+        let new_heads = self
+            .block_notifications()
+            .filter_map(|notification| {
+                futures::future::ready(match notification {
+                    BlockNotification::Imported(header) => Some(Ok(header)),
+                    BlockNotification::Retracted(_) => None,
+                })
+            })
+            .boxed();
+        Ok(new_heads)
+    }
+
+    async fn subscribe_storage(
+        &self,
+        key: Vec<u8>,
+    ) -> Result<BoxStream<'static, Result<Option<Vec<u8>>, Error>>, Error> {
+        let new_heads = self.subscribe_new_heads().await?;
+        Ok(backend::storage_at_new_heads(self.clone(), key, new_heads))
+    }
+
+    async fn finalized_block_hash(&self) -> Result<BlockHash, Error> {
+        Ok(self.state.lock().unwrap().tip_header.hash())
+    }
+
     fn get_genesis_hash(&self) -> Hash {
         self.genesis_hash
     }
@@ -246,22 +512,72 @@ impl backend::Backend for Emulator {
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
         Ok(radicle_registry_runtime::VERSION)
     }
+
+    async fn runtime_metadata(&self) -> Result<Vec<u8>, Error> {
+        Ok(Runtime::metadata().encode())
+    }
+
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error> {
+        Ok(None)
+    }
+
+    async fn capabilities(&self) -> Result<Capabilities, Error> {
+        Ok(Capabilities {
+            id_status_rpc: true,
+            node_limits_rpc: false,
+        })
+    }
+
+    async fn id_status(&self, id: &Id) -> Result<Option<IdStatus>, Error> {
+        let mut state = self.state.lock().unwrap();
+        Ok(Some(
+            state
+                .test_ext
+                .execute_with(|| registry::store::IdStatuses1::get(id)),
+        ))
+    }
+
+    /// The emulator only keeps a single chain. If `parent_hash` is an ancestor of the tip, the
+    /// blocks after it are retracted like with [EmulatorControl::reorg].
+    async fn create_block(&self, parent_hash: Option<BlockHash>) -> Result<BlockHash, Error> {
+        if let Some(parent_hash) = parent_hash {
+            let depth = self.depth_of(parent_hash).ok_or(Error::BlockMissing {
+                block_hash: parent_hash,
+            })?;
+            if depth > 0 {
+                self.rollback(depth);
+            }
+        }
+        let (block, _) = self.add_block(vec![]);
+        Ok(block.hash())
+    }
+
+    /// All blocks of the emulator are final. We only check that the block exists.
+    async fn finalize_block(&self, block_hash: BlockHash) -> Result<(), Error> {
+        let state = self.state.lock().unwrap();
+        if state.headers.contains_key(&block_hash) {
+            Ok(())
+        } else {
+            Err(Error::BlockMissing { block_hash })
+        }
+    }
 }
 
 /// Create [GenesisConfig] for the emulated chain.
 ///
-/// Initializes the balance of the `//Alice` account with `2^60` tokens.
+/// Initializes the balance of the `//Alice` account with `2^60` tokens and makes `//Alice` the sudo
+/// key.
 fn make_genesis_config() -> GenesisConfig {
+    let alice = ed25519::Pair::from_string("//Alice", None)
+        .unwrap()
+        .public();
     GenesisConfig {
         pallet_balances: Some(BalancesConfig {
-            balances: vec![(
-                ed25519::Pair::from_string("//Alice", None)
-                    .unwrap()
-                    .public(),
-                1 << 60,
-            )],
+            balances: vec![(alice, 1 << 60)],
         }),
-        pallet_sudo: None,
+        pallet_sudo: Some(SudoConfig { key: alice }),
+        pallet_vesting: None,
+        registry: None,
         system: None,
     }
 }
@@ -15,19 +15,29 @@
 
 //! Define trait for client backends and provide emulator and remote node implementation
 use futures::future::BoxFuture;
+use futures::stream::{BoxStream, StreamExt as _, TryStreamExt as _};
+use sp_runtime::traits::Header as _;
 
-pub use radicle_registry_runtime::{Hash, Header, RuntimeVersion, UncheckedExtrinsic};
+pub use radicle_registry_runtime::{Block, Hash, Header, RuntimeVersion, UncheckedExtrinsic};
 
 use crate::event::Event;
 use crate::interface::*;
 
 mod emulator;
 mod remote_node;
+#[cfg(feature = "native")]
 mod remote_node_with_executor;
+mod tracker;
 
-pub use emulator::{Emulator, EmulatorControl, BLOCK_AUTHOR as EMULATOR_BLOCK_AUTHOR};
+pub use emulator::{
+    BlockNotification, Emulator, EmulatorControl, BLOCK_AUTHOR as EMULATOR_BLOCK_AUTHOR,
+};
 pub use remote_node::RemoteNode;
+pub(crate) use remote_node::SYSTEM_EVENTS_STORAGE_KEY;
+#[cfg(feature = "native")]
 pub use remote_node_with_executor::RemoteNodeWithExecutor;
+pub use tracker::TrackedTransactionStatus;
+pub(crate) use tracker::TransactionTracker;
 
 pub type TransactionStatus = sp_transaction_pool::TransactionStatus<TxHash, BlockHash>;
 
@@ -40,6 +50,9 @@ pub struct TransactionIncluded {
     pub block: Hash,
     /// Events emitted by this transaction
     pub events: Vec<Event>,
+    /// Resolves to the hash of the finalized block that includes the transaction once the
+    /// transaction has been finalized.
+    pub finalized: BoxFuture<'static, Result<Hash, Error>>,
 }
 
 /// Backend for talking to the ledger on a block chain.
@@ -62,6 +75,14 @@ pub trait Backend {
         block_hash: Option<BlockHash>,
     ) -> Result<Option<Vec<u8>>, Error>;
 
+    /// Fetch the values of several keys from the runtime state storage at the given block in one
+    /// request. Returns the values in the order of `keys`.
+    async fn fetch_many(
+        &self,
+        keys: &[Vec<u8>],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error>;
+
     /// Fetch all keys with the given prefix from the state storage at the given block.
     async fn fetch_keys(
         &self,
@@ -69,13 +90,92 @@ pub trait Backend {
         block_hash: Option<BlockHash>,
     ) -> Result<Vec<Vec<u8>>, Error>;
 
+    /// Fetch at most `limit` keys with the given prefix from the state storage at the given block.
+    ///
+    /// Keys are returned in ascending order starting with the first key after `start_key`. If
+    /// `start_key` is `None` the page starts with the first key with the prefix.
+    async fn fetch_keys_paged(
+        &self,
+        prefix: &[u8],
+        start_key: Option<&[u8]>,
+        limit: u32,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Vec<u8>>, Error>;
+
+    /// Fetch the Merkle proof for the value stored at `key` in the state of the given block. The
+    /// proof is a list of trie nodes.
+    async fn read_proof(&self, key: &[u8], block_hash: BlockHash) -> Result<Vec<Vec<u8>>, Error>;
+
     /// Fetch the header of the given block hash.
     /// If the block hash is `None`, fetch the header of the best chain tip.
     async fn block_header(&self, block_hash: Option<BlockHash>) -> Result<Option<Header>, Error>;
 
+    /// Fetch the block with the given hash including its extrinsics. Returns `None` if the block
+    /// is not known.
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error>;
+
+    /// Subscribe to the headers of new blocks added to the best chain.
+    async fn subscribe_new_heads(&self)
+        -> Result<BoxStream<'static, Result<Header, Error>>, Error>;
+
+    /// Subscribe to the value stored at `key` in the best chain.
+    ///
+    /// Emits the value whenever a block that changes it becomes the best block. This includes
+    /// blocks of a fork that becomes the best chain. The stream may emit unchanged values.
+    async fn subscribe_storage(
+        &self,
+        key: Vec<u8>,
+    ) -> Result<BoxStream<'static, Result<Option<Vec<u8>>, Error>>, Error>;
+
+    /// Get the hash of the latest finalized block.
+    async fn finalized_block_hash(&self) -> Result<BlockHash, Error>;
+
     /// Get the genesis hash of the blockchain. This must be obtained on backend creation.
     fn get_genesis_hash(&self) -> Hash;
 
     /// Get the runtime version at the latest block
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error>;
+
+    /// Get the SCALE encoded runtime metadata at the latest block
+    async fn runtime_metadata(&self) -> Result<Vec<u8>, Error>;
+
+    /// Get the transaction pool and block building limits of the node. Returns `None` if the
+    /// backend does not impose limits or the node does not report them.
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error>;
+
+    /// Report which optional RPC methods the backend provides.
+    async fn capabilities(&self) -> Result<Capabilities, Error>;
+
+    /// Get the status of an org or user id at the best block from the registry runtime API.
+    /// Returns `None` if the node does not provide the registry RPC methods.
+    async fn id_status(&self, id: &Id) -> Result<Option<IdStatus>, Error>;
+
+    /// Author an empty block on top of `parent_hash` or, if it is `None`, on top of the best
+    /// chain and return its hash.
+    ///
+    /// Only supported by nodes running the `dev` chain.
+    async fn create_block(&self, parent_hash: Option<BlockHash>) -> Result<BlockHash, Error>;
+
+    /// Finalize the given block.
+    ///
+    /// Only supported by nodes running the `dev` chain.
+    async fn finalize_block(&self, block_hash: BlockHash) -> Result<(), Error>;
+}
+
+/// Emit the value stored at `key` at every header of `new_heads`.
+///
+/// Used to implement [Backend::subscribe_storage] for backends that cannot subscribe to storage
+/// changes directly.
+fn storage_at_new_heads<B: Backend + Clone + Send + Sync + 'static>(
+    backend: B,
+    key: Vec<u8>,
+    new_heads: BoxStream<'static, Result<Header, Error>>,
+) -> BoxStream<'static, Result<Option<Vec<u8>>, Error>> {
+    new_heads
+        .and_then(move |header| {
+            let backend = backend.clone();
+            let key = key.clone();
+            async move { backend.fetch(&key, Some(header.hash())).await }
+        })
+        .boxed()
 }
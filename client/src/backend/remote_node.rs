@@ -17,18 +17,26 @@
 use futures::compat::{Future01CompatExt as _, Stream01CompatExt as _};
 use futures::future::BoxFuture;
 use futures::prelude::*;
+use futures::stream::{self, BoxStream};
 use futures01::stream::Stream as _;
-use jsonrpc_core_client::RpcChannel;
+use jsonrpc_core_client::{
+    jsonrpc_core::{ErrorCode, Params},
+    RawClient, RpcChannel, RpcError,
+};
 use lazy_static::lazy_static;
 use parity_scale_codec::{DecodeAll, Encode as _};
 use sc_rpc_api::{author::AuthorClient, chain::ChainClient, state::StateClient};
 use sp_core::{storage::StorageKey, twox_128};
 use sp_rpc::{list::ListOrValue, number::NumberOrHex};
-use sp_runtime::{generic::SignedBlock, traits::Hash as _};
-use std::sync::Arc;
-use url::Url;
+use sp_runtime::{
+    generic::SignedBlock,
+    traits::{Hash as _, Header as _},
+};
+use sp_state_machine::StorageProof;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use radicle_registry_runtime::{Block, BlockNumber, Hash, Hashing, Header, VERSION};
+use radicle_registry_runtime::{Block, BlockNumber, Hash, Hashing, Header};
 
 use crate::backend::{self, Backend, TransactionStatus};
 use crate::event;
@@ -40,16 +48,55 @@ struct Rpc {
     state: StateClient<BlockHash>,
     chain: ChainClient<BlockNumber, Hash, Header, SignedBlock<Block>>,
     author: AuthorClient<Hash, BlockHash>,
+    /// Client for RPC methods without a typed client, e.g. the manual seal methods of the `dev`
+    /// chain.
+    raw: RawClient,
+}
+
+/// Response of the `engine_createBlock` RPC method.
+#[derive(serde::Deserialize)]
+struct CreatedBlock {
+    hash: BlockHash,
+}
+
+/// Response of the `rpc_methods` RPC method.
+#[derive(serde::Deserialize)]
+struct RpcMethods {
+    methods: Vec<String>,
+}
+
+/// Interval in which a node connected over HTTP is polled for new blocks.
+const HTTP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Upper bound for the time we wait between two reconnection attempts.
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Transport of the RPC connection to the node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Transport {
+    WebSocket,
+    Http,
+}
+
+/// RPC connection to the node that is replaced when it fails. See [RemoteNode::call].
+struct Connection {
+    config: ClientConfig,
+    rpc: Mutex<Arc<Rpc>>,
 }
 
 #[derive(Clone)]
 pub struct RemoteNode {
     genesis_hash: Hash,
-    rpc: Arc<Rpc>,
+    connection: Arc<Connection>,
+    transport: Transport,
+    /// If true, verify the events of a transaction against the header of the including block.
+    /// See [RemoteNode::with_event_verification].
+    verify_events: bool,
 }
 
 lazy_static! {
-    static ref SYSTEM_EVENTS_STORAGE_KEY: [u8; 32] = {
+    /// Storage key of the events of the system module. The value is a `Vec<event::Record>`.
+    pub(crate) static ref SYSTEM_EVENTS_STORAGE_KEY: [u8; 32] = {
         let mut events_key = [0u8; 32];
         events_key[0..16].copy_from_slice(&twox_128(b"System"));
         events_key[16..32].copy_from_slice(&twox_128(b"Events"));
@@ -58,17 +105,15 @@ lazy_static! {
 }
 
 impl RemoteNode {
-    pub async fn create(host: url::Host) -> Result<Self, Error> {
-        let url = Url::parse(&format!("ws://{}:9944", host)).expect("Is valid url; qed");
-        let channel: RpcChannel = jsonrpc_core_client::transports::ws::connect(&url)
-            .compat()
-            .await?;
-        let rpc = Arc::new(Rpc {
-            state: channel.clone().into(),
-            chain: channel.clone().into(),
-            author: channel.clone().into(),
-        });
-        check_runtime_version(&rpc).await?;
+    /// Connect to the node described by `config`.
+    ///
+    /// Fails with [Error::ConnectionTimeout] if the connection is not established within
+    /// [ClientConfig::timeout].
+    pub async fn create(config: ClientConfig) -> Result<Self, Error> {
+        let (rpc, transport) = open_rpc(&config).await?;
+        let runtime_version = runtime_version(&rpc, None).await?;
+        let metadata = rpc.state.metadata(None).compat().await?;
+        crate::version::check_chain(&runtime_version, &metadata.0)?;
         let genesis_hash_result = rpc
             .chain
             .block_hash(Some(NumberOrHex::Number(0).into()))
@@ -78,19 +123,128 @@ impl RemoteNode {
             ListOrValue::Value(Some(genesis_hash)) => genesis_hash,
             response => return Err(Error::InvalidBlockHashResponse { response }),
         };
-        Ok(RemoteNode { genesis_hash, rpc })
+        Ok(RemoteNode {
+            genesis_hash,
+            connection: Arc::new(Connection {
+                config,
+                rpc: Mutex::new(rpc),
+            }),
+            transport,
+            verify_events: false,
+        })
+    }
+
+    /// Verify the events of submitted transactions against the header of the block that includes
+    /// the transaction.
+    ///
+    /// The events are fetched with a storage proof that is checked against the state root of the
+    /// header. The block extrinsics are checked against the extrinsics root of the header. This
+    /// guards against a faulty or malicious node that attributes fabricated events to a
+    /// transaction. It requires one additional RPC call per transaction.
+    pub fn with_event_verification(self) -> Self {
+        RemoteNode {
+            verify_events: true,
+            ..self
+        }
+    }
+
+    /// Make an RPC call.
+    ///
+    /// If the call fails because the connection to the node failed we reconnect (see
+    /// [RemoteNode::reconnect]) and make the call once more.
+    async fn call<T, F, Fut>(&self, call: F) -> Result<T, Error>
+    where
+        F: Fn(&Rpc) -> Fut,
+        Fut: futures01::Future<Item = T, Error = RpcError>,
+    {
+        let rpc = self.rpc();
+        match call(&*rpc).compat().await {
+            Err(error)
+                if is_connection_error(&error) && self.connection.config.reconnect_attempts > 0 =>
+            {
+                log::warn!("RPC call failed, reconnecting: {}", error);
+                let rpc = self.reconnect(&rpc).await?;
+                Ok(call(&*rpc).compat().await?)
+            }
+            result => Ok(result?),
+        }
+    }
+
+    /// Call the RPC `method` that only some nodes provide, like the custom registry methods.
+    ///
+    /// Returns `None` and logs a warning if the node does not provide the method, for example
+    /// because it runs an older version.
+    async fn call_optional_method(
+        &self,
+        method: &'static str,
+        params: Params,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        match self
+            .call(|rpc| rpc.raw.call_method(method, params.clone()))
+            .await
+        {
+            Err(Error::Rpc(error)) if is_method_not_found(error.get_ref()) => {
+                log::warn!("Node does not provide the RPC method {}", method);
+                Ok(None)
+            }
+            result => result.map(Some),
+        }
+    }
+
+    /// The current RPC connection.
+    fn rpc(&self) -> Arc<Rpc> {
+        self.connection.rpc.lock().unwrap().clone()
+    }
+
+    /// Replace the `failed` RPC connection with a new connection and return it.
+    ///
+    /// We make [ClientConfig::reconnect_attempts] attempts and wait between two attempts starting
+    /// with [ClientConfig::reconnect_backoff] and doubling the wait time after every attempt. If
+    /// the connection has already been replaced by another call we return the new connection.
+    async fn reconnect(&self, failed: &Arc<Rpc>) -> Result<Arc<Rpc>, Error> {
+        let config = &self.connection.config;
+        let mut backoff = config.reconnect_backoff;
+        let mut attempt = 1;
+        loop {
+            let current = self.rpc();
+            if !Arc::ptr_eq(&current, failed) {
+                return Ok(current);
+            }
+            match open_rpc(config).await {
+                Ok((rpc, _)) => {
+                    *self.connection.rpc.lock().unwrap() = rpc.clone();
+                    return Ok(rpc);
+                }
+                Err(error) if attempt >= config.reconnect_attempts => return Err(error),
+                Err(error) => {
+                    log::warn!("Reconnection attempt {} failed: {}", attempt, error);
+                    futures_timer::Delay::new(backoff).await;
+                    backoff = std::cmp::min(backoff * 2, MAX_RECONNECT_BACKOFF);
+                    attempt += 1;
+                }
+            }
+        }
     }
 
     /// Submit a transaction and return the block hash once it is included in a block.
+    ///
+    /// Together with the block hash we return a future that resolves to the hash of the finalized
+    /// block that includes the transaction. Both are driven by the `author.watch_extrinsic`
+    /// subscription. If the subscription is lost, for example because the connection to the node
+    /// failed, we follow the transaction by its hash with [RemoteNode::poll_included] and
+    /// [RemoteNode::poll_finalized] instead.
     async fn submit_transaction(
         &self,
         xt: backend::UncheckedExtrinsic,
-    ) -> Result<impl Future<Output = Result<Hash, Error>>, Error> {
+    ) -> Result<
+        impl Future<Output = Result<(Hash, BoxFuture<'static, Result<Hash, Error>>), Error>>,
+        Error,
+    > {
+        let tx_hash = Hashing::hash_of(&xt);
+        let tip = self.best_header().await?;
+        let xt_bytes: sp_core::Bytes = xt.encode().into();
         let tx_status_stream = self
-            .rpc
-            .author
-            .watch_extrinsic(xt.encode().into())
-            .compat()
+            .call(|rpc| rpc.author.watch_extrinsic(xt_bytes.clone()))
             .await?;
 
         let mut tx_status_stream = tx_status_stream.map_err(Error::from).compat();
@@ -102,37 +256,201 @@ impl RemoteNode {
                 TransactionStatus::Future
                 | TransactionStatus::Ready
                 | TransactionStatus::Broadcast(_) => (),
-                tx_status => {
-                    return Err(Error::InvalidTransactionStatus {
-                        tx_hash: Hashing::hash_of(&xt),
-                        tx_status,
-                    })
-                }
+                tx_status => return Err(Error::InvalidTransactionStatus { tx_hash, tx_status }),
             },
         }
 
+        let this = self.clone();
         Ok(async move {
             loop {
-                let opt_tx_status = tx_status_stream.try_next().await?;
-                match opt_tx_status {
-                    None => return Err(Error::WatchExtrinsicStreamTerminated),
-                    Some(tx_status) => match tx_status {
-                        TransactionStatus::Future
-                        | TransactionStatus::Ready
-                        | TransactionStatus::Broadcast(_) => continue,
-                        TransactionStatus::InBlock(block_hash) => return Ok(block_hash),
-                        tx_status => {
-                            return Err(Error::InvalidTransactionStatus {
-                                tx_hash: Hashing::hash_of(&xt),
-                                tx_status,
-                            })
-                        }
-                    },
+                let tx_status = match tx_status_stream.try_next().await {
+                    Ok(Some(tx_status)) => tx_status,
+                    Ok(None) => break,
+                    Err(error) if is_connection_failure(&error) => break,
+                    Err(error) => return Err(error),
+                };
+                match tx_status {
+                    TransactionStatus::Future
+                    | TransactionStatus::Ready
+                    | TransactionStatus::Broadcast(_) => continue,
+                    TransactionStatus::InBlock(block_hash) => {
+                        let finalized = this
+                            .wait_finalized(tx_hash, block_hash, tx_status_stream)
+                            .boxed();
+                        return Ok((block_hash, finalized));
+                    }
+                    tx_status => {
+                        return Err(Error::InvalidTransactionStatus { tx_hash, tx_status })
+                    }
                 }
             }
+            log::warn!(
+                "Lost subscription for transaction {}, polling for its inclusion",
+                tx_hash
+            );
+            let block = this.poll_included(tx_hash, tip).await?;
+            let block_hash = block.hash();
+            Ok((block_hash, this.poll_finalized(tx_hash, block).boxed()))
         })
     }
 
+    /// Wait for the `author.watch_extrinsic` subscription of an included transaction to report that
+    /// the transaction has been finalized and return the hash of the finalized block.
+    ///
+    /// If the block that includes the transaction is retracted the transaction may be included in
+    /// another block. The returned hash is then different from `block_hash`. If the subscription
+    /// is lost we poll for the finalization of the latest block that included the transaction
+    /// with [RemoteNode::poll_finalized].
+    async fn wait_finalized(
+        self,
+        tx_hash: TxHash,
+        mut block_hash: BlockHash,
+        mut tx_status_stream: impl Stream<Item = Result<TransactionStatus, Error>> + Unpin,
+    ) -> Result<Hash, Error> {
+        loop {
+            let tx_status = match tx_status_stream.try_next().await {
+                Ok(Some(tx_status)) => tx_status,
+                Ok(None) => break,
+                Err(error) if is_connection_failure(&error) => break,
+                Err(error) => return Err(error),
+            };
+            match tx_status {
+                TransactionStatus::Finalized(block_hash) => return Ok(block_hash),
+                TransactionStatus::InBlock(new_block_hash) => block_hash = new_block_hash,
+                TransactionStatus::Future
+                | TransactionStatus::Ready
+                | TransactionStatus::Broadcast(_)
+                | TransactionStatus::Retracted(_) => continue,
+                tx_status @ TransactionStatus::FinalityTimeout(_)
+                | tx_status @ TransactionStatus::Usurped(_)
+                | tx_status @ TransactionStatus::Dropped
+                | tx_status @ TransactionStatus::Invalid => {
+                    return Err(Error::TransactionNotFinalized { tx_hash, tx_status })
+                }
+            }
+        }
+        log::warn!(
+            "Lost subscription for transaction {}, polling for its finalization",
+            tx_hash
+        );
+        let block = self.header(block_hash).await?;
+        self.poll_finalized(tx_hash, block).await
+    }
+
+    /// Submit a transaction without a subscription and poll the best chain until a block includes
+    /// the transaction. Used for [Transport::Http].
+    ///
+    /// The returned future does not resolve if the transaction is never included, for example
+    /// because the node drops it.
+    async fn submit_transaction_polling(
+        &self,
+        xt: backend::UncheckedExtrinsic,
+    ) -> Result<
+        impl Future<Output = Result<(Hash, BoxFuture<'static, Result<Hash, Error>>), Error>>,
+        Error,
+    > {
+        let tx_hash = Hashing::hash_of(&xt);
+        let tip = self.best_header().await?;
+        let xt_bytes: sp_core::Bytes = xt.encode().into();
+        self.call(|rpc| rpc.author.submit_extrinsic(xt_bytes.clone()))
+            .await?;
+        let this = self.clone();
+        Ok(async move {
+            let block = this.poll_included(tx_hash, tip).await?;
+            let block_hash = block.hash();
+            let finalized = this.poll_finalized(tx_hash, block).boxed();
+            Ok((block_hash, finalized))
+        })
+    }
+
+    /// Poll the best chain until a block after `tip` includes the transaction and return the
+    /// header of that block.
+    async fn poll_included(&self, tx_hash: TxHash, tip: Header) -> Result<Header, Error> {
+        let mut checked = std::collections::HashSet::new();
+        loop {
+            futures_timer::Delay::new(HTTP_POLL_INTERVAL).await;
+            let mut header = self.best_header().await?;
+            while header.number > tip.number && checked.insert(header.hash()) {
+                let block_hash = header.hash();
+                let signed_block = self
+                    .call(|rpc| rpc.chain.block(Some(block_hash)))
+                    .await?
+                    .ok_or_else(|| Error::BlockMissing { block_hash })?;
+                if signed_block
+                    .block
+                    .extrinsics
+                    .iter()
+                    .any(|xt| Hashing::hash_of(xt) == tx_hash)
+                {
+                    return Ok(header);
+                }
+                header = self.header(header.parent_hash).await?;
+            }
+        }
+    }
+
+    /// Poll the latest finalized block until it includes `block` and return the hash of `block`.
+    ///
+    /// Fails with [Error::TransactionRetracted] if a different block with the same number is
+    /// finalized.
+    async fn poll_finalized(self, tx_hash: TxHash, block: Header) -> Result<Hash, Error> {
+        let block_hash = block.hash();
+        let mut finalized = loop {
+            futures_timer::Delay::new(HTTP_POLL_INTERVAL).await;
+            let finalized_hash = self.finalized_block_hash().await?;
+            let finalized = self.header(finalized_hash).await?;
+            if finalized.number >= block.number {
+                break finalized;
+            }
+        };
+        while finalized.number > block.number {
+            finalized = self.header(finalized.parent_hash).await?;
+        }
+        if finalized.hash() == block_hash {
+            Ok(block_hash)
+        } else {
+            Err(Error::TransactionRetracted {
+                tx_hash,
+                block_hash,
+            })
+        }
+    }
+
+    /// Poll the tip of the best chain and emit its header whenever it changes. Used for
+    /// [Transport::Http].
+    ///
+    /// Blocks that are added between two polls are not emitted.
+    fn poll_new_heads(&self) -> BoxStream<'static, Result<Header, Error>> {
+        stream::unfold(
+            (self.clone(), None),
+            |(this, last_hash): (Self, Option<Hash>)| async move {
+                loop {
+                    match this.best_header().await {
+                        Ok(header) if Some(header.hash()) != last_hash => {
+                            let hash = header.hash();
+                            return Some((Ok(header), (this, Some(hash))));
+                        }
+                        Ok(_) => futures_timer::Delay::new(HTTP_POLL_INTERVAL).await,
+                        Err(error) => return Some((Err(error), (this, last_hash))),
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+
+    async fn best_header(&self) -> Result<Header, Error> {
+        self.block_header(None)
+            .await?
+            .ok_or(Error::BestChainTipHeaderMissing)
+    }
+
+    async fn header(&self, block_hash: BlockHash) -> Result<Header, Error> {
+        self.block_header(Some(block_hash))
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })
+    }
+
     /// Return all the events belonging to the transaction included in the given block.
     ///
     /// This requires the transaction to be included in the given block.
@@ -141,10 +459,19 @@ impl RemoteNode {
         tx_hash: TxHash,
         block_hash: BlockHash,
     ) -> Result<Vec<event::Event>, Error> {
-        let events_data = self
-            .fetch(SYSTEM_EVENTS_STORAGE_KEY.as_ref(), Some(block_hash))
+        let signed_block = self
+            .call(|rpc| rpc.chain.block(Some(block_hash)))
             .await?
-            .unwrap_or_default();
+            .ok_or_else(|| Error::BlockMissing { block_hash })?;
+
+        let events_data = if self.verify_events {
+            self.fetch_verified_events_data(&signed_block.block, block_hash)
+                .await?
+        } else {
+            self.fetch(SYSTEM_EVENTS_STORAGE_KEY.as_ref(), Some(block_hash))
+                .await?
+        }
+        .unwrap_or_default();
         let event_records = Vec::<event::Record>::decode_all(&events_data).map_err(|error| {
             Error::StateDecoding {
                 error,
@@ -152,13 +479,6 @@ impl RemoteNode {
             }
         })?;
 
-        let signed_block = self
-            .rpc
-            .chain
-            .block(Some(block_hash))
-            .compat()
-            .await?
-            .ok_or_else(|| Error::BlockMissing { block_hash })?;
         extract_transaction_events(tx_hash, &signed_block.block, event_records).ok_or_else(|| {
             Error::EventsMissing {
                 tx_hash,
@@ -166,6 +486,43 @@ impl RemoteNode {
             }
         })
     }
+
+    /// Fetch the raw events of `block` with a storage proof and verify the proof and the block
+    /// data against the block header.
+    async fn fetch_verified_events_data(
+        &self,
+        block: &Block,
+        block_hash: BlockHash,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let verification_error = |reason| Error::BlockDataVerification { block_hash, reason };
+        let header = &block.header;
+        if header.hash() != block_hash {
+            return Err(verification_error("header does not match the block hash"));
+        }
+        let extrinsics_root =
+            Hashing::ordered_trie_root(block.extrinsics.iter().map(|xt| xt.encode()).collect());
+        if extrinsics_root != header.extrinsics_root {
+            return Err(verification_error(
+                "extrinsics do not match the extrinsics root of the header",
+            ));
+        }
+
+        let key = SYSTEM_EVENTS_STORAGE_KEY.to_vec();
+        let read_proof = self
+            .call(|rpc| {
+                rpc.state
+                    .read_proof(vec![StorageKey(key.clone())], Some(block_hash))
+            })
+            .await?;
+        let proof = StorageProof::new(read_proof.proof.into_iter().map(|node| node.0).collect());
+        let mut values = sp_state_machine::read_proof_check::<Hashing, _>(
+            header.state_root,
+            proof,
+            std::iter::once(&key),
+        )
+        .map_err(|_| verification_error("invalid storage proof for the block events"))?;
+        Ok(values.remove(&key).flatten())
+    }
 }
 
 #[async_trait::async_trait]
@@ -175,16 +532,20 @@ impl backend::Backend for RemoteNode {
         xt: backend::UncheckedExtrinsic,
     ) -> Result<BoxFuture<'static, Result<backend::TransactionIncluded, Error>>, Error> {
         let tx_hash = Hashing::hash_of(&xt);
-        let block_hash_future = self.submit_transaction(xt).await?;
+        let block_hash_future = match self.transport {
+            Transport::WebSocket => self.submit_transaction(xt).await?.boxed(),
+            Transport::Http => self.submit_transaction_polling(xt).await?.boxed(),
+        };
         let this = self.clone();
 
         Ok(Box::pin(async move {
-            let block_hash = block_hash_future.await?;
+            let (block_hash, finalized) = block_hash_future.await?;
             let events = this.get_transaction_events(tx_hash, block_hash).await?;
             Ok(backend::TransactionIncluded {
                 tx_hash,
                 block: block_hash,
                 events,
+                finalized,
             })
         }))
     }
@@ -195,10 +556,40 @@ impl backend::Backend for RemoteNode {
         block_hash: Option<BlockHash>,
     ) -> Result<Option<Vec<u8>>, Error> {
         let key = StorageKey(Vec::from(key));
-        let maybe_data = self.rpc.state.storage(key, block_hash).compat().await?;
+        let maybe_data = self
+            .call(|rpc| rpc.state.storage(key.clone(), block_hash))
+            .await?;
         Ok(maybe_data.map(|data| data.0))
     }
 
+    async fn read_proof(&self, key: &[u8], block_hash: BlockHash) -> Result<Vec<Vec<u8>>, Error> {
+        let key = StorageKey(Vec::from(key));
+        let read_proof = self
+            .call(|rpc| rpc.state.read_proof(vec![key.clone()], Some(block_hash)))
+            .await?;
+        Ok(read_proof.proof.into_iter().map(|node| node.0).collect())
+    }
+
+    async fn fetch_many(
+        &self,
+        keys: &[Vec<u8>],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let storage_keys: Vec<_> = keys.iter().cloned().map(StorageKey).collect();
+        let change_sets = self
+            .call(|rpc| rpc.state.query_storage_at(storage_keys.clone(), block_hash))
+            .await?;
+        let mut values = change_sets
+            .into_iter()
+            .flat_map(|change_set| change_set.changes)
+            .map(|(key, data)| (key.0, data.map(|data| data.0)))
+            .collect::<std::collections::HashMap<_, _>>();
+        Ok(keys
+            .iter()
+            .map(|key| values.remove(key).flatten())
+            .collect())
+    }
+
     async fn fetch_keys(
         &self,
         prefix: &[u8],
@@ -206,10 +597,29 @@ impl backend::Backend for RemoteNode {
     ) -> Result<Vec<Vec<u8>>, Error> {
         let prefix = StorageKey(Vec::from(prefix));
         let keys = self
-            .rpc
-            .state
-            .storage_keys(prefix, block_hash)
-            .compat()
+            .call(|rpc| rpc.state.storage_keys(prefix.clone(), block_hash))
+            .await?;
+        Ok(keys.into_iter().map(|key| key.0).collect())
+    }
+
+    async fn fetch_keys_paged(
+        &self,
+        prefix: &[u8],
+        start_key: Option<&[u8]>,
+        limit: u32,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let prefix = StorageKey(Vec::from(prefix));
+        let start_key = start_key.map(|key| StorageKey(Vec::from(key)));
+        let keys = self
+            .call(|rpc| {
+                rpc.state.storage_keys_paged(
+                    Some(prefix.clone()),
+                    limit,
+                    start_key.clone(),
+                    block_hash,
+                )
+            })
             .await?;
         Ok(keys.into_iter().map(|key| key.0).collect())
     }
@@ -218,12 +628,57 @@ impl backend::Backend for RemoteNode {
         &self,
         block_hash: Option<BlockHash>,
     ) -> Result<Option<BlockHeader>, Error> {
-        self.rpc
-            .chain
-            .header(block_hash)
-            .compat()
-            .await
+        self.call(|rpc| rpc.chain.header(block_hash)).await
+    }
+
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<Block>, Error> {
+        let signed_block = self.call(|rpc| rpc.chain.block(Some(block_hash))).await?;
+        Ok(signed_block.map(|signed_block| signed_block.block))
+    }
+
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<Header, Error>>, Error> {
+        if self.transport == Transport::Http {
+            return Ok(self.poll_new_heads());
+        }
+        let new_heads = self.call(|rpc| rpc.chain.subscribe_new_heads()).await?;
+        Ok(new_heads.map_err(Error::from).compat().boxed())
+    }
+
+    /// Uses the `state_subscribeStorage` RPC method. Nodes connected over HTTP are polled for new
+    /// blocks instead.
+    async fn subscribe_storage(
+        &self,
+        key: Vec<u8>,
+    ) -> Result<BoxStream<'static, Result<Option<Vec<u8>>, Error>>, Error> {
+        if self.transport == Transport::Http {
+            let new_heads = self.poll_new_heads();
+            return Ok(backend::storage_at_new_heads(self.clone(), key, new_heads));
+        }
+        let key = StorageKey(key);
+        let change_sets = self
+            .call(|rpc| rpc.state.subscribe_storage(Some(vec![key.clone()])))
+            .await?;
+        let values = change_sets
             .map_err(Error::from)
+            .compat()
+            .map_ok(move |change_set| {
+                let key = key.clone();
+                let values = change_set
+                    .changes
+                    .into_iter()
+                    .filter(move |(changed_key, _)| *changed_key == key)
+                    .map(|(_, maybe_data)| Ok(maybe_data.map(|data| data.0)));
+                stream::iter(values)
+            })
+            .try_flatten()
+            .boxed();
+        Ok(values)
+    }
+
+    async fn finalized_block_hash(&self) -> Result<BlockHash, Error> {
+        self.call(|rpc| rpc.chain.finalized_head()).await
     }
 
     fn get_genesis_hash(&self) -> Hash {
@@ -231,15 +686,193 @@ impl backend::Backend for RemoteNode {
     }
 
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
-        runtime_version(&self.rpc, None).await
+        self.call(|rpc| rpc.state.runtime_version(None)).await
+    }
+
+    async fn runtime_metadata(&self) -> Result<Vec<u8>, Error> {
+        let metadata = self.call(|rpc| rpc.state.metadata(None)).await?;
+        Ok(metadata.0)
+    }
+
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error> {
+        let response = match self
+            .call_optional_method("registry_nodeLimits", Params::None)
+            .await?
+        {
+            Some(response) => response,
+            None => return Ok(None),
+        };
+        let node_limits = serde_json::from_value(response)
+            .map_err(|error| RpcError::ParseError("NodeLimits".to_string(), error.into()))?;
+        Ok(Some(node_limits))
+    }
+
+    async fn capabilities(&self) -> Result<Capabilities, Error> {
+        let response = match self
+            .call_optional_method("rpc_methods", Params::None)
+            .await?
+        {
+            Some(response) => response,
+            None => return Ok(Capabilities::default()),
+        };
+        let rpc_methods: RpcMethods = serde_json::from_value(response)
+            .map_err(|error| RpcError::ParseError("RpcMethods".to_string(), error.into()))?;
+        let provides = |method: &str| rpc_methods.methods.iter().any(|m| m == method);
+        Ok(Capabilities {
+            id_status_rpc: provides("registry_idAvailable"),
+            node_limits_rpc: provides("registry_nodeLimits"),
+        })
+    }
+
+    async fn id_status(&self, id: &Id) -> Result<Option<IdStatus>, Error> {
+        let params = Params::Array(vec![serde_json::to_value(id).expect("Id is serializable")]);
+        let response = match self
+            .call_optional_method("registry_idAvailable", params)
+            .await?
+        {
+            Some(response) => response,
+            None => return Ok(None),
+        };
+        let id_status = serde_json::from_value(response)
+            .map_err(|error| RpcError::ParseError("IdStatus".to_string(), error.into()))?;
+        Ok(Some(id_status))
+    }
+
+    async fn create_block(&self, parent_hash: Option<BlockHash>) -> Result<BlockHash, Error> {
+        // Parameters are `create_empty`, `finalize` and `parent_hash`.
+        let params = Params::Array(vec![
+            true.into(),
+            false.into(),
+            serde_json::to_value(parent_hash).expect("Block hash is serializable"),
+        ]);
+        let response = self
+            .call(|rpc| rpc.raw.call_method("engine_createBlock", params.clone()))
+            .await?;
+        let created_block: CreatedBlock = serde_json::from_value(response)
+            .map_err(|error| RpcError::ParseError("CreatedBlock".to_string(), error.into()))?;
+        Ok(created_block.hash)
+    }
+
+    async fn finalize_block(&self, block_hash: BlockHash) -> Result<(), Error> {
+        // Parameters are `hash` and `justification`.
+        let params = Params::Array(vec![
+            serde_json::to_value(block_hash).expect("Block hash is serializable"),
+            serde_json::Value::Null,
+        ]);
+        self.call(|rpc| rpc.raw.call_method("engine_finalizeBlock", params.clone()))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Open an RPC connection to the node described by `config`.
+///
+/// Fails with [Error::ConnectionTimeout] if the connection is not established within
+/// [ClientConfig::timeout].
+async fn open_rpc(config: &ClientConfig) -> Result<(Arc<Rpc>, Transport), Error> {
+    let url = config.url();
+    let connect = async {
+        match (config.use_http, config.use_tls) {
+            (false, _) => Ok((connect_ws(&url).await?, Transport::WebSocket)),
+            (true, false) => Ok((connect_http(&url).await?, Transport::Http)),
+            (true, true) => Err(Error::UnsupportedUrlScheme {
+                scheme: url.scheme().to_string(),
+            }),
+        }
+    };
+    futures::pin_mut!(connect);
+    let timeout = config.timeout;
+    let (channel, transport) =
+        match future::select(connect, futures_timer::Delay::new(timeout)).await {
+            future::Either::Left((result, _)) => result?,
+            future::Either::Right(_) => return Err(Error::ConnectionTimeout { timeout }),
+        };
+    let rpc = Arc::new(Rpc {
+        state: channel.clone().into(),
+        chain: channel.clone().into(),
+        author: channel.clone().into(),
+        raw: channel.into(),
+    });
+    Ok((rpc, transport))
+}
+
+#[cfg(not(feature = "browser"))]
+async fn connect_ws(url: &url::Url) -> Result<RpcChannel, Error> {
+    let channel = jsonrpc_core_client::transports::ws::connect(url)
+        .compat()
+        .await?;
+    Ok(channel)
+}
+
+#[cfg(not(feature = "browser"))]
+async fn connect_http(url: &url::Url) -> Result<RpcChannel, Error> {
+    let channel = jsonrpc_core_client::transports::http::connect(url.as_str())
+        .compat()
+        .await?;
+    Ok(channel)
+}
+
+/// Connect with the WebSocket API of the browser.
+///
+/// The connection is driven by a task spawned on the JavaScript event loop.
+#[cfg(feature = "browser")]
+async fn connect_ws(url: &url::Url) -> Result<RpcChannel, Error> {
+    use ws_stream_wasm::{WsMessage, WsMeta};
+
+    let to_rpc_error =
+        |error: ws_stream_wasm::WsErr| RpcError::Other(failure::format_err!("{}", error));
+    let (_meta, ws_stream) = WsMeta::connect(url.as_str(), None)
+        .await
+        .map_err(to_rpc_error)?;
+    let (sink, stream) = ws_stream.split();
+    let sink = sink
+        .with(|request: String| future::ok::<_, ws_stream_wasm::WsErr>(WsMessage::Text(request)))
+        .sink_map_err(to_rpc_error);
+    let stream = stream.filter_map(|message| {
+        future::ready(match message {
+            WsMessage::Text(response) => Some(Ok::<_, RpcError>(response)),
+            WsMessage::Binary(_) => None,
+        })
+    });
+    let (duplex, channel) = jsonrpc_core_client::transports::duplex(
+        futures::compat::CompatSink::new(sink),
+        stream.compat(),
+    );
+    wasm_bindgen_futures::spawn_local(duplex.compat().map(|_| ()));
+    Ok(channel)
+}
+
+/// Browsers only support WebSocket connections to the node.
+#[cfg(feature = "browser")]
+async fn connect_http(url: &url::Url) -> Result<RpcChannel, Error> {
+    Err(Error::UnsupportedUrlScheme {
+        scheme: url.scheme().to_string(),
+    })
+}
+
+/// Returns true if the RPC call failed because the connection to the node failed and not because
+/// the node responded with an error.
+fn is_connection_error(error: &RpcError) -> bool {
+    match error {
+        RpcError::Other(_) | RpcError::Timeout => true,
+        RpcError::JsonRpcError(_) | RpcError::ParseError(_, _) => false,
+    }
+}
+
+/// Returns true if the node responded that it does not know the RPC method.
+fn is_method_not_found(error: &RpcError) -> bool {
+    match error {
+        RpcError::JsonRpcError(error) => error.code == ErrorCode::MethodNotFound,
+        _ => false,
     }
 }
 
-async fn check_runtime_version(rpc: &Rpc) -> Result<(), Error> {
-    const CURRENT_SPEC_VERSION: u32 = VERSION.spec_version;
-    match runtime_version(rpc, None).await?.spec_version {
-        17 | CURRENT_SPEC_VERSION => Ok(()),
-        other => Err(Error::IncompatibleRuntimeVersion(other)),
+/// Returns true if `error` is an [Error::Rpc] caused by a failed connection. See
+/// [is_connection_error].
+fn is_connection_failure(error: &Error) -> bool {
+    match error {
+        Error::Rpc(error) => is_connection_error(error.get_ref()),
+        _ => false,
     }
 }
 
@@ -16,6 +16,7 @@
 //! Provides [RemoteNodeWithExecutor] backend
 use futures::compat::Executor01CompatExt;
 use futures::future::BoxFuture;
+use futures::stream::BoxStream;
 use futures::task::SpawnExt;
 use std::sync::Arc;
 
@@ -31,10 +32,10 @@ pub struct RemoteNodeWithExecutor {
 }
 
 impl RemoteNodeWithExecutor {
-    pub async fn create(host: url::Host) -> Result<Self, Error> {
+    pub async fn create(config: ClientConfig) -> Result<Self, Error> {
         let runtime = tokio::runtime::Runtime::new().unwrap();
         let backend = Executor01CompatExt::compat(runtime.executor())
-            .spawn_with_handle(backend::RemoteNode::create(host))
+            .spawn_with_handle(backend::RemoteNode::create(config))
             .unwrap()
             .await?;
         Ok(RemoteNodeWithExecutor {
@@ -56,7 +57,14 @@ impl backend::Backend for RemoteNodeWithExecutor {
             .spawn_with_handle(async move { backend.submit(xt).await })
             .unwrap();
         let fut = handle.await?;
-        Ok(Box::pin(exec.spawn_with_handle(fut).unwrap()))
+        let tx_included_handle = exec.spawn_with_handle(fut).unwrap();
+        Ok(Box::pin(async move {
+            let tx_included = tx_included_handle.await?;
+            Ok(backend::TransactionIncluded {
+                finalized: Box::pin(exec.spawn_with_handle(tx_included.finalized).unwrap()),
+                ..tx_included
+            })
+        }))
     }
 
     async fn fetch(
@@ -72,6 +80,28 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn read_proof(&self, key: &[u8], block_hash: BlockHash) -> Result<Vec<Vec<u8>>, Error> {
+        let backend = self.backend.clone();
+        let key = Vec::from(key);
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.read_proof(&key, block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn fetch_many(
+        &self,
+        keys: &[Vec<u8>],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Option<Vec<u8>>>, Error> {
+        let backend = self.backend.clone();
+        let keys = Vec::from(keys);
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.fetch_many(&keys, block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
     async fn fetch_keys(
         &self,
         prefix: &[u8],
@@ -85,6 +115,26 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn fetch_keys_paged(
+        &self,
+        prefix: &[u8],
+        start_key: Option<&[u8]>,
+        limit: u32,
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let backend = self.backend.clone();
+        let prefix = Vec::from(prefix);
+        let start_key = start_key.map(Vec::from);
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move {
+                backend
+                    .fetch_keys_paged(&prefix, start_key.as_deref(), limit, block_hash)
+                    .await
+            })
+            .unwrap();
+        handle.await
+    }
+
     async fn block_header(
         &self,
         block_hash: Option<BlockHash>,
@@ -96,6 +146,43 @@ impl backend::Backend for RemoteNodeWithExecutor {
         handle.await
     }
 
+    async fn block(&self, block_hash: BlockHash) -> Result<Option<backend::Block>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.block(block_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn subscribe_new_heads(
+        &self,
+    ) -> Result<BoxStream<'static, Result<Header, Error>>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.subscribe_new_heads().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn subscribe_storage(
+        &self,
+        key: Vec<u8>,
+    ) -> Result<BoxStream<'static, Result<Option<Vec<u8>>, Error>>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.subscribe_storage(key).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn finalized_block_hash(&self) -> Result<BlockHash, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.finalized_block_hash().await })
+            .unwrap();
+        handle.await
+    }
+
     fn get_genesis_hash(&self) -> Hash {
         self.backend.get_genesis_hash()
     }
@@ -103,4 +190,53 @@ impl backend::Backend for RemoteNodeWithExecutor {
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
         self.backend.runtime_version().await
     }
+
+    async fn runtime_metadata(&self) -> Result<Vec<u8>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.runtime_metadata().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.node_limits().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn capabilities(&self) -> Result<Capabilities, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.capabilities().await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn id_status(&self, id: &Id) -> Result<Option<IdStatus>, Error> {
+        let backend = self.backend.clone();
+        let id = id.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.id_status(&id).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn create_block(&self, parent_hash: Option<BlockHash>) -> Result<BlockHash, Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.create_block(parent_hash).await })
+            .unwrap();
+        handle.await
+    }
+
+    async fn finalize_block(&self, block_hash: BlockHash) -> Result<(), Error> {
+        let backend = self.backend.clone();
+        let handle = Executor01CompatExt::compat(self.runtime.executor())
+            .spawn_with_handle(async move { backend.finalize_block(block_hash).await })
+            .unwrap();
+        handle.await
+    }
 }
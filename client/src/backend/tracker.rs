@@ -0,0 +1,148 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Track submitted transactions across reorganizations of the chain.
+use futures::future::BoxFuture;
+use futures::prelude::*;
+use futures::stream::{self, BoxStream};
+use sp_runtime::traits::Header as _;
+use std::sync::Arc;
+
+use crate::backend::{self, Backend, Header, UncheckedExtrinsic};
+use crate::interface::*;
+
+/// Status of a transaction tracked with [crate::Client::track_transaction].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrackedTransactionStatus {
+    /// The transaction has been submitted and waits to be included in a block.
+    Pending,
+    /// The transaction is included in the given block of the best chain.
+    InBlock(BlockHash),
+    /// The given block that included the transaction was removed from the best chain. The
+    /// transaction is submitted again.
+    Retracted(BlockHash),
+    /// The given block that includes the transaction has been finalized.
+    Finalized(BlockHash),
+}
+
+/// Submits a transaction and follows it until it is finalized. See
+/// [crate::Client::track_transaction].
+pub(crate) struct TransactionTracker {
+    backend: Arc<dyn Backend + Sync + Send>,
+    xt: UncheckedExtrinsic,
+    new_heads: BoxStream<'static, Result<Header, Error>>,
+    state: State,
+}
+
+enum State {
+    /// The transaction needs to be submitted.
+    Unsubmitted,
+    /// The transaction has been submitted and the future resolves when it is included.
+    Pending(BoxFuture<'static, Result<backend::TransactionIncluded, Error>>),
+    /// The transaction is included in the block with the given header.
+    InBlock(Header),
+    /// The transaction has been finalized or tracking failed.
+    Done,
+}
+
+impl TransactionTracker {
+    pub(crate) async fn new(
+        backend: Arc<dyn Backend + Sync + Send>,
+        xt: UncheckedExtrinsic,
+    ) -> Result<Self, Error> {
+        // We subscribe before we submit so that we don’t miss any blocks.
+        let new_heads = backend.subscribe_new_heads().await?;
+        Ok(TransactionTracker {
+            backend,
+            xt,
+            new_heads,
+            state: State::Unsubmitted,
+        })
+    }
+
+    /// Stream of the status changes of the transaction. The stream ends after the transaction has
+    /// been finalized or after the first error.
+    pub(crate) fn into_stream(self) -> BoxStream<'static, Result<TrackedTransactionStatus, Error>> {
+        stream::unfold(self, |mut tracker| async move {
+            match tracker.next_status().await {
+                Ok(Some(status)) => Some((Ok(status), tracker)),
+                Ok(None) => None,
+                Err(error) => {
+                    tracker.state = State::Done;
+                    Some((Err(error), tracker))
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Advance the tracking state and return the next status. Returns `None` when tracking is
+    /// done.
+    async fn next_status(&mut self) -> Result<Option<TrackedTransactionStatus>, Error> {
+        loop {
+            match std::mem::replace(&mut self.state, State::Done) {
+                State::Unsubmitted => {
+                    let tx_included_future = self.backend.submit(self.xt.clone()).await?;
+                    self.state = State::Pending(tx_included_future);
+                    return Ok(Some(TrackedTransactionStatus::Pending));
+                }
+                State::Pending(tx_included_future) => {
+                    let block_hash = tx_included_future.await?.block;
+                    let block = self.header(block_hash).await?;
+                    self.state = State::InBlock(block);
+                    return Ok(Some(TrackedTransactionStatus::InBlock(block_hash)));
+                }
+                State::InBlock(block) => {
+                    self.new_heads
+                        .try_next()
+                        .await?
+                        .ok_or(Error::BlockSubscriptionTerminated)?;
+                    let block_hash = block.hash();
+                    let tip = self
+                        .backend
+                        .block_header(None)
+                        .await?
+                        .ok_or(Error::BestChainTipHeaderMissing)?;
+                    if !self.is_ancestor(&block, tip).await? {
+                        self.state = State::Unsubmitted;
+                        return Ok(Some(TrackedTransactionStatus::Retracted(block_hash)));
+                    }
+                    let finalized_hash = self.backend.finalized_block_hash().await?;
+                    let finalized = self.header(finalized_hash).await?;
+                    if self.is_ancestor(&block, finalized).await? {
+                        return Ok(Some(TrackedTransactionStatus::Finalized(block_hash)));
+                    }
+                    self.state = State::InBlock(block);
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns true if `block` is `descendant` or one of its ancestors.
+    async fn is_ancestor(&self, block: &Header, mut descendant: Header) -> Result<bool, Error> {
+        while descendant.number > block.number {
+            descendant = self.header(descendant.parent_hash).await?;
+        }
+        Ok(descendant.hash() == block.hash())
+    }
+
+    async fn header(&self, block_hash: BlockHash) -> Result<Header, Error> {
+        self.backend
+            .block_header(Some(block_hash))
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })
+    }
+}
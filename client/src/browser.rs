@@ -0,0 +1,249 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! JavaScript bindings of the client for browsers. Enabled with the `browser` feature.
+//!
+//! Values are passed to and from JavaScript as JSON compatible objects. Account ids are SS58
+//! addresses and balances are strings because they may exceed the precision of JavaScript
+//! numbers.
+//!
+//! ```js
+//! const client = await BrowserClient.connect("wss://registry.example.com");
+//! const status = await client.getIdStatus("monadic");
+//! await client.signAndSubmitMessage("//Alice", { type: "RegisterOrg", orgId: "monadic" }, "1");
+//! ```
+
+use js_sys::Promise;
+use serde::{Deserialize, Serialize};
+use sp_core::crypto::Ss58Codec as _;
+use std::convert::TryFrom;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::*;
+
+/// Client that is exported to JavaScript. See the [module documentation](self).
+///
+/// All methods except [BrowserClient::connect] return a promise.
+#[wasm_bindgen]
+pub struct BrowserClient {
+    client: Client,
+}
+
+#[wasm_bindgen]
+impl BrowserClient {
+    /// Connect to the node at the WebSocket URL `url`.
+    pub async fn connect(url: String) -> Result<BrowserClient, JsValue> {
+        let url = url::Url::parse(&url).map_err(to_js_error)?;
+        let config = ClientConfig::from_url(&url).map_err(to_js_error)?;
+        let client = Client::create(config).await.map_err(to_js_error)?;
+        Ok(BrowserClient { client })
+    }
+
+    /// Sign `message` with the key pair derived from the secret URI `seed` and submit it.
+    /// Resolves to the transaction hash, the block hash and the error of the transaction once it
+    /// is included in a block.
+    ///
+    /// `message` is an object with a `type` field that names the message and the fields of the
+    /// message in camel case.
+    #[wasm_bindgen(js_name = signAndSubmitMessage)]
+    pub fn sign_and_submit_message(&self, seed: String, message: JsValue, fee: String) -> Promise {
+        let client = self.client.clone();
+        let message = message.into_serde::<AnyMessage>();
+        future_to_promise(async move {
+            let author = ed25519::Pair::from_string(&seed, None)
+                .map_err(|error| JsValue::from_str(&format!("Invalid seed: {:?}", error)))?;
+            let message = message.map_err(to_js_error)?;
+            let fee = fee.parse::<Balance>().map_err(to_js_error)?;
+            let tx_included = match message {
+                AnyMessage::RegisterOrg(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::UnregisterOrg(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::RegisterUser(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::UnregisterUser(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::RegisterMember(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::RegisterProject(message) => {
+                    submit(&client, &author, message, fee).await
+                }
+                AnyMessage::UnregisterProject(message) => {
+                    submit(&client, &author, message, fee).await
+                }
+                AnyMessage::Transfer(message) => submit(&client, &author, message, fee).await,
+                AnyMessage::TransferFromOrg(message) => {
+                    submit(&client, &author, message, fee).await
+                }
+            }?;
+            to_js_value(&SubmitResult {
+                tx_hash: tx_included.tx_hash,
+                block: tx_included.block,
+                error: tx_included.result.err().map(|error| error.to_string()),
+            })
+        })
+    }
+
+    /// Resolves to the free balance of the account as a string.
+    #[wasm_bindgen(js_name = freeBalance)]
+    pub fn free_balance(&self, address: String) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let account_id = parse_address(&address)?;
+            let balance = client
+                .free_balance(&account_id)
+                .await
+                .map_err(to_js_error)?;
+            Ok(JsValue::from_str(&balance.to_string()))
+        })
+    }
+
+    #[wasm_bindgen(js_name = accountNonce)]
+    pub fn account_nonce(&self, address: String) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let account_id = parse_address(&address)?;
+            let nonce = client
+                .account_nonce(&account_id)
+                .await
+                .map_err(to_js_error)?;
+            Ok(JsValue::from(nonce))
+        })
+    }
+
+    /// Resolves to `"available"`, `"taken"`, `"retired"` or `"reserved"`.
+    #[wasm_bindgen(js_name = getIdStatus)]
+    pub fn get_id_status(&self, id: String) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let id = parse_id(&id)?;
+            let status = client.get_id_status(&id).await.map_err(to_js_error)?;
+            to_js_value(&status)
+        })
+    }
+
+    /// Resolves to `null` if the org does not exist.
+    #[wasm_bindgen(js_name = getOrg)]
+    pub fn get_org(&self, id: String) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let id = parse_id(&id)?;
+            let org = client.get_org(id).await.map_err(to_js_error)?;
+            to_js_value(&org.map(|org| Org {
+                account_id: org.account_id().to_ss58check(),
+                members: org.members().clone(),
+                projects: org.projects().clone(),
+            }))
+        })
+    }
+
+    /// Resolves to `null` if the user does not exist.
+    #[wasm_bindgen(js_name = getUser)]
+    pub fn get_user(&self, id: String) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let id = parse_id(&id)?;
+            let user = client.get_user(id).await.map_err(to_js_error)?;
+            to_js_value(&user.map(|user| User {
+                account_id: user.account_id().to_ss58check(),
+                projects: user.projects().clone(),
+            }))
+        })
+    }
+
+    #[wasm_bindgen(js_name = listOrgs)]
+    pub fn list_orgs(&self) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let orgs = client.list_orgs().await.map_err(to_js_error)?;
+            to_js_value(&orgs)
+        })
+    }
+
+    #[wasm_bindgen(js_name = listUsers)]
+    pub fn list_users(&self) -> Promise {
+        let client = self.client.clone();
+        future_to_promise(async move {
+            let users = client.list_users().await.map_err(to_js_error)?;
+            to_js_value(&users)
+        })
+    }
+}
+
+/// Messages that can be submitted with [BrowserClient::sign_and_submit_message].
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum AnyMessage {
+    RegisterOrg(message::RegisterOrg),
+    UnregisterOrg(message::UnregisterOrg),
+    RegisterUser(message::RegisterUser),
+    UnregisterUser(message::UnregisterUser),
+    RegisterMember(message::RegisterMember),
+    RegisterProject(message::RegisterProject),
+    UnregisterProject(message::UnregisterProject),
+    Transfer(message::Transfer),
+    TransferFromOrg(message::TransferFromOrg),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubmitResult {
+    tx_hash: TxHash,
+    block: BlockHash,
+    /// Set if the transaction failed in the runtime.
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Org {
+    account_id: String,
+    members: Vec<Id>,
+    projects: Vec<ProjectName>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    account_id: String,
+    projects: Vec<ProjectName>,
+}
+
+async fn submit<Message_: Message>(
+    client: &Client,
+    author: &ed25519::Pair,
+    message: Message_,
+    fee: Balance,
+) -> Result<TransactionIncluded, JsValue> {
+    client
+        .sign_and_submit_message(author, message, fee)
+        .await
+        .map_err(to_js_error)?
+        .await
+        .map_err(to_js_error)
+}
+
+fn parse_address(address: &str) -> Result<AccountId, JsValue> {
+    parse_ss58_address(address)
+        .map_err(|error| JsValue::from_str(&format!("Invalid address: {:?}", error)))
+}
+
+fn parse_id(id: &str) -> Result<Id, JsValue> {
+    Id::try_from(id).map_err(|error| JsValue::from_str(&format!("Invalid id: {:?}", error)))
+}
+
+fn to_js_value(value: &impl Serialize) -> Result<JsValue, JsValue> {
+    JsValue::from_serde(value).map_err(to_js_error)
+}
+
+fn to_js_error(error: impl std::fmt::Display) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
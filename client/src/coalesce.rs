@@ -0,0 +1,187 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Share state reads that are in flight between concurrent callers.
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use futures::channel::oneshot;
+use futures::future::{BoxFuture, Future, FutureExt as _, Shared};
+
+use crate::interface::*;
+
+/// Storage key and block of a state read.
+type Request = (Vec<u8>, Option<BlockHash>);
+
+/// Resolves to the value read by the leading request or to `None` if the leading request failed
+/// or was dropped.
+type InFlight = Shared<BoxFuture<'static, Option<Option<Vec<u8>>>>>;
+
+/// Coalesces identical concurrent state reads into one backend request.
+///
+/// The first caller for a key and block becomes the leader and reads from the backend. Callers
+/// that ask for the same key and block while the leader is in flight wait for the leader’s value.
+/// If the leader fails, the waiting callers read from the backend themselves so that every caller
+/// gets its own [Error].
+#[derive(Default)]
+pub(crate) struct FetchCoalescer {
+    in_flight: Mutex<HashMap<Request, InFlight>>,
+}
+
+impl FetchCoalescer {
+    /// Read the value for `key` at `block_hash` with `fetch` unless an identical read is already
+    /// in flight.
+    ///
+    /// `fetch` is called by the leader and by waiting callers if the leader fails.
+    pub async fn fetch<F>(
+        &self,
+        key: &[u8],
+        block_hash: Option<BlockHash>,
+        fetch: impl Fn() -> F,
+    ) -> Result<Option<Vec<u8>>, Error>
+    where
+        F: Future<Output = Result<Option<Vec<u8>>, Error>>,
+    {
+        let request = (key.to_vec(), block_hash);
+        let leader = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get(&request) {
+                Some(value) => Err(value.clone()),
+                None => {
+                    let (sender, receiver) = oneshot::channel();
+                    let value = receiver.map(Result::ok).boxed().shared();
+                    in_flight.insert(request.clone(), value);
+                    Ok(Leader {
+                        coalescer: self,
+                        request,
+                        sender: Some(sender),
+                    })
+                }
+            }
+        };
+
+        match leader {
+            Ok(mut leader) => {
+                let result = fetch().await;
+                if let Ok(value) = &result {
+                    leader.resolve(value.clone());
+                }
+                result
+            }
+            Err(in_flight) => match in_flight.await {
+                Some(value) => Ok(value),
+                None => fetch().await,
+            },
+        }
+    }
+}
+
+/// Request that reads from the backend on behalf of all identical concurrent requests.
+///
+/// Removes the request from [FetchCoalescer::in_flight] when dropped. If the value was not
+/// resolved at that point, the waiting requests are notified by dropping the sender.
+struct Leader<'a> {
+    coalescer: &'a FetchCoalescer,
+    request: Request,
+    sender: Option<oneshot::Sender<Option<Vec<u8>>>>,
+}
+
+impl Leader<'_> {
+    fn resolve(&mut self, value: Option<Vec<u8>>) {
+        self.remove_in_flight();
+        if let Some(sender) = self.sender.take() {
+            // Waiting requests may have been dropped in the meantime.
+            let _ = sender.send(value);
+        }
+    }
+
+    fn remove_in_flight(&self) {
+        self.coalescer
+            .in_flight
+            .lock()
+            .unwrap()
+            .remove(&self.request);
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        if self.sender.is_some() {
+            self.remove_in_flight();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn coalesce_identical_fetches() {
+        let coalescer = FetchCoalescer::default();
+        let calls = AtomicUsize::new(0);
+        let fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            futures_timer::Delay::new(Duration::from_millis(10)).await;
+            Ok(Some(vec![1, 2, 3]))
+        };
+
+        let fetches = (0..5).map(|_| coalescer.fetch(b"key", None, fetch));
+        let values = future::try_join_all(fetches).await.unwrap();
+
+        assert_eq!(values, vec![Some(vec![1, 2, 3]); 5]);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(coalescer.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[async_std::test]
+    async fn different_blocks_are_not_coalesced() {
+        let coalescer = FetchCoalescer::default();
+        let calls = AtomicUsize::new(0);
+        let fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            futures_timer::Delay::new(Duration::from_millis(10)).await;
+            Ok(None)
+        };
+
+        let block_hashes = vec![None, Some(BlockHash::repeat_byte(1))];
+        let fetches = block_hashes
+            .into_iter()
+            .map(|block_hash| coalescer.fetch(b"key", block_hash, fetch));
+        future::try_join_all(fetches).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[async_std::test]
+    async fn failed_leader() {
+        let coalescer = FetchCoalescer::default();
+        let calls = AtomicUsize::new(0);
+        let fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            futures_timer::Delay::new(Duration::from_millis(10)).await;
+            Err(Error::InvalidTransaction)
+        };
+
+        let fetches = (0..3).map(|_| coalescer.fetch(b"key", None, fetch));
+        let results = future::join_all(fetches).await;
+
+        assert!(results.iter().all(Result::is_err));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,376 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Check that the runtime of a chain encodes calls, events, and storage like the runtime the
+//! client was built with.
+//!
+//! The client constructs extrinsics, decodes events, and reads storage with the types of the
+//! runtime it was built with. If the module or call indices of the chain differ, extrinsics are
+//! rejected or, worse, interpreted as a different call. If the storage entries differ, reading
+//! state fails with obscure decoding errors.
+use std::collections::HashMap;
+
+use frame_metadata::{StorageEntryMetadata, StorageEntryType};
+use parity_scale_codec::Encode as _;
+use radicle_registry_runtime::{Runtime, VERSION};
+
+use crate::constants::{decode_metadata, decoded};
+use crate::interface::*;
+
+/// Modules that the client constructs calls for, decodes events of, or reads storage of.
+const CHECKED_MODULES: &[&str] = &["System", "Balances", "Sudo", "Registry"];
+
+/// Calls, events, and storage entries of a runtime module as they determine the encoding of
+/// extrinsics, events, and state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ModuleLayout {
+    /// Index of the module in the runtime `Call` enum.
+    call_index: Option<usize>,
+    /// Call names in the order of their indices.
+    calls: Vec<String>,
+    /// Index of the module in the runtime `Event` enum.
+    event_index: Option<usize>,
+    /// Event names in the order of their indices.
+    events: Vec<String>,
+    /// Prefix of the storage keys of the module.
+    storage_prefix: Option<String>,
+    /// Descriptions of the storage entries including hashers and types by entry name.
+    storage: HashMap<String, String>,
+}
+
+/// Check that the chain with the given SCALE encoded runtime metadata and spec version is
+/// compatible with the client. Returns [Error::IncompatibleChain] listing all differences
+/// otherwise.
+///
+/// The module indices of [CHECKED_MODULES] must be equal. The calls of a module only need to agree
+/// on the calls both runtimes know of so that calls can be added to the end of a module. The
+/// events of the chain must be known to the client so that it can decode them. The storage
+/// entries of the client must exist on the chain with the same hashers and types. The chain may
+/// have additional storage entries.
+///
+/// A chain running an older spec version than the client may lack storage entries that were
+/// added since. Reading such an entry returns its default value, so they are not reported. See
+/// [crate::version] for the supported spec versions.
+pub(crate) fn check_compatibility(
+    chain_metadata: &[u8],
+    chain_spec_version: u32,
+) -> Result<(), Error> {
+    let expected = module_layouts(&Runtime::metadata().encode())?;
+    let found = module_layouts(chain_metadata)?;
+    let allow_missing_storage = chain_spec_version < VERSION.spec_version;
+    let differences = CHECKED_MODULES
+        .iter()
+        .flat_map(|name| {
+            let expected = expected
+                .get(*name)
+                .expect("Checked module is part of the runtime");
+            module_differences(name, expected, found.get(*name), allow_missing_storage)
+        })
+        .collect::<Vec<_>>();
+    if differences.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::IncompatibleChain { differences })
+    }
+}
+
+fn module_differences(
+    name: &str,
+    expected: &ModuleLayout,
+    found: Option<&ModuleLayout>,
+    allow_missing_storage: bool,
+) -> Vec<String> {
+    let found = match found {
+        Some(found) => found,
+        None => return vec![format!("module {} is missing", name)],
+    };
+    let mut differences = Vec::new();
+    if found.call_index != expected.call_index {
+        differences.push(format!(
+            "{} call index is {:?}, expected {:?}",
+            name, found.call_index, expected.call_index
+        ));
+    }
+    if !found.calls.starts_with(&expected.calls) && !expected.calls.starts_with(&found.calls) {
+        differences.push(format!(
+            "{} calls are [{}], expected [{}]",
+            name,
+            found.calls.join(", "),
+            expected.calls.join(", ")
+        ));
+    }
+    if found.event_index != expected.event_index {
+        differences.push(format!(
+            "{} event index is {:?}, expected {:?}",
+            name, found.event_index, expected.event_index
+        ));
+    }
+    if !expected.events.starts_with(&found.events) {
+        differences.push(format!(
+            "{} events are [{}], expected [{}]",
+            name,
+            found.events.join(", "),
+            expected.events.join(", ")
+        ));
+    }
+    if found.storage_prefix != expected.storage_prefix {
+        differences.push(format!(
+            "{} storage prefix is {:?}, expected {:?}",
+            name, found.storage_prefix, expected.storage_prefix
+        ));
+    }
+    let mut expected_storage = expected.storage.iter().collect::<Vec<_>>();
+    expected_storage.sort();
+    for (entry, expected_entry) in expected_storage {
+        match found.storage.get(entry) {
+            None if allow_missing_storage => {}
+            None => differences.push(format!("{} storage {} is missing", name, entry)),
+            Some(found_entry) if found_entry != expected_entry => differences.push(format!(
+                "{} storage {} is {}, expected {}",
+                name, entry, found_entry, expected_entry
+            )),
+            Some(_) => {}
+        }
+    }
+    differences
+}
+
+/// Extract the [ModuleLayout] of all modules from SCALE encoded runtime metadata.
+fn module_layouts(metadata: &[u8]) -> Result<HashMap<String, ModuleLayout>, Error> {
+    let modules = decoded(decode_metadata(metadata)?.modules);
+
+    let mut layouts = HashMap::new();
+    let mut next_call_index = 0;
+    let mut next_event_index = 0;
+    for module in modules {
+        let calls = module.calls.map(|calls| {
+            decoded(calls)
+                .into_iter()
+                .map(|call| decoded(call.name))
+                .collect::<Vec<_>>()
+        });
+        let events = module.event.map(|events| {
+            decoded(events)
+                .into_iter()
+                .map(|event| decoded(event.name))
+                .collect::<Vec<_>>()
+        });
+        let storage = module.storage.map(decoded);
+        let layout = ModuleLayout {
+            call_index: calls.as_ref().map(|_| next_call_index),
+            calls: calls.unwrap_or_default(),
+            event_index: events.as_ref().map(|_| next_event_index),
+            events: events.unwrap_or_default(),
+            storage_prefix: storage
+                .as_ref()
+                .map(|storage| decoded(storage.prefix.clone())),
+            storage: storage
+                .map(|storage| {
+                    decoded(storage.entries)
+                        .into_iter()
+                        .map(|entry| (decoded(entry.name.clone()), describe_storage_entry(entry)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        if layout.call_index.is_some() {
+            next_call_index += 1;
+        }
+        if layout.event_index.is_some() {
+            next_event_index += 1;
+        }
+        layouts.insert(decoded(module.name), layout);
+    }
+    Ok(layouts)
+}
+
+/// Describe the modifier, the hashers, and the key and value types of a storage entry.
+///
+/// Two entries with the same description are read in the same way by the client.
+fn describe_storage_entry(entry: StorageEntryMetadata) -> String {
+    let ty = match entry.ty {
+        StorageEntryType::Plain(value) => format!("value {}", decoded(value)),
+        StorageEntryType::Map {
+            hasher, key, value, ..
+        } => format!("map {:?}({}) => {}", hasher, decoded(key), decoded(value)),
+        StorageEntryType::DoubleMap {
+            hasher,
+            key1,
+            key2,
+            value,
+            key2_hasher,
+        } => format!(
+            "double map {:?}({}), {:?}({}) => {}",
+            hasher,
+            decoded(key1),
+            key2_hasher,
+            decoded(key2),
+            decoded(value)
+        ),
+    };
+    format!("{:?} {}", entry.modifier, ty)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use frame_metadata::{
+        DecodeDifferent, RuntimeMetadata, RuntimeMetadataPrefixed, StorageHasher,
+    };
+    use parity_scale_codec::Decode as _;
+
+    /// Runtime metadata of the client runtime with the given modification applied to the list of
+    /// modules.
+    fn modified_metadata(modify: impl FnOnce(&mut Vec<frame_metadata::ModuleMetadata>)) -> Vec<u8> {
+        let mut metadata =
+            RuntimeMetadataPrefixed::decode(&mut &Runtime::metadata().encode()[..]).unwrap();
+        match metadata.1 {
+            RuntimeMetadata::V11(ref mut metadata) => match metadata.modules {
+                DecodeDifferent::Decoded(ref mut modules) => modify(modules),
+                DecodeDifferent::Encode(_) => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+        metadata.encode()
+    }
+
+    #[test]
+    fn client_runtime_is_compatible() {
+        check_compatibility(&Runtime::metadata().encode(), VERSION.spec_version).unwrap();
+    }
+
+    #[test]
+    fn missing_module() {
+        let metadata = modified_metadata(|modules| {
+            modules.retain(|module| decoded(module.name.clone()) != "Sudo")
+        });
+        match check_compatibility(&metadata, VERSION.spec_version) {
+            Err(Error::IncompatibleChain { differences }) => {
+                assert!(differences.contains(&"module Sudo is missing".to_string()));
+                // Removing a module shifts the indices of the registry module.
+                assert!(differences
+                    .iter()
+                    .any(|difference| difference.starts_with("Registry call index")));
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn reordered_calls() {
+        let metadata = modified_metadata(|modules| {
+            let registry = modules
+                .iter_mut()
+                .find(|module| decoded(module.name.clone()) == "Registry")
+                .unwrap();
+            if let Some(DecodeDifferent::Decoded(ref mut calls)) = registry.calls {
+                calls.reverse();
+            }
+        });
+        match check_compatibility(&metadata, VERSION.spec_version) {
+            Err(Error::IncompatibleChain { differences }) => {
+                assert_eq!(differences.len(), 1);
+                assert!(differences[0].starts_with("Registry calls are"));
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+
+    /// Runtime metadata of the client runtime with the given modification applied to the storage
+    /// entries of the registry module.
+    fn modified_registry_storage(modify: impl FnOnce(&mut Vec<StorageEntryMetadata>)) -> Vec<u8> {
+        modified_metadata(|modules| {
+            let registry = modules
+                .iter_mut()
+                .find(|module| decoded(module.name.clone()) == "Registry")
+                .unwrap();
+            if let Some(DecodeDifferent::Decoded(ref mut storage)) = registry.storage {
+                if let DecodeDifferent::Decoded(ref mut entries) = storage.entries {
+                    modify(entries)
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn missing_storage_entry() {
+        let metadata = modified_registry_storage(|entries| {
+            entries.retain(|entry| decoded(entry.name.clone()) != "Orgs1")
+        });
+        match check_compatibility(&metadata, VERSION.spec_version) {
+            Err(Error::IncompatibleChain { differences }) => {
+                assert_eq!(
+                    differences,
+                    vec!["Registry storage Orgs1 is missing".to_string()]
+                );
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+
+    /// Test that a chain running the previous runtime is compatible even though it lacks the
+    /// storage entries, calls, and events that were added since.
+    #[test]
+    fn previous_runtime_without_new_entries() {
+        let metadata = modified_metadata(|modules| {
+            let registry = modules
+                .iter_mut()
+                .find(|module| decoded(module.name.clone()) == "Registry")
+                .unwrap();
+            if let Some(DecodeDifferent::Decoded(ref mut storage)) = registry.storage {
+                if let DecodeDifferent::Decoded(ref mut entries) = storage.entries {
+                    entries.retain(|entry| decoded(entry.name.clone()) != "ReservedProjectNames1");
+                }
+            }
+            if let Some(DecodeDifferent::Decoded(ref mut calls)) = registry.calls {
+                calls.pop();
+            }
+            if let Some(DecodeDifferent::Decoded(ref mut events)) = registry.event {
+                events.pop();
+            }
+        });
+        check_compatibility(&metadata, VERSION.spec_version - 1).unwrap();
+    }
+
+    #[test]
+    fn changed_storage_hasher() {
+        let metadata = modified_registry_storage(|entries| {
+            let users = entries
+                .iter_mut()
+                .find(|entry| decoded(entry.name.clone()) == "Users1")
+                .unwrap();
+            if let StorageEntryType::Map { ref mut hasher, .. } = users.ty {
+                *hasher = StorageHasher::Twox64Concat;
+            }
+        });
+        match check_compatibility(&metadata, VERSION.spec_version) {
+            Err(Error::IncompatibleChain { differences }) => {
+                assert_eq!(differences.len(), 1);
+                assert!(differences[0].starts_with("Registry storage Users1 is"));
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn additional_storage_entry() {
+        let metadata = modified_registry_storage(|entries| {
+            let mut entry = entries[0].clone();
+            entry.name = DecodeDifferent::Decoded("Unknown1".to_string());
+            entries.push(entry);
+        });
+        check_compatibility(&metadata, VERSION.spec_version).unwrap();
+    }
+}
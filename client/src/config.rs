@@ -0,0 +1,171 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Provides [ClientConfig] to configure the connection to a node.
+use std::time::Duration;
+use url::Url;
+
+use crate::Error;
+
+/// Port of the RPC websocket server of a node. Default for [ClientConfig::port].
+pub const DEFAULT_RPC_PORT: u16 = 9944;
+
+/// Default for [ClientConfig::timeout].
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default for [ClientConfig::reconnect_attempts].
+pub const DEFAULT_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// Default for [ClientConfig::reconnect_backoff].
+pub const DEFAULT_RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Configuration of the connection to a node. See [crate::Client::create].
+///
+/// A configuration with default values is created from a [url::Host] with [ClientConfig::new] or
+/// `From<url::Host>`. A configuration can also be parsed from a URL with
+/// [ClientConfig::from_url].
+///
+/// ```
+/// # use radicle_registry_client::*;
+/// let host = url::Host::parse("rpc.example.com").unwrap();
+/// let config = ClientConfig {
+///     port: 8443,
+///     use_tls: true,
+///     ..ClientConfig::new(host)
+/// };
+/// assert_eq!(config.url().as_str(), "wss://rpc.example.com:8443/");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClientConfig {
+    /// Host of the node.
+    pub host: url::Host,
+
+    /// Port of the RPC server of the node.
+    pub port: u16,
+
+    /// Connect with TLS, that is with `wss` instead of `ws`. TLS is not supported for HTTP.
+    pub use_tls: bool,
+
+    /// Send every request with HTTP instead of using a websocket.
+    ///
+    /// HTTP does not support subscriptions. Instead the node is polled for new blocks and for the
+    /// inclusion of submitted transactions.
+    pub use_http: bool,
+
+    /// Time to wait for the connection to the node to be established.
+    pub timeout: Duration,
+
+    /// Number of attempts to reconnect to the node if the connection fails. Zero disables
+    /// reconnecting.
+    ///
+    /// Requests that failed because of the connection are sent again after reconnecting.
+    /// Transactions that were submitted before the connection failed are followed by polling the
+    /// node.
+    pub reconnect_attempts: u32,
+
+    /// Time to wait after the first failed reconnection attempt. The time doubles after every
+    /// further attempt.
+    pub reconnect_backoff: Duration,
+}
+
+impl ClientConfig {
+    /// Configuration for a websocket connection without TLS to [DEFAULT_RPC_PORT] of `host`.
+    pub fn new(host: url::Host) -> Self {
+        ClientConfig {
+            host,
+            port: DEFAULT_RPC_PORT,
+            use_tls: false,
+            use_http: false,
+            timeout: DEFAULT_CONNECT_TIMEOUT,
+            reconnect_attempts: DEFAULT_RECONNECT_ATTEMPTS,
+            reconnect_backoff: DEFAULT_RECONNECT_BACKOFF,
+        }
+    }
+
+    /// Create a configuration from a `ws`, `wss` or `http` URL. If the URL has no port we use
+    /// [DEFAULT_RPC_PORT].
+    ///
+    /// Fails with [Error::UnsupportedUrlScheme] for other schemes and with
+    /// [Error::NodeUrlHostMissing] if the URL has no host.
+    pub fn from_url(url: &Url) -> Result<Self, Error> {
+        let (use_tls, use_http) = match url.scheme() {
+            "ws" => (false, false),
+            "wss" => (true, false),
+            "http" => (false, true),
+            scheme => {
+                return Err(Error::UnsupportedUrlScheme {
+                    scheme: scheme.to_string(),
+                })
+            }
+        };
+        let host = url.host().ok_or_else(|| Error::NodeUrlHostMissing {
+            url: url.to_string(),
+        })?;
+        Ok(ClientConfig {
+            port: url.port().unwrap_or(DEFAULT_RPC_PORT),
+            use_tls,
+            use_http,
+            ..ClientConfig::new(host.to_owned())
+        })
+    }
+
+    /// URL of the RPC endpoint of the node.
+    pub fn url(&self) -> Url {
+        let scheme = match (self.use_http, self.use_tls) {
+            (false, false) => "ws",
+            (false, true) => "wss",
+            (true, false) => "http",
+            (true, true) => "https",
+        };
+        Url::parse(&format!("{}://{}:{}", scheme, self.host, self.port)).expect("Is valid url; qed")
+    }
+}
+
+impl From<url::Host> for ClientConfig {
+    fn from(host: url::Host) -> Self {
+        ClientConfig::new(host)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_url() {
+        let url = Url::parse("http://127.0.0.1:9933").unwrap();
+        let config = ClientConfig::from_url(&url).unwrap();
+        assert_eq!(
+            config,
+            ClientConfig {
+                port: 9933,
+                use_http: true,
+                ..ClientConfig::new(url::Host::parse("127.0.0.1").unwrap())
+            }
+        );
+        assert_eq!(config.url(), url);
+
+        let url = Url::parse("wss://rpc.example.com").unwrap();
+        let config = ClientConfig::from_url(&url).unwrap();
+        assert_eq!(config.port, DEFAULT_RPC_PORT);
+        assert!(config.use_tls);
+
+        let url = Url::parse("https://rpc.example.com").unwrap();
+        match ClientConfig::from_url(&url) {
+            Err(Error::UnsupportedUrlScheme { scheme }) => assert_eq!(scheme, "https"),
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Read runtime constants from the runtime metadata of the chain.
+use std::time::Duration;
+
+use frame_metadata::{
+    DecodeDifferent, ModuleMetadata, RuntimeMetadata, RuntimeMetadataLastVersion,
+    RuntimeMetadataPrefixed,
+};
+use parity_scale_codec::Decode;
+use sp_runtime::Permill;
+
+use crate::interface::*;
+
+/// Name of the registry module in the runtime metadata.
+const REGISTRY_MODULE: &str = "Registry";
+
+/// Name of the balances module in the runtime metadata.
+const BALANCES_MODULE: &str = "Balances";
+
+/// Constants of the chain runtime. See [ClientT::chain_constants].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChainConstants {
+    /// Target time between two blocks.
+    pub target_block_time: Duration,
+
+    /// Funds credited for every block.
+    pub block_reward: Balance,
+
+    /// Fee charged for registering an org or a user.
+    pub registration_fee: Balance,
+
+    /// Minimum fee a transaction must pay to be accepted by the chain.
+    pub minimum_tx_fee: Balance,
+
+    /// Share of every transaction fee that is burned instead of credited to the block author.
+    pub fee_burn_share: Permill,
+
+    /// Minimum balance an account must hold to exist.
+    pub existential_deposit: Balance,
+}
+
+impl ChainConstants {
+    /// Extract the constants from SCALE encoded runtime metadata.
+    pub(crate) fn from_metadata(metadata: &[u8]) -> Result<Self, Error> {
+        let modules = decoded(decode_metadata(metadata)?.modules);
+
+        let target_block_time_ms: u64 = constant(&modules, REGISTRY_MODULE, "TargetBlockTime")?;
+        Ok(ChainConstants {
+            target_block_time: Duration::from_millis(target_block_time_ms),
+            block_reward: constant(&modules, REGISTRY_MODULE, "BlockReward")?,
+            registration_fee: constant(&modules, REGISTRY_MODULE, "RegistrationFee")?,
+            minimum_tx_fee: constant(&modules, REGISTRY_MODULE, "MinimumTxFee")?,
+            fee_burn_share: constant(&modules, REGISTRY_MODULE, "FeeBurnShare")?,
+            existential_deposit: constant(&modules, BALANCES_MODULE, "ExistentialDeposit")?,
+        })
+    }
+}
+
+/// Find and decode the constant `name` declared by `module`.
+fn constant<T: Decode>(
+    modules: &[ModuleMetadata],
+    module: &'static str,
+    name: &'static str,
+) -> Result<T, Error> {
+    let value = modules
+        .iter()
+        .find(|module_metadata| decoded(module_metadata.name.clone()) == module)
+        .map(|module_metadata| decoded(module_metadata.constants.clone()))
+        .unwrap_or_default()
+        .into_iter()
+        .find(|constant| decoded(constant.name.clone()) == name)
+        .map(|constant| decoded(constant.value))
+        .ok_or(Error::RuntimeConstantMissing { module, name })?;
+    T::decode(&mut &value[..]).map_err(Error::MetadataDecoding)
+}
+
+/// Decode SCALE encoded runtime metadata.
+///
+/// Fails with [Error::UnsupportedMetadataVersion] if the metadata is not the latest version.
+pub(crate) fn decode_metadata(metadata: &[u8]) -> Result<RuntimeMetadataLastVersion, Error> {
+    let metadata =
+        RuntimeMetadataPrefixed::decode(&mut &metadata[..]).map_err(Error::MetadataDecoding)?;
+    match metadata.1 {
+        RuntimeMetadata::V11(metadata) => Ok(metadata),
+        _ => Err(Error::UnsupportedMetadataVersion),
+    }
+}
+
+/// Unwrap a metadata value obtained by decoding.
+///
+/// [DecodeDifferent::Encode] only occurs in metadata that was constructed by the runtime and never
+/// in decoded metadata.
+pub(crate) fn decoded<B: 'static, O: 'static>(value: DecodeDifferent<B, O>) -> O {
+    match value {
+        DecodeDifferent::Decoded(value) => value,
+        DecodeDifferent::Encode(_) => unreachable!("Decoded metadata is always `Decoded`"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parity_scale_codec::Encode as _;
+    use radicle_registry_runtime::{fees, registry, Runtime, TARGET_BLOCK_TIME_MS};
+
+    #[test]
+    fn chain_constants_from_metadata() {
+        let metadata = Runtime::metadata().encode();
+        let constants = ChainConstants::from_metadata(&metadata).unwrap();
+        assert_eq!(
+            constants,
+            ChainConstants {
+                target_block_time: Duration::from_millis(TARGET_BLOCK_TIME_MS),
+                block_reward: registry::BLOCK_REWARD,
+                registration_fee: fees::REGISTRATION_FEE,
+                minimum_tx_fee: fees::MINIMUM_TX_FEE,
+                fee_burn_share: fees::FEE_BURN_SHARE,
+                existential_deposit: 1,
+            }
+        );
+    }
+}
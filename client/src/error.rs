@@ -43,6 +43,34 @@ pub enum Error {
     #[error("Chain is running an incompatible runtime specification version {0}")]
     IncompatibleRuntimeVersion(u32),
 
+    /// The client cannot create transactions for the transaction version of the chain runtime.
+    /// See [crate::VersionCompatibility::ReadOnly].
+    #[error("Cannot create transactions for the runtime transaction version {0}")]
+    IncompatibleTransactionVersion(u32),
+
+    /// The chain stores a versioned storage entry of the registry in a version that the client
+    /// cannot read. `version` is `None` if the chain does not have the entry at all.
+    #[error("Chain stores {name} in an unsupported version {version:?}")]
+    IncompatibleStorageVersion {
+        name: &'static str,
+        version: Option<u32>,
+    },
+
+    /// Failed to decode the runtime metadata
+    #[error("Failed to decode runtime metadata")]
+    MetadataDecoding(#[source] CodecError),
+
+    /// The runtime metadata has a version that the client does not support
+    #[error("Unsupported runtime metadata version")]
+    UnsupportedMetadataVersion,
+
+    /// A runtime constant required by the client is missing from the runtime metadata
+    #[error("Runtime constant {module}::{name} is missing from the runtime metadata")]
+    RuntimeConstantMissing {
+        module: &'static str,
+        name: &'static str,
+    },
+
     /// Failed to extract required events for a transaction
     #[error("Failed to extract required events for transaction {tx_hash}")]
     EventExtraction {
@@ -60,13 +88,84 @@ pub enum Error {
         tx_hash: crate::TxHash,
     },
 
+    /// The block data returned by the node is inconsistent with the block header.
+    ///
+    /// Only checked if event verification is enabled. See
+    /// [crate::Client::create_with_event_verification]. The node is either faulty or malicious.
+    #[error("Failed to verify data of block {block_hash}: {reason}")]
+    BlockDataVerification {
+        block_hash: crate::BlockHash,
+        reason: &'static str,
+    },
+
+    /// A storage proof could not be verified against the block header. See
+    /// [crate::StorageReadProof::verify].
+    #[error("Failed to verify storage proof for block {block_hash}: {reason}")]
+    StorageProofVerification {
+        block_hash: crate::BlockHash,
+        reason: &'static str,
+    },
+
+    /// A transaction found in a block could not be decoded.
+    #[error("Failed to decode transaction {tx_hash}")]
+    TransactionDecoding {
+        tx_hash: crate::TxHash,
+        #[source]
+        error: crate::TransactionDecodingError,
+    },
+
+    /// The [crate::Signer] failed to sign a transaction.
+    #[error("Failed to sign transaction")]
+    Signing(#[source] crate::SignerError),
+
+    /// The org a client operation refers to is not registered.
+    #[error("Org {org_id} does not exist")]
+    OrgMissing { org_id: crate::Id },
+
     #[error("Could not obtain header of tip of best chain")]
     BestChainTipHeaderMissing,
 
+    /// The best chain did not reach the block number before the timeout elapsed. See
+    /// [crate::Client::wait_for_block].
+    #[error("Timed out waiting for block #{block_number}")]
+    BlockWaitTimeout { block_number: crate::BlockNumber },
+
+    /// The subscription to new blocks terminated prematurely.
+    #[error("Block subscription terminated")]
+    BlockSubscriptionTerminated,
+
+    /// The scheme of a node URL is not supported. See [crate::ClientConfig::from_url].
+    #[error("Unsupported node URL scheme \"{scheme}\"")]
+    UnsupportedUrlScheme { scheme: String },
+
+    /// A node URL has no host. See [crate::ClientConfig::from_url].
+    #[error("Node URL {url} has no host")]
+    NodeUrlHostMissing { url: String },
+
+    /// The connection to the node was not established within [crate::ClientConfig::timeout].
+    #[error("Connecting to the node timed out after {timeout:?}")]
+    ConnectionTimeout { timeout: std::time::Duration },
+
     /// Block could not be found.
     #[error("Block {block_hash} could not be found")]
     BlockMissing { block_hash: crate::BlockHash },
 
+    /// The block that included a transaction was removed from the best chain before the
+    /// transaction was confirmed. See [crate::ConfirmationPolicy].
+    #[error("Block {block_hash} including transaction {tx_hash} was removed from the best chain")]
+    TransactionRetracted {
+        tx_hash: crate::TxHash,
+        block_hash: crate::BlockHash,
+    },
+
+    /// The runtime of the chain encodes calls, events, or storage differently than the runtime the
+    /// client was built with.
+    ///
+    /// Every entry of `differences` describes one mismatch of a module, call, or event index or
+    /// of a storage entry.
+    #[error("Chain is incompatible with the client: {}", .differences.join("; "))]
+    IncompatibleChain { differences: Vec<String> },
+
     /// Invalid response from the node for the `chain.block_hash` method.
     ///
     /// The node is violating the application protocol.
@@ -90,6 +189,16 @@ pub enum Error {
         tx_hash: crate::TxHash,
         tx_status: crate::backend::TransactionStatus,
     },
+
+    /// A transaction that has been included in a block will not be finalized.
+    ///
+    /// The transaction was dropped from the pool, replaced by another transaction or became
+    /// invalid after the block that included it was retracted.
+    #[error("Transaction {tx_hash} will not be finalized, status {tx_status:?}")]
+    TransactionNotFinalized {
+        tx_hash: crate::TxHash,
+        tx_status: crate::backend::TransactionStatus,
+    },
 }
 
 impl From<RpcError> for Error {
@@ -27,6 +27,41 @@ pub enum EventExtractionError {
     EventMissing,
 }
 
+/// JSON encoding of registry events for indexers and other consumers that store events outside of
+/// the chain.
+pub trait ToJson {
+    /// Encode the event as JSON. The event is encoded as an object with the variant name as the
+    /// only key. The value is the field for variants with one field, for example `{"OrgUpdated":
+    /// "monadic"}`, and an array of the fields otherwise. Account ids are SS58 addresses.
+    fn to_json(&self) -> String;
+}
+
+impl ToJson for event::Registry {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Registry events are serializable")
+    }
+}
+
+/// Return the registry events of the given events.
+pub fn registry_events(events: &[Event]) -> Vec<event::Registry> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::registry(event) => Some(event.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Return the registry event of the record or `None` if the record holds an event of a different
+/// module.
+pub fn registry_event(record: &Record) -> Option<&event::Registry> {
+    match &record.event {
+        Event::registry(event) => Some(event),
+        _ => None,
+    }
+}
+
 /// Looks for `ExtrinsicSuccess` and `ExtrinsicFailed` in the events and constructs the inner
 /// result accordingly. Returns an [EventExtractionError::ExstrinsicStatusMissing] error if none of
 /// these events is found.
@@ -53,3 +88,23 @@ fn extrinsic_result(event: &Event) -> Option<Result<(), DispatchError>> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::convert::TryFrom;
+    use radicle_registry_core::Id;
+
+    #[test]
+    fn registry_event_to_json() {
+        let org_id = Id::try_from("monadic").unwrap();
+        assert_eq!(
+            event::Registry::OrgUpdated(org_id).to_json(),
+            r#"{"OrgUpdated":"monadic"}"#
+        );
+        assert_eq!(
+            event::Registry::MigrationExecuted(1, 2, 3).to_json(),
+            r#"{"MigrationExecuted":[1,2,3]}"#
+        );
+    }
+}
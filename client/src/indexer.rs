@@ -0,0 +1,488 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Follow the finalized chain and write registry events and state changes to a [Sink].
+//!
+//! The [Indexer] writes one [IndexedBlock] per finalized block to its sink, starting at the
+//! genesis block for an empty sink and at the block after the last indexed block otherwise. If
+//! the chain the sink was built from is no longer the finalized chain the indexer removes the
+//! retracted blocks from the sink before it writes the blocks of the new chain.
+//!
+//! Implement [Sink] to write blocks to a database. [JsonLinesSink] writes one JSON object per
+//! block to a file.
+//!
+//! ```no_run
+//! # use radicle_registry_client::{indexer::*, Client};
+//! # async fn example(client: Client) -> Result<(), IndexerError> {
+//! let sink = JsonLinesSink::open("registry.jsonl")?;
+//! Indexer::new(client, sink).run().await
+//! # }
+//! ```
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::path::{Path, PathBuf};
+
+use futures::StreamExt as _;
+use serde_json::json;
+use sp_runtime::traits::Header as _;
+
+use radicle_registry_runtime::{call, Call as RuntimeCall};
+
+use crate::{event, interface::*, Client};
+
+/// Error returned by [Sink] implementations.
+pub type SinkError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IndexerError {
+    #[error("Failed to fetch data from the chain")]
+    Client(#[from] Error),
+
+    #[error("Failed to access the indexer sink")]
+    Sink(#[source] SinkError),
+}
+
+impl From<SinkError> for IndexerError {
+    fn from(error: SinkError) -> Self {
+        IndexerError::Sink(error)
+    }
+}
+
+/// Storage for the blocks written by an [Indexer].
+#[async_trait::async_trait]
+pub trait Sink: Send {
+    /// Return the hash of the stored block with the given number or `None` if there is no such
+    /// block.
+    async fn block_hash(&mut self, number: BlockNumber) -> Result<Option<BlockHash>, SinkError>;
+
+    /// Store `block`. The indexer writes blocks in ascending order without gaps starting at
+    /// block zero.
+    async fn write_block(&mut self, block: &IndexedBlock) -> Result<(), SinkError>;
+
+    /// Remove all blocks with a number greater than or equal to `from`.
+    async fn truncate(&mut self, from: BlockNumber) -> Result<(), SinkError>;
+}
+
+/// Registry data of a finalized block.
+#[derive(Clone, Debug)]
+pub struct IndexedBlock {
+    pub header: BlockHeader,
+
+    /// Signed transactions of the block.
+    pub transactions: Vec<TransactionInfo>,
+
+    /// Registry events deposited in the block.
+    pub events: Vec<IndexedEvent>,
+
+    /// State of the orgs, users and projects changed by the successful transactions of the
+    /// block after the block was applied.
+    pub state_changes: Vec<StateChange>,
+}
+
+/// A registry event deposited in an [IndexedBlock].
+#[derive(Clone, Debug)]
+pub struct IndexedEvent {
+    /// Index of the transaction that deposited the event. `None` for events deposited when the
+    /// block was initialized or finalized, for example [event::Registry::BlockRewardCredited].
+    pub transaction_index: Option<u32>,
+    pub event: event::Registry,
+}
+
+/// New state of an entity changed in an [IndexedBlock]. The state is `None` if the entity was
+/// removed.
+#[derive(Clone, Debug)]
+pub enum StateChange {
+    Org(Id, Option<state::Orgs1Data>),
+    User(Id, Option<state::Users1Data>),
+    Project(ProjectId, Option<state::Projects1Data>),
+}
+
+/// Entity that may be changed by a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Entity {
+    Org(Id),
+    User(Id),
+    Project(ProjectId),
+}
+
+/// Writes the finalized blocks of a chain to a [Sink].
+pub struct Indexer<S> {
+    client: Client,
+    sink: S,
+}
+
+impl<S: Sink> Indexer<S> {
+    pub fn new(client: Client, sink: S) -> Self {
+        Indexer { client, sink }
+    }
+
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Remove all blocks from the sink and index the finalized chain from genesis.
+    pub async fn replay(&mut self) -> Result<u32, IndexerError> {
+        self.sink.truncate(0).await?;
+        self.sync().await
+    }
+
+    /// Index all finalized blocks that are not in the sink and return the number of blocks
+    /// written.
+    ///
+    /// Blocks in the sink that are not part of the finalized chain are removed first.
+    pub async fn sync(&mut self) -> Result<u32, IndexerError> {
+        let finalized = self.client.at_best_finalized().await?;
+        let mut block_hash = finalized
+            .pinned_block()
+            .expect("Client returned by at_best_finalized is pinned");
+
+        // Walk back from the finalized block until we reach a block that is in the sink.
+        let mut headers = Vec::new();
+        loop {
+            let header = self
+                .client
+                .block_header(block_hash)
+                .await?
+                .ok_or(Error::BlockMissing { block_hash })?;
+            if self.sink.block_hash(header.number).await? == Some(block_hash) {
+                self.sink.truncate(header.number + 1).await?;
+                break;
+            }
+            let parent_hash = header.parent_hash;
+            let is_genesis = header.number == 0;
+            headers.push(header);
+            if is_genesis {
+                self.sink.truncate(0).await?;
+                break;
+            }
+            block_hash = parent_hash;
+        }
+
+        let mut written = 0;
+        for header in headers.into_iter().rev() {
+            let block = self.index_block(header).await?;
+            self.sink.write_block(&block).await?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Index the finalized chain and keep indexing new finalized blocks until an error occurs.
+    pub async fn run(mut self) -> Result<(), IndexerError> {
+        let mut finalized_blocks = self.client.subscribe_finalized_blocks().await?;
+        self.sync().await?;
+        while let Some(header) = finalized_blocks.next().await {
+            let header = header?;
+            if self.sink.block_hash(header.number).await? != Some(header.hash()) {
+                self.sync().await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn index_block(&self, header: BlockHeader) -> Result<IndexedBlock, IndexerError> {
+        let block_hash = header.hash();
+        let transactions = self
+            .client
+            .block_body(block_hash)
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })?;
+        let events = self
+            .client
+            .block_event_records(block_hash)
+            .await?
+            .iter()
+            .filter_map(|record| {
+                event::registry_event(record).map(|event| IndexedEvent {
+                    transaction_index: event::transaction_index(record),
+                    event: event.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let client = self.client.at(block_hash);
+        let mut state_changes = Vec::new();
+        for entity in changed_entities(&transactions) {
+            state_changes.push(match entity {
+                Entity::Org(id) => StateChange::Org(id.clone(), client.get_org(id).await?),
+                Entity::User(id) => StateChange::User(id.clone(), client.get_user(id).await?),
+                Entity::Project((name, domain)) => StateChange::Project(
+                    (name.clone(), domain.clone()),
+                    client.get_project(name, domain).await?,
+                ),
+            });
+        }
+
+        Ok(IndexedBlock {
+            header,
+            transactions,
+            events,
+            state_changes,
+        })
+    }
+}
+
+/// Return the entities changed by the successful transactions in the order of their first
+/// change.
+fn changed_entities(transactions: &[TransactionInfo]) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    for tx in transactions.iter().filter(|tx| tx.result.is_ok()) {
+        let from_events = event::registry_events(&tx.events)
+            .into_iter()
+            .flat_map(|event| match event {
+                event::Registry::ProjectTransferred(project_name, _, recipient) => vec![
+                    Entity::Project((project_name, ProjectDomain::User(recipient.clone()))),
+                    Entity::User(recipient),
+                ],
+                event::Registry::UserIdTransferred(user_id, _, _) => vec![Entity::User(user_id)],
+                _ => vec![],
+            });
+        for entity in call_entities(&tx.call).into_iter().chain(from_events) {
+            if !entities.contains(&entity) {
+                entities.push(entity);
+            }
+        }
+    }
+    entities
+}
+
+/// Return the entities a call changes if it succeeds.
+fn call_entities(call: &RuntimeCall) -> Vec<Entity> {
+    let registry_call = match call {
+        RuntimeCall::Registry(registry_call) => registry_call,
+        RuntimeCall::Sudo(call::Sudo::sudo(sudo_call)) => return call_entities(sudo_call),
+        _ => return vec![],
+    };
+    let project = |name: &ProjectName, domain: &ProjectDomain| {
+        let owner = match domain {
+            ProjectDomain::Org(org_id) => Entity::Org(org_id.clone()),
+            ProjectDomain::User(user_id) => Entity::User(user_id.clone()),
+        };
+        vec![Entity::Project((name.clone(), domain.clone())), owner]
+    };
+    match registry_call {
+        call::Registry::register_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::unregister_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::dissolve_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::update_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::register_member(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::transfer_org_membership(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::freeze_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::unfreeze_org(m) => vec![Entity::Org(m.org_id.clone())],
+        call::Registry::register_user(m) => vec![Entity::User(m.user_id.clone())],
+        call::Registry::unregister_user(m) => vec![Entity::User(m.user_id.clone())],
+        call::Registry::update_user(m) => vec![Entity::User(m.user_id.clone())],
+        call::Registry::register_project(m) => project(&m.project_name, &m.project_domain),
+        call::Registry::unregister_project(m) => project(&m.project_name, &m.project_domain),
+        call::Registry::accept_project_ownership(m) => {
+            project(&m.project_name, &ProjectDomain::User(m.user_id.clone()))
+        }
+        call::Registry::transfer_project(m) => [
+            project(&m.project_name, &m.from),
+            project(&m.project_name, &m.to),
+        ]
+        .concat(),
+        call::Registry::rename_project(m) => [
+            project(&m.old_name, &m.domain),
+            project(&m.new_name, &m.domain),
+        ]
+        .concat(),
+        _ => vec![],
+    }
+}
+
+impl IndexedBlock {
+    /// Encode the block as a JSON object.
+    ///
+    /// Events are encoded with [event::ToJson]. Messages of transactions are encoded as an object
+    /// with the message name in camel case as the only key, for example `{"registerOrg":
+    /// {"orgId": "monadic"}}`. Messages of other modules are `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                json!({
+                    "txHash": tx.tx_hash,
+                    "index": tx.index,
+                    "author": tx.author,
+                    "nonce": tx.nonce,
+                    "fee": tx.fee.to_string(),
+                    "message": call_json(&tx.call),
+                    "error": tx.result.as_ref().err().map(|error| error.to_string()),
+                })
+            })
+            .collect::<Vec<_>>();
+        let events = self
+            .events
+            .iter()
+            .map(|indexed| {
+                json!({
+                    "transactionIndex": indexed.transaction_index,
+                    "event": indexed.event,
+                })
+            })
+            .collect::<Vec<_>>();
+        let state_changes = self
+            .state_changes
+            .iter()
+            .map(|change| match change {
+                StateChange::Org(id, org) => json!({
+                    "org": id,
+                    "state": org.as_ref().map(|org| json!({
+                        "accountId": org.account_id(),
+                        "members": org.members(),
+                        "projects": org.projects(),
+                        "metadata": org.metadata(),
+                    })),
+                }),
+                StateChange::User(id, user) => json!({
+                    "user": id,
+                    "state": user.as_ref().map(|user| json!({
+                        "accountId": user.account_id(),
+                        "projects": user.projects(),
+                        "metadata": user.metadata(),
+                    })),
+                }),
+                StateChange::Project((name, domain), project) => json!({
+                    "project": { "name": name, "domain": domain },
+                    "state": project.as_ref().map(|project| json!({
+                        "metadata": project.metadata(),
+                        "registeredAt": project.registered_at(),
+                    })),
+                }),
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "number": self.header.number,
+            "hash": self.header.hash(),
+            "parentHash": self.header.parent_hash,
+            "transactions": transactions,
+            "events": events,
+            "stateChanges": state_changes,
+        })
+    }
+}
+
+/// Encode the registry message of `call`. See [IndexedBlock::to_json].
+fn call_json(call: &RuntimeCall) -> serde_json::Value {
+    let registry_call = match call {
+        RuntimeCall::Registry(registry_call) => registry_call,
+        RuntimeCall::Sudo(call::Sudo::sudo(sudo_call)) => return call_json(sudo_call),
+        _ => return serde_json::Value::Null,
+    };
+    match registry_call {
+        call::Registry::register_project(m) => json!({ "registerProject": m }),
+        call::Registry::register_member(m) => json!({ "registerMember": m }),
+        call::Registry::register_org(m) => json!({ "registerOrg": m }),
+        call::Registry::unregister_org(m) => json!({ "unregisterOrg": m }),
+        call::Registry::dissolve_org(m) => json!({ "dissolveOrg": m }),
+        call::Registry::register_user(m) => json!({ "registerUser": m }),
+        call::Registry::unregister_user(m) => json!({ "unregisterUser": m }),
+        call::Registry::transfer_from_org(m) => json!({ "transferFromOrg": m }),
+        call::Registry::transfer(m) => json!({ "transfer": m }),
+        call::Registry::propose_treasury_spend(m) => json!({ "proposeTreasurySpend": m }),
+        call::Registry::approve_treasury_spend(m) => json!({ "approveTreasurySpend": m }),
+        call::Registry::reject_treasury_spend(m) => json!({ "rejectTreasurySpend": m }),
+        call::Registry::cancel_treasury_spend(m) => json!({ "cancelTreasurySpend": m }),
+        call::Registry::unregister_project(m) => json!({ "unregisterProject": m }),
+        call::Registry::reserve_id(m) => json!({ "reserveId": m }),
+        call::Registry::cancel_id_reservation(m) => json!({ "cancelIdReservation": m }),
+        call::Registry::reserve_project_name(m) => json!({ "reserveProjectName": m }),
+        call::Registry::cancel_project_name_reservation(m) => {
+            json!({ "cancelProjectNameReservation": m })
+        }
+        call::Registry::transfer_project_ownership(m) => json!({ "transferProjectOwnership": m }),
+        call::Registry::accept_project_ownership(m) => json!({ "acceptProjectOwnership": m }),
+        call::Registry::update_org(m) => json!({ "updateOrg": m }),
+        call::Registry::update_user(m) => json!({ "updateUser": m }),
+        call::Registry::transfer_user_id(m) => json!({ "transferUserId": m }),
+        call::Registry::transfer_org_membership(m) => json!({ "transferOrgMembership": m }),
+        call::Registry::transfer_project(m) => json!({ "transferProject": m }),
+        call::Registry::rename_project(m) => json!({ "renameProject": m }),
+        call::Registry::freeze_org(m) => json!({ "freezeOrg": m }),
+        call::Registry::unfreeze_org(m) => json!({ "unfreezeOrg": m }),
+        _ => serde_json::Value::Null,
+    }
+}
+
+/// [Sink] that appends blocks as JSON objects to a file, one block per line. See
+/// [IndexedBlock::to_json] for the encoding.
+pub struct JsonLinesSink {
+    path: PathBuf,
+    file: File,
+    /// Hashes of the blocks in the file ordered by block number.
+    block_hashes: Vec<BlockHash>,
+}
+
+impl JsonLinesSink {
+    /// Open the file at `path` for appending. The file is created if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SinkError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let mut block_hashes = Vec::new();
+        for line in BufReader::new(File::open(&path)?).lines() {
+            let block: serde_json::Value = serde_json::from_str(&line?)?;
+            let hash = serde_json::from_value(block["hash"].clone())?;
+            block_hashes.push(hash);
+        }
+        Ok(JsonLinesSink {
+            path,
+            file,
+            block_hashes,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Sink for JsonLinesSink {
+    async fn block_hash(&mut self, number: BlockNumber) -> Result<Option<BlockHash>, SinkError> {
+        Ok(self.block_hashes.get(number as usize).copied())
+    }
+
+    async fn write_block(&mut self, block: &IndexedBlock) -> Result<(), SinkError> {
+        if block.header.number as usize != self.block_hashes.len() {
+            return Err(format!(
+                "Expected block {} but got block {}",
+                self.block_hashes.len(),
+                block.header.number
+            )
+            .into());
+        }
+        writeln!(self.file, "{}", block.to_json())?;
+        self.file.flush()?;
+        self.block_hashes.push(block.header.hash());
+        Ok(())
+    }
+
+    async fn truncate(&mut self, from: BlockNumber) -> Result<(), SinkError> {
+        let from = from as usize;
+        if from >= self.block_hashes.len() {
+            return Ok(());
+        }
+        let lines = BufReader::new(File::open(&self.path)?)
+            .lines()
+            .take(from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut file = File::create(&self.path)?;
+        for line in lines {
+            writeln!(file, "{}", line)?;
+        }
+        file.flush()?;
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.block_hashes.truncate(from);
+        Ok(())
+    }
+}
@@ -17,8 +17,12 @@
 //!
 //! The [ClientT] trait defines one method for each transaction of the registry ledger as well as
 //! methods to get the ledger state.
+use frame_support::traits::GetCallMetadata as _;
 use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use radicle_registry_runtime::Call as RuntimeCall;
 
+pub use frame_metadata::RuntimeMetadataLastVersion;
 pub use radicle_registry_core::*;
 
 pub use radicle_registry_runtime::{
@@ -27,11 +31,23 @@ pub use radicle_registry_runtime::{
 pub use sp_core::crypto::{
     Pair as CryptoPair, Public as CryptoPublic, SecretStringError as CryptoError,
 };
-pub use sp_core::{ed25519, H256};
+pub use sp_core::{ed25519, sr25519, H256};
 
+pub use crate::config::{
+    ClientConfig, DEFAULT_CONNECT_TIMEOUT, DEFAULT_RECONNECT_ATTEMPTS, DEFAULT_RECONNECT_BACKOFF,
+    DEFAULT_RPC_PORT,
+};
+pub use crate::constants::ChainConstants;
 pub use crate::error::Error;
 pub use crate::message::Message;
-pub use crate::transaction::{Transaction, TransactionExtra};
+pub use crate::org_balance::{OrgBalanceAlert, OrgBalanceUpdate};
+pub use crate::proof::{verify_storage_proof, StorageReadProof};
+pub use crate::signer::{Signer, SignerError};
+pub use crate::statement::{AccountStatementEntry, AccountStatementEntryKind};
+pub use crate::transaction::{
+    Transaction, TransactionDecodingError, TransactionExtra, TransactionParts,
+};
+pub use crate::version::VersionCompatibility;
 
 /// The hash of a block. Uniquely identifies a block.
 #[doc(inline)]
@@ -47,8 +63,8 @@ pub type BlockHeader = Header;
 
 /// Result of a transaction being included in a block.
 ///
-/// Returned after submitting an transaction to the blockchain.
-#[derive(Clone, Debug)]
+/// Returned after submitting an transaction to the blockchain. Use
+/// [TransactionIncluded::wait_finalized] to wait until the transaction cannot be reverted anymore.
 pub struct TransactionIncluded {
     pub tx_hash: TxHash,
     /// The hash of the block the transaction is included in.
@@ -57,23 +73,229 @@ pub struct TransactionIncluded {
     ///
     /// See [Message::result_from_events].
     pub result: Result<(), TransactionError>,
+    /// Registry events deposited by the transaction.
+    pub events: Vec<crate::event::Registry>,
+    /// Time from submitting the transaction to the node until the transaction was included in
+    /// a block and its events were retrieved.
+    pub inclusion_latency: std::time::Duration,
+    pub(crate) submitted_at: instant::Instant,
+    pub(crate) finalized: Response<BlockHash, Error>,
+}
+
+impl TransactionIncluded {
+    /// Wait until the transaction has been finalized and return the finalized block that
+    /// includes it.
+    ///
+    /// The returned block differs from [TransactionIncluded::block] if that block was retracted
+    /// and the transaction was included in another block. Fails with
+    /// [Error::TransactionNotFinalized] if the transaction will not be finalized.
+    pub async fn wait_finalized(self) -> Result<TransactionFinalized, Error> {
+        let block = self.finalized.await?;
+        let finalization_latency = self.submitted_at.elapsed();
+        #[cfg(feature = "metrics")]
+        crate::metrics::observe_finalization_latency(finalization_latency);
+        Ok(TransactionFinalized {
+            block,
+            finalization_latency,
+        })
+    }
+}
+
+/// Result of [TransactionIncluded::wait_finalized].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TransactionFinalized {
+    /// The hash of the finalized block the transaction is included in.
+    pub block: BlockHash,
+    /// Time from submitting the transaction to the node until the client observed that a block
+    /// including the transaction was finalized.
+    ///
+    /// The finalization is observed when [TransactionIncluded::wait_finalized] is polled. Await
+    /// it right after the transaction was included to get an accurate latency.
+    pub finalization_latency: std::time::Duration,
+}
+
+impl std::fmt::Debug for TransactionIncluded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransactionIncluded")
+            .field("tx_hash", &self.tx_hash)
+            .field("block", &self.block)
+            .field("result", &self.result)
+            .field("events", &self.events)
+            .field("inclusion_latency", &self.inclusion_latency)
+            .finish()
+    }
+}
+
+/// A transaction included in a block. Returned by [ClientT::get_transaction] and
+/// [ClientT::block_body].
+#[derive(Clone, Debug)]
+pub struct TransactionInfo {
+    pub tx_hash: TxHash,
+    /// The hash of the block the transaction is included in.
+    pub block: BlockHash,
+    /// Position of the transaction in the extrinsics of the block.
+    pub index: u32,
+    /// The account that signed the transaction and paid its fee.
+    pub author: AccountId,
+    pub nonce: state::AccountTransactionIndex,
+    pub fee: Balance,
+    /// The runtime call of the transaction. Use [TransactionInfo::message] to decode it.
+    pub call: RuntimeCall,
+    /// Events emitted by the transaction.
+    pub events: Vec<Event>,
+    /// The dispatch result of the transaction derived from the `ExtrinsicSuccess` or
+    /// `ExtrinsicFailed` event.
+    pub result: Result<(), TransactionError>,
+}
+
+impl TransactionInfo {
+    /// Decode the call of the transaction into `Message_`. Returns `None` if the transaction
+    /// carries a different message.
+    pub fn message<Message_: Message>(&self) -> Option<Message_> {
+        Message_::from_runtime_call(self.call.clone())
+    }
+
+    /// Name of the runtime call of the transaction in the form `Module.call`, for example
+    /// `Registry.register_org`.
+    pub fn call_name(&self) -> String {
+        let metadata = self.call.get_call_metadata();
+        format!("{}.{}", metadata.pallet_name, metadata.function_name)
+    }
+}
+
+/// Number of blocks that must be built on top of the block that includes a transaction before the
+/// transaction is considered confirmed.
+///
+/// Blocks of a proof-of-work chain may be removed from the best chain by a reorg. The more blocks
+/// are built on top of a block the less likely it is removed. See
+/// [ClientT::submit_transaction_with_confirmation].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConfirmationPolicy {
+    /// Number of descendants the including block must have on the best chain. With a depth of
+    /// zero the transaction is confirmed as soon as it is included.
+    pub depth: u32,
 }
 
 /// Return type for all [ClientT] methods.
 pub type Response<T, Error> = BoxFuture<'static, Result<T, Error>>;
 
-/// The availability status of an org or user Id
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum IdStatus {
-    /// The id is available and can be claimed
-    Available,
+/// A page of entries returned by [ClientT::list_orgs_paged], [ClientT::list_users_paged] and
+/// [ClientT::list_projects_paged].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Page<T> {
+    /// Entries of the page in the order of their storage keys.
+    pub items: Vec<T>,
+
+    /// Storage key to pass as `start_key` to get the next page. `None` if this is the last page.
+    pub next_key: Option<Vec<u8>>,
+}
+
+/// Balances and vesting schedule of an account. See [ClientT::account_info].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountInfo {
+    /// Balance that can be used to pay fees and, unless it is locked, be transferred.
+    pub free: Balance,
+
+    /// Balance that is reserved and cannot be used.
+    pub reserved: Balance,
 
-    /// The id is curently taken by a user or by an org
-    Taken,
+    /// Part of the free balance that cannot be transferred, for example because it is still
+    /// vesting. This is the largest lock on the account.
+    pub locked: Balance,
 
-    /// The id has been unregistered and is now retired
-    Retired,
+    /// Vesting schedule of the account, if any.
+    pub vesting: Option<VestingSchedule>,
+}
+
+/// Linear release of a locked balance. See [AccountInfo::vesting].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Balance that is locked at `starting_block`.
+    pub locked: Balance,
+
+    /// Balance released with every block after `starting_block`.
+    pub per_block: Balance,
+
+    pub starting_block: BlockNumber,
+}
+
+impl VestingSchedule {
+    /// Balance that is still locked by the schedule at the given block.
+    ///
+    /// The lock on the account is only reduced to this amount when the vesting module's `vest`
+    /// call is submitted for the account.
+    pub fn locked_at(&self, block_number: BlockNumber) -> Balance {
+        let vested_blocks = block_number.saturating_sub(self.starting_block);
+        let vested = self.per_block.saturating_mul(Balance::from(vested_blocks));
+        self.locked.saturating_sub(vested)
+    }
+}
+
+/// Fees and deposits a transaction is expected to cost. See [ClientT::estimate_fee].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// Account that pays the transaction fee. This is the org account for org transactions
+    /// authored by a member of the org and the author otherwise.
+    pub payer: AccountId,
+
+    /// Minimum transaction fee the chain accepts.
+    pub tx_fee: Balance,
+
+    /// Registration fee the author pays when the transaction is applied. Zero for messages that
+    /// do not register an org or a user.
+    pub registration_fee: Balance,
+
+    /// Balance the payer must keep after paying the fees.
+    pub existential_deposit: Balance,
+}
+
+impl FeeEstimate {
+    /// Free balance the payer needs for the transaction to be accepted and applied.
+    pub fn required_balance(&self) -> Balance {
+        self.tx_fee + self.registration_fee + self.existential_deposit
+    }
+}
+
+/// Optional RPC methods a node provides. See [ClientT::capabilities].
+///
+/// Older nodes do not provide the custom registry RPC methods. The client then falls back to
+/// reading the storage where it can.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Capabilities {
+    /// The node provides the `registry_idAvailable` RPC method. Otherwise
+    /// [ClientT::id_available] reads the storage.
+    pub id_status_rpc: bool,
+
+    /// The node provides the `registry_nodeLimits` RPC method. Otherwise
+    /// [ClientT::node_limits] returns `None`.
+    pub node_limits_rpc: bool,
+}
+
+/// Transaction pool and block building limits a node is configured with. See
+/// [ClientT::node_limits].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeLimits {
+    /// Maximum number of transactions in the ready queue of the transaction pool.
+    pub pool_ready_count: usize,
+
+    /// Maximum size of all transactions in the ready queue of the transaction pool in bytes.
+    pub pool_ready_bytes: usize,
+
+    /// Maximum number of transactions in the future queue of the transaction pool.
+    pub pool_future_count: usize,
+
+    /// Maximum size of all transactions in the future queue of the transaction pool in bytes.
+    pub pool_future_bytes: usize,
+
+    /// Maximum number of transactions of a single account in the transaction pool.
+    pub pool_account_limit: Option<u32>,
+
+    /// Total weight of the transactions the node includes in a block it authors.
+    pub block_weight_target: u64,
+
+    /// Total encoded length of the transactions the node includes in a block it authors.
+    pub block_length_target: u32,
 }
 
 /// Trait for ledger clients sending transactions and looking up state.
@@ -113,13 +335,64 @@ pub trait ClientT {
         transaction: Transaction<Message_>,
     ) -> Result<Response<TransactionIncluded, Error>, Error>;
 
+    /// Submit a SCALE encoded signed extrinsic, for example one produced by an offline signer or
+    /// other tooling.
+    ///
+    /// Behaves like [ClientT::submit_transaction]. Since the message type is not known, the
+    /// [TransactionIncluded::result] is derived from the `ExtrinsicSuccess` or `ExtrinsicFailed`
+    /// system event. Fails with [Error::TransactionDecoding] if `encoded_extrinsic` is not a
+    /// signed extrinsic of the runtime. Use [Transaction::from_encoded] to check that an extrinsic
+    /// carries a specific message.
+    async fn submit_raw(
+        &self,
+        encoded_extrinsic: Vec<u8>,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
+    /// Submit a signed transaction and wait until it is confirmed according to `policy`.
+    ///
+    /// Same as [ClientT::submit_transaction] but the returned future only resolves when the block
+    /// that includes the transaction has [ConfirmationPolicy::depth] descendants on the best
+    /// chain. Fails with [Error::TransactionRetracted] if the block is removed from the best
+    /// chain before that.
+    async fn submit_transaction_with_confirmation<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+        policy: ConfirmationPolicy,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
     /// Sign and submit a ledger message as a transaction to the blockchain.
     ///
     /// Same as [ClientT::submit_transaction] but takes care of signing the message.
+    ///
+    /// The client assigns consecutive nonces to the transactions of an author so that several
+    /// messages of the same author can be submitted without waiting for the previous ones to be
+    /// included.
     async fn sign_and_submit_message<Message_: Message>(
         &self,
-        author: &ed25519::Pair,
+        author: &dyn Signer,
+        message: Message_,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error>;
+
+    /// Estimate the fees of submitting `message` as a transaction authored by `author`.
+    ///
+    /// The estimate uses the fee rules of the runtime and the current chain state. It may be off
+    /// if the state changes before the transaction is applied, for example if the author leaves
+    /// the org that would pay for it.
+    async fn estimate_fee<Message_: Message>(
+        &self,
+        author: &AccountId,
         message: Message_,
+    ) -> Result<FeeEstimate, Error>;
+
+    /// Transfer `amount` from the author to the account of the given org.
+    ///
+    /// Fails with [Error::OrgMissing] before submitting a transaction if the org does not exist.
+    async fn fund_org(
+        &self,
+        author: &dyn Signer,
+        org_id: Id,
+        amount: Balance,
         fee: Balance,
     ) -> Result<Response<TransactionIncluded, Error>, Error>;
 
@@ -138,29 +411,260 @@ pub trait ClientT {
     /// Fetch the header of the best chain tip
     async fn block_header_best_chain(&self) -> Result<BlockHeader, Error>;
 
+    /// Find the transaction with the given hash and return its message, author and result.
+    ///
+    /// The transaction is first looked up in the block `block_hash_hint`. If it is not included
+    /// there or no hint is given we search the [crate::TRANSACTION_SEARCH_DEPTH] latest blocks of
+    /// the best chain. Returns `None` if the transaction was not found.
+    ///
+    /// Fails with [Error::TransactionDecoding] if the extrinsic with the hash is not a signed
+    /// transaction.
+    async fn get_transaction(
+        &self,
+        tx_hash: TxHash,
+        block_hash_hint: Option<BlockHash>,
+    ) -> Result<Option<TransactionInfo>, Error>;
+
+    /// Fetch the transactions included in the given block in the order of the block.
+    ///
+    /// Inherent extrinsics like the block timestamp are not signed and are skipped. Returns `None`
+    /// if the block is not known.
+    async fn block_body(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Option<Vec<TransactionInfo>>, Error>;
+
     /// Return the genesis hash of the chain we are communicating with.
     fn genesis_hash(&self) -> Hash;
 
+    /// List the balance changes of `account_id` in the blocks `from_block` to `to_block`
+    /// (inclusive) of the best chain, ordered by block number.
+    ///
+    /// The entries are assembled from the events of every block in the range. The blocks are
+    /// traversed backwards from the tip of the best chain or from the block the client is pinned
+    /// to (see [crate::Client::at]). Blocks after the tip are ignored.
+    async fn account_statement(
+        &self,
+        account_id: &AccountId,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> Result<Vec<AccountStatementEntry>, Error>;
+
+    /// Get the account that controls privileged operations through the sudo module, like runtime
+    /// updates and treasury spends. Returns `None` if no sudo key is set.
+    async fn sudo_key(&self) -> Result<Option<AccountId>, Error>;
+
     /// Get the runtime version at the latest block
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error>;
 
+    /// Get the decoded runtime metadata at the latest block. The metadata describes the modules
+    /// of the runtime with their calls, events, storage entries, and constants.
+    async fn runtime_metadata(&self) -> Result<RuntimeMetadataLastVersion, Error>;
+
+    /// Check the runtime and storage versions of the chain against the versions the client
+    /// supports.
+    ///
+    /// Fails with [Error::IncompatibleRuntimeVersion], [Error::IncompatibleStorageVersion] or
+    /// [Error::IncompatibleChain] if the client cannot work with the chain. These are the same
+    /// checks that [crate::Client::create] runs on connect. Returns [VersionCompatibility::ReadOnly] if the client
+    /// can read the state of the chain but cannot create transactions for it.
+    async fn version_compatibility(&self) -> Result<VersionCompatibility, Error>;
+
+    /// Get the transaction pool and block building limits the node is configured with.
+    ///
+    /// Returns `None` for the emulator, which does not impose any limits, and for nodes that do
+    /// not provide the `registry_nodeLimits` RPC method.
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error>;
+
+    /// Report which optional RPC methods the node provides.
+    ///
+    /// Nodes that do not provide the standard `rpc_methods` method are reported without any
+    /// capabilities.
+    async fn capabilities(&self) -> Result<Capabilities, Error>;
+
+    /// Get the balance of the treasury account that receives a share of the block rewards.
+    async fn treasury_balance(&self) -> Result<Balance, Error>;
+
+    /// Get the treasury spend proposal with the given id.
+    async fn get_treasury_proposal(
+        &self,
+        proposal_id: TreasuryProposalId,
+    ) -> Result<Option<state::TreasuryProposals1Data>, Error>;
+
+    /// List the ids of all open treasury spend proposals in ascending order.
+    async fn list_treasury_proposals(&self) -> Result<Vec<TreasuryProposalId>, Error>;
+
+    /// List all storage migrations of the registry that were executed on runtime upgrades in the
+    /// order of execution.
+    async fn migration_history(&self) -> Result<Vec<state::MigrationHistory1Data>, Error>;
+
+    /// Constants of the chain runtime like the block reward and the fees.
+    ///
+    /// The values are read from the runtime metadata and cached for the lifetime of the client.
+    async fn chain_constants(&self) -> Result<ChainConstants, Error>;
+
+    /// Fee charged by the chain for registering an org or a user.
+    ///
+    /// The value is read from the runtime metadata and cached for the lifetime of the client.
+    async fn registration_fee(&self) -> Result<Balance, Error>;
+
+    /// Minimum fee a transaction must pay to be accepted by the chain.
+    ///
+    /// The value is read from the runtime metadata and cached for the lifetime of the client.
+    async fn minimum_tx_fee(&self) -> Result<Balance, Error>;
+
     async fn free_balance(&self, account_id: &AccountId) -> Result<Balance, Error>;
 
+    /// Get the free, reserved and locked balance and the vesting schedule of an account.
+    async fn account_info(&self, account_id: &AccountId) -> Result<AccountInfo, Error>;
+
+    /// Get the availability status of an org or user id. See [IdStatus].
     async fn get_id_status(&self, id: &Id) -> Result<IdStatus, Error>;
 
+    /// Ask the node whether `id` can be claimed by an org or a user.
+    ///
+    /// Uses the `registry_idAvailable` RPC method of the node which is backed by the registry
+    /// runtime API. Returns the same status as [ClientT::get_id_status] without the client
+    /// knowing the storage layout.
+    ///
+    /// Falls back to reading the storage if the node does not provide the RPC method or if the
+    /// client is pinned to a block with [crate::Client::at].
+    async fn id_available(&self, id: &Id) -> Result<IdStatus, Error>;
+
+    /// List all reserved org and user ids in ascending order together with the account that
+    /// holds the reservation.
+    async fn list_id_reservations(&self) -> Result<Vec<(Id, AccountId)>, Error>;
+
+    /// List all reserved project names in ascending order together with the org or user they are
+    /// reserved for.
+    async fn list_project_name_reservations(
+        &self,
+    ) -> Result<Vec<(ProjectName, ProjectDomain)>, Error>;
+
     async fn get_org(&self, org_id: Id) -> Result<Option<state::Orgs1Data>, Error>;
 
+    /// Get an org together with a Merkle proof of the read.
+    ///
+    /// The value is read at the block the client is pinned to or at the best block. Use
+    /// [StorageReadProof::verify] to check the value against a trusted header of the block.
+    async fn get_org_with_proof(
+        &self,
+        org_id: Id,
+    ) -> Result<StorageReadProof<state::Orgs1Data>, Error>;
+
+    /// Subscribe to the state of an org in the best chain.
+    ///
+    /// Emits the current state right away and then the new state whenever it changes, including
+    /// changes caused by reorgs. `None` is emitted if the org does not exist.
+    async fn subscribe_org(
+        &self,
+        org_id: Id,
+    ) -> Result<BoxStream<'static, Result<Option<state::Orgs1Data>, Error>>, Error>;
+
+    /// List the ids of all registered orgs in ascending order.
     async fn list_orgs(&self) -> Result<Vec<Id>, Error>;
 
+    /// List the ids of at most `limit` registered orgs, starting after the storage key
+    /// `start_key`. Pass `None` to get the first page and [Page::next_key] to get the following
+    /// pages.
+    ///
+    /// Unlike [ClientT::list_orgs], only the requested keys are fetched from the node. Ids are
+    /// ordered by their storage keys, which is not the order of the ids.
+    async fn list_orgs_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<Id>, Error>;
+
+    /// List all registered orgs together with their data in ascending order of their ids.
+    ///
+    /// The data is fetched in batches instead of with one request per org.
+    async fn list_orgs_with_data(&self) -> Result<Vec<(Id, state::Orgs1Data)>, Error>;
+
     async fn get_user(&self, user_id: Id) -> Result<Option<state::Users1Data>, Error>;
 
+    /// Get a user together with a Merkle proof of the read. See [ClientT::get_org_with_proof].
+    async fn get_user_with_proof(
+        &self,
+        user_id: Id,
+    ) -> Result<StorageReadProof<state::Users1Data>, Error>;
+
+    /// Get the user associated with the given account.
+    async fn get_user_by_account(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<(Id, state::Users1Data)>, Error>;
+
+    /// List the ids of all registered users in ascending order.
     async fn list_users(&self) -> Result<Vec<Id>, Error>;
 
+    /// List the ids of at most `limit` registered users, starting after the storage key
+    /// `start_key`. See [ClientT::list_orgs_paged].
+    async fn list_users_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<Id>, Error>;
+
+    /// List all registered users together with their data in ascending order of their ids.
+    /// See [ClientT::list_orgs_with_data].
+    async fn list_users_with_data(&self) -> Result<Vec<(Id, state::Users1Data)>, Error>;
+
     async fn get_project(
         &self,
         project_name: ProjectName,
         project_domain: ProjectDomain,
     ) -> Result<Option<state::Projects1Data>, Error>;
 
+    /// Get a project together with a Merkle proof of the read. See
+    /// [ClientT::get_org_with_proof].
+    async fn get_project_with_proof(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<StorageReadProof<state::Projects1Data>, Error>;
+
+    /// Subscribe to the state of a project in the best chain. See [ClientT::subscribe_org].
+    async fn subscribe_project(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<BoxStream<'static, Result<Option<state::Projects1Data>, Error>>, Error>;
+
+    /// Get all projects registered under the given org or user ordered by project name.
+    ///
+    /// Returns an empty list if the org or user does not exist.
+    async fn list_projects_for(
+        &self,
+        project_domain: ProjectDomain,
+    ) -> Result<Vec<(ProjectName, state::Projects1Data)>, Error>;
+
+    /// Get the recipient of the pending transfer of the project `project_name` owned by the user
+    /// `user_id`. See [message::TransferProjectOwnership].
+    ///
+    /// Returns `None` if no transfer of the project is pending.
+    async fn get_pending_project_transfer(
+        &self,
+        project_name: ProjectName,
+        user_id: Id,
+    ) -> Result<Option<Id>, Error>;
+
+    /// List the ids of all registered projects in ascending order.
+    ///
+    /// See [ProjectId] for the order of project ids.
     async fn list_projects(&self) -> Result<Vec<ProjectId>, Error>;
+
+    /// List the ids of at most `limit` registered projects, starting after the storage key
+    /// `start_key`. See [ClientT::list_orgs_paged].
+    async fn list_projects_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<ProjectId>, Error>;
+
+    /// List all registered projects together with their data in ascending order of their ids.
+    /// See [ClientT::list_orgs_with_data].
+    async fn list_projects_with_data(
+        &self,
+    ) -> Result<Vec<(ProjectId, state::Projects1Data)>, Error>;
 }
@@ -24,30 +24,96 @@
 //!
 //! [Client::create_with_executor] creates a client that uses its own runtime to spawn futures.
 //!
+//! # Browser
+//!
+//! With the `browser` feature and without the default `native` feature the crate compiles to
+//! `wasm32-unknown-unknown`. The client then connects to the node with the WebSocket API of the
+//! browser and [Client::create_with_executor] is not available. The [browser] module provides
+//! JavaScript bindings.
+//!
 //! # Transactions
 //!
 //! A [Transaction] can be created and signed offline using [Transaction::new_signed]. This
 //! constructor requires the account nonce and genesis hash of the chain. Those can be obtained
 //! using [ClientT::account_nonce] and [ClientT::genesis_hash]. See [Transaction] for more details.
-use std::sync::Arc;
+//!
+//! Transactions are signed by a [Signer]. Key pairs implement [Signer]. Implement it for other
+//! kinds of keys, like hardware wallets or remote signing services.
+//!
+//! # Consistent reads
+//!
+//! By default every read uses the state of the latest block. Consecutive reads may thus observe
+//! different blocks. [Client::at] and [Client::at_best_finalized] return a client that reads all
+//! state from the same block.
+use instant::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt as _};
+use futures::{future, TryStreamExt as _};
 
 use parity_scale_codec::{Decode, FullCodec};
 
 use frame_support::storage::generator::{StorageMap, StorageValue};
 use frame_support::storage::StoragePrefixedMap;
-use radicle_registry_runtime::{store, store::DecodeKey as _};
+use radicle_registry_runtime::{fees, registry, store, store::DecodeKey, Hashing};
+use sp_core::twox_128;
+use sp_runtime::traits::{Hash as _, Header as _};
+
+#[cfg(not(any(feature = "native", feature = "browser")))]
+std::compile_error!("Either the \"native\" or the \"browser\" feature must be enabled.");
 
 mod backend;
+#[cfg(feature = "browser")]
+pub mod browser;
+mod coalesce;
+mod compatibility;
+mod config;
+mod constants;
 mod error;
-mod event;
+pub mod event;
+#[cfg(feature = "native")]
+pub mod indexer;
 mod interface;
 pub mod message;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod nonce;
+mod org_balance;
+mod proof;
+mod signer;
+mod statement;
 mod transaction;
+mod version;
 
 pub use crate::interface::*;
-pub use backend::{EmulatorControl, EMULATOR_BLOCK_AUTHOR};
+pub use backend::{
+    BlockNotification, EmulatorControl, TrackedTransactionStatus, EMULATOR_BLOCK_AUTHOR,
+};
 pub use radicle_registry_core::{state, Balance};
-pub use radicle_registry_runtime::fees::{MINIMUM_TX_FEE, REGISTRATION_FEE};
+pub use radicle_registry_runtime::registry::rewards::treasury_account;
+
+use coalesce::FetchCoalescer;
+use nonce::NonceManager;
+
+/// Number of storage values that are fetched with one request by the `list_*_with_data` methods
+/// of [ClientT].
+const FETCH_BATCH_SIZE: usize = 256;
+
+/// Number of blocks of the best chain that [ClientT::get_transaction] searches for a transaction
+/// that is not included in the hinted block.
+pub const TRANSACTION_SEARCH_DEPTH: u32 = 256;
+
+lazy_static::lazy_static! {
+    /// Storage key of the account that controls the sudo module. The storage item is private to
+    /// the sudo module so we cannot use a typed accessor.
+    static ref SUDO_KEY_STORAGE_KEY: [u8; 32] = {
+        let mut key = [0u8; 32];
+        key[0..16].copy_from_slice(&twox_128(b"Sudo"));
+        key[16..32].copy_from_slice(&twox_128(b"Key"));
+        key
+    };
+}
 
 /// Client to interact with the radicle registry ledger via an implementation of [ClientT].
 ///
@@ -56,14 +122,28 @@ pub use radicle_registry_runtime::fees::{MINIMUM_TX_FEE, REGISTRATION_FEE};
 #[derive(Clone)]
 pub struct Client {
     backend: Arc<dyn backend::Backend + Sync + Send>,
+    /// Constants of the connected chain. Fetched on first use.
+    constants: Arc<Mutex<Option<ChainConstants>>>,
+    /// Nonces of transactions submitted with [ClientT::sign_and_submit_message]. Shared by all
+    /// clones of the client.
+    nonces: Arc<NonceManager>,
+    /// Shares identical state reads that are in flight. Shared by all clones of the client.
+    coalescer: Arc<FetchCoalescer>,
+    /// Block that all state is read from. If `None` the latest block is used.
+    block_hash: Option<BlockHash>,
 }
 
 impl Client {
-    /// Connects to a registry node running on the given host and returns a [Client].
+    /// Connects to a registry node as configured by [ClientConfig] and returns a [Client].
     ///
-    /// Fails if it cannot connect to a node. Uses websocket over port 9944.
-    pub async fn create(host: url::Host) -> Result<Self, Error> {
-        let backend = backend::RemoteNode::create(host).await?;
+    /// Accepts a [ClientConfig] or a [url::Host]. For a host we connect with a websocket over port
+    /// 9944.
+    ///
+    /// Fails if it cannot connect to a node within [ClientConfig::timeout] or if the chain is not
+    /// compatible with the client (see [Error::IncompatibleChain] and
+    /// [ClientT::version_compatibility]).
+    pub async fn create(config: impl Into<ClientConfig>) -> Result<Self, Error> {
+        let backend = backend::RemoteNode::create(config.into()).await?;
         Ok(Self::new(backend))
     }
 
@@ -72,8 +152,24 @@ impl Client {
     ///
     /// This makes it possible to call block on future in the client even if that function is
     /// called in an event loop of another executor.
-    pub async fn create_with_executor(host: url::Host) -> Result<Self, Error> {
-        let backend = backend::RemoteNodeWithExecutor::create(host).await?;
+    #[cfg(feature = "native")]
+    pub async fn create_with_executor(config: impl Into<ClientConfig>) -> Result<Self, Error> {
+        let backend = backend::RemoteNodeWithExecutor::create(config.into()).await?;
+        Ok(Self::new(backend))
+    }
+
+    /// Same as [Client::create] but the client verifies the events of every submitted
+    /// transaction against the header of the block that includes the transaction.
+    ///
+    /// Use this if you do not trust the node. The events are fetched with a storage proof that is
+    /// checked against the state root of the block header. If the verification fails, the
+    /// transaction result is an [Error::BlockDataVerification] error.
+    pub async fn create_with_event_verification(
+        config: impl Into<ClientConfig>,
+    ) -> Result<Self, Error> {
+        let backend = backend::RemoteNode::create(config.into())
+            .await?
+            .with_event_verification();
         Ok(Self::new(backend))
     }
 
@@ -90,25 +186,335 @@ impl Client {
     fn new(backend: impl backend::Backend + Sync + Send + 'static) -> Self {
         Client {
             backend: Arc::new(backend),
+            constants: Arc::new(Mutex::new(None)),
+            nonces: Arc::new(NonceManager::default()),
+            coalescer: Arc::new(FetchCoalescer::default()),
+            block_hash: None,
+        }
+    }
+
+    /// Return a client that reads all state at the given block.
+    ///
+    /// Transactions submitted with the returned client are not affected. They are always
+    /// constructed with the state of the latest block.
+    pub fn at(&self, block_hash: BlockHash) -> Self {
+        Client {
+            block_hash: Some(block_hash),
+            ..self.clone()
         }
     }
 
+    /// Return a client that reads all state at the latest finalized block. See [Client::at].
+    ///
+    /// Use this to get a consistent view for queries that consist of multiple reads.
+    pub async fn at_best_finalized(&self) -> Result<Self, Error> {
+        let block_hash = self.backend.finalized_block_hash().await?;
+        Ok(self.at(block_hash))
+    }
+
+    /// Return a client that reads all state at the latest block.
+    pub fn at_latest(&self) -> Self {
+        Client {
+            block_hash: None,
+            ..self.clone()
+        }
+    }
+
+    /// The block that all state is read from. `None` if the client reads from the latest block.
+    pub fn pinned_block(&self) -> Option<BlockHash> {
+        self.block_hash
+    }
+
+    /// Wait until the best chain includes the block with the given number and return the header
+    /// of the best chain tip at that point.
+    ///
+    /// Returns immediately if the best chain already includes the block. Fails with
+    /// [Error::BlockWaitTimeout] if the block is not reached within `timeout`.
+    pub async fn wait_for_block(
+        &self,
+        block_number: BlockNumber,
+        timeout: Duration,
+    ) -> Result<BlockHeader, Error> {
+        // We subscribe before we check the tip so that we don’t miss any blocks.
+        let mut new_heads = self.backend.subscribe_new_heads().await?;
+        let wait = async move {
+            let tip = self.block_header_best_chain().await?;
+            if tip.number >= block_number {
+                return Ok(tip);
+            }
+            while let Some(header) = new_heads.try_next().await? {
+                if header.number >= block_number {
+                    return Ok(header);
+                }
+            }
+            Err(Error::BlockSubscriptionTerminated)
+        };
+        futures::pin_mut!(wait);
+        match future::select(wait, futures_timer::Delay::new(timeout)).await {
+            future::Either::Left((result, _)) => result,
+            future::Either::Right(_) => Err(Error::BlockWaitTimeout { block_number }),
+        }
+    }
+
+    /// Wait until the block that includes the transaction has [ConfirmationPolicy::depth]
+    /// descendants on the best chain.
+    ///
+    /// Fails with [Error::TransactionRetracted] if the block is not or no longer part of the best
+    /// chain. See [ClientT::submit_transaction_with_confirmation].
+    pub async fn wait_for_confirmation(
+        &self,
+        tx_included: &TransactionIncluded,
+        policy: ConfirmationPolicy,
+    ) -> Result<(), Error> {
+        let tx_hash = tx_included.tx_hash;
+        let block_hash = tx_included.block;
+        let retracted = Error::TransactionRetracted {
+            tx_hash,
+            block_hash,
+        };
+        let block = self
+            .backend
+            .block_header(Some(block_hash))
+            .await?
+            .ok_or(Error::BlockMissing { block_hash })?;
+        // We subscribe before we check the tip so that we don’t miss any blocks.
+        let mut new_heads = self.backend.subscribe_new_heads().await?;
+        let mut tip = self.block_header_best_chain().await?;
+        loop {
+            if tip.number < block.number {
+                return Err(retracted);
+            }
+            let mut ancestor = tip.clone();
+            while ancestor.number > block.number {
+                let parent_hash = ancestor.parent_hash;
+                ancestor = self.backend.block_header(Some(parent_hash)).await?.ok_or(
+                    Error::BlockMissing {
+                        block_hash: parent_hash,
+                    },
+                )?;
+            }
+            if ancestor.hash() != block_hash {
+                return Err(retracted);
+            }
+            if tip.number >= block.number + policy.depth {
+                return Ok(());
+            }
+            tip = new_heads
+                .try_next()
+                .await?
+                .ok_or(Error::BlockSubscriptionTerminated)?;
+        }
+    }
+
+    /// Submit a transaction and follow it until it is finalized.
+    ///
+    /// The returned stream emits [TrackedTransactionStatus::Pending] when the transaction has been
+    /// submitted and [TrackedTransactionStatus::InBlock] when it has been included in a block.
+    /// Whenever a block is added to the best chain we check whether the including block is still
+    /// part of the best chain. If it is not, the stream emits
+    /// [TrackedTransactionStatus::Retracted] and the transaction is submitted again. The stream
+    /// ends after [TrackedTransactionStatus::Finalized] or after the first error.
+    ///
+    /// A node may return the transactions of retracted blocks to its transaction pool by itself.
+    /// Submitting such a transaction again fails and the error is emitted by the stream.
+    pub async fn track_transaction<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+    ) -> Result<BoxStream<'static, Result<TrackedTransactionStatus, Error>>, Error> {
+        let tracker =
+            backend::TransactionTracker::new(self.backend.clone(), transaction.extrinsic).await?;
+        Ok(tracker.into_stream())
+    }
+
+    /// Wait until `n` blocks were added to the current tip of the best chain. See
+    /// [Client::wait_for_block].
+    pub async fn wait_for_blocks(&self, n: u32, timeout: Duration) -> Result<BlockHeader, Error> {
+        let tip = self.block_header_best_chain().await?;
+        self.wait_for_block(tip.number + n, timeout).await
+    }
+
+    /// Subscribe to all events deposited in new blocks of the best chain.
+    ///
+    /// For every new block the events are fetched from the state of the block and emitted in the
+    /// order in which they were deposited. Use [event::registry_event] to select registry events.
+    pub async fn subscribe_events(
+        &self,
+    ) -> Result<BoxStream<'static, Result<event::Record, Error>>, Error> {
+        let new_heads = self.backend.subscribe_new_heads().await?;
+        let client = self.at_latest();
+        let records = new_heads
+            .and_then(move |header| {
+                let client = client.clone();
+                async move { client.block_event_records(header.hash()).await }
+            })
+            .map_ok(|records| stream::iter(records.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed();
+        Ok(records)
+    }
+
+    /// Subscribe to registry events deposited in new blocks of the best chain. See
+    /// [Client::subscribe_events].
+    pub async fn subscribe_registry_events(
+        &self,
+    ) -> Result<BoxStream<'static, Result<event::Registry, Error>>, Error> {
+        let records = self.subscribe_events().await?;
+        let registry_events = records
+            .try_filter_map(|record| future::ready(Ok(event::registry_event(&record).cloned())))
+            .boxed();
+        Ok(registry_events)
+    }
+
+    /// Watch the balance of the account of an org and alert when it drops below
+    /// `low_water_mark`.
+    ///
+    /// Subscribes to the account storage of the org with [backend::Backend::subscribe_storage].
+    /// Emits an [OrgBalanceUpdate] with the current balances right away and then whenever the
+    /// balance at the best block changed. Alerts are based on the best balance. Fails with
+    /// [Error::OrgMissing] if the org does not exist at the best block.
+    pub async fn watch_org_treasury(
+        &self,
+        org_id: Id,
+        low_water_mark: Balance,
+    ) -> Result<BoxStream<'static, Result<OrgBalanceUpdate, Error>>, Error> {
+        let client = self.at_latest();
+        let org = client
+            .get_org(org_id.clone())
+            .await?
+            .ok_or(Error::OrgMissing { org_id })?;
+        let account_id = org.account_id();
+        let account_infos = client
+            .subscribe_map_value::<store::Account, _, _>(account_id)
+            .await?;
+        let updates = account_infos
+            .map_ok(|maybe_account_info| maybe_account_info.unwrap_or_default().data.free)
+            .scan(None, move |previous: &mut Option<Balance>, result| {
+                let item = match result {
+                    Ok(balance) if *previous == Some(balance) => None,
+                    Ok(balance) => {
+                        let alert = org_balance::balance_alert(*previous, balance, low_water_mark);
+                        *previous = Some(balance);
+                        Some(Ok((balance, alert)))
+                    }
+                    Err(error) => Some(Err(error)),
+                };
+                future::ready(Some(item))
+            })
+            .filter_map(future::ready)
+            .and_then(move |(best_balance, alert)| {
+                let client = client.clone();
+                async move {
+                    client
+                        .org_balance_update(&account_id, best_balance, alert)
+                        .await
+                }
+            })
+            .boxed();
+        Ok(updates)
+    }
+
+    /// Subscribe to the headers of blocks as they are finalized.
+    ///
+    /// Whenever a block is added to the best chain the headers of all blocks that were finalized
+    /// since the last emitted header are emitted in ascending order. Blocks that were finalized
+    /// before the subscription was created are not emitted. Use [Client::block_event_records] to
+    /// get the events of a block.
+    pub async fn subscribe_finalized_blocks(
+        &self,
+    ) -> Result<BoxStream<'static, Result<BlockHeader, Error>>, Error> {
+        let new_heads = self.backend.subscribe_new_heads().await?;
+        let client = self.at_latest();
+        let finalized_hash = client.backend.finalized_block_hash().await?;
+        let last_finalized = client
+            .backend
+            .block_header(Some(finalized_hash))
+            .await?
+            .ok_or(Error::BlockMissing {
+                block_hash: finalized_hash,
+            })?
+            .number;
+        let headers = stream::unfold(
+            (new_heads, client, last_finalized),
+            |(mut new_heads, client, last_finalized)| async move {
+                if let Err(error) = new_heads.next().await? {
+                    return Some((Err(error), (new_heads, client, last_finalized)));
+                }
+                match client.finalized_headers_after(last_finalized).await {
+                    Ok(headers) => {
+                        let last_finalized = headers
+                            .last()
+                            .map(|header| header.number)
+                            .unwrap_or(last_finalized);
+                        Some((Ok(headers), (new_heads, client, last_finalized)))
+                    }
+                    Err(error) => Some((Err(error), (new_heads, client, last_finalized))),
+                }
+            },
+        )
+        .map_ok(|headers| stream::iter(headers.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed();
+        Ok(headers)
+    }
+
+    /// Fetch the records of all events deposited in the given block.
+    pub async fn block_event_records(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Vec<event::Record>, Error> {
+        let key = &backend::SYSTEM_EVENTS_STORAGE_KEY[..];
+        let maybe_data = self.fetch(key, Some(block_hash)).await?;
+        match maybe_data {
+            Some(data) => Decode::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
+                error,
+                key: key.to_vec(),
+            }),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Author an empty block on top of the best chain and return its hash.
+    ///
+    /// Only supported by the emulator and by nodes running the `dev` chain. Use this in tests to
+    /// control block production.
+    pub async fn dev_create_block(&self) -> Result<BlockHash, Error> {
+        self.backend.create_block(None).await
+    }
+
+    /// Author an empty block on top of `parent_hash` and return its hash.
+    ///
+    /// Use this in tests to create forks and trigger reorgs. A node makes the new block the best
+    /// block only if its chain is the longest. The emulator only keeps a single chain and
+    /// retracts all blocks after `parent_hash`. Fails with [Error::BlockMissing] if the emulator
+    /// does not know `parent_hash` or it is not part of the best chain.
+    ///
+    /// Only supported by the emulator and by nodes running the `dev` chain.
+    pub async fn dev_create_block_at(&self, parent_hash: BlockHash) -> Result<BlockHash, Error> {
+        self.backend.create_block(Some(parent_hash)).await
+    }
+
+    /// Finalize the given block.
+    ///
+    /// Only supported by the emulator and by nodes running the `dev` chain. For the emulator all
+    /// blocks are final.
+    pub async fn dev_finalize_block(&self, block_hash: BlockHash) -> Result<(), Error> {
+        self.backend.finalize_block(block_hash).await
+    }
+
     /// Fetch a value from the state storage based on a [StorageValue] implementation provided by
     /// the runtime.
     ///
     /// ```ignore
     /// client.fetch_value::<frame_balance::TotalIssuance<Runtime>, _>();
     /// ```
-    #[allow(dead_code)]
     async fn fetch_value<S: StorageValue<Value>, Value: FullCodec + Send + 'static>(
         &self,
     ) -> Result<S::Query, Error>
     where
         S::Query: Send + 'static,
     {
-        let backend = self.backend.clone();
         let key = S::storage_value_final_key();
-        let maybe_data = backend.fetch(&key, None).await?;
+        let maybe_data = self.fetch(&key, self.block_hash).await?;
         let value = match maybe_data {
             Some(data) => {
                 let value =
@@ -135,11 +541,166 @@ impl Client {
     where
         S::Query: Send + 'static,
     {
-        let backend = self.backend.clone();
         // We cannot move this code into the async block. The compiler complains about a processing
         // cycle (E0391)
         let key = S::storage_map_final_key(key);
-        backend.fetch(&key, None).await.map(|data| data.is_some())
+        self.fetch(&key, self.block_hash)
+            .await
+            .map(|data| data.is_some())
+    }
+
+    /// Fetch and decode all entries of the storage map `S`. The values are fetched in batches of
+    /// [FETCH_BATCH_SIZE]. Entries are sorted by key.
+    ///
+    /// Entries that are removed between fetching the keys and fetching the values are skipped
+    /// if the client is not pinned to a block.
+    async fn fetch_map_entries<S: StoragePrefixedMap<Value> + DecodeKey, Value: FullCodec>(
+        &self,
+    ) -> Result<Vec<(S::Key, Value)>, Error>
+    where
+        S::Key: Ord,
+    {
+        let keys = self
+            .backend
+            .fetch_keys(&S::final_prefix(), self.block_hash)
+            .await?;
+        let mut entries = Vec::with_capacity(keys.len());
+        for batch in keys.chunks(FETCH_BATCH_SIZE) {
+            let values = self.backend.fetch_many(batch, self.block_hash).await?;
+            for (key, data) in batch.iter().zip(values) {
+                let data = match data {
+                    Some(data) => data,
+                    None => continue,
+                };
+                let decoding_error = |error| Error::StateDecoding {
+                    error,
+                    key: key.clone(),
+                };
+                let map_key = S::decode_key(key).map_err(decoding_error)?;
+                let value = Value::decode(&mut &data[..]).map_err(decoding_error)?;
+                entries.push((map_key, value));
+            }
+        }
+        entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+        Ok(entries)
+    }
+
+    /// Fetch a page of at most `limit` keys of the storage map `S` starting after `start_key` and
+    /// decode them.
+    async fn fetch_map_keys_paged<S: StoragePrefixedMap<Value> + DecodeKey, Value: FullCodec>(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<S::Key>, Error> {
+        let keys = self
+            .backend
+            .fetch_keys_paged(
+                &S::final_prefix(),
+                start_key.as_deref(),
+                limit,
+                self.block_hash,
+            )
+            .await?;
+        let next_key = if keys.len() == limit as usize {
+            keys.last().cloned()
+        } else {
+            None
+        };
+        let items = keys
+            .iter()
+            .map(|key| {
+                S::decode_key(key).map_err(|error| Error::StateDecoding {
+                    error,
+                    key: key.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Page { items, next_key })
+    }
+
+    /// Fetch a raw value from the state storage. Identical concurrent reads share one backend
+    /// request.
+    async fn fetch(
+        &self,
+        key: &[u8],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        self.coalescer
+            .fetch(key, block_hash, || self.backend.fetch(key, block_hash))
+            .await
+    }
+
+    /// Return the transaction with the given hash if it is included in the block.
+    ///
+    /// Returns `None` if the block is not known.
+    async fn find_transaction_in_block(
+        &self,
+        tx_hash: TxHash,
+        block_hash: BlockHash,
+    ) -> Result<Option<TransactionInfo>, Error> {
+        let block = match self.backend.block(block_hash).await? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let index = match block
+            .extrinsics
+            .iter()
+            .position(|extrinsic| Hashing::hash_of(extrinsic) == tx_hash)
+        {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let event_records = self.block_event_records(block_hash).await?;
+        let info = transaction_info(
+            block_hash,
+            index as u32,
+            &block.extrinsics[index],
+            &event_records,
+        )?;
+        Ok(Some(info))
+    }
+
+    /// Complete an [OrgBalanceUpdate] for the new `best_balance` of the org account with the
+    /// balance at the latest finalized block. See [Client::watch_org_treasury].
+    async fn org_balance_update(
+        &self,
+        account_id: &AccountId,
+        best_balance: Balance,
+        alert: Option<OrgBalanceAlert>,
+    ) -> Result<OrgBalanceUpdate, Error> {
+        let block_number = self.block_header_best_chain().await?.number;
+        let finalized_balance = self
+            .at_best_finalized()
+            .await?
+            .free_balance(account_id)
+            .await?;
+        Ok(OrgBalanceUpdate {
+            block_number,
+            best_balance,
+            finalized_balance,
+            alert,
+        })
+    }
+
+    /// Fetch the headers of the latest finalized block and its ancestors with a block number
+    /// greater than `after` in ascending order.
+    async fn finalized_headers_after(&self, after: BlockNumber) -> Result<Vec<BlockHeader>, Error> {
+        let mut block_hash = self.backend.finalized_block_hash().await?;
+        let mut headers = Vec::new();
+        loop {
+            let header = self
+                .backend
+                .block_header(Some(block_hash))
+                .await?
+                .ok_or(Error::BlockMissing { block_hash })?;
+            if header.number <= after {
+                break;
+            }
+            block_hash = header.parent_hash;
+            headers.push(header);
+        }
+        headers.reverse();
+        Ok(headers)
     }
 
     /// Fetch a value from a map in the state storage based on a [StorageMap] implementation
@@ -159,11 +720,10 @@ impl Client {
     where
         S::Query: Send + 'static,
     {
-        let backend = self.backend.clone();
         // We cannot move this code into the async block. The compiler complains about a processing
         // cycle (E0391)
         let key = S::storage_map_final_key(key);
-        let maybe_data = backend.fetch(&key, None).await?;
+        let maybe_data = self.fetch(&key, self.block_hash).await?;
         let value = match maybe_data {
             Some(data) => {
                 let value = Decode::decode(&mut &data[..])
@@ -174,45 +734,179 @@ impl Client {
         };
         Ok(S::from_optional_value_to_query(value))
     }
-}
 
-#[async_trait::async_trait]
-impl ClientT for Client {
-    async fn submit_transaction<Message_: Message>(
+    /// Submit `extrinsic` and derive the [TransactionIncluded::result] from the events of the
+    /// transaction with `result_from_events`.
+    async fn submit_extrinsic(
         &self,
-        transaction: Transaction<Message_>,
+        extrinsic: backend::UncheckedExtrinsic,
+        result_from_events: fn(
+            Vec<event::Event>,
+        )
+            -> Result<Result<(), TransactionError>, event::EventExtractionError>,
     ) -> Result<Response<TransactionIncluded, Error>, Error> {
-        let backend = self.backend.clone();
-        let tx_included_future = backend.submit(transaction.extrinsic).await?;
+        let submitted_at = Instant::now();
+        let tx_included_future = self.backend.submit(extrinsic).await?;
         Ok(Box::pin(async move {
             let tx_included = tx_included_future.await?;
+            let inclusion_latency = submitted_at.elapsed();
+            #[cfg(feature = "metrics")]
+            metrics::observe_inclusion_latency(inclusion_latency);
             let events = tx_included.events;
             let tx_hash = tx_included.tx_hash;
             let block = tx_included.block;
-            let result = Message_::result_from_events(events)
+            let registry_events = event::registry_events(&events);
+            let result = result_from_events(events)
                 .map_err(|error| Error::EventExtraction { error, tx_hash })?;
             Ok(TransactionIncluded {
                 tx_hash,
                 block,
                 result,
+                events: registry_events,
+                inclusion_latency,
+                submitted_at,
+                finalized: tx_included.finalized,
             })
         }))
     }
 
+    /// Fetch a value of a [StorageMap] together with a Merkle proof of the read.
+    ///
+    /// The value is read at [Client::block_hash] or at the best block. The value is taken from the
+    /// proof so that it is consistent with the proof.
+    async fn fetch_map_value_with_proof<
+        S: StorageMap<Key, Value>,
+        Key: FullCodec,
+        Value: FullCodec + Send + 'static,
+    >(
+        &self,
+        key: Key,
+    ) -> Result<StorageReadProof<Value>, Error> {
+        let key = S::storage_map_final_key(key);
+        let header = self
+            .backend
+            .block_header(self.block_hash)
+            .await?
+            .ok_or_else(|| match self.block_hash {
+                Some(block_hash) => Error::BlockMissing { block_hash },
+                None => Error::BestChainTipHeaderMissing,
+            })?;
+        let block_hash = header.hash();
+        let proof = self.backend.read_proof(&key, block_hash).await?;
+        let value = verify_storage_proof(&header, &key, proof.clone())?
+            .map(|data| {
+                Value::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
+                    error,
+                    key: key.clone(),
+                })
+            })
+            .transpose()?;
+        Ok(StorageReadProof {
+            block_hash,
+            key,
+            value,
+            proof,
+        })
+    }
+
+    /// Subscribe to the value of a [StorageMap] entry in the best chain.
+    ///
+    /// Emits the current value right away and then every new value reported by
+    /// [backend::Backend::subscribe_storage]. Consecutive equal values are only emitted once.
+    async fn subscribe_map_value<
+        S: StorageMap<Key, Value>,
+        Key: FullCodec,
+        Value: FullCodec + Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+        key: Key,
+    ) -> Result<BoxStream<'static, Result<Option<Value>, Error>>, Error> {
+        let key = S::storage_map_final_key(key);
+        // We subscribe before we fetch the current value so that we don’t miss any changes.
+        let changes = self.backend.subscribe_storage(key.clone()).await?;
+        let current = self.backend.fetch(&key, None).await?;
+        let values = stream::once(future::ready(Ok(current)))
+            .chain(changes)
+            .map(move |maybe_data| {
+                maybe_data?
+                    .map(|data| {
+                        Value::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
+                            error,
+                            key: key.clone(),
+                        })
+                    })
+                    .transpose()
+            })
+            .scan(None, |last: &mut Option<Option<Value>>, result| {
+                let item = match result {
+                    Ok(value) if last.as_ref() == Some(&value) => None,
+                    Ok(value) => {
+                        *last = Some(value.clone());
+                        Some(Ok(value))
+                    }
+                    Err(error) => Some(Err(error)),
+                };
+                future::ready(Some(item))
+            })
+            .filter_map(future::ready)
+            .boxed();
+        Ok(values)
+    }
+}
+
+#[async_trait::async_trait]
+impl ClientT for Client {
+    async fn submit_transaction<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        self.submit_extrinsic(transaction.extrinsic, Message_::result_from_events)
+            .await
+    }
+
+    async fn submit_raw(
+        &self,
+        encoded_extrinsic: Vec<u8>,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        let decoding_error = |error| Error::TransactionDecoding {
+            tx_hash: Hashing::hash(&encoded_extrinsic),
+            error,
+        };
+        let extrinsic = backend::UncheckedExtrinsic::decode(&mut &encoded_extrinsic[..])
+            .map_err(|error| decoding_error(TransactionDecodingError::Codec(error)))?;
+        transaction::extrinsic_parts(&extrinsic).map_err(decoding_error)?;
+        self.submit_extrinsic(extrinsic, |events| event::get_dispatch_result(&events))
+            .await
+    }
+
+    async fn submit_transaction_with_confirmation<Message_: Message>(
+        &self,
+        transaction: Transaction<Message_>,
+        policy: ConfirmationPolicy,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        let tx_included_future = self.submit_transaction(transaction).await?;
+        let client = self.at_latest();
+        Ok(Box::pin(async move {
+            let tx_included = tx_included_future.await?;
+            client.wait_for_confirmation(&tx_included, policy).await?;
+            Ok(tx_included)
+        }))
+    }
+
     async fn sign_and_submit_message<Message_: Message>(
         &self,
-        author: &ed25519::Pair,
+        author: &dyn Signer,
         message: Message_,
         fee: Balance,
     ) -> Result<Response<TransactionIncluded, Error>, Error> {
-        let account_id = author.public();
-        let key_pair = author.clone();
+        let account_id = author.account_id();
         let genesis_hash = self.genesis_hash();
-        let client = self.clone();
-        let nonce = client.account_nonce(&account_id).await?;
+        let client = self.at_latest();
+        let chain_nonce = client.account_nonce(&account_id).await?;
         let runtime_transaction_version = self.runtime_version().await?.transaction_version;
-        let transaction = Transaction::new_signed(
-            &key_pair,
+        let nonce = self.nonces.reserve(account_id, chain_nonce);
+        let transaction = match Transaction::new_signed(
+            author,
             message,
             TransactionExtra {
                 nonce,
@@ -220,8 +914,85 @@ impl ClientT for Client {
                 fee,
                 runtime_transaction_version,
             },
-        );
-        client.submit_transaction(transaction).await
+        )
+        .await
+        {
+            Ok(transaction) => transaction,
+            Err(error) => {
+                self.nonces.release(account_id, nonce);
+                return Err(error);
+            }
+        };
+        let tx_included_fut = match client.submit_transaction(transaction).await {
+            Ok(tx_included_fut) => tx_included_fut,
+            Err(error) => {
+                self.nonces.release(account_id, nonce);
+                return Err(error);
+            }
+        };
+        let nonces = self.nonces.clone();
+        Ok(Box::pin(async move {
+            let result = tx_included_fut.await;
+            if result.is_err() {
+                // The transaction may have been dropped. We cannot tell which nonces are still
+                // valid and start over with the chain nonce.
+                nonces.reset(account_id);
+            }
+            result
+        }))
+    }
+
+    async fn estimate_fee<Message_: Message>(
+        &self,
+        author: &AccountId,
+        message: Message_,
+    ) -> Result<FeeEstimate, Error> {
+        let client = self.at_latest();
+        let constants = client.chain_constants().await?;
+        let call = message.into_runtime_call();
+
+        // Mirrors `payer_account` in the runtime: the org pays if the author is a member.
+        let payer = match fees::paying_org(&call) {
+            Some(org_id) => match client.get_org(org_id.clone()).await? {
+                Some(org) => match client.get_user_by_account(author).await? {
+                    Some((user_id, _)) if org.members().contains(&user_id) => org.account_id(),
+                    _ => *author,
+                },
+                None => *author,
+            },
+            None => *author,
+        };
+        let registration_fee = if fees::charges_registration_fee(&call) {
+            constants.registration_fee
+        } else {
+            0
+        };
+
+        Ok(FeeEstimate {
+            payer,
+            tx_fee: constants.minimum_tx_fee,
+            registration_fee,
+            existential_deposit: constants.existential_deposit,
+        })
+    }
+
+    async fn fund_org(
+        &self,
+        author: &dyn Signer,
+        org_id: Id,
+        amount: Balance,
+        fee: Balance,
+    ) -> Result<Response<TransactionIncluded, Error>, Error> {
+        let org = self
+            .at_latest()
+            .get_org(org_id.clone())
+            .await?
+            .ok_or(Error::OrgMissing { org_id })?;
+        let message = message::Transfer {
+            recipient: org.account_id(),
+            amount,
+        };
+        self.sign_and_submit_message(author, message, fee).await
     }
 
     async fn block_header(&self, block_hash: BlockHash) -> Result<Option<BlockHeader>, Error> {
@@ -233,10 +1004,90 @@ impl ClientT for Client {
         maybe_header.ok_or_else(|| Error::BestChainTipHeaderMissing)
     }
 
+    async fn get_transaction(
+        &self,
+        tx_hash: TxHash,
+        block_hash_hint: Option<BlockHash>,
+    ) -> Result<Option<TransactionInfo>, Error> {
+        if let Some(block_hash) = block_hash_hint {
+            if let Some(info) = self.find_transaction_in_block(tx_hash, block_hash).await? {
+                return Ok(Some(info));
+            }
+        }
+
+        let mut header = self.block_header_best_chain().await?;
+        for _ in 0..TRANSACTION_SEARCH_DEPTH {
+            let block_hash = header.hash();
+            if Some(block_hash) != block_hash_hint {
+                if let Some(info) = self.find_transaction_in_block(tx_hash, block_hash).await? {
+                    return Ok(Some(info));
+                }
+            }
+            header = match self.backend.block_header(Some(header.parent_hash)).await? {
+                Some(parent) => parent,
+                None => break,
+            };
+        }
+        Ok(None)
+    }
+
+    async fn block_body(
+        &self,
+        block_hash: BlockHash,
+    ) -> Result<Option<Vec<TransactionInfo>>, Error> {
+        let block = match self.backend.block(block_hash).await? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let event_records = self.block_event_records(block_hash).await?;
+        block
+            .extrinsics
+            .iter()
+            .enumerate()
+            .filter(|(_, extrinsic)| extrinsic.signature.is_some())
+            .map(|(index, extrinsic)| {
+                transaction_info(block_hash, index as u32, extrinsic, &event_records)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
     fn genesis_hash(&self) -> Hash {
         self.backend.get_genesis_hash()
     }
 
+    async fn account_statement(
+        &self,
+        account_id: &AccountId,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> Result<Vec<AccountStatementEntry>, Error> {
+        let mut entries = Vec::new();
+        let mut maybe_header = self.backend.block_header(self.block_hash).await?;
+        while let Some(header) = maybe_header {
+            if header.number < from_block {
+                break;
+            }
+            let block_hash = header.hash();
+            if header.number <= to_block {
+                let events = self
+                    .block_event_records(block_hash)
+                    .await?
+                    .into_iter()
+                    .map(|record| record.event)
+                    .collect::<Vec<_>>();
+                let block_entries =
+                    statement::statement_entries(account_id, header.number, block_hash, &events);
+                entries.splice(0..0, block_entries);
+            }
+            if header.number == 0 {
+                break;
+            }
+            maybe_header = self.backend.block_header(Some(header.parent_hash)).await?;
+        }
+        Ok(entries)
+    }
+
     async fn account_exists(&self, account_id: &AccountId) -> Result<bool, Error> {
         self.store_contains_key::<store::Account, _, _>(*account_id)
             .await
@@ -259,78 +1110,413 @@ impl ClientT for Client {
         Ok(account_info.data.free)
     }
 
-    async fn get_id_status(&self, id: &Id) -> Result<IdStatus, Error> {
-        if self.get_org(id.clone()).await?.is_some() || self.get_user(id.clone()).await?.is_some() {
-            Ok(IdStatus::Taken)
-        } else if self
-            .store_contains_key::<store::RetiredIds1, _, _>(id.clone())
+    async fn account_info(&self, account_id: &AccountId) -> Result<AccountInfo, Error> {
+        let account_info = self
+            .fetch_map_value::<store::Account, _, _>(*account_id)
+            .await?;
+        let vesting = self
+            .fetch_map_value::<store::Vesting, _, _>(*account_id)
             .await?
-        {
-            Ok(IdStatus::Retired)
-        } else {
-            Ok(IdStatus::Available)
+            .map(|vesting| VestingSchedule {
+                locked: vesting.locked,
+                per_block: vesting.per_block,
+                starting_block: vesting.starting_block,
+            });
+        Ok(AccountInfo {
+            free: account_info.data.free,
+            reserved: account_info.data.reserved,
+            locked: account_info
+                .data
+                .misc_frozen
+                .max(account_info.data.fee_frozen),
+            vesting,
+        })
+    }
+
+    async fn get_id_status(&self, id: &Id) -> Result<IdStatus, Error> {
+        self.fetch_map_value::<store::IdStatuses1, _, _>(id.clone())
+            .await
+    }
+
+    async fn id_available(&self, id: &Id) -> Result<IdStatus, Error> {
+        // The registry RPC only answers for the best block.
+        if self.block_hash.is_some() {
+            return self.get_id_status(id).await;
+        }
+        match self.backend.id_status(id).await? {
+            Some(id_status) => Ok(id_status),
+            None => self.get_id_status(id).await,
         }
     }
 
+    async fn list_id_reservations(&self) -> Result<Vec<(Id, AccountId)>, Error> {
+        let reservations_prefix = store::ReservedIds1::final_prefix();
+        let keys = self
+            .backend
+            .fetch_keys(&reservations_prefix, self.block_hash)
+            .await?;
+        let mut ids = Vec::with_capacity(keys.len());
+        for key in keys {
+            let id = store::ReservedIds1::decode_key(&key)
+                .expect("Invalid runtime state key. Cannot extract reserved ID");
+            ids.push(id);
+        }
+        ids.sort();
+        let holders = future::try_join_all(
+            ids.iter()
+                .map(|id| self.fetch_map_value::<store::ReservedIds1, _, _>(id.clone())),
+        )
+        .await?;
+        Ok(ids
+            .into_iter()
+            .zip(holders)
+            .filter_map(|(id, holder)| holder.map(|holder| (id, holder)))
+            .collect())
+    }
+
+    async fn list_project_name_reservations(
+        &self,
+    ) -> Result<Vec<(ProjectName, ProjectDomain)>, Error> {
+        let reservations_prefix = store::ReservedProjectNames1::final_prefix();
+        let keys = self
+            .backend
+            .fetch_keys(&reservations_prefix, self.block_hash)
+            .await?;
+        let mut project_names = Vec::with_capacity(keys.len());
+        for key in keys {
+            let project_name = store::ReservedProjectNames1::decode_key(&key)
+                .expect("Invalid runtime state key. Cannot extract reserved project name");
+            project_names.push(project_name);
+        }
+        project_names.sort();
+        let domains = future::try_join_all(project_names.iter().map(|project_name| {
+            self.fetch_map_value::<store::ReservedProjectNames1, _, _>(project_name.clone())
+        }))
+        .await?;
+        Ok(project_names
+            .into_iter()
+            .zip(domains)
+            .filter_map(|(project_name, domain)| domain.map(|domain| (project_name, domain)))
+            .collect())
+    }
+
     async fn get_org(&self, id: Id) -> Result<Option<state::Orgs1Data>, Error> {
-        self.fetch_map_value::<store::Orgs1, _, _>(id.clone()).await
+        self.fetch_map_value::<store::Orgs1, _, _>(id).await
+    }
+
+    async fn get_org_with_proof(
+        &self,
+        org_id: Id,
+    ) -> Result<StorageReadProof<state::Orgs1Data>, Error> {
+        self.fetch_map_value_with_proof::<store::Orgs1, _, _>(org_id)
+            .await
+    }
+
+    async fn subscribe_org(
+        &self,
+        org_id: Id,
+    ) -> Result<BoxStream<'static, Result<Option<state::Orgs1Data>, Error>>, Error> {
+        self.subscribe_map_value::<store::Orgs1, _, _>(org_id).await
     }
 
     async fn list_orgs(&self) -> Result<Vec<Id>, Error> {
         let orgs_prefix = store::Orgs1::final_prefix();
-        let keys = self.backend.fetch_keys(&orgs_prefix, None).await?;
+        let keys = self
+            .backend
+            .fetch_keys(&orgs_prefix, self.block_hash)
+            .await?;
         let mut org_ids: Vec<Id> = Vec::with_capacity(keys.len());
         for key in keys {
             let org_id = store::Orgs1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract org ID");
             org_ids.push(org_id)
         }
+        org_ids.sort();
         Ok(org_ids)
     }
 
+    async fn list_orgs_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<Id>, Error> {
+        self.fetch_map_keys_paged::<store::Orgs1, _>(start_key, limit)
+            .await
+    }
+
+    async fn list_orgs_with_data(&self) -> Result<Vec<(Id, state::Orgs1Data)>, Error> {
+        self.fetch_map_entries::<store::Orgs1, _>().await
+    }
+
     async fn get_user(&self, id: Id) -> Result<Option<state::Users1Data>, Error> {
-        self.fetch_map_value::<store::Users1, _, _>(id.clone())
+        self.fetch_map_value::<store::Users1, _, _>(id).await
+    }
+
+    async fn get_user_with_proof(
+        &self,
+        user_id: Id,
+    ) -> Result<StorageReadProof<state::Users1Data>, Error> {
+        self.fetch_map_value_with_proof::<store::Users1, _, _>(user_id)
             .await
     }
 
+    async fn get_user_by_account(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<(Id, state::Users1Data)>, Error> {
+        let user_id = match self
+            .fetch_map_value::<store::UsersByAccountId1, _, _>(*account_id)
+            .await?
+        {
+            Some(user_id) => user_id,
+            None => return Ok(None),
+        };
+        let user = self.get_user(user_id.clone()).await?;
+        Ok(user.map(|user| (user_id, user)))
+    }
+
     async fn list_users(&self) -> Result<Vec<Id>, Error> {
         let users_prefix = store::Users1::final_prefix();
-        let keys = self.backend.fetch_keys(&users_prefix, None).await?;
+        let keys = self
+            .backend
+            .fetch_keys(&users_prefix, self.block_hash)
+            .await?;
         let mut user_ids: Vec<Id> = Vec::with_capacity(keys.len());
         for key in keys {
             let user_id = store::Users1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract user ID");
             user_ids.push(user_id);
         }
-
+        user_ids.sort();
         Ok(user_ids)
     }
 
+    async fn list_users_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<Id>, Error> {
+        self.fetch_map_keys_paged::<store::Users1, _>(start_key, limit)
+            .await
+    }
+
+    async fn list_users_with_data(&self) -> Result<Vec<(Id, state::Users1Data)>, Error> {
+        self.fetch_map_entries::<store::Users1, _>().await
+    }
+
     async fn get_project(
         &self,
         project_name: ProjectName,
         project_domain: ProjectDomain,
     ) -> Result<Option<state::Projects1Data>, Error> {
-        let project_id = (project_name.clone(), project_domain.clone());
-        self.fetch_map_value::<store::Projects1, _, _>(project_id.clone())
+        self.fetch_map_value::<store::Projects1, _, _>((project_name, project_domain))
             .await
     }
 
+    async fn get_project_with_proof(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<StorageReadProof<state::Projects1Data>, Error> {
+        self.fetch_map_value_with_proof::<store::Projects1, _, _>((project_name, project_domain))
+            .await
+    }
+
+    async fn subscribe_project(
+        &self,
+        project_name: ProjectName,
+        project_domain: ProjectDomain,
+    ) -> Result<BoxStream<'static, Result<Option<state::Projects1Data>, Error>>, Error> {
+        self.subscribe_map_value::<store::Projects1, _, _>((project_name, project_domain))
+            .await
+    }
+
+    async fn get_pending_project_transfer(
+        &self,
+        project_name: ProjectName,
+        user_id: Id,
+    ) -> Result<Option<Id>, Error> {
+        let project_id = (project_name, ProjectDomain::User(user_id));
+        self.fetch_map_value::<store::PendingProjectTransfers1, _, _>(project_id)
+            .await
+    }
+
+    async fn list_projects_for(
+        &self,
+        project_domain: ProjectDomain,
+    ) -> Result<Vec<(ProjectName, state::Projects1Data)>, Error> {
+        let mut project_names = match &project_domain {
+            ProjectDomain::Org(org_id) => self
+                .get_org(org_id.clone())
+                .await?
+                .map(|org| org.projects().clone()),
+            ProjectDomain::User(user_id) => self
+                .get_user(user_id.clone())
+                .await?
+                .map(|user| user.projects().clone()),
+        }
+        .unwrap_or_default();
+        project_names.sort();
+
+        let projects =
+            future::try_join_all(project_names.iter().map(|project_name| {
+                self.get_project(project_name.clone(), project_domain.clone())
+            }))
+            .await?;
+        Ok(project_names
+            .into_iter()
+            .zip(projects)
+            .filter_map(|(project_name, project)| project.map(|project| (project_name, project)))
+            .collect())
+    }
+
     async fn list_projects(&self) -> Result<Vec<ProjectId>, Error> {
         let project_prefix = store::Projects1::final_prefix();
-        let keys = self.backend.fetch_keys(&project_prefix, None).await?;
+        let keys = self
+            .backend
+            .fetch_keys(&project_prefix, self.block_hash)
+            .await?;
         let mut project_ids = Vec::with_capacity(keys.len());
         for key in keys {
             let project_id = store::Projects1::decode_key(&key)
                 .expect("Invalid runtime state key. Cannot extract project ID");
             project_ids.push(project_id);
         }
+        project_ids.sort();
         Ok(project_ids)
     }
 
+    async fn list_projects_paged(
+        &self,
+        start_key: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Page<ProjectId>, Error> {
+        self.fetch_map_keys_paged::<store::Projects1, _>(start_key, limit)
+            .await
+    }
+
+    async fn list_projects_with_data(
+        &self,
+    ) -> Result<Vec<(ProjectId, state::Projects1Data)>, Error> {
+        self.fetch_map_entries::<store::Projects1, _>().await
+    }
+
+    async fn sudo_key(&self) -> Result<Option<AccountId>, Error> {
+        let key = &SUDO_KEY_STORAGE_KEY[..];
+        let maybe_data = self.fetch(key, self.block_hash).await?;
+        maybe_data
+            .map(|data| {
+                Decode::decode(&mut &data[..]).map_err(|error| Error::StateDecoding {
+                    error,
+                    key: key.to_vec(),
+                })
+            })
+            .transpose()
+    }
+
     async fn runtime_version(&self) -> Result<RuntimeVersion, Error> {
         self.backend.runtime_version().await
     }
+
+    async fn runtime_metadata(&self) -> Result<RuntimeMetadataLastVersion, Error> {
+        let metadata = self.backend.runtime_metadata().await?;
+        constants::decode_metadata(&metadata)
+    }
+
+    async fn version_compatibility(&self) -> Result<VersionCompatibility, Error> {
+        let metadata = self.backend.runtime_metadata().await?;
+        version::check_chain(&self.runtime_version().await?, &metadata)
+    }
+
+    async fn node_limits(&self) -> Result<Option<NodeLimits>, Error> {
+        self.backend.node_limits().await
+    }
+
+    async fn capabilities(&self) -> Result<Capabilities, Error> {
+        self.backend.capabilities().await
+    }
+
+    async fn treasury_balance(&self) -> Result<Balance, Error> {
+        self.free_balance(&registry::rewards::treasury_account())
+            .await
+    }
+
+    async fn get_treasury_proposal(
+        &self,
+        proposal_id: TreasuryProposalId,
+    ) -> Result<Option<state::TreasuryProposals1Data>, Error> {
+        self.fetch_map_value::<store::TreasuryProposals1, _, _>(proposal_id)
+            .await
+    }
+
+    async fn list_treasury_proposals(&self) -> Result<Vec<TreasuryProposalId>, Error> {
+        let proposals_prefix = store::TreasuryProposals1::final_prefix();
+        let keys = self
+            .backend
+            .fetch_keys(&proposals_prefix, self.block_hash)
+            .await?;
+        let mut proposal_ids = Vec::with_capacity(keys.len());
+        for key in keys {
+            let proposal_id = store::TreasuryProposals1::decode_key(&key)
+                .expect("Invalid runtime state key. Cannot extract treasury proposal ID");
+            proposal_ids.push(proposal_id);
+        }
+        proposal_ids.sort();
+        Ok(proposal_ids)
+    }
+
+    async fn migration_history(&self) -> Result<Vec<state::MigrationHistory1Data>, Error> {
+        self.fetch_value::<store::MigrationHistory1, _>().await
+    }
+
+    async fn chain_constants(&self) -> Result<ChainConstants, Error> {
+        if let Some(constants) = *self.constants.lock().unwrap() {
+            return Ok(constants);
+        }
+        let metadata = self.backend.runtime_metadata().await?;
+        let constants = ChainConstants::from_metadata(&metadata)?;
+        *self.constants.lock().unwrap() = Some(constants);
+        Ok(constants)
+    }
+
+    async fn registration_fee(&self) -> Result<Balance, Error> {
+        Ok(self.chain_constants().await?.registration_fee)
+    }
+
+    async fn minimum_tx_fee(&self) -> Result<Balance, Error> {
+        Ok(self.chain_constants().await?.minimum_tx_fee)
+    }
+}
+
+/// Decode the extrinsic at position `index` of the block and collect its events from the
+/// `event_records` of the block.
+fn transaction_info(
+    block_hash: BlockHash,
+    index: u32,
+    extrinsic: &backend::UncheckedExtrinsic,
+    event_records: &[event::Record],
+) -> Result<TransactionInfo, Error> {
+    let tx_hash = Hashing::hash_of(extrinsic);
+    let parts = transaction::extrinsic_parts(extrinsic)
+        .map_err(|error| Error::TransactionDecoding { tx_hash, error })?;
+    let events = event_records
+        .iter()
+        .filter(|record| event::transaction_index(record) == Some(index))
+        .map(|record| record.event.clone())
+        .collect::<Vec<_>>();
+    let result = event::get_dispatch_result(&events)
+        .map_err(|error| Error::EventExtraction { error, tx_hash })?;
+    Ok(TransactionInfo {
+        tx_hash,
+        block: block_hash,
+        index,
+        author: parts.author,
+        nonce: parts.nonce,
+        fee: parts.fee,
+        call: parts.message,
+        events,
+        result,
+    })
 }
 
 /// Parse an [AccountId] from str expected to be in the ss58 format, failing otherwise.
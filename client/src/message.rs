@@ -35,6 +35,28 @@ pub trait Message: Send + 'static {
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError>;
 
     fn into_runtime_call(self) -> RuntimeCall;
+
+    /// Inverse of [Message::into_runtime_call]. Returns `None` if `call` was not created from a
+    /// message of this type.
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+/// Return the registry call wrapped by `call`.
+fn registry_call(call: RuntimeCall) -> Option<call::Registry> {
+    match call {
+        RuntimeCall::Registry(registry_call) => Some(registry_call),
+        _ => None,
+    }
+}
+
+/// Return the call dispatched by `call` through the sudo module.
+fn sudo_call(call: RuntimeCall) -> Option<RuntimeCall> {
+    match call {
+        RuntimeCall::Sudo(call::Sudo::sudo(sudo_call)) => Some(*sudo_call),
+        _ => None,
+    }
 }
 
 impl Message for message::RegisterProject {
@@ -47,6 +69,45 @@ impl Message for message::RegisterProject {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::register_project(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::register_project(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::UnregisterProject {
+    /// A successful unregistration is accompanied by the `ProjectUnregistered` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        Event::registry(event::Registry::ProjectUnregistered(..))
+                    )
+                })
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::unregister_project(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::unregister_project(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::RegisterMember {
@@ -59,6 +120,13 @@ impl Message for message::RegisterMember {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::register_member(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::register_member(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::RegisterOrg {
@@ -71,6 +139,13 @@ impl Message for message::RegisterOrg {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::register_org(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::register_org(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::UnregisterOrg {
@@ -83,6 +158,40 @@ impl Message for message::UnregisterOrg {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::unregister_org(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::unregister_org(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::DissolveOrg {
+    /// A successful dissolution is accompanied by the `OrgDissolved` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::OrgDissolved(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::dissolve_org(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::dissolve_org(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::RegisterUser {
@@ -90,6 +199,13 @@ impl Message for message::RegisterUser {
         call::Registry::register_user(self).into()
     }
 
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::register_user(message) => Some(message),
+            _ => None,
+        }
+    }
+
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
@@ -107,6 +223,13 @@ impl Message for message::UnregisterUser {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::unregister_user(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::unregister_user(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::Transfer {
@@ -119,18 +242,549 @@ impl Message for message::Transfer {
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::transfer(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::TransferFromOrg {
+    /// A successful transfer is accompanied by the `OrgTransferred` registry event.
     fn result_from_events(
         events: Vec<Event>,
     ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
-        event::get_dispatch_result(&events)
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::OrgTransferred(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
     }
 
     fn into_runtime_call(self) -> RuntimeCall {
         call::Registry::transfer_from_org(self).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer_from_org(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::ProposeTreasurySpend {
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        event::get_dispatch_result(&events)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::propose_treasury_spend(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::propose_treasury_spend(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the result of a registry call that was dispatched through the sudo module.
+///
+/// The result of the inner call is given by the `Sudid` event. If the inner call succeeded, an
+/// event matching `is_success_event` must be present.
+fn sudo_result_from_events(
+    events: Vec<Event>,
+    is_success_event: impl Fn(&Event) -> bool,
+) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+    let dispatch_result = event::get_dispatch_result(&events)?;
+    if dispatch_result.is_err() {
+        return Ok(dispatch_result);
+    }
+    let sudo_result = events
+        .iter()
+        .find_map(|event| match event {
+            Event::pallet_sudo(event::Sudo::Sudid(result)) => Some(*result),
+            _ => None,
+        })
+        .ok_or(event::EventExtractionError::EventMissing)?;
+    if sudo_result.is_ok() {
+        events
+            .iter()
+            .find(|event| is_success_event(event))
+            .ok_or(event::EventExtractionError::EventMissing)?;
+    }
+    Ok(sudo_result.map_err(TransactionError::from))
+}
+
+impl Message for message::ApproveTreasurySpend {
+    /// The message is dispatched through the sudo module. A successful approval is accompanied by
+    /// the `TreasurySpendApproved` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(
+                event,
+                Event::registry(event::Registry::TreasurySpendApproved(..))
+            )
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let approve_call: RuntimeCall = call::Registry::approve_treasury_spend(self).into();
+        call::Sudo::sudo(Box::new(approve_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::approve_treasury_spend(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::RejectTreasurySpend {
+    /// The message is dispatched through the sudo module. A successful rejection is accompanied
+    /// by the `TreasurySpendRejected` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(
+                event,
+                Event::registry(event::Registry::TreasurySpendRejected(..))
+            )
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let reject_call: RuntimeCall = call::Registry::reject_treasury_spend(self).into();
+        call::Sudo::sudo(Box::new(reject_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::reject_treasury_spend(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::CancelTreasurySpend {
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        event::get_dispatch_result(&events)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::cancel_treasury_spend(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::cancel_treasury_spend(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::ReserveId {
+    /// The message is dispatched through the sudo module. A successful reservation is
+    /// accompanied by the `IdReserved` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(event, Event::registry(event::Registry::IdReserved(..)))
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let reserve_call: RuntimeCall = call::Registry::reserve_id(self).into();
+        call::Sudo::sudo(Box::new(reserve_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::reserve_id(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::CancelIdReservation {
+    /// The message is dispatched through the sudo module. A successful cancellation is
+    /// accompanied by the `IdReservationCancelled` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(
+                event,
+                Event::registry(event::Registry::IdReservationCancelled(..))
+            )
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let cancel_call: RuntimeCall = call::Registry::cancel_id_reservation(self).into();
+        call::Sudo::sudo(Box::new(cancel_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::cancel_id_reservation(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::ReserveProjectName {
+    /// The message is dispatched through the sudo module. A successful reservation is
+    /// accompanied by the `ProjectNameReserved` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(
+                event,
+                Event::registry(event::Registry::ProjectNameReserved(..))
+            )
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let reserve_call: RuntimeCall = call::Registry::reserve_project_name(self).into();
+        call::Sudo::sudo(Box::new(reserve_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::reserve_project_name(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::CancelProjectNameReservation {
+    /// The message is dispatched through the sudo module. A successful cancellation is
+    /// accompanied by the `ProjectNameReservationCancelled` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(
+                event,
+                Event::registry(event::Registry::ProjectNameReservationCancelled(..))
+            )
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let cancel_call: RuntimeCall = call::Registry::cancel_project_name_reservation(self).into();
+        call::Sudo::sudo(Box::new(cancel_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::cancel_project_name_reservation(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::FreezeOrg {
+    /// The message is dispatched through the sudo module. A successful freeze is accompanied by
+    /// the `OrgFrozen` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(event, Event::registry(event::Registry::OrgFrozen(..)))
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let freeze_call: RuntimeCall = call::Registry::freeze_org(self).into();
+        call::Sudo::sudo(Box::new(freeze_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::freeze_org(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::UnfreezeOrg {
+    /// The message is dispatched through the sudo module. A successful unfreeze is accompanied by
+    /// the `OrgUnfrozen` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        sudo_result_from_events(events, |event| {
+            matches!(event, Event::registry(event::Registry::OrgUnfrozen(..)))
+        })
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        let unfreeze_call: RuntimeCall = call::Registry::unfreeze_org(self).into();
+        call::Sudo::sudo(Box::new(unfreeze_call)).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(sudo_call(call)?)? {
+            call::Registry::unfreeze_org(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::TransferProjectOwnership {
+    /// A successful transfer offer is accompanied by the `ProjectTransferProposed` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        Event::registry(event::Registry::ProjectTransferProposed(..))
+                    )
+                })
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::transfer_project_ownership(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer_project_ownership(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::AcceptProjectOwnership {
+    /// A successful transfer is accompanied by the `ProjectTransferred` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        Event::registry(event::Registry::ProjectTransferred(..))
+                    )
+                })
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::accept_project_ownership(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::accept_project_ownership(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::TransferProject {
+    /// A successful transfer is accompanied by the `ProjectMoved` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::ProjectMoved(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::transfer_project(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer_project(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::RenameProject {
+    /// A successful rename is accompanied by the `ProjectRenamed` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::ProjectRenamed(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::rename_project(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::rename_project(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::UpdateOrg {
+    /// A successful update is accompanied by the `OrgUpdated` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::OrgUpdated(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::update_org(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::update_org(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::UpdateUser {
+    /// A successful update is accompanied by the `UserUpdated` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| matches!(event, Event::registry(event::Registry::UserUpdated(..))))
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::update_user(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::update_user(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::TransferUserId {
+    /// A successful transfer is accompanied by the `UserIdTransferred` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        Event::registry(event::Registry::UserIdTransferred(..))
+                    )
+                })
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::transfer_user_id(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer_user_id(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl Message for message::TransferOrgMembership {
+    /// A successful transfer is accompanied by the `OrgMembershipTransferred` registry event.
+    fn result_from_events(
+        events: Vec<Event>,
+    ) -> Result<Result<(), TransactionError>, event::EventExtractionError> {
+        let dispatch_result = event::get_dispatch_result(&events)?;
+        if dispatch_result.is_ok() {
+            events
+                .iter()
+                .find(|event| {
+                    matches!(
+                        event,
+                        Event::registry(event::Registry::OrgMembershipTransferred(..))
+                    )
+                })
+                .ok_or(event::EventExtractionError::EventMissing)?;
+        }
+        Ok(dispatch_result)
+    }
+
+    fn into_runtime_call(self) -> RuntimeCall {
+        call::Registry::transfer_org_membership(self).into()
+    }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match registry_call(call)? {
+            call::Registry::transfer_org_membership(message) => Some(message),
+            _ => None,
+        }
+    }
 }
 
 impl Message for message::UpdateRuntime {
@@ -153,15 +807,78 @@ impl Message for message::UpdateRuntime {
         let set_code_call: RuntimeCall = call::System::set_code(self.code).into();
         call::Sudo::sudo(Box::new(set_code_call)).into()
     }
+
+    fn from_runtime_call(call: RuntimeCall) -> Option<Self> {
+        match sudo_call(call)? {
+            RuntimeCall::System(call::System::set_code(code)) => {
+                Some(message::UpdateRuntime { code })
+            }
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use core::convert::TryFrom;
 
     use radicle_registry_runtime::event;
     use radicle_registry_runtime::Event;
 
+    #[test]
+    fn dissolve_org_event_ok() {
+        let org_id = Id::try_from("monadic").unwrap();
+        let recipient = sp_core::ed25519::Public::from_raw([1; 32]);
+        let events = vec![
+            Event::registry(event::Registry::OrgDissolved(org_id, recipient, 10)),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = message::DissolveOrg::result_from_events(events).unwrap();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn dissolve_org_event_missing() {
+        let events = vec![Event::system(event::System::ExtrinsicSuccess(
+            Default::default(),
+        ))];
+        let result = message::DissolveOrg::result_from_events(events);
+        assert!(matches!(
+            result,
+            Err(crate::event::EventExtractionError::EventMissing)
+        ))
+    }
+
+    #[test]
+    fn approve_treasury_spend_event_ok() {
+        let recipient = sp_core::ed25519::Public::from_raw([1; 32]);
+        let events = vec![
+            Event::registry(event::Registry::TreasurySpendApproved(0, recipient, 10)),
+            Event::pallet_sudo(event::Sudo::Sudid(Ok(()))),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = message::ApproveTreasurySpend::result_from_events(events).unwrap();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn approve_treasury_spend_inner_call_failed() {
+        let events = vec![
+            Event::pallet_sudo(event::Sudo::Sudid(Err(
+                RegistryError::InexistentTreasuryProposal.into(),
+            ))),
+            Event::system(event::System::ExtrinsicSuccess(Default::default())),
+        ];
+        let result = message::ApproveTreasurySpend::result_from_events(events).unwrap();
+        assert_eq!(
+            result,
+            Err(TransactionError::from(
+                RegistryError::InexistentTreasuryProposal
+            ))
+        )
+    }
+
     #[test]
     fn update_runtime_event_ok() {
         let events = vec![
@@ -0,0 +1,45 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of submitted transactions. Enabled with the `metrics` feature.
+//!
+//! The metrics are registered with the default registry of the `prometheus` crate. Collect them
+//! with `prometheus::gather()`.
+use lazy_static::lazy_static;
+use prometheus::{register_histogram, Histogram};
+use std::time::Duration;
+
+lazy_static! {
+    static ref INCLUSION_LATENCY: Histogram = register_histogram!(
+        "radicle_registry_client_tx_inclusion_latency_seconds",
+        "Time from submitting a transaction until it was included in a block"
+    )
+    .expect("metric is registered only once");
+    static ref FINALIZATION_LATENCY: Histogram = register_histogram!(
+        "radicle_registry_client_tx_finalization_latency_seconds",
+        "Time from submitting a transaction until a block including it was finalized"
+    )
+    .expect("metric is registered only once");
+}
+
+/// Record [crate::TransactionIncluded::inclusion_latency].
+pub(crate) fn observe_inclusion_latency(latency: Duration) {
+    INCLUSION_LATENCY.observe(latency.as_secs_f64());
+}
+
+/// Record [crate::TransactionFinalized::finalization_latency].
+pub(crate) fn observe_finalization_latency(latency: Duration) {
+    FINALIZATION_LATENCY.observe(latency.as_secs_f64());
+}
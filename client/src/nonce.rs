@@ -0,0 +1,121 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Track the nonces of transactions submitted by the client.
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use radicle_registry_core::{state::AccountTransactionIndex, AccountId};
+
+/// Hands out nonces for transactions signed by the client so that several transactions of the
+/// same account can be in flight at the same time.
+///
+/// The nonce of an account on chain only increases when a transaction is included in a block.
+/// Reading it for every submission would assign the same nonce to concurrent transactions. The
+/// manager remembers the nonces it handed out and never hands out a nonce twice unless it was
+/// released.
+#[derive(Debug, Default)]
+pub(crate) struct NonceManager {
+    /// Next nonce to hand out for each account.
+    next_nonces: Mutex<HashMap<AccountId, AccountTransactionIndex>>,
+}
+
+impl NonceManager {
+    /// Reserve the nonce for the next transaction of `account_id`.
+    ///
+    /// `chain_nonce` is the nonce of the account at the latest block. It is used if it is ahead of
+    /// the tracked nonce, for example because transactions were submitted by another client.
+    pub fn reserve(
+        &self,
+        account_id: AccountId,
+        chain_nonce: AccountTransactionIndex,
+    ) -> AccountTransactionIndex {
+        let mut next_nonces = self.next_nonces.lock().unwrap();
+        let next_nonce = next_nonces.entry(account_id).or_insert(chain_nonce);
+        let nonce = std::cmp::max(*next_nonce, chain_nonce);
+        *next_nonce = nonce + 1;
+        nonce
+    }
+
+    /// Release a reserved nonce whose transaction was not accepted by the node.
+    ///
+    /// The nonce is only handed out again if no later nonce was reserved in the meantime.
+    /// Otherwise the later transactions stay valid and the gap is filled by the chain nonce once
+    /// the tracked nonces are behind it.
+    pub fn release(&self, account_id: AccountId, nonce: AccountTransactionIndex) {
+        let mut next_nonces = self.next_nonces.lock().unwrap();
+        if let Some(next_nonce) = next_nonces.get_mut(&account_id) {
+            if *next_nonce == nonce + 1 {
+                *next_nonce = nonce;
+            }
+        }
+    }
+
+    /// Forget the tracked nonce of `account_id`. The next reserved nonce is the chain nonce.
+    pub fn reset(&self, account_id: AccountId) {
+        self.next_nonces.lock().unwrap().remove(&account_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sp_core::{ed25519, Pair as _};
+
+    #[test]
+    fn reserve_consecutive_nonces() {
+        let nonces = NonceManager::default();
+        let account_id = ed25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public();
+
+        assert_eq!(nonces.reserve(account_id, 3), 3);
+        // The chain nonce does not change while the transactions are in flight.
+        assert_eq!(nonces.reserve(account_id, 3), 4);
+        assert_eq!(nonces.reserve(account_id, 3), 5);
+        // The chain nonce overtakes the tracked nonce.
+        assert_eq!(nonces.reserve(account_id, 10), 10);
+    }
+
+    #[test]
+    fn release_nonce() {
+        let nonces = NonceManager::default();
+        let account_id = ed25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public();
+
+        let first = nonces.reserve(account_id, 0);
+        let second = nonces.reserve(account_id, 0);
+        nonces.release(account_id, first);
+        assert_eq!(nonces.reserve(account_id, 0), 2);
+
+        nonces.release(account_id, 2);
+        assert_eq!(nonces.reserve(account_id, 0), 2);
+        assert_eq!(second, 1);
+    }
+
+    #[test]
+    fn reset_nonce() {
+        let nonces = NonceManager::default();
+        let account_id = ed25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public();
+
+        nonces.reserve(account_id, 0);
+        nonces.reserve(account_id, 0);
+        nonces.reset(account_id);
+        assert_eq!(nonces.reserve(account_id, 1), 1);
+    }
+}
@@ -0,0 +1,79 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Balance updates and low balance alerts for org accounts. See [crate::Client::watch_org_treasury].
+use crate::interface::*;
+
+/// Balance of an org account after it changed at the best block. Emitted by
+/// [crate::Client::watch_org_treasury].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgBalanceUpdate {
+    /// Number of the best block the balances were read after.
+    pub block_number: BlockNumber,
+
+    /// Balance of the org account at the best block.
+    pub best_balance: Balance,
+
+    /// Balance of the org account at the latest finalized block when the update was emitted.
+    pub finalized_balance: Balance,
+
+    /// Set if the best balance crossed the low water mark with this update.
+    pub alert: Option<OrgBalanceAlert>,
+}
+
+/// Alert attached to an [OrgBalanceUpdate] when the best balance crosses the low water mark.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrgBalanceAlert {
+    /// The best balance dropped below the low water mark. Also emitted for the first update if
+    /// the balance is already below the low water mark.
+    Low,
+    /// The best balance rose to or above the low water mark again after a [OrgBalanceAlert::Low]
+    /// alert.
+    Recovered,
+}
+
+/// Determine the alert for a change of the best balance from `previous` to `current`. `previous`
+/// is `None` for the first update.
+pub(crate) fn balance_alert(
+    previous: Option<Balance>,
+    current: Balance,
+    low_water_mark: Balance,
+) -> Option<OrgBalanceAlert> {
+    let was_low = previous.map(|previous| previous < low_water_mark);
+    let is_low = current < low_water_mark;
+    match (was_low, is_low) {
+        (None, true) | (Some(false), true) => Some(OrgBalanceAlert::Low),
+        (Some(true), false) => Some(OrgBalanceAlert::Recovered),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn alerts() {
+        assert_eq!(balance_alert(None, 5, 10), Some(OrgBalanceAlert::Low));
+        assert_eq!(balance_alert(None, 10, 10), None);
+        assert_eq!(balance_alert(Some(12), 9, 10), Some(OrgBalanceAlert::Low));
+        assert_eq!(balance_alert(Some(9), 8, 10), None);
+        assert_eq!(
+            balance_alert(Some(9), 10, 10),
+            Some(OrgBalanceAlert::Recovered)
+        );
+        assert_eq!(balance_alert(Some(12), 11, 10), None);
+    }
+}
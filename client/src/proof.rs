@@ -0,0 +1,81 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Verifiable reads of the registry state. See [crate::ClientT::get_org_with_proof].
+use parity_scale_codec::Encode;
+use sp_runtime::traits::Header as _;
+use sp_state_machine::StorageProof;
+
+use radicle_registry_runtime::Hashing;
+
+use crate::interface::*;
+
+/// A storage value read at a block together with a Merkle proof of the read.
+///
+/// Returned by [crate::ClientT::get_org_with_proof] and similar methods. Use
+/// [StorageReadProof::verify] to check the proof against a trusted block header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageReadProof<T> {
+    /// Block whose state the value was read from.
+    pub block_hash: BlockHash,
+    /// Final storage key of the value.
+    pub key: Vec<u8>,
+    /// The value or `None` if the key is not set at the block.
+    pub value: Option<T>,
+    /// Trie nodes that prove the value against the state root of the block.
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl<T: Encode> StorageReadProof<T> {
+    /// Check that `header` is the header of [StorageReadProof::block_hash] and that the proof
+    /// proves [StorageReadProof::value] against the state root of the header.
+    ///
+    /// Fails with [Error::StorageProofVerification] otherwise.
+    pub fn verify(&self, header: &Header) -> Result<(), Error> {
+        let verification_error = |reason| Error::StorageProofVerification {
+            block_hash: self.block_hash,
+            reason,
+        };
+        if header.hash() != self.block_hash {
+            return Err(verification_error("header does not match the block hash"));
+        }
+        let proven_value = verify_storage_proof(header, &self.key, self.proof.clone())?;
+        if proven_value != self.value.as_ref().map(Encode::encode) {
+            return Err(verification_error("proven value does not match the value"));
+        }
+        Ok(())
+    }
+}
+
+/// Check a storage proof for `key` against the state root of `header` and return the proven raw
+/// value.
+///
+/// Fails with [Error::StorageProofVerification] if the proof is invalid or does not cover `key`.
+pub fn verify_storage_proof(
+    header: &Header,
+    key: &[u8],
+    proof: Vec<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, Error> {
+    let mut values = sp_state_machine::read_proof_check::<Hashing, _>(
+        header.state_root,
+        StorageProof::new(proof),
+        std::iter::once(key),
+    )
+    .map_err(|_| Error::StorageProofVerification {
+        block_hash: header.hash(),
+        reason: "invalid storage proof",
+    })?;
+    Ok(values.remove(key).flatten())
+}
@@ -0,0 +1,79 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Provides the [Signer] trait that abstracts over the key that authors transactions.
+use crate::{ed25519, sr25519, CryptoPair as _};
+use radicle_registry_core::{AccountId, Signature};
+
+/// Signs transactions on behalf of an account.
+///
+/// Implemented for [ed25519::Pair] and [sr25519::Pair]. Implement it to sign with keys that are not available to the
+/// client, for example keys held by a hardware wallet, an HSM or a remote signing service.
+///
+/// ```
+/// # use radicle_registry_client::*;
+/// struct RemoteSigner {
+///     account_id: AccountId,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl Signer for RemoteSigner {
+///     fn account_id(&self) -> AccountId {
+///         self.account_id
+///     }
+///
+///     async fn sign(&self, payload: Vec<u8>) -> Result<Signature, SignerError> {
+///         // Send the payload to the remote service and return the signature.
+///         # unimplemented!()
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait Signer: Send + Sync {
+    /// The account that authors the transactions. Signatures must be valid for this account.
+    fn account_id(&self) -> AccountId;
+
+    /// Sign `payload` with the key of [Signer::account_id].
+    ///
+    /// `payload` is the SCALE encoded signed payload of a transaction. It is hashed if it is
+    /// longer than 256 bytes.
+    async fn sign(&self, payload: Vec<u8>) -> Result<Signature, SignerError>;
+}
+
+/// Error returned by [Signer::sign], for example if a hardware wallet rejects the payload.
+pub type SignerError = Box<dyn std::error::Error + Send + Sync>;
+
+#[async_trait::async_trait]
+impl Signer for ed25519::Pair {
+    fn account_id(&self) -> AccountId {
+        self.public()
+    }
+
+    async fn sign(&self, payload: Vec<u8>) -> Result<Signature, SignerError> {
+        Ok(ed25519::Pair::sign(self, &payload).into())
+    }
+}
+
+/// The account of an Sr25519 key pair is identified by its public key.
+#[async_trait::async_trait]
+impl Signer for sr25519::Pair {
+    fn account_id(&self) -> AccountId {
+        AccountId::from_raw(self.public().0)
+    }
+
+    async fn sign(&self, payload: Vec<u8>) -> Result<Signature, SignerError> {
+        Ok(sr25519::Pair::sign(self, &payload).into())
+    }
+}
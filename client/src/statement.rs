@@ -0,0 +1,135 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Account statements assembled from runtime events. See [crate::ClientT::account_statement].
+use crate::event::{self, Event};
+use crate::interface::*;
+
+/// Balance change of an account in a block. Returned by [crate::ClientT::account_statement].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountStatementEntry {
+    pub block_number: BlockNumber,
+    pub block_hash: BlockHash,
+    pub kind: AccountStatementEntryKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountStatementEntryKind {
+    /// Funds were transferred to the account.
+    TransferIn { from: AccountId, amount: Balance },
+    /// Funds were transferred from the account.
+    TransferOut { to: AccountId, amount: Balance },
+    /// The account paid a transaction fee or a registration fee.
+    FeePaid { amount: Balance },
+    /// The account was credited with a block reward.
+    RewardReceived { amount: Balance },
+}
+
+/// Extract the statement entries for `account_id` from the events of a block.
+pub(crate) fn statement_entries(
+    account_id: &AccountId,
+    block_number: BlockNumber,
+    block_hash: BlockHash,
+    events: &[Event],
+) -> Vec<AccountStatementEntry> {
+    events
+        .iter()
+        .filter_map(|event| statement_entry_kind(account_id, event))
+        .map(|kind| AccountStatementEntry {
+            block_number,
+            block_hash,
+            kind,
+        })
+        .collect()
+}
+
+fn statement_entry_kind(
+    account_id: &AccountId,
+    event: &Event,
+) -> Option<AccountStatementEntryKind> {
+    match event {
+        Event::pallet_balances(event::Balances::Transfer(from, to, amount)) => {
+            if to == account_id {
+                Some(AccountStatementEntryKind::TransferIn {
+                    from: *from,
+                    amount: *amount,
+                })
+            } else if from == account_id {
+                Some(AccountStatementEntryKind::TransferOut {
+                    to: *to,
+                    amount: *amount,
+                })
+            } else {
+                None
+            }
+        }
+        Event::registry(event::Registry::FeePaid(payer, amount)) if payer == account_id => {
+            Some(AccountStatementEntryKind::FeePaid { amount: *amount })
+        }
+        Event::registry(event::Registry::BlockRewardCredited(recipient, amount))
+            if recipient == account_id =>
+        {
+            Some(AccountStatementEntryKind::RewardReceived { amount: *amount })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sp_core::crypto::Pair as _;
+
+    #[test]
+    fn transfer_entries() {
+        let alice = ed25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public();
+        let bob = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+        let events = vec![
+            Event::from(event::Balances::Transfer(alice, bob, 10)),
+            Event::from(event::Registry::FeePaid(alice, 1)),
+            Event::from(event::Registry::BlockRewardCredited(bob, 5)),
+        ];
+        let block_hash = BlockHash::default();
+
+        let kinds = |account_id| {
+            statement_entries(&account_id, 1, block_hash, &events)
+                .into_iter()
+                .map(|entry| entry.kind)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            kinds(alice),
+            vec![
+                AccountStatementEntryKind::TransferOut {
+                    to: bob,
+                    amount: 10
+                },
+                AccountStatementEntryKind::FeePaid { amount: 1 },
+            ]
+        );
+        assert_eq!(
+            kinds(bob),
+            vec![
+                AccountStatementEntryKind::TransferIn {
+                    from: alice,
+                    amount: 10
+                },
+                AccountStatementEntryKind::RewardReceived { amount: 5 },
+            ]
+        );
+    }
+}
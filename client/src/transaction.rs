@@ -15,12 +15,12 @@
 
 //! Provides [Transaction] and [TransactionExtra].
 use core::marker::PhantomData;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Compact, Decode, Encode};
 use sp_runtime::generic::{Era, SignedPayload};
 use sp_runtime::traits::{Hash as _, SignedExtension};
 
-use crate::{ed25519, message::Message, CryptoPair as _, TxHash};
-use radicle_registry_core::state::AccountTransactionIndex;
+use crate::{message::Message, Error, Signer, TxHash};
+use radicle_registry_core::{state::AccountTransactionIndex, AccountId};
 use radicle_registry_runtime::{
     fees::PayTxFee, Balance, Call as RuntimeCall, Hash, Hashing, SignedExtra, UncheckedExtrinsic,
 };
@@ -65,7 +65,8 @@ use radicle_registry_runtime::{
 ///         amount: 1000,
 ///     },
 ///     transaction_extra,
-/// );
+/// )
+/// .await?;
 ///
 /// client.submit_transaction(transfer_tx).await?.await?;
 /// # Ok(())
@@ -77,22 +78,158 @@ pub struct Transaction<Message_: Message> {
 }
 
 impl<Message_: Message> Transaction<Message_> {
-    /// Create and sign a transaction for the given message.
-    pub fn new_signed(
-        signer: &ed25519::Pair,
+    /// Create a transaction for the given message and sign it with `signer`.
+    ///
+    /// Fails with [Error::Signing] if the signer fails to sign the transaction. Fails with
+    /// [Error::IncompatibleTransactionVersion] if the runtime transaction version of
+    /// `transaction_extra` is not the one of the client since the transaction would be encoded
+    /// incorrectly.
+    pub async fn new_signed(
+        signer: &dyn Signer,
         message: Message_,
         transaction_extra: TransactionExtra,
-    ) -> Self {
-        let extrinsic = signed_extrinsic(signer, message.into_runtime_call(), transaction_extra);
-        Transaction {
+    ) -> Result<Self, Error> {
+        crate::version::check_transaction_version(transaction_extra.runtime_transaction_version)?;
+        let extrinsic =
+            signed_extrinsic(signer, message.into_runtime_call(), transaction_extra).await?;
+        Ok(Transaction {
             _phantom_data: PhantomData,
             extrinsic,
-        }
+        })
     }
 
     pub fn hash(self) -> TxHash {
         Hashing::hash_of(&self.extrinsic)
     }
+
+    /// SCALE encoding of the signed extrinsic. Restore the transaction with
+    /// [Transaction::decode].
+    pub fn encode(&self) -> Vec<u8> {
+        self.extrinsic.encode()
+    }
+
+    /// Decode a transaction encoded with [Transaction::encode].
+    ///
+    /// The call of the extrinsic is not checked against `Message_`. If it differs, the result of
+    /// the submitted transaction may be extracted incorrectly.
+    pub fn decode(data: &[u8]) -> Result<Self, parity_scale_codec::Error> {
+        let extrinsic = UncheckedExtrinsic::decode(&mut &data[..])?;
+        Ok(Transaction {
+            _phantom_data: PhantomData,
+            extrinsic,
+        })
+    }
+
+    /// Decode a signed extrinsic encoded by other tooling, for example an offline signer, and
+    /// check that its call is a `Message_`.
+    ///
+    /// Unlike [Transaction::decode] this fails with [TransactionDecodingError::Unsigned] or
+    /// [TransactionDecodingError::MessageMismatch] if the extrinsic cannot be submitted as a
+    /// `Message_` transaction. Use [crate::ClientT::submit_raw] to submit extrinsics with any call.
+    pub fn from_encoded(data: &[u8]) -> Result<Self, TransactionDecodingError> {
+        let transaction = Self::decode(data).map_err(TransactionDecodingError::Codec)?;
+        transaction.parts()?;
+        Ok(transaction)
+    }
+
+    /// Hex encoding of [Transaction::encode] with a `0x` prefix.
+    ///
+    /// This is the format the `author_submitExtrinsic` RPC method of Substrate nodes accepts, so
+    /// the transaction can be broadcast by any node or relay. Restore the transaction with
+    /// [Transaction::from_hex].
+    pub fn to_hex(&self) -> String {
+        sp_core::bytes::to_hex(&self.encode(), false)
+    }
+
+    /// Decode a transaction encoded with [Transaction::to_hex]. The `0x` prefix is optional.
+    ///
+    /// Like [Transaction::decode] this does not check the call of the extrinsic against
+    /// `Message_`. Use [Transaction::parts] for that.
+    pub fn from_hex(data: &str) -> Result<Self, TransactionDecodingError> {
+        let data = if data.starts_with("0x") {
+            &data[2..]
+        } else {
+            data
+        };
+        let bytes = sp_core::bytes::from_hex(data)
+            .map_err(|error| TransactionDecodingError::InvalidHex(error.to_string()))?;
+        Self::decode(&bytes).map_err(TransactionDecodingError::Codec)
+    }
+
+    /// Extract the message, the author and the signed extra data from the transaction.
+    ///
+    /// Fails if the transaction is unsigned or if its call is not a `Message_`.
+    pub fn parts(&self) -> Result<TransactionParts<Message_>, TransactionDecodingError> {
+        let parts = extrinsic_parts(&self.extrinsic)?;
+        let message = Message_::from_runtime_call(parts.message)
+            .ok_or(TransactionDecodingError::MessageMismatch)?;
+        Ok(TransactionParts {
+            message,
+            author: parts.author,
+            nonce: parts.nonce,
+            fee: parts.fee,
+        })
+    }
+}
+
+/// Extract the call, the author and the signed extra data from a signed extrinsic.
+///
+/// Fails if the extrinsic is unsigned.
+pub(crate) fn extrinsic_parts(
+    extrinsic: &UncheckedExtrinsic,
+) -> Result<TransactionParts<RuntimeCall>, TransactionDecodingError> {
+    let (author, _signature, extra) = extrinsic
+        .signature
+        .clone()
+        .ok_or(TransactionDecodingError::Unsigned)?;
+    let (_check_version, _check_genesis, _check_era, check_nonce, _check_weight, pay_tx_fee) =
+        extra;
+    // The nonce field of `CheckNonce` is private. It is encoded as a compact integer.
+    let nonce = Compact::<AccountTransactionIndex>::decode(&mut &check_nonce.encode()[..])
+        .expect("CheckNonce is encoded as a compact nonce")
+        .0;
+    Ok(TransactionParts {
+        message: extrinsic.function.clone(),
+        author,
+        nonce,
+        fee: pay_tx_fee.fee,
+    })
+}
+
+/// The data of a signed [Transaction]. See [Transaction::parts].
+///
+/// The genesis hash and the runtime transaction version of [TransactionExtra] are only part of the
+/// signed payload and cannot be recovered from a transaction. A transaction signed for a different
+/// chain or runtime is rejected when it is submitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionParts<Message_> {
+    pub message: Message_,
+    /// The account that signed the transaction and pays its fee.
+    pub author: AccountId,
+    /// The nonce of the author account the transaction is valid for.
+    pub nonce: AccountTransactionIndex,
+    /// The fee offered by the transaction.
+    pub fee: Balance,
+}
+
+/// Error returned when decoding a [Transaction] or extracting its [TransactionParts].
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionDecodingError {
+    /// The input is not valid hex.
+    #[error("Invalid hex encoding: {0}")]
+    InvalidHex(String),
+
+    /// The input is not a SCALE encoded extrinsic.
+    #[error("Failed to decode extrinsic")]
+    Codec(#[source] parity_scale_codec::Error),
+
+    /// The extrinsic is not signed.
+    #[error("Extrinsic is not signed")]
+    Unsigned,
+
+    /// The call of the extrinsic does not match the message type of the transaction.
+    #[error("Extrinsic call does not match the message type")]
+    MessageMismatch,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -114,17 +251,23 @@ pub struct TransactionExtra {
 /// validation is performed.
 ///
 /// `genesis_hash` is the genesis hash of the block chain this intrinsic is valid for.
-fn signed_extrinsic(
-    signer: &ed25519::Pair,
+async fn signed_extrinsic(
+    signer: &dyn Signer,
     call: RuntimeCall,
     extra: TransactionExtra,
-) -> UncheckedExtrinsic {
+) -> Result<UncheckedExtrinsic, Error> {
     let (runtime_extra, additional_signed) = transaction_extra_to_runtime_extra(extra);
     let raw_payload = SignedPayload::from_raw(call, runtime_extra, additional_signed);
-    let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
+    let payload = raw_payload.using_encoded(|payload| payload.to_vec());
+    let signature = signer.sign(payload).await.map_err(Error::Signing)?;
     let (call, extra, _) = raw_payload.deconstruct();
 
-    UncheckedExtrinsic::new_signed(call, signer.public(), signature, extra)
+    Ok(UncheckedExtrinsic::new_signed(
+        call,
+        signer.account_id(),
+        signature,
+        extra,
+    ))
 }
 
 /// Return the [SignedExtra] data that is part of [UncheckedExtrinsic] and the associated
@@ -174,22 +317,23 @@ fn transaction_extra_to_runtime_extra(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::message;
+    use crate::{ed25519, message, sr25519, CryptoPair as _, Signature};
     use radicle_registry_runtime::{genesis::GenesisConfig, Runtime};
     use sp_core::H256;
     use sp_runtime::traits::{Checkable, IdentityLookup};
     use sp_runtime::{BuildStorage as _, Perbill};
 
-    #[test]
-    /// Assert that extrinsics created with [create_and_sign] are validated by the runtime.
-    fn check_extrinsic() {
+    #[async_std::test]
+    /// Assert that extrinsics created with [signed_extrinsic] are validated by the runtime.
+    async fn check_extrinsic() {
         let genesis_config = GenesisConfig {
             pallet_balances: None,
             pallet_sudo: None,
+            pallet_vesting: None,
+            registry: None,
             system: None,
         };
         let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
-        let (key_pair, _) = ed25519::Pair::generate();
 
         type System = frame_system::Module<Runtime>;
         let genesis_hash = test_ext.execute_with(|| {
@@ -203,25 +347,148 @@ mod test {
             System::block_hash(0)
         });
 
-        let xt = signed_extrinsic(
-            &key_pair,
-            frame_system::Call::fill_block(Perbill::from_parts(0)).into(),
+        let signers: Vec<Box<dyn Signer>> = vec![
+            Box::new(ed25519::Pair::generate().0),
+            Box::new(sr25519::Pair::generate().0),
+        ];
+        for signer in signers {
+            let xt = signed_extrinsic(
+                &*signer,
+                frame_system::Call::fill_block(Perbill::from_parts(0)).into(),
+                TransactionExtra {
+                    nonce: 0,
+                    genesis_hash,
+                    fee: 3,
+                    runtime_transaction_version: radicle_registry_runtime::VERSION
+                        .transaction_version,
+                },
+            )
+            .await
+            .unwrap();
+
+            test_ext
+                .execute_with(move || xt.check(&IdentityLookup::default()))
+                .unwrap();
+        }
+    }
+
+    #[async_std::test]
+    /// Check that a signed transaction's hash equals its extrinsic's hash.
+    async fn check_transaction_hash() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let signed_tx = Transaction::new_signed(
+            &alice,
+            message::Transfer {
+                recipient: alice.public(),
+                amount: 1000,
+            },
+            TransactionExtra {
+                nonce: 0,
+                genesis_hash: H256::random(),
+                fee: 9,
+                runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            },
+        )
+        .await
+        .unwrap();
+        let extrinsic_hash = Hashing::hash_of(&signed_tx.extrinsic);
+
+        assert_eq!(signed_tx.hash(), extrinsic_hash);
+    }
+
+    #[async_std::test]
+    async fn new_signed_incompatible_transaction_version() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let transaction_version = radicle_registry_runtime::VERSION.transaction_version + 1;
+        let result = Transaction::new_signed(
+            &alice,
+            message::Transfer {
+                recipient: alice.public(),
+                amount: 1000,
+            },
             TransactionExtra {
                 nonce: 0,
-                genesis_hash,
-                fee: 3,
+                genesis_hash: H256::random(),
+                fee: 9,
+                runtime_transaction_version: transaction_version,
+            },
+        )
+        .await;
+        match result {
+            Err(Error::IncompatibleTransactionVersion(version)) => {
+                assert_eq!(version, transaction_version)
+            }
+            Err(error) => panic!("Unexpected error {:?}", error),
+            Ok(_) => panic!("Transaction was created"),
+        }
+    }
+
+    #[async_std::test]
+    async fn encode_then_decode() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let signed_tx = Transaction::new_signed(
+            &alice,
+            message::Transfer {
+                recipient: alice.public(),
+                amount: 1000,
+            },
+            TransactionExtra {
+                nonce: 0,
+                genesis_hash: H256::random(),
+                fee: 9,
                 runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
             },
+        )
+        .await
+        .unwrap();
+        let decoded_tx = Transaction::<message::Transfer>::decode(&signed_tx.encode()).unwrap();
+
+        assert_eq!(decoded_tx.extrinsic, signed_tx.extrinsic);
+    }
+
+    #[async_std::test]
+    async fn hex_roundtrip_and_parts() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let message = message::Transfer {
+            recipient: alice.public(),
+            amount: 1000,
+        };
+        let signed_tx = Transaction::new_signed(
+            &alice,
+            message.clone(),
+            TransactionExtra {
+                nonce: 3,
+                genesis_hash: H256::random(),
+                fee: 9,
+                runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            },
+        )
+        .await
+        .unwrap();
+        let hex = signed_tx.to_hex();
+        assert!(hex.starts_with("0x"));
+
+        let decoded_tx = Transaction::<message::Transfer>::from_hex(&hex).unwrap();
+        assert_eq!(decoded_tx.extrinsic, signed_tx.extrinsic);
+        assert_eq!(
+            decoded_tx.parts().unwrap(),
+            TransactionParts {
+                message,
+                author: alice.public(),
+                nonce: 3,
+                fee: 9,
+            }
         );
 
-        test_ext
-            .execute_with(move || xt.check(&IdentityLookup::default()))
-            .unwrap();
+        let other_message_tx = Transaction::<message::RegisterUser>::from_hex(&hex).unwrap();
+        assert!(matches!(
+            other_message_tx.parts(),
+            Err(TransactionDecodingError::MessageMismatch)
+        ));
     }
 
-    #[test]
-    /// Check that a signed transaction's hash equals its extrinsic's hash.
-    fn check_transaction_hash() {
+    #[async_std::test]
+    async fn from_encoded() {
         let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
         let signed_tx = Transaction::new_signed(
             &alice,
@@ -235,9 +502,58 @@ mod test {
                 fee: 9,
                 runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
             },
+        )
+        .await
+        .unwrap();
+        let encoded = signed_tx.encode();
+
+        let decoded_tx = Transaction::<message::Transfer>::from_encoded(&encoded).unwrap();
+        assert_eq!(decoded_tx.extrinsic, signed_tx.extrinsic);
+        assert!(matches!(
+            Transaction::<message::RegisterUser>::from_encoded(&encoded),
+            Err(TransactionDecodingError::MessageMismatch)
+        ));
+
+        let unsigned = UncheckedExtrinsic::new_unsigned(
+            frame_system::Call::fill_block(Perbill::from_parts(0)).into(),
         );
-        let extrinsic_hash = Hashing::hash_of(&signed_tx.extrinsic);
+        assert!(matches!(
+            Transaction::<message::Transfer>::from_encoded(&unsigned.encode()),
+            Err(TransactionDecodingError::Unsigned)
+        ));
+    }
 
-        assert_eq!(signed_tx.hash(), extrinsic_hash);
+    /// Signs nothing and fails instead.
+    struct RejectingSigner(AccountId);
+
+    #[async_trait::async_trait]
+    impl Signer for RejectingSigner {
+        fn account_id(&self) -> AccountId {
+            self.0
+        }
+
+        async fn sign(&self, _payload: Vec<u8>) -> Result<Signature, crate::SignerError> {
+            Err("rejected by user".into())
+        }
+    }
+
+    #[async_std::test]
+    async fn signer_error() {
+        let alice = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let result = Transaction::new_signed(
+            &RejectingSigner(alice.public()),
+            message::Transfer {
+                recipient: alice.public(),
+                amount: 1000,
+            },
+            TransactionExtra {
+                nonce: 0,
+                genesis_hash: H256::random(),
+                fee: 9,
+                runtime_transaction_version: radicle_registry_runtime::VERSION.transaction_version,
+            },
+        )
+        .await;
+        assert!(matches!(result, Err(Error::Signing(_))));
     }
 }
@@ -0,0 +1,243 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Policy for the runtime and storage versions of a chain that the client supports.
+//!
+//! The client creates transactions and decodes state with the types of the runtime it was built
+//! with. It supports chains running this runtime or the previous runtime version. Transactions can
+//! only be created if the transaction version of the chain is the one of the client. Otherwise the
+//! client is [VersionCompatibility::ReadOnly].
+//!
+//! Versioned storage entries of the registry like `Orgs1` must be stored in a version the client
+//! can read. See [REGISTRY_STORAGE_VERSIONS].
+use radicle_registry_runtime::VERSION;
+
+use crate::constants::{decode_metadata, decoded};
+use crate::interface::*;
+
+/// Name of the registry module in the runtime metadata.
+const REGISTRY_MODULE: &str = "Registry";
+
+/// Versioned storage entry of the registry module that the client reads.
+struct StorageVersion {
+    /// Name of the storage entry without the version suffix.
+    name: &'static str,
+    /// Version the client reads and writes.
+    current: u32,
+    /// Oldest version the client can still read.
+    oldest_readable: u32,
+}
+
+/// Versioned storage entries the client reads.
+const REGISTRY_STORAGE_VERSIONS: &[StorageVersion] = &[
+    StorageVersion {
+        name: "Orgs",
+        current: 1,
+        oldest_readable: 1,
+    },
+    StorageVersion {
+        name: "Users",
+        current: 1,
+        oldest_readable: 1,
+    },
+    StorageVersion {
+        name: "Projects",
+        current: 1,
+        oldest_readable: 1,
+    },
+];
+
+/// What the client can do on a chain. See [crate::ClientT::version_compatibility].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum VersionCompatibility {
+    /// The client can read the state of the chain but refuses to create transactions because
+    /// their encoding may differ from the one of the chain.
+    ReadOnly,
+    /// The chain has the transaction version of the client.
+    Full,
+}
+
+/// Check the runtime version of a chain.
+///
+/// Fails with [Error::IncompatibleRuntimeVersion] if the chain does not run the registry runtime
+/// or if its spec version is neither the one of the client nor the previous one.
+pub(crate) fn check_runtime_version(
+    runtime_version: &RuntimeVersion,
+) -> Result<VersionCompatibility, Error> {
+    let supported_spec_versions = VERSION.spec_version.saturating_sub(1)..=VERSION.spec_version;
+    if runtime_version.spec_name != VERSION.spec_name
+        || !supported_spec_versions.contains(&runtime_version.spec_version)
+    {
+        return Err(Error::IncompatibleRuntimeVersion(
+            runtime_version.spec_version,
+        ));
+    }
+    if runtime_version.transaction_version == VERSION.transaction_version {
+        Ok(VersionCompatibility::Full)
+    } else {
+        Ok(VersionCompatibility::ReadOnly)
+    }
+}
+
+/// Check that the client can read the state of the chain with the given runtime version and SCALE
+/// encoded runtime metadata and whether it can create transactions for it.
+///
+/// Combines [check_runtime_version], [check_storage_versions] and
+/// [crate::compatibility::check_compatibility] so that a chain running the previous runtime
+/// version passes all of them.
+pub(crate) fn check_chain(
+    runtime_version: &RuntimeVersion,
+    chain_metadata: &[u8],
+) -> Result<VersionCompatibility, Error> {
+    let compatibility = check_runtime_version(runtime_version)?;
+    check_storage_versions(chain_metadata)?;
+    crate::compatibility::check_compatibility(chain_metadata, runtime_version.spec_version)?;
+    Ok(compatibility)
+}
+
+/// Check that the client can create transactions for a runtime with the given transaction
+/// version. Fails with [Error::IncompatibleTransactionVersion] otherwise.
+pub(crate) fn check_transaction_version(transaction_version: u32) -> Result<(), Error> {
+    if transaction_version == VERSION.transaction_version {
+        Ok(())
+    } else {
+        Err(Error::IncompatibleTransactionVersion(transaction_version))
+    }
+}
+
+/// Check that the chain with the given SCALE encoded runtime metadata stores all
+/// [REGISTRY_STORAGE_VERSIONS] in a version the client can read.
+///
+/// The version of a storage entry is the numeric suffix of its name. If the chain has multiple
+/// versions of an entry, the latest one is checked. Fails with [Error::IncompatibleStorageVersion]
+/// for the first entry that is missing or has an unsupported version.
+pub(crate) fn check_storage_versions(chain_metadata: &[u8]) -> Result<(), Error> {
+    let entry_names = decoded(decode_metadata(chain_metadata)?.modules)
+        .into_iter()
+        .find(|module| decoded(module.name.clone()) == REGISTRY_MODULE)
+        .and_then(|module| module.storage)
+        .map(|storage| {
+            decoded(decoded(storage).entries)
+                .into_iter()
+                .map(|entry| decoded(entry.name))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    for storage_version in REGISTRY_STORAGE_VERSIONS {
+        let version = entry_names
+            .iter()
+            .filter(|entry_name| entry_name.starts_with(storage_version.name))
+            .filter_map(|entry_name| entry_name[storage_version.name.len()..].parse::<u32>().ok())
+            .max();
+        match version {
+            Some(version)
+                if (storage_version.oldest_readable..=storage_version.current)
+                    .contains(&version) => {}
+            _ => {
+                return Err(Error::IncompatibleStorageVersion {
+                    name: storage_version.name,
+                    version,
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use frame_metadata::{DecodeDifferent, RuntimeMetadata, RuntimeMetadataPrefixed};
+    use parity_scale_codec::{Decode as _, Encode as _};
+    use radicle_registry_runtime::Runtime;
+
+    fn runtime_version(spec_version: u32, transaction_version: u32) -> RuntimeVersion {
+        RuntimeVersion {
+            spec_version,
+            transaction_version,
+            ..VERSION
+        }
+    }
+
+    #[test]
+    fn current_runtime_version() {
+        assert_eq!(
+            check_runtime_version(&VERSION).unwrap(),
+            VersionCompatibility::Full
+        );
+    }
+
+    #[test]
+    fn previous_runtime_version_is_read_only() {
+        let previous = VERSION.spec_version - 1;
+        assert_eq!(
+            check_runtime_version(&runtime_version(previous, previous)).unwrap(),
+            VersionCompatibility::ReadOnly
+        );
+    }
+
+    #[test]
+    fn unsupported_runtime_versions() {
+        for spec_version in &[VERSION.spec_version - 2, VERSION.spec_version + 1] {
+            match check_runtime_version(&runtime_version(*spec_version, *spec_version)) {
+                Err(Error::IncompatibleRuntimeVersion(version)) => {
+                    assert_eq!(version, *spec_version)
+                }
+                result => panic!("Unexpected result {:?}", result),
+            }
+        }
+
+        let other_runtime = RuntimeVersion {
+            spec_name: "other".into(),
+            ..VERSION
+        };
+        assert!(check_runtime_version(&other_runtime).is_err());
+    }
+
+    #[test]
+    fn client_storage_versions() {
+        check_storage_versions(&Runtime::metadata().encode()).unwrap();
+    }
+
+    #[test]
+    fn unsupported_storage_version() {
+        let mut metadata =
+            RuntimeMetadataPrefixed::decode(&mut &Runtime::metadata().encode()[..]).unwrap();
+        if let RuntimeMetadata::V11(ref mut metadata) = metadata.1 {
+            if let DecodeDifferent::Decoded(ref mut modules) = metadata.modules {
+                for module in modules.iter_mut() {
+                    if let Some(DecodeDifferent::Decoded(ref mut storage)) = module.storage {
+                        if let DecodeDifferent::Decoded(ref mut entries) = storage.entries {
+                            for entry in entries.iter_mut() {
+                                if decoded(entry.name.clone()) == "Users1" {
+                                    entry.name = DecodeDifferent::Decoded("Users2".to_string());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        match check_storage_versions(&metadata.encode()) {
+            Err(Error::IncompatibleStorageVersion { name, version }) => {
+                assert_eq!(name, "Users");
+                assert_eq!(version, Some(2));
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+}
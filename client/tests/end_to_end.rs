@@ -19,6 +19,7 @@
 //! To avoid AccountUserAssociated errors, use a distinct author for each test.
 
 use serial_test::serial;
+use sp_runtime::traits::Header as _;
 
 use radicle_registry_client::*;
 use radicle_registry_test_utils::*;
@@ -99,6 +100,7 @@ async fn register_member() {
         &author,
         message::RegisterOrg {
             org_id: org_id.clone(),
+            idempotency_key: None,
         },
     )
     .await;
@@ -170,6 +172,7 @@ async fn register_org() {
         &author,
         message::RegisterOrg {
             org_id: org_id.clone(),
+            idempotency_key: None,
         },
         random_fee,
     )
@@ -184,7 +187,7 @@ async fn register_org() {
 
     assert_eq!(
         client.free_balance(&author.public()).await.unwrap(),
-        initial_balance - random_fee - REGISTRATION_FEE,
+        initial_balance - random_fee - client.registration_fee().await.unwrap(),
         "The tx fee was not charged properly."
     );
 }
@@ -206,6 +209,7 @@ async fn register_user() {
         &author,
         message::RegisterUser {
             user_id: user_id.clone(),
+            idempotency_key: None,
         },
         random_fee,
     )
@@ -222,11 +226,50 @@ async fn register_user() {
 
     assert_eq!(
         client.free_balance(&author.public()).await.unwrap(),
-        initial_balance - random_fee - REGISTRATION_FEE,
+        initial_balance - random_fee - client.registration_fee().await.unwrap(),
         "The tx fee was not charged properly."
     );
 }
 
+/// Test that the node accepts transactions signed by an account backed by an Sr25519 key pair.
+#[async_std::test]
+#[serial]
+async fn register_user_with_sr25519_author() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+    let (author, _) = sr25519::Pair::generate();
+    transfer(&client, &root_key_pair(), author.account_id(), 100_000).await;
+
+    let random_fee = random_balance();
+    let user_id = random_id();
+    let tx_included = client
+        .sign_and_submit_message(
+            &author,
+            message::RegisterUser {
+                user_id: user_id.clone(),
+                idempotency_key: None,
+            },
+            random_fee,
+        )
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    let (associated_user_id, _) = client
+        .get_user_by_account(&author.account_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(associated_user_id, user_id);
+    assert_eq!(
+        client.free_balance(&author.account_id()).await.unwrap(),
+        100_000 - random_fee - client.registration_fee().await.unwrap()
+    );
+}
+
 #[async_std::test]
 #[serial]
 /// Submit a transaction with an invalid genesis hash and expect an error.
@@ -249,7 +292,9 @@ async fn invalid_transaction() {
             fee: 123,
             runtime_transaction_version,
         },
-    );
+    )
+    .await
+    .unwrap();
 
     let response = client.submit_transaction(transfer_tx).await;
     match response {
@@ -287,7 +332,7 @@ async fn insufficient_fee() {
 async fn insufficient_funds() {
     let node_host = url::Host::parse("127.0.0.1").unwrap();
     let client = Client::create_with_executor(node_host).await.unwrap();
-    let tx_author = ed25519::Pair::generate().0;
+    let tx_author = random_key_pair();
     assert_eq!(client.free_balance(&tx_author.public()).await.unwrap(), 0);
 
     let whatever_message = random_register_org_message();
@@ -312,7 +357,10 @@ async fn register_org_with_id_taken_by_org() {
     let (author, _) = key_pair_with_associated_user(&client).await;
     let (org_id, _) = register_random_org(&client, &author).await;
 
-    let register_org_message = message::RegisterOrg { org_id };
+    let register_org_message = message::RegisterOrg {
+        org_id,
+        idempotency_key: None,
+    };
     let tx_included_twice = submit_ok(&client, &author, register_org_message).await;
     assert_eq!(
         tx_included_twice.result,
@@ -328,7 +376,10 @@ async fn register_org_with_taken_user_id() {
     let client = Client::create_with_executor(node_host).await.unwrap();
     let (author, id) = key_pair_with_associated_user(&client).await;
 
-    let register_org_message = message::RegisterOrg { org_id: id.clone() };
+    let register_org_message = message::RegisterOrg {
+        org_id: id.clone(),
+        idempotency_key: None,
+    };
     let tx_included_org = submit_ok(&client, &author, register_org_message.clone()).await;
     assert_eq!(
         tx_included_org.result,
@@ -344,7 +395,10 @@ async fn register_user_with_id_taken_by_user() {
     let client = Client::create_with_executor(node_host).await.unwrap();
     let (author, id) = key_pair_with_associated_user(&client).await;
 
-    let register_user_message = message::RegisterUser { user_id: id };
+    let register_user_message = message::RegisterUser {
+        user_id: id,
+        idempotency_key: None,
+    };
     let tx_included_twice = submit_ok(&client, &author, register_user_message).await;
     assert_eq!(
         tx_included_twice.result,
@@ -361,10 +415,173 @@ async fn register_user_with_id_taken_by_org() {
     let (author, _) = key_pair_with_associated_user(&client).await;
     let (org_id, _) = register_random_org(&client, &author).await;
 
-    let register_user_message = message::RegisterUser { user_id: org_id };
+    let register_user_message = message::RegisterUser {
+        user_id: org_id,
+        idempotency_key: None,
+    };
     let tx_included_user = submit_ok(&client, &author, register_user_message).await;
     assert_eq!(
         tx_included_user.result,
         Err(RegistryError::IdAlreadyTaken.into())
     );
 }
+
+/// Create and finalize a block with the manual seal RPC of the dev node.
+#[async_std::test]
+#[serial]
+async fn dev_create_and_finalize_block() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+    let tip = client.block_header_best_chain().await.unwrap();
+
+    let block_hash = client.dev_create_block().await.unwrap();
+    let block = client.block_header(block_hash).await.unwrap().unwrap();
+    assert_eq!(block.number, tip.number + 1);
+
+    client.dev_finalize_block(block_hash).await.unwrap();
+    let finalized = client.at_best_finalized().await.unwrap();
+    assert_eq!(finalized.pinned_block(), Some(block_hash));
+}
+
+/// Create a fork with the manual seal RPC of the dev node and assert that the node switches to
+/// the fork once it is the longest chain.
+#[async_std::test]
+#[serial]
+async fn dev_create_block_at() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+    let fork_point = client.dev_create_block().await.unwrap();
+    let retracted = client.dev_create_block().await.unwrap();
+
+    let fork_block = client.dev_create_block_at(fork_point).await.unwrap();
+    assert_ne!(fork_block, retracted);
+    let fork_tip = client.dev_create_block_at(fork_block).await.unwrap();
+
+    let tip = client.block_header_best_chain().await.unwrap();
+    assert_eq!(tip.hash(), fork_tip);
+    assert_eq!(tip.parent_hash, fork_block);
+}
+
+/// Wait for a submitted transaction to be finalized after finalizing its block with the manual
+/// seal RPC of the dev node.
+#[async_std::test]
+#[serial]
+async fn wait_finalized() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+
+    let tx_included = submit_ok(&client, &author, random_register_org_message()).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let block_hash = tx_included.block;
+    let tx_included_latency = tx_included.inclusion_latency;
+    client.dev_finalize_block(block_hash).await.unwrap();
+    let tx_finalized = tx_included.wait_finalized().await.unwrap();
+    assert_eq!(tx_finalized.block, block_hash);
+    assert!(tx_finalized.finalization_latency >= tx_included_latency);
+}
+
+/// Query the node and submit a transaction over HTTP.
+#[async_std::test]
+#[serial]
+async fn http_transport() {
+    let _ = env_logger::try_init();
+    let node_url = url::Url::parse("http://127.0.0.1:9933").unwrap();
+    let config = ClientConfig::from_url(&node_url).unwrap();
+    let client = Client::create_with_executor(config).await.unwrap();
+    let author = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::Transfer {
+            recipient,
+            amount: 1000,
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 1000);
+}
+
+/// Query the status of ids with the `registry_idAvailable` RPC method of the node.
+#[async_std::test]
+#[serial]
+async fn id_available() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+    let (_, user_id) = key_pair_with_associated_user(&client).await;
+
+    assert_eq!(
+        client.id_available(&user_id).await.unwrap(),
+        IdStatus::Taken
+    );
+    assert_eq!(
+        client.id_available(&random_id()).await.unwrap(),
+        IdStatus::Available
+    );
+}
+
+/// The dev node provides all the registry RPC methods the client uses.
+#[async_std::test]
+#[serial]
+async fn capabilities() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+
+    assert_eq!(
+        client.capabilities().await.unwrap(),
+        Capabilities {
+            id_status_rpc: true,
+            node_limits_rpc: true,
+        }
+    );
+}
+
+/// Connect to the dev node and read its state. The dev node runs the runtime of the client or,
+/// with `scripts/run-tests-all-runtimes`, the previous runtime from the runtime cache.
+#[async_std::test]
+#[serial]
+async fn read_state_of_supported_runtime_version() {
+    let _ = env_logger::try_init();
+    let node_host = url::Host::parse("127.0.0.1").unwrap();
+    let client = Client::create_with_executor(node_host).await.unwrap();
+
+    let chain_version = client.runtime_version().await.unwrap();
+    let client_version = radicle_registry_runtime::VERSION;
+    assert!(
+        chain_version.spec_version == client_version.spec_version
+            || chain_version.spec_version + 1 == client_version.spec_version
+    );
+    let expected_compatibility =
+        if chain_version.transaction_version == client_version.transaction_version {
+            VersionCompatibility::Full
+        } else {
+            VersionCompatibility::ReadOnly
+        };
+    assert_eq!(
+        client.version_compatibility().await.unwrap(),
+        expected_compatibility
+    );
+
+    for org_id in client.list_orgs().await.unwrap() {
+        assert!(client.get_org(org_id).await.unwrap().is_some());
+    }
+    for user_id in client.list_users().await.unwrap() {
+        assert!(client.get_user(user_id).await.unwrap().is_some());
+    }
+    for (project_name, project_domain) in client.list_projects().await.unwrap() {
+        let project = client
+            .get_project(project_name, project_domain)
+            .await
+            .unwrap();
+        assert!(project.is_some());
+    }
+}
@@ -0,0 +1,134 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Generate the SCALE layout descriptions of all state, message and signature types. See `src/layout.rs`.
+//!
+//! We parse the source files that define the types and emit a `TypeLayout` for every struct and
+//! enum that derives `Encode`. The output is included by the `layout` module.
+
+use quote::ToTokens as _;
+use std::fmt::Write as _;
+
+/// Source files to scan and the module path of the types they define, relative to the crate root.
+const SOURCES: &[(&str, &str)] = &[
+    ("src/lib.rs", ""),
+    ("src/signature.rs", ""),
+    ("src/message.rs", "message::"),
+    ("src/state.rs", "state::"),
+];
+
+fn main() {
+    let mut out = String::from("&[\n");
+    for (path, module) in SOURCES {
+        println!("cargo:rerun-if-changed={}", path);
+        let source = std::fs::read_to_string(path).expect("Failed to read source file");
+        let file = syn::parse_file(&source).expect("Failed to parse source file");
+        for item in file.items {
+            match item {
+                syn::Item::Struct(item) if derives_encode(&item.attrs) => {
+                    writeln!(
+                        out,
+                        "TypeLayout {{ name: \"{}{}\", kind: LayoutKind::Struct {{ fields: {} }} }},",
+                        module,
+                        item.ident,
+                        fields(&item.fields)
+                    )
+                    .unwrap();
+                }
+                syn::Item::Enum(item) if derives_encode(&item.attrs) => {
+                    let mut variants = String::from("&[");
+                    for (position, variant) in item.variants.iter().enumerate() {
+                        write!(
+                            variants,
+                            "VariantLayout {{ index: {}, name: \"{}\", fields: {} }},",
+                            variant_index(variant, position),
+                            variant.ident,
+                            fields(&variant.fields)
+                        )
+                        .unwrap();
+                    }
+                    variants.push(']');
+                    writeln!(
+                        out,
+                        "TypeLayout {{ name: \"{}{}\", kind: LayoutKind::Enum {{ variants: {} }} }},",
+                        module, item.ident, variants
+                    )
+                    .unwrap();
+                }
+                _ => {}
+            }
+        }
+    }
+    out.push(']');
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(std::path::Path::new(&out_dir).join("layouts.rs"), out)
+        .expect("Failed to write layouts");
+}
+
+fn derives_encode(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("derive") && attr.tokens.to_string().contains("Encode"))
+}
+
+/// Render the encoded fields in declaration order. Fields with `#[codec(skip)]` are not encoded.
+fn fields(fields: &syn::Fields) -> String {
+    let mut out = String::from("&[");
+    for field in fields {
+        let codec = codec_attr(&field.attrs);
+        if codec.contains("skip") {
+            continue;
+        }
+        let mut ty = field.ty.to_token_stream().to_string();
+        ty.retain(|c| !c.is_whitespace());
+        if codec.contains("compact") {
+            ty = format!("Compact<{}>", ty);
+        }
+        let name = match &field.ident {
+            Some(ident) => format!("Some(\"{}\")", ident),
+            None => "None".to_string(),
+        };
+        write!(out, "FieldLayout {{ name: {}, ty: \"{}\" }},", name, ty).unwrap();
+    }
+    out.push(']');
+    out
+}
+
+/// The byte that encodes the variant: `#[codec(index = "…")]`, the explicit discriminant or the
+/// position of the variant.
+fn variant_index(variant: &syn::Variant, position: usize) -> u8 {
+    let codec = codec_attr(&variant.attrs);
+    if let Some(index) = codec.split("index").nth(1) {
+        let index: String = index.chars().filter(char::is_ascii_digit).collect();
+        return index.parse().expect("Invalid codec index");
+    }
+    if let Some((_, discriminant)) = &variant.discriminant {
+        return discriminant
+            .to_token_stream()
+            .to_string()
+            .parse()
+            .expect("Invalid discriminant");
+    }
+    position as u8
+}
+
+fn codec_attr(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("codec"))
+        .map(|attr| attr.tokens.to_string())
+        .collect()
+}
@@ -74,6 +74,24 @@ impl Bytes128 {
     }
 }
 
+/// Serializes as a `0x` prefixed hex string.
+#[cfg(feature = "std")]
+impl serde::Serialize for Bytes128 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        sp_core::bytes::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserializes from a hex string with an optional `0x` prefix. Fails if the decoded bytes exceed
+/// the length limit.
+#[cfg(feature = "std")]
+impl<'de> serde::Deserialize<'de> for Bytes128 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vector = sp_core::bytes::deserialize(deserializer)?;
+        Bytes128::from_vec(vector).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Decode for Bytes128 {
     fn decode<I: Input>(input: &mut I) -> Result<Self, CodecError> {
         let decoded: Vec<u8> = Vec::decode(input)?;
@@ -143,6 +161,32 @@ mod test {
         assert!(decoding_result.is_err())
     }
 
+    #[test]
+    fn serde_json_hex_encoding() {
+        let bytes128 = Bytes128::from_vec(vec![0x01, 0xab, 0xff]).unwrap();
+        let json = serde_json::to_string(&bytes128).unwrap();
+        assert_eq!(json, "\"0x01abff\"");
+        assert_eq!(serde_json::from_str::<Bytes128>(&json).unwrap(), bytes128);
+    }
+
+    #[test]
+    fn serde_json_deserialize_after_serialize_is_identity() {
+        let bytes128 = Bytes128::random();
+        let json = serde_json::to_string(&bytes128).unwrap();
+        assert_eq!(serde_json::from_str::<Bytes128>(&json).unwrap(), bytes128);
+    }
+
+    #[test]
+    fn serde_json_deserialize_inordinate_vector_fails() {
+        let json = serde_json::to_string(&Bytes128(random_vector(129))).unwrap();
+        assert!(serde_json::from_str::<Bytes128>(&json).is_err());
+    }
+
+    #[test]
+    fn serde_json_deserialize_invalid_hex_fails() {
+        assert!(serde_json::from_str::<Bytes128>("\"0xzz\"").is_err());
+    }
+
     fn random_vector(size: usize) -> Vec<u8> {
         (0..size).map(|_| rand::random::<u8>()).collect()
     }
@@ -42,106 +42,135 @@ impl From<DispatchError> for TransactionError {
 }
 
 /// Errors describing failed Registry transactions.
+///
+/// [RegistryError::description] provides a human readable description for every error that is
+/// also used for the [core::fmt::Display] implementation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive)]
-#[cfg_attr(feature = "std", derive(thiserror::Error))]
 #[repr(u8)]
 pub enum RegistryError {
-    #[cfg_attr(feature = "std", error("the provided checkpoint does not exist"))]
     InexistentCheckpointId = 0,
-
-    #[cfg_attr(
-        feature = "std",
-        error("a registered project must have an initial checkpoint")
-    )]
     InexistentInitialProjectCheckpoint = 1,
-
-    #[cfg_attr(feature = "std", error("the provided org does not exist"))]
     InexistentOrg = 2,
-
-    #[cfg_attr(feature = "std", error("the provided project does not exist"))]
     InexistentProjectId = 3,
-
-    #[cfg_attr(feature = "std", error("the provided user does not exist"))]
     InexistentUser = 4,
-
     #[deprecated(note = "Superseded by IdAlreadyTaken")]
-    #[cfg_attr(feature = "std", error("an org with the same ID already exists"))]
     DuplicateOrgId = 5,
-
-    #[cfg_attr(feature = "std", error("a project with the same ID already exists"))]
     DuplicateProjectId = 6,
-
     #[deprecated(note = "Superseded by IdAlreadyTaken")]
-    #[cfg_attr(feature = "std", error("a user with the same ID already exists."))]
     DuplicateUserId = 7,
-
-    #[cfg_attr(feature = "std", error("the user is already a member of the org"))]
     AlreadyAMember = 8,
-
-    #[cfg_attr(feature = "std", error("the provided fee is insufficient"))]
     InsufficientFee = 9,
-
-    #[cfg_attr(feature = "std", error("the sender is not a project member"))]
     InsufficientSenderPermissions = 10,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the provided checkpoint is not a descendant of the project's initial checkpoint")
-    )]
     InvalidCheckpointAncestry = 11,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the provided user is not eligible for unregistration")
-    )]
     UnregisterableUser = 12,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the provided org is not elibile for unregistration")
-    )]
     UnregisterableOrg = 13,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the account is already associated with a user")
-    )]
     UserAccountAssociated = 14,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the tx author needs to have an associated user")
-    )]
     AuthorHasNoAssociatedUser = 15,
-
-    #[cfg_attr(
-        feature = "std",
-        error(
-            "failed to update the chain runtime, ensure that the author is the chain's sudo key, \
-            the 'spec_name' matches and the WASM 'spec_version' is greater"
-        )
-    )]
     FailedChainRuntimeUpdate = 16,
-
-    #[cfg_attr(
-        feature = "std",
-        error("an org or a user with the same ID already exists")
-    )]
     IdAlreadyTaken = 17,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the ID has been unregistered and can't be claimed again")
-    )]
     IdRetired = 18,
-
-    #[cfg_attr(
-        feature = "std",
-        error("the author has insufficient funds to cover the registration fee")
-    )]
     FailedRegistrationFeePayment = 19,
+    InsufficientOrgBalance = 20,
+    InexistentTreasuryProposal = 21,
+    InsufficientTreasuryBalance = 22,
+    IdempotencyKeyUsed = 23,
+    ProjectIdRetired = 24,
+    IdReserved = 25,
+    InexistentIdReservation = 26,
+    InexistentProjectTransfer = 27,
+    OrgFrozen = 28,
+    OrgNotFrozen = 29,
+    TreasuryProposalIdsExhausted = 30,
+    ProjectNameReserved = 31,
+    InexistentProjectNameReservation = 32,
 }
 
+impl RegistryError {
+    /// Stable, human readable description of the error.
+    #[allow(deprecated)]
+    pub fn description(&self) -> &'static str {
+        match self {
+            RegistryError::InexistentCheckpointId => "the provided checkpoint does not exist",
+            RegistryError::InexistentInitialProjectCheckpoint => {
+                "a registered project must have an initial checkpoint"
+            }
+            RegistryError::InexistentOrg => "the provided org does not exist",
+            RegistryError::InexistentProjectId => "the provided project does not exist",
+            RegistryError::InexistentUser => "the provided user does not exist",
+            RegistryError::DuplicateOrgId => "an org with the same ID already exists",
+            RegistryError::DuplicateProjectId => "a project with the same ID already exists",
+            RegistryError::DuplicateUserId => "a user with the same ID already exists.",
+            RegistryError::AlreadyAMember => "the user is already a member of the org",
+            RegistryError::InsufficientFee => "the provided fee is insufficient",
+            RegistryError::InsufficientSenderPermissions => "the sender is not a project member",
+            RegistryError::InvalidCheckpointAncestry => {
+                "the provided checkpoint is not a descendant of the project's initial checkpoint"
+            }
+            RegistryError::UnregisterableUser => {
+                "the provided user is not eligible for unregistration"
+            }
+            RegistryError::UnregisterableOrg => {
+                "the provided org is not elibile for unregistration"
+            }
+            RegistryError::UserAccountAssociated => "the account is already associated with a user",
+            RegistryError::AuthorHasNoAssociatedUser => {
+                "the tx author needs to have an associated user"
+            }
+            RegistryError::FailedChainRuntimeUpdate => {
+                "failed to update the chain runtime, ensure that the author is the chain's sudo key, \
+                the 'spec_name' matches and the WASM 'spec_version' is greater"
+            }
+            RegistryError::IdAlreadyTaken => "an org or a user with the same ID already exists",
+            RegistryError::IdRetired => "the ID has been unregistered and can't be claimed again",
+            RegistryError::FailedRegistrationFeePayment => {
+                "the author has insufficient funds to cover the registration fee"
+            }
+            RegistryError::InsufficientOrgBalance => {
+                "the org account has insufficient funds for the transfer \
+                or would fall below the existential deposit"
+            }
+            RegistryError::InexistentTreasuryProposal => {
+                "the provided treasury spend proposal does not exist"
+            }
+            RegistryError::InsufficientTreasuryBalance => {
+                "the treasury account has insufficient funds for the spend \
+                or would fall below the existential deposit"
+            }
+            RegistryError::IdempotencyKeyUsed => {
+                "a registration with the same idempotency key was already applied"
+            }
+            RegistryError::ProjectIdRetired => {
+                "the project has been unregistered and can't be registered again"
+            }
+            RegistryError::IdReserved => "the ID is reserved for another account",
+            RegistryError::InexistentIdReservation => "the provided ID is not reserved",
+            RegistryError::InexistentProjectTransfer => {
+                "no transfer of the provided project is pending"
+            }
+            RegistryError::OrgFrozen => "the org is frozen",
+            RegistryError::OrgNotFrozen => "the org is not frozen",
+            RegistryError::TreasuryProposalIdsExhausted => {
+                "no treasury spend proposal ids are left"
+            }
+            RegistryError::ProjectNameReserved => {
+                "the project name is reserved for another org or user"
+            }
+            RegistryError::InexistentProjectNameReservation => {
+                "the provided project name is not reserved"
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {}
+
 // The index with which the registry runtime module is declared
 // in the Radicle Registry runtime - see the `construct_runtime`
 // declaration in the `runtime` crate.
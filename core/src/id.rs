@@ -22,7 +22,8 @@ use alloc::string::{String, ToString};
 use core::convert::{From, Into, TryFrom};
 use parity_scale_codec as codec;
 
-#[derive(codec::Encode, Clone, Debug, Eq, PartialEq)]
+/// Ids are ordered lexicographically by their string representation.
+#[derive(codec::Encode, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", serde(try_from = "String"))]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct Id(String);
@@ -194,6 +195,24 @@ mod test {
         assert!(valid.is_ok());
     }
 
+    #[test]
+    fn id_order() {
+        let mut ids = vec![
+            Id::from_string("radicle".into()).unwrap(),
+            Id::from_string("monadic".into()).unwrap(),
+            Id::from_string("monadic-labs".into()).unwrap(),
+        ];
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                Id::from_string("monadic".into()).unwrap(),
+                Id::from_string("monadic-labs".into()).unwrap(),
+                Id::from_string("radicle".into()).unwrap(),
+            ]
+        );
+    }
+
     #[test]
     fn encode_then_decode() {
         let id = Id::from_string("monadic".into()).unwrap();
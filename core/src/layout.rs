@@ -0,0 +1,141 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Description of the SCALE encoding of all state and message types.
+//!
+//! [LAYOUTS] is generated at build time from the type definitions in [crate::message],
+//! [crate::state], [crate::Signature] and the crate root. It lists the encoded fields in order and the index of every
+//! enum variant. Field types are given as written in the source.
+//!
+//! `core/layout.json` contains a dump of [LAYOUTS] together with [LAYOUT_VERSION]. A test fails if
+//! the layouts differ from the dump. Changing the encoding of a type breaks the on-chain encoding,
+//! so [LAYOUT_VERSION] must be bumped when the dump is updated. See the README for details.
+
+#[cfg(feature = "std")]
+use serde::Serialize;
+
+/// Version of the SCALE layout of state and message types.
+///
+/// Must be bumped whenever [LAYOUTS] changes.
+pub const LAYOUT_VERSION: u32 = 5;
+
+/// Layouts of all state, message and signature types in the order of their definition.
+pub const LAYOUTS: &[TypeLayout] = include!(concat!(env!("OUT_DIR"), "/layouts.rs"));
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct TypeLayout {
+    /// Path of the type relative to the crate root, e.g. `message::RegisterOrg`.
+    pub name: &'static str,
+
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub kind: LayoutKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(Serialize),
+    serde(tag = "kind", rename_all = "lowercase")
+)]
+pub enum LayoutKind {
+    Struct { fields: &'static [FieldLayout] },
+    Enum { variants: &'static [VariantLayout] },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct VariantLayout {
+    /// The first byte of the encoding that identifies the variant.
+    pub index: u8,
+    pub name: &'static str,
+    pub fields: &'static [FieldLayout],
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct FieldLayout {
+    /// `None` for fields of tuple structs and tuple variants.
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+    pub name: Option<&'static str>,
+
+    #[cfg_attr(feature = "std", serde(rename = "type"))]
+    pub ty: &'static str,
+}
+
+/// Find the layout of the type with the given name.
+pub fn layout(name: &str) -> Option<&'static TypeLayout> {
+    LAYOUTS.iter().find(|layout| layout.name == name)
+}
+
+/// The JSON dump of [LAYOUT_VERSION] and [LAYOUTS] as stored in `core/layout.json`.
+#[cfg(feature = "std")]
+pub fn dump_json() -> serde_json::Value {
+    serde_json::json!({
+        "version": LAYOUT_VERSION,
+        "types": LAYOUTS,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DUMP_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/layout.json");
+
+    /// Fails if the layouts changed without bumping [LAYOUT_VERSION] or if the dump is outdated.
+    ///
+    /// Run the test with `UPDATE_LAYOUT_DUMP=1` to update the dump after bumping
+    /// [LAYOUT_VERSION].
+    #[test]
+    fn layouts_match_dump() {
+        let current = dump_json();
+        let dump: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(DUMP_PATH).unwrap()).unwrap();
+        if dump == current {
+            return;
+        }
+
+        assert_ne!(
+            dump["version"], current["version"],
+            "The SCALE layout of state or message types changed. \
+             Bump LAYOUT_VERSION and run the test with UPDATE_LAYOUT_DUMP=1."
+        );
+        if std::env::var("UPDATE_LAYOUT_DUMP").is_ok() {
+            let content = serde_json::to_string_pretty(&current).unwrap();
+            std::fs::write(DUMP_PATH, content + "\n").unwrap();
+        } else {
+            panic!(
+                "{} is outdated. Run the test with UPDATE_LAYOUT_DUMP=1.",
+                DUMP_PATH
+            );
+        }
+    }
+
+    #[test]
+    fn enum_indices() {
+        let layout = layout("state::Orgs1Data").unwrap();
+        match layout.kind {
+            LayoutKind::Enum { variants } => {
+                let indices = variants
+                    .iter()
+                    .map(|variant| (variant.name, variant.index))
+                    .collect::<Vec<_>>();
+                assert_eq!(indices, vec![("V1", 0), ("V2", 1)]);
+            }
+            LayoutKind::Struct { .. } => panic!("Orgs1Data is an enum"),
+        }
+    }
+}
@@ -25,6 +25,7 @@ use sp_runtime::traits::BlakeTwo256;
 
 pub use sp_runtime::DispatchError;
 
+pub mod layout;
 pub mod message;
 pub mod state;
 
@@ -40,10 +41,16 @@ pub use project_name::{InvalidProjectNameError, ProjectName};
 mod error;
 pub use error::{RegistryError, TransactionError};
 
+mod signature;
+pub use signature::Signature;
+
 /// The hashing algorithm to use
 pub type Hashing = BlakeTwo256;
 
-/// Identifier for accounts, an Ed25519 public key.
+/// Identifier for accounts, the public key of an Ed25519 or an Sr25519 key pair.
+///
+/// The type of an Ed25519 public key is used to hold the key bytes for both schemes. Transactions
+/// of the account are signed with a [Signature] of the same scheme.
 ///
 /// Each account has an associated [state::AccountBalance] and [state::AccountTransactionIndex].
 pub type AccountId = ed25519::Public;
@@ -61,10 +68,26 @@ pub const fn rad_to_balance(rad: u64) -> Balance {
 }
 
 /// The id of a project. Used as storage key.
+///
+/// Project ids are ordered by project name first and by domain second.
 pub type ProjectId = (ProjectName, ProjectDomain);
 
+/// The id of a treasury spend proposal. Ids are assigned sequentially starting at zero.
+pub type TreasuryProposalId = u32;
+
+/// Client-supplied key that identifies a registration message across resubmissions.
+///
+/// If a registration with the same key by the same author was applied recently, resubmitting it
+/// fails with [RegistryError::IdempotencyKeyUsed].
+pub type IdempotencyKey = sp_core::H256;
+
+/// Number of blocks for which an applied [IdempotencyKey] is remembered.
+pub const IDEMPOTENCY_KEY_LIFETIME: u32 = 1000;
+
 /// The domain under which a [crate::state::Projects1Data] lives.
-#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+///
+/// Org domains are ordered before user domains. Domains of the same kind are ordered by [Id].
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProjectDomain {
     Org(Id),
@@ -78,3 +101,33 @@ impl ProjectDomain {
         }
     }
 }
+
+/// The availability status of an org or user [Id].
+///
+/// Ids are [IdStatus::Available] unless a status is stored for them.
+#[derive(Decode, Encode, Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum IdStatus {
+    /// The id is available and can be claimed
+    Available,
+
+    /// The id is curently taken by a user or by an org
+    Taken,
+
+    /// The id has been unregistered and is now retired
+    Retired,
+
+    /// The id is reserved and can only be claimed by the holder of the reservation. See
+    /// [message::ReserveId].
+    Reserved,
+}
+
+impl Default for IdStatus {
+    fn default() -> Self {
+        IdStatus::Available
+    }
+}
@@ -18,7 +18,10 @@
 //! See the README.md for more information on how to document messages.
 extern crate alloc;
 
-use crate::{AccountId, Balance, Bytes128, Id, ProjectDomain, ProjectName};
+use crate::{
+    AccountId, Balance, Bytes128, Id, IdempotencyKey, ProjectDomain, ProjectName,
+    TreasuryProposalId,
+};
 use alloc::prelude::v1::Vec;
 use parity_scale_codec::{Decode, Encode};
 
@@ -39,11 +42,25 @@ use parity_scale_codec::{Decode, Encode};
 ///
 /// A User with the same ID must not yet exist.
 ///
+/// If the ID is reserved with [ReserveId], the author must be the holder of the reservation.
+///
 /// A user associated with the author must exist.
 ///
+/// If `idempotency_key` is set, no registration with the same key by the same author must have
+/// been applied in the last [crate::IDEMPOTENCY_KEY_LIFETIME] blocks.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct RegisterOrg {
     pub org_id: Id,
+
+    /// Optional key that identifies this registration across resubmissions. See
+    /// [crate::IdempotencyKey].
+    pub idempotency_key: Option<IdempotencyKey>,
 }
 
 /// Unregisters an org on the Radicle Registry with the given ID.
@@ -58,10 +75,43 @@ pub struct RegisterOrg {
 /// associated with the author must exist and be its only member.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct UnregisterOrg {
     pub org_id: Id,
 }
 
+/// Dissolves an org: transfers all remaining funds of the org account
+/// to `recipient` and unregisters the org.
+///
+/// # State changes
+///
+/// If successful, the whole free balance of the org account is added
+/// to the recipient account, the org account is removed and the
+/// targeted Org is removed from the state. If the recipient account
+/// did not exist before, it is created.
+///
+/// # State-dependent validations
+///
+/// The targeted org must exist, have no projects, and a user
+/// associated with the author must exist and be its only member.
+///
+/// The org must not be frozen with [FreezeOrg].
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct DissolveOrg {
+    pub org_id: Id,
+    pub recipient: AccountId,
+}
+
 /// Registers a user on the Radicle Registry with the given ID.
 ///
 /// # State changes
@@ -76,9 +126,23 @@ pub struct UnregisterOrg {
 ///
 /// An Org with the same ID must not yet exist.
 ///
+/// If the ID is reserved with [ReserveId], the author must be the holder of the reservation.
+///
+/// If `idempotency_key` is set, no registration with the same key by the same author must have
+/// been applied in the last [crate::IDEMPOTENCY_KEY_LIFETIME] blocks.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct RegisterUser {
     pub user_id: Id,
+
+    /// Optional key that identifies this registration across resubmissions. See
+    /// [crate::IdempotencyKey].
+    pub idempotency_key: Option<IdempotencyKey>,
 }
 
 /// Unregisters a user on the Radicle Registry with the given ID.
@@ -93,6 +157,11 @@ pub struct RegisterUser {
 /// the transaction origin must be the associated account.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct UnregisterUser {
     pub user_id: Id,
 }
@@ -114,6 +183,11 @@ pub struct UnregisterUser {
 /// The `user_id` must not already be a member of the org.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct RegisterMember {
     // The member to register, unique in the org.
     pub user_id: Id,
@@ -138,10 +212,21 @@ pub struct RegisterMember {
 ///
 /// The user associated with the author must a member of
 /// the involved org, when an org is specified as the project domain.
+/// The org must not be frozen with [FreezeOrg].
 ///
 /// A project with the same name must not yet exist in domain.
 ///
+/// The project name must not be reserved for another domain with [ReserveProjectName].
+///
+/// If `idempotency_key` is set, no registration with the same key by the same author must have
+/// been applied in the last [crate::IDEMPOTENCY_KEY_LIFETIME] blocks.
+///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct RegisterProject {
     // The name of the project to register, unique under its domain.
     pub project_name: ProjectName,
@@ -151,6 +236,40 @@ pub struct RegisterProject {
 
     /// Opaque and imutable metadata, used by the application.
     pub metadata: Bytes128,
+
+    /// Optional key that identifies this registration across resubmissions. See
+    /// [crate::IdempotencyKey].
+    pub idempotency_key: Option<IdempotencyKey>,
+}
+
+/// Unregister a project from the Radicle Registry.
+///
+/// # State changes
+///
+/// If successful, the project is removed from the state and from the
+/// projects of its org or user. The project ID is retired and cannot be
+/// registered again.
+///
+/// # State-dependent validations
+///
+/// The project must exist.
+///
+/// The user associated with the author must be a member of the org,
+/// when the project belongs to an org, or must be the user that owns
+/// the project.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct UnregisterProject {
+    /// The name of the project to unregister.
+    pub project_name: ProjectName,
+
+    /// The domain of the project.
+    pub project_domain: ProjectDomain,
 }
 
 /// Transfer funds from an org account to an account.
@@ -169,13 +288,27 @@ pub struct RegisterProject {
 /// A user associated with the transaction author must exist and
 /// be a member of the Org of the given project.
 ///
-/// The org account must have a balance of at least `amount`.
+/// The org account must have a balance of at least `amount`. Unless
+/// `allow_death` is set, the remaining balance of the org account
+/// must not fall below the existential deposit.
+///
+/// The org must not be frozen with [FreezeOrg].
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct TransferFromOrg {
     pub org_id: Id,
     pub recipient: AccountId,
     pub amount: Balance,
+
+    /// Allow the transfer to drain the org account below the
+    /// existential deposit, which removes the account. Used to
+    /// wind down an org.
+    pub allow_death: bool,
 }
 
 /// Transfer funds from one account to another.
@@ -193,6 +326,11 @@ pub struct TransferFromOrg {
 /// The author account must have a balance of at least `amount`.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct Transfer {
     pub recipient: AccountId,
     pub amount: Balance,
@@ -213,6 +351,515 @@ pub struct Transfer {
 /// the `spec_version` of the chain runtime.
 ///
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct UpdateRuntime {
     pub code: Vec<u8>,
 }
+
+/// Propose to spend funds from the treasury account.
+///
+/// # State changes
+///
+/// If successful, a new [crate::state::TreasuryProposals1Data] with the transaction author as the
+/// proposer is added to the state. The proposal gets the next free [crate::TreasuryProposalId].
+///
+/// # State-dependent validations
+///
+/// There must be an unused [crate::TreasuryProposalId] left.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ProposeTreasurySpend {
+    pub recipient: AccountId,
+    pub amount: Balance,
+}
+
+/// Approve a treasury spend proposal and pay it out.
+///
+/// # State changes
+///
+/// If successful, the proposed amount is transferred from the treasury account to the recipient
+/// of the proposal and the proposal is removed from the state.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The proposal must exist.
+///
+/// The treasury account must have a balance of at least the proposed amount and the remaining
+/// balance must not fall below the existential deposit.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ApproveTreasurySpend {
+    pub proposal_id: TreasuryProposalId,
+}
+
+/// Reject a treasury spend proposal.
+///
+/// # State changes
+///
+/// If successful, the proposal is removed from the state without paying it out.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The proposal must exist.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct RejectTreasurySpend {
+    pub proposal_id: TreasuryProposalId,
+}
+
+/// Withdraw a treasury spend proposal.
+///
+/// # State changes
+///
+/// If successful, the proposal is removed from the state without paying it out.
+///
+/// # State-dependent validations
+///
+/// The proposal must exist.
+///
+/// The tx author must be the proposer.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CancelTreasurySpend {
+    pub proposal_id: TreasuryProposalId,
+}
+
+/// Reserve an org or user id for the given holder account, for example to protect a trademark.
+///
+/// Only `holder` can register an org or a user with a reserved id. The reservation is removed
+/// once the id is registered.
+///
+/// # State changes
+///
+/// If successful, `id` is added to the reserved ids with `holder` as the holder. An existing
+/// reservation of `id` is replaced.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// An org or user with the given id must not exist.
+///
+/// The id must not be retired.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ReserveId {
+    pub id: Id,
+    pub holder: AccountId,
+}
+
+/// Cancel the reservation of an org or user id.
+///
+/// # State changes
+///
+/// If successful, `id` is removed from the reserved ids and can be registered by anyone.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The id must be reserved.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CancelIdReservation {
+    pub id: Id,
+}
+
+/// Reserve a project name for an org or user, for example to protect a trademark.
+///
+/// Projects with a reserved name can only be registered in, transferred to or renamed in the
+/// reserved domain. Projects with the name that already exist in other domains are not affected.
+///
+/// # State changes
+///
+/// If successful, `project_name` is added to the reserved project names with `domain` as the
+/// holder. An existing reservation of `project_name` is replaced.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The org or user `domain` must exist.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ReserveProjectName {
+    pub project_name: ProjectName,
+    pub domain: ProjectDomain,
+}
+
+/// Cancel the reservation of a project name.
+///
+/// # State changes
+///
+/// If successful, `project_name` is removed from the reserved project names and can be used in
+/// any domain.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The project name must be reserved.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CancelProjectNameReservation {
+    pub project_name: ProjectName,
+}
+
+/// Offer a project registered in the domain of a user to another user.
+///
+/// The project is transferred once the recipient accepts the offer with
+/// [AcceptProjectOwnership].
+///
+/// # State changes
+///
+/// If successful, a pending transfer of the project to `recipient` is recorded. An existing
+/// pending transfer of the project is replaced.
+///
+/// # State-dependent validations
+///
+/// The project `(project_name, ProjectDomain::User(user_id))` must exist.
+///
+/// The user `user_id` must be associated with the tx author.
+///
+/// The user `recipient` must exist.
+///
+/// The project id `(project_name, ProjectDomain::User(recipient))` must neither exist nor be
+/// retired.
+///
+/// The project name must not be reserved for another domain with [ReserveProjectName].
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TransferProjectOwnership {
+    /// The name of the project to transfer.
+    pub project_name: ProjectName,
+
+    /// The user that currently owns the project.
+    pub user_id: Id,
+
+    /// The user to transfer the project to.
+    pub recipient: Id,
+}
+
+/// Accept a project transfer offered with [TransferProjectOwnership].
+///
+/// # State changes
+///
+/// If successful, the project is moved from the domain of `user_id` to the domain of the
+/// recipient user. The project is removed from the projects of `user_id` and added to the
+/// projects of the recipient. The previous project id is retired and the pending transfer is
+/// removed.
+///
+/// # State-dependent validations
+///
+/// A transfer of the project `(project_name, ProjectDomain::User(user_id))` must be pending.
+///
+/// The recipient of the pending transfer must be associated with the tx author.
+///
+/// The project id `(project_name, ProjectDomain::User(recipient))` must neither exist nor be
+/// retired.
+///
+/// The project name must not be reserved for another domain with [ReserveProjectName].
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct AcceptProjectOwnership {
+    /// The name of the project to accept.
+    pub project_name: ProjectName,
+
+    /// The user that currently owns the project.
+    pub user_id: Id,
+}
+
+/// Set the metadata of an org.
+///
+/// # State changes
+///
+/// If successful, [crate::state::Orgs1Data::metadata] of `org_id` is replaced by `metadata`.
+///
+/// # State-dependent validations
+///
+/// The identified org must exist.
+///
+/// The user associated with the author must be a member of the identified org.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct UpdateOrg {
+    /// The org to update.
+    pub org_id: Id,
+
+    /// Opaque metadata, used by the application.
+    pub metadata: Bytes128,
+}
+
+/// Set the metadata of a user.
+///
+/// # State changes
+///
+/// If successful, [crate::state::Users1Data::metadata] of `user_id` is replaced by `metadata`.
+///
+/// # State-dependent validations
+///
+/// The identified user must exist.
+///
+/// The user must be associated with the author.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct UpdateUser {
+    /// The user to update.
+    pub user_id: Id,
+
+    /// Opaque metadata, used by the application.
+    pub metadata: Bytes128,
+}
+
+/// Associate the user of the author with another account.
+///
+/// Allows a user to rotate the key that controls the user without losing the user id, its
+/// projects or its org memberships.
+///
+/// # State changes
+///
+/// If successful, [crate::state::Users1Data::account_id] of the user associated with the author
+/// is set to `new_account_id`. The author is no longer associated with the user.
+///
+/// Funds are not moved. Funds held by the previous account need to be transferred separately.
+///
+/// # State-dependent validations
+///
+/// A user associated with the author must exist.
+///
+/// `new_account_id` must not be associated with a user.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TransferUserId {
+    /// The account that controls the user after the transfer.
+    pub new_account_id: AccountId,
+}
+
+/// Hand over the membership of the author's user in an org to another user.
+///
+/// # State changes
+///
+/// If successful, the user associated with the author is replaced by `recipient` in
+/// [crate::state::Orgs1Data::members] of `org_id`.
+///
+/// # State-dependent validations
+///
+/// The identified org must exist.
+///
+/// The user associated with the author must be a member of the identified org.
+///
+/// The user `recipient` must exist and must not already be a member of the org.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TransferOrgMembership {
+    /// The org in which the membership is transferred.
+    pub org_id: Id,
+
+    /// The user that takes over the membership.
+    pub recipient: Id,
+}
+
+/// Move a project from one domain to another, for example from a user to an org.
+///
+/// # State changes
+///
+/// If successful, the project is moved from `from` to `to`. The project is removed from the
+/// projects of the org or user `from` and added to the projects of the org or user `to`. The
+/// previous project id is retired and a pending transfer of the project with
+/// [TransferProjectOwnership] is removed.
+///
+/// # State-dependent validations
+///
+/// The project `(project_name, from)` must exist.
+///
+/// The author must be authorized in both domains. For an org domain the user associated with the
+/// author must be a member of the org. For a user domain the user must be associated with the
+/// author.
+///
+/// The project id `(project_name, to)` must neither exist nor be retired.
+///
+/// The project name must not be reserved for another domain with [ReserveProjectName].
+///
+/// Neither `from` nor `to` may be an org that is frozen with [FreezeOrg].
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TransferProject {
+    /// The name of the project to move.
+    pub project_name: ProjectName,
+
+    /// The domain that currently owns the project.
+    pub from: ProjectDomain,
+
+    /// The domain to move the project to.
+    pub to: ProjectDomain,
+}
+
+/// Rename a project while keeping it in the same domain.
+///
+/// # State changes
+///
+/// If successful, the project `(old_name, domain)` is stored as `(new_name, domain)` and
+/// `old_name` is replaced with `new_name` in the projects of the org or user `domain`. The
+/// previous project id is retired and a pending transfer of the project with
+/// [TransferProjectOwnership] is removed.
+///
+/// # State-dependent validations
+///
+/// The project `(old_name, domain)` must exist.
+///
+/// For an org domain the user associated with the author must be a member of the org. For a user
+/// domain the user must be associated with the author.
+///
+/// The project id `(new_name, domain)` must neither exist nor be retired.
+///
+/// `new_name` must not be reserved for another domain with [ReserveProjectName].
+///
+/// If `domain` is an org, the org must not be frozen with [FreezeOrg].
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct RenameProject {
+    /// The current name of the project.
+    pub old_name: ProjectName,
+
+    /// The name to register the project under.
+    pub new_name: ProjectName,
+
+    /// The domain that owns the project.
+    pub domain: ProjectDomain,
+}
+
+/// Freeze an org, for example when the keys of its members are suspected to be compromised.
+///
+/// # State changes
+///
+/// If successful, the org is marked as frozen. While frozen, funds cannot be transferred from the
+/// org with [TransferFromOrg] or [DissolveOrg], no projects can be registered under the org or
+/// moved in or out of it, and transaction fees are paid by the author instead of the org.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The org must exist and must not be frozen.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct FreezeOrg {
+    /// The org to freeze.
+    pub org_id: Id,
+}
+
+/// Unfreeze an org that was frozen with [FreezeOrg].
+///
+/// # State changes
+///
+/// If successful, the org is no longer marked as frozen.
+///
+/// # State-dependent validations
+///
+/// The tx author must be the chain's sudo key.
+///
+/// The org must exist and must be frozen.
+///
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct UnfreezeOrg {
+    /// The org to unfreeze.
+    pub org_id: Id,
+}
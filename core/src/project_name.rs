@@ -21,7 +21,8 @@ use alloc::string::{String, ToString};
 use core::convert::{From, Into, TryFrom};
 use parity_scale_codec as codec;
 
-#[derive(codec::Encode, Clone, Debug, Eq, PartialEq)]
+/// Project names are ordered lexicographically by their string representation.
+#[derive(codec::Encode, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "std", serde(try_from = "String"))]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct ProjectName(String);
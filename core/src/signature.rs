@@ -0,0 +1,85 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Signatures of transactions.
+
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{ed25519, sr25519};
+use sp_runtime::traits::{Lazy, Verify};
+
+use crate::AccountId;
+
+/// Signature of a transaction made with the key pair of the author account.
+///
+/// An [AccountId] is the public key of either an Ed25519 or an Sr25519 key pair. The variant
+/// determines the scheme the signature is verified with. The encoding matches the corresponding
+/// variants of [sp_runtime::MultiSignature] so that wallets that produce multi-signatures can sign
+/// transactions.
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub enum Signature {
+    Ed25519(ed25519::Signature),
+    Sr25519(sr25519::Signature),
+}
+
+impl Verify for Signature {
+    type Signer = AccountId;
+
+    fn verify<L: Lazy<[u8]>>(&self, msg: L, signer: &AccountId) -> bool {
+        match self {
+            Signature::Ed25519(signature) => signature.verify(msg, signer),
+            Signature::Sr25519(signature) => {
+                signature.verify(msg, &sr25519::Public::from_raw(signer.0))
+            }
+        }
+    }
+}
+
+impl From<ed25519::Signature> for Signature {
+    fn from(signature: ed25519::Signature) -> Self {
+        Signature::Ed25519(signature)
+    }
+}
+
+impl From<sr25519::Signature> for Signature {
+    fn from(signature: sr25519::Signature) -> Self {
+        Signature::Sr25519(signature)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sp_core::crypto::Pair as _;
+
+    #[test]
+    fn verify_with_scheme_of_variant() {
+        let ed25519_pair = ed25519::Pair::from_string("//Alice", None).unwrap();
+        let ed25519_account = ed25519_pair.public();
+        let ed25519_signature = Signature::from(ed25519_pair.sign(b"payload"));
+        assert!(ed25519_signature.verify(&b"payload"[..], &ed25519_account));
+        assert!(!ed25519_signature.verify(&b"other payload"[..], &ed25519_account));
+
+        let sr25519_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let sr25519_account = AccountId::from_raw(sr25519_pair.public().0);
+        let sr25519_signature = sr25519_pair.sign(b"payload");
+        assert!(
+            Signature::from(sr25519_signature.clone()).verify(&b"payload"[..], &sr25519_account)
+        );
+
+        // An Sr25519 signature is not valid as an Ed25519 signature.
+        let misdeclared = Signature::Ed25519(ed25519::Signature::from_raw(sr25519_signature.0));
+        assert!(!misdeclared.verify(&b"payload"[..], &sr25519_account));
+    }
+}
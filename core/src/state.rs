@@ -26,21 +26,40 @@ use crate::{AccountId, Balance, Bytes128, Id, ProjectName};
 /// # Relevant messages
 ///
 /// * [crate::message::RegisterProject]
+/// * [crate::message::UnregisterProject]
+/// * [crate::message::AcceptProjectOwnership]
+/// * [crate::message::TransferProject]
+/// * [crate::message::RenameProject]
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub enum Projects1Data {
     V1(ProjectV1),
+    V2(ProjectV2),
 }
 
 impl Projects1Data {
     /// Creates new instance in the most up to date version
-    pub fn new(metadata: Bytes128) -> Self {
-        Self::V1(ProjectV1 { metadata })
+    pub fn new(metadata: Bytes128, registered_at: u32) -> Self {
+        Self::V2(ProjectV2 {
+            metadata,
+            registered_at,
+        })
     }
 
     /// Opaque metadata that is controlled by the App.
     pub fn metadata(&self) -> &Bytes128 {
         match self {
             Self::V1(project) => &project.metadata,
+            Self::V2(project) => &project.metadata,
+        }
+    }
+
+    /// Number of the block in which the project was registered.
+    ///
+    /// `None` for projects that were registered before the block number was recorded.
+    pub fn registered_at(&self) -> Option<u32> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(project) => Some(project.registered_at),
         }
     }
 }
@@ -54,6 +73,19 @@ pub struct ProjectV1 {
     pub metadata: Bytes128,
 }
 
+/// # Invariants
+///
+/// * `metadata` is immutable
+/// * `registered_at` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct ProjectV2 {
+    /// Opaque metadata that is controlled by the DApp.
+    pub metadata: Bytes128,
+
+    /// Number of the block in which the project was registered.
+    pub registered_at: u32,
+}
+
 /// Balance associated with an [crate::AccountId].
 ///
 /// See the [Balances Pallet](https://substrate.dev/rustdocs/master/pallet_balances/index.html) for
@@ -67,6 +99,8 @@ pub struct ProjectV1 {
 ///
 /// * [crate::message::Transfer]
 /// * [crate::message::TransferFromOrg]
+/// * [crate::message::DissolveOrg]
+/// * [crate::message::ApproveTreasurySpend]
 pub type AccountBalance = Balance;
 
 /// Next index (nonce) for a transaction of an account.
@@ -88,18 +122,37 @@ pub type AccountTransactionIndex = u32;
 ///
 /// * [crate::message::RegisterOrg]
 /// * [crate::message::UnregisterOrg]
+/// * [crate::message::DissolveOrg]
+/// * [crate::message::UnregisterProject]
+/// * [crate::message::UpdateOrg]
+/// * [crate::message::TransferOrgMembership]
+/// * [crate::message::TransferProject]
+/// * [crate::message::RenameProject]
+/// * [crate::message::FreezeOrg]
+/// * [crate::message::UnfreezeOrg]
 #[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
 pub enum Orgs1Data {
     V1(OrgV1),
+    V2(OrgV2),
+    V3(OrgV3),
+    V4(OrgV4),
 }
 
 impl Orgs1Data {
     /// Creates new instance in the most up to date version
-    pub fn new(account_id: AccountId, members: Vec<Id>, projects: Vec<ProjectName>) -> Self {
-        Self::V1(OrgV1 {
+    pub fn new(
+        account_id: AccountId,
+        members: Vec<Id>,
+        projects: Vec<ProjectName>,
+        registered_at: u32,
+    ) -> Self {
+        Self::V4(OrgV4 {
             account_id,
             members,
             projects,
+            registered_at: Some(registered_at),
+            metadata: None,
+            frozen: false,
         })
     }
 
@@ -110,6 +163,9 @@ impl Orgs1Data {
     pub fn account_id(&self) -> AccountId {
         match self {
             Self::V1(org) => org.account_id,
+            Self::V2(org) => org.account_id,
+            Self::V3(org) => org.account_id,
+            Self::V4(org) => org.account_id,
         }
     }
 
@@ -118,10 +174,15 @@ impl Orgs1Data {
     ///
     /// It is initialized with the user id associated with the author
     /// of the [crate::message::RegisterOrg] transaction.
-    /// It cannot be changed at the moment.
+    /// Members are added with [crate::message::RegisterMember] and
+    /// a member hands over their membership with
+    /// [crate::message::TransferOrgMembership].
     pub fn members(&self) -> &Vec<Id> {
         match self {
             Self::V1(org) => &org.members,
+            Self::V2(org) => &org.members,
+            Self::V3(org) => &org.members,
+            Self::V4(org) => &org.members,
         }
     }
 
@@ -130,6 +191,91 @@ impl Orgs1Data {
     pub fn projects(&self) -> &Vec<ProjectName> {
         match self {
             Self::V1(org) => &org.projects,
+            Self::V2(org) => &org.projects,
+            Self::V3(org) => &org.projects,
+            Self::V4(org) => &org.projects,
+        }
+    }
+
+    /// Number of the block in which the org was registered.
+    ///
+    /// `None` for orgs that were registered before the block number was recorded.
+    pub fn registered_at(&self) -> Option<u32> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(org) => Some(org.registered_at),
+            Self::V3(org) => org.registered_at,
+            Self::V4(org) => org.registered_at,
+        }
+    }
+
+    /// Metadata of the org, for example a display name or the hash of an external profile.
+    ///
+    /// `None` until the metadata is set with [crate::message::UpdateOrg].
+    pub fn metadata(&self) -> Option<&Bytes128> {
+        match self {
+            Self::V1(_) | Self::V2(_) => None,
+            Self::V3(org) => org.metadata.as_ref(),
+            Self::V4(org) => org.metadata.as_ref(),
+        }
+    }
+
+    /// Whether the org is frozen with [crate::message::FreezeOrg]. Funds cannot be transferred
+    /// from a frozen org and no projects can be registered under it.
+    pub fn frozen(&self) -> bool {
+        match self {
+            Self::V1(_) | Self::V2(_) | Self::V3(_) => false,
+            Self::V4(org) => org.frozen,
+        }
+    }
+
+    /// Return the org with [Orgs1Data::metadata] replaced by `metadata`.
+    ///
+    /// Orgs stored in an older version are upgraded to the most up to date version.
+    pub fn set_metadata(self, metadata: Bytes128) -> Self {
+        let org = self.into_latest();
+        Self::V4(OrgV4 {
+            metadata: Some(metadata),
+            ..org
+        })
+    }
+
+    /// Return the org with [Orgs1Data::frozen] replaced by `frozen`.
+    ///
+    /// Orgs stored in an older version are upgraded to the most up to date version.
+    pub fn set_frozen(self, frozen: bool) -> Self {
+        let org = self.into_latest();
+        Self::V4(OrgV4 { frozen, ..org })
+    }
+
+    /// Convert the org to the most up to date version.
+    fn into_latest(self) -> OrgV4 {
+        match self {
+            Self::V1(org) => OrgV4 {
+                account_id: org.account_id,
+                members: org.members,
+                projects: org.projects,
+                registered_at: None,
+                metadata: None,
+                frozen: false,
+            },
+            Self::V2(org) => OrgV4 {
+                account_id: org.account_id,
+                members: org.members,
+                projects: org.projects,
+                registered_at: Some(org.registered_at),
+                metadata: None,
+                frozen: false,
+            },
+            Self::V3(org) => OrgV4 {
+                account_id: org.account_id,
+                members: org.members,
+                projects: org.projects,
+                registered_at: org.registered_at,
+                metadata: org.metadata,
+                frozen: false,
+            },
+            Self::V4(org) => org,
         }
     }
 
@@ -139,6 +285,19 @@ impl Orgs1Data {
     pub fn add_project(self, project_name: ProjectName) -> Self {
         match self {
             Self::V1(org) => Self::V1(org.add_project(project_name)),
+            Self::V2(org) => Self::V2(org.add_project(project_name)),
+            Self::V3(org) => Self::V3(org.add_project(project_name)),
+            Self::V4(org) => Self::V4(org.add_project(project_name)),
+        }
+    }
+
+    /// Remove the given project from the list of [Orgs1Data::projects].
+    pub fn remove_project(self, project_name: &ProjectName) -> Self {
+        match self {
+            Self::V1(org) => Self::V1(org.remove_project(project_name)),
+            Self::V2(org) => Self::V2(org.remove_project(project_name)),
+            Self::V3(org) => Self::V3(org.remove_project(project_name)),
+            Self::V4(org) => Self::V4(org.remove_project(project_name)),
         }
     }
 
@@ -148,6 +307,20 @@ impl Orgs1Data {
     pub fn add_member(self, user_id: Id) -> Self {
         match self {
             Self::V1(org) => Self::V1(org.add_member(user_id)),
+            Self::V2(org) => Self::V2(org.add_member(user_id)),
+            Self::V3(org) => Self::V3(org.add_member(user_id)),
+            Self::V4(org) => Self::V4(org.add_member(user_id)),
+        }
+    }
+
+    /// Replace the member `user_id` in the list of [Orgs1Data::members] by `new_user_id`.
+    /// Return the same org if `user_id` is not a member.
+    pub fn replace_member(self, user_id: &Id, new_user_id: Id) -> Self {
+        match self {
+            Self::V1(org) => Self::V1(org.replace_member(user_id, new_user_id)),
+            Self::V2(org) => Self::V2(org.replace_member(user_id, new_user_id)),
+            Self::V3(org) => Self::V3(org.replace_member(user_id, new_user_id)),
+            Self::V4(org) => Self::V4(org.replace_member(user_id, new_user_id)),
         }
     }
 }
@@ -169,7 +342,9 @@ pub struct OrgV1 {
     ///
     /// It is initialized with the user id associated with the author
     /// of the [crate::message::RegisterOrg] transaction.
-    /// It cannot be changed at the moment.
+    /// Members are added with [crate::message::RegisterMember] and
+    /// a member hands over their membership with
+    /// [crate::message::TransferOrgMembership].
     pub members: Vec<Id>,
 
     /// Set of all projects owned by the org. Members are allowed to register
@@ -178,6 +353,12 @@ pub struct OrgV1 {
 }
 
 impl OrgV1 {
+    /// Remove the given project from the list of [OrgV1::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
     /// Add the given project to the list of [OrgV1::projects].
     /// Return a new Org with the new project included or the
     /// same org if the org already contains that project.
@@ -197,6 +378,240 @@ impl OrgV1 {
         }
         self
     }
+
+    /// Replace the member `user_id` in the list of [OrgV1::members] by `new_user_id`.
+    /// Return the same org if `user_id` is not a member.
+    pub fn replace_member(mut self, user_id: &Id, new_user_id: Id) -> Self {
+        for member in self.members.iter_mut() {
+            if member == user_id {
+                *member = new_user_id.clone();
+            }
+        }
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` is immutable
+/// * `projects` is a set of all the projects owned by the Org.
+/// * `registered_at` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct OrgV2 {
+    /// Account ID that holds the org funds.
+    ///
+    /// It is randomly generated and, unlike for other accounts,
+    /// there is no private key that controls this account.
+    pub account_id: AccountId,
+
+    /// Set of members of the org. Members are allowed to manage
+    /// the org, its projects, and transfer funds.
+    ///
+    /// It is initialized with the user id associated with the author
+    /// of the [crate::message::RegisterOrg] transaction.
+    /// Members are added with [crate::message::RegisterMember] and
+    /// a member hands over their membership with
+    /// [crate::message::TransferOrgMembership].
+    pub members: Vec<Id>,
+
+    /// Set of all projects owned by the org. Members are allowed to register
+    /// a project by sending a [crate::message::RegisterProject] transaction.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the org was registered.
+    pub registered_at: u32,
+}
+
+impl OrgV2 {
+    /// Remove the given project from the list of [OrgV2::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
+    /// Add the given project to the list of [OrgV2::projects].
+    /// Return a new Org with the new project included or the
+    /// same org if the org already contains that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+
+    /// Add the given user to the list of [OrgV2::members].
+    /// Return a new Org with the new member included or the
+    /// same org if the org already contains that member.
+    pub fn add_member(mut self, user_id: Id) -> Self {
+        if !self.members.contains(&user_id) {
+            self.members.push(user_id);
+        }
+        self
+    }
+
+    /// Replace the member `user_id` in the list of [OrgV2::members] by `new_user_id`.
+    /// Return the same org if `user_id` is not a member.
+    pub fn replace_member(mut self, user_id: &Id, new_user_id: Id) -> Self {
+        for member in self.members.iter_mut() {
+            if member == user_id {
+                *member = new_user_id.clone();
+            }
+        }
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` is immutable
+/// * `projects` is a set of all the projects owned by the Org.
+/// * `registered_at` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct OrgV3 {
+    /// Account ID that holds the org funds.
+    ///
+    /// It is randomly generated and, unlike for other accounts,
+    /// there is no private key that controls this account.
+    pub account_id: AccountId,
+
+    /// Set of members of the org. Members are allowed to manage
+    /// the org, its projects, and transfer funds.
+    ///
+    /// It is initialized with the user id associated with the author
+    /// of the [crate::message::RegisterOrg] transaction.
+    /// Members are added with [crate::message::RegisterMember] and
+    /// a member hands over their membership with
+    /// [crate::message::TransferOrgMembership].
+    pub members: Vec<Id>,
+
+    /// Set of all projects owned by the org. Members are allowed to register
+    /// a project by sending a [crate::message::RegisterProject] transaction.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the org was registered.
+    ///
+    /// `None` for orgs that were registered before the block number was recorded.
+    pub registered_at: Option<u32>,
+
+    /// Metadata of the org set by its members with [crate::message::UpdateOrg].
+    pub metadata: Option<Bytes128>,
+}
+
+impl OrgV3 {
+    /// Remove the given project from the list of [OrgV3::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
+    /// Add the given project to the list of [OrgV3::projects].
+    /// Return a new Org with the new project included or the
+    /// same org if the org already contains that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+
+    /// Add the given user to the list of [OrgV3::members].
+    /// Return a new Org with the new member included or the
+    /// same org if the org already contains that member.
+    pub fn add_member(mut self, user_id: Id) -> Self {
+        if !self.members.contains(&user_id) {
+            self.members.push(user_id);
+        }
+        self
+    }
+
+    /// Replace the member `user_id` in the list of [OrgV3::members] by `new_user_id`.
+    /// Return the same org if `user_id` is not a member.
+    pub fn replace_member(mut self, user_id: &Id, new_user_id: Id) -> Self {
+        for member in self.members.iter_mut() {
+            if member == user_id {
+                *member = new_user_id.clone();
+            }
+        }
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` is immutable
+/// * `projects` is a set of all the projects owned by the Org.
+/// * `registered_at` is immutable
+#[derive(Decode, Encode, Clone, Debug, Eq, PartialEq)]
+pub struct OrgV4 {
+    /// Account ID that holds the org funds.
+    ///
+    /// It is randomly generated and, unlike for other accounts,
+    /// there is no private key that controls this account.
+    pub account_id: AccountId,
+
+    /// Set of members of the org. Members are allowed to manage
+    /// the org, its projects, and transfer funds.
+    ///
+    /// It is initialized with the user id associated with the author
+    /// of the [crate::message::RegisterOrg] transaction.
+    /// Members are added with [crate::message::RegisterMember] and
+    /// a member hands over their membership with
+    /// [crate::message::TransferOrgMembership].
+    pub members: Vec<Id>,
+
+    /// Set of all projects owned by the org. Members are allowed to register
+    /// a project by sending a [crate::message::RegisterProject] transaction.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the org was registered.
+    ///
+    /// `None` for orgs that were registered before the block number was recorded.
+    pub registered_at: Option<u32>,
+
+    /// Metadata of the org set by its members with [crate::message::UpdateOrg].
+    pub metadata: Option<Bytes128>,
+
+    /// Whether the org is frozen with [crate::message::FreezeOrg].
+    pub frozen: bool,
+}
+
+impl OrgV4 {
+    /// Remove the given project from the list of [OrgV4::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
+    /// Add the given project to the list of [OrgV4::projects].
+    /// Return a new Org with the new project included or the
+    /// same org if the org already contains that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+
+    /// Add the given user to the list of [OrgV4::members].
+    /// Return a new Org with the new member included or the
+    /// same org if the org already contains that member.
+    pub fn add_member(mut self, user_id: Id) -> Self {
+        if !self.members.contains(&user_id) {
+            self.members.push(user_id);
+        }
+        self
+    }
+
+    /// Replace the member `user_id` in the list of [OrgV4::members] by `new_user_id`.
+    /// Return the same org if `user_id` is not a member.
+    pub fn replace_member(mut self, user_id: &Id, new_user_id: Id) -> Self {
+        for member in self.members.iter_mut() {
+            if member == user_id {
+                *member = new_user_id.clone();
+            }
+        }
+        self
+    }
 }
 
 /// Users are stored as a map with the key derived from [crate::Id].
@@ -206,17 +621,27 @@ impl OrgV1 {
 ///
 /// * [crate::message::RegisterUser]
 /// * [crate::message::UnregisterUser]
+/// * [crate::message::UnregisterProject]
+/// * [crate::message::AcceptProjectOwnership]
+/// * [crate::message::UpdateUser]
+/// * [crate::message::TransferUserId]
+/// * [crate::message::TransferProject]
+/// * [crate::message::RenameProject]
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
 pub enum Users1Data {
     V1(UserV1),
+    V2(UserV2),
+    V3(UserV3),
 }
 
 impl Users1Data {
     /// Creates new instance in the most up to date version
-    pub fn new(account_id: AccountId, projects: Vec<ProjectName>) -> Self {
-        Self::V1(UserV1 {
+    pub fn new(account_id: AccountId, projects: Vec<ProjectName>, registered_at: u32) -> Self {
+        Self::V3(UserV3 {
             account_id,
             projects,
+            registered_at: Some(registered_at),
+            metadata: None,
         })
     }
 
@@ -224,6 +649,17 @@ impl Users1Data {
     pub fn account_id(&self) -> AccountId {
         match self {
             Self::V1(user) => user.account_id,
+            Self::V2(user) => user.account_id,
+            Self::V3(user) => user.account_id,
+        }
+    }
+
+    /// Return the user with [Users1Data::account_id] replaced by `account_id`.
+    pub fn set_account_id(self, account_id: AccountId) -> Self {
+        match self {
+            Self::V1(user) => Self::V1(UserV1 { account_id, ..user }),
+            Self::V2(user) => Self::V2(UserV2 { account_id, ..user }),
+            Self::V3(user) => Self::V3(UserV3 { account_id, ..user }),
         }
     }
 
@@ -231,6 +667,52 @@ impl Users1Data {
     pub fn projects(&self) -> &Vec<ProjectName> {
         match self {
             Self::V1(user) => &user.projects,
+            Self::V2(user) => &user.projects,
+            Self::V3(user) => &user.projects,
+        }
+    }
+
+    /// Number of the block in which the user was registered.
+    ///
+    /// `None` for users that were registered before the block number was recorded.
+    pub fn registered_at(&self) -> Option<u32> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(user) => Some(user.registered_at),
+            Self::V3(user) => user.registered_at,
+        }
+    }
+
+    /// Metadata of the user, for example a display name, an avatar or the hash of an external
+    /// profile.
+    ///
+    /// `None` until the metadata is set with [crate::message::UpdateUser].
+    pub fn metadata(&self) -> Option<&Bytes128> {
+        match self {
+            Self::V1(_) | Self::V2(_) => None,
+            Self::V3(user) => user.metadata.as_ref(),
+        }
+    }
+
+    /// Return the user with [Users1Data::metadata] replaced by `metadata`.
+    ///
+    /// Users stored in an older version are upgraded to the most up to date version.
+    pub fn set_metadata(self, metadata: Bytes128) -> Self {
+        let metadata = Some(metadata);
+        match self {
+            Self::V1(user) => Self::V3(UserV3 {
+                account_id: user.account_id,
+                projects: user.projects,
+                registered_at: None,
+                metadata,
+            }),
+            Self::V2(user) => Self::V3(UserV3 {
+                account_id: user.account_id,
+                projects: user.projects,
+                registered_at: Some(user.registered_at),
+                metadata,
+            }),
+            Self::V3(user) => Self::V3(UserV3 { metadata, ..user }),
         }
     }
 
@@ -240,13 +722,24 @@ impl Users1Data {
     pub fn add_project(self, project_name: ProjectName) -> Self {
         match self {
             Self::V1(user) => Self::V1(user.add_project(project_name)),
+            Self::V2(user) => Self::V2(user.add_project(project_name)),
+            Self::V3(user) => Self::V3(user.add_project(project_name)),
+        }
+    }
+
+    /// Remove the given project from the list of [Users1Data::projects].
+    pub fn remove_project(self, project_name: &ProjectName) -> Self {
+        match self {
+            Self::V1(user) => Self::V1(user.remove_project(project_name)),
+            Self::V2(user) => Self::V2(user.remove_project(project_name)),
+            Self::V3(user) => Self::V3(user.remove_project(project_name)),
         }
     }
 }
 
 /// # Invariants
 ///
-/// * `account_id` is immutable
+/// * `account_id` only changes with [crate::message::TransferUserId]
 /// * `projects` is a set of all the projects owned by the User.
 #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
 pub struct UserV1 {
@@ -258,6 +751,12 @@ pub struct UserV1 {
 }
 
 impl UserV1 {
+    /// Remove the given project from the list of [UserV1::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
     /// Add the given project to the list of [UserV1::projects].
     /// Return a new User with the new project included or the
     /// same user if the user already owns that project.
@@ -268,3 +767,206 @@ impl UserV1 {
         self
     }
 }
+
+/// # Invariants
+///
+/// * `account_id` only changes with [crate::message::TransferUserId]
+/// * `projects` is a set of all the projects owned by the User.
+/// * `registered_at` is immutable
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct UserV2 {
+    /// Account ID that holds the user funds.
+    pub account_id: AccountId,
+
+    /// Set of all projects owned by the user.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the user was registered.
+    pub registered_at: u32,
+}
+
+impl UserV2 {
+    /// Remove the given project from the list of [UserV2::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
+    /// Add the given project to the list of [UserV2::projects].
+    /// Return a new User with the new project included or the
+    /// same user if the user already owns that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+}
+
+/// # Invariants
+///
+/// * `account_id` only changes with [crate::message::TransferUserId]
+/// * `projects` is a set of all the projects owned by the User.
+/// * `registered_at` is immutable
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct UserV3 {
+    /// Account ID that holds the user funds.
+    pub account_id: AccountId,
+
+    /// Set of all projects owned by the user.
+    pub projects: Vec<ProjectName>,
+
+    /// Number of the block in which the user was registered.
+    ///
+    /// `None` for users that were registered before the block number was recorded.
+    pub registered_at: Option<u32>,
+
+    /// Metadata of the user set with [crate::message::UpdateUser].
+    pub metadata: Option<Bytes128>,
+}
+
+impl UserV3 {
+    /// Remove the given project from the list of [UserV3::projects].
+    pub fn remove_project(mut self, project_name: &ProjectName) -> Self {
+        self.projects.retain(|name| name != project_name);
+        self
+    }
+
+    /// Add the given project to the list of [UserV3::projects].
+    /// Return a new User with the new project included or the
+    /// same user if the user already owns that project.
+    pub fn add_project(mut self, project_name: ProjectName) -> Self {
+        if !self.projects.contains(&project_name) {
+            self.projects.push(project_name);
+        }
+        self
+    }
+}
+
+/// Record of a storage migration of the registry runtime module.
+///
+/// # Storage
+///
+/// Migration records are stored as a list in the order in which the migrations were executed.
+///
+/// # Invariants
+///
+/// * The `from_version` of a record equals the `to_version` of the previous record.
+///
+/// # Relevant messages
+///
+/// Migrations are not triggered by a message of their own. They run when a new runtime is enacted
+/// with [crate::message::UpdateRuntime].
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub enum MigrationHistory1Data {
+    V1(MigrationV1),
+}
+
+impl MigrationHistory1Data {
+    /// Creates new instance in the most up to date version
+    pub fn new(from_version: u32, to_version: u32, items_migrated: u32) -> Self {
+        Self::V1(MigrationV1 {
+            from_version,
+            to_version,
+            items_migrated,
+        })
+    }
+
+    /// Storage version of the registry module before the migration.
+    pub fn from_version(&self) -> u32 {
+        match self {
+            Self::V1(migration) => migration.from_version,
+        }
+    }
+
+    /// Storage version of the registry module after the migration.
+    pub fn to_version(&self) -> u32 {
+        match self {
+            Self::V1(migration) => migration.to_version,
+        }
+    }
+
+    /// Number of storage items that were migrated.
+    pub fn items_migrated(&self) -> u32 {
+        match self {
+            Self::V1(migration) => migration.items_migrated,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct MigrationV1 {
+    /// Storage version of the registry module before the migration.
+    pub from_version: u32,
+
+    /// Storage version of the registry module after the migration.
+    pub to_version: u32,
+
+    /// Number of storage items that were migrated.
+    pub items_migrated: u32,
+}
+
+/// Proposal to spend funds from the treasury account.
+///
+/// # Storage
+///
+/// Proposals are stored as a map with the key derived from [crate::TreasuryProposalId]. The
+/// proposal ID can be extracted from the storage key.
+///
+/// # Relevant messages
+///
+/// * [crate::message::ProposeTreasurySpend]
+/// * [crate::message::ApproveTreasurySpend]
+/// * [crate::message::RejectTreasurySpend]
+/// * [crate::message::CancelTreasurySpend]
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub enum TreasuryProposals1Data {
+    V1(TreasuryProposalV1),
+}
+
+impl TreasuryProposals1Data {
+    /// Creates new instance in the most up to date version
+    pub fn new(proposer: AccountId, recipient: AccountId, amount: Balance) -> Self {
+        Self::V1(TreasuryProposalV1 {
+            proposer,
+            recipient,
+            amount,
+        })
+    }
+
+    /// Account that submitted the proposal.
+    pub fn proposer(&self) -> AccountId {
+        match self {
+            Self::V1(proposal) => proposal.proposer,
+        }
+    }
+
+    /// Account that receives the funds when the proposal is approved.
+    pub fn recipient(&self) -> AccountId {
+        match self {
+            Self::V1(proposal) => proposal.recipient,
+        }
+    }
+
+    /// Amount transferred from the treasury account when the proposal is approved.
+    pub fn amount(&self) -> Balance {
+        match self {
+            Self::V1(proposal) => proposal.amount,
+        }
+    }
+}
+
+/// # Invariants
+///
+/// * All fields are immutable
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct TreasuryProposalV1 {
+    /// Account that submitted the proposal.
+    pub proposer: AccountId,
+
+    /// Account that receives the funds when the proposal is approved.
+    pub recipient: AccountId,
+
+    /// Amount transferred from the treasury account when the proposal is approved.
+    pub amount: Balance,
+}
@@ -18,9 +18,17 @@ use crate::pow::config::Config as PowAlgConfig;
 use radicle_registry_runtime::{genesis, AccountId, Balance};
 use sc_service::{config::MultiaddrWithPeerId, ChainType, GenericChainSpec};
 use sp_core::{crypto::CryptoType, Pair};
+use sp_runtime::Permill;
 use std::convert::TryFrom;
 use std::path::PathBuf;
 
+mod genesis_builder;
+
+pub use genesis_builder::{
+    GenesisBalance, GenesisBuilder, GenesisOrg, GenesisProject, GenesisProjectId,
+    GenesisReservedId, GenesisUser,
+};
+
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = GenericChainSpec<genesis::GenesisConfig>;
 
@@ -29,23 +37,25 @@ const LATEST_RUNTIME_WASM: &[u8] = include_bytes!("../../runtime-cache/latest.wa
 
 /// Chain for local development with a single node.
 ///
-/// If `runtime` is given, it is used as the genesis runtime. Uses dummy PoW that does not eat up
-/// your CPU.
-pub fn dev() -> ChainSpec {
+/// Blocks are authored instantly when a transaction is submitted. `genesis_state` is added to the
+/// genesis state of the chain.
+pub fn dev(genesis_state: GenesisBuilder) -> ChainSpec {
     ChainParams {
         id: String::from("dev"),
         chain_type: ChainType::Development,
         boot_nodes: vec![],
-        pow_alg: PowAlgConfig::Dummy,
+        pow_alg: PowAlgConfig::InstantSeal,
         runtime: LATEST_RUNTIME_WASM.to_owned(),
         balances: dev_balances(),
         sudo_key: account_id("Alice"),
+        treasury_reward_share: Permill::zero(),
+        genesis_state,
     }
     .into_chain_spec()
 }
 
 /// Chain that is running on the cloud and is frequently updated and reset.
-pub fn devnet() -> ChainSpec {
+pub fn devnet(genesis_state: GenesisBuilder) -> ChainSpec {
     ChainParams {
         id: String::from("devnet"),
         chain_type: ChainType::Development,
@@ -59,15 +69,16 @@ pub fn devnet() -> ChainSpec {
         runtime: LATEST_RUNTIME_WASM.to_owned(),
         balances: dev_balances(),
         sudo_key: account_id("Alice"),
+        treasury_reward_share: Permill::zero(),
+        genesis_state,
     }
     .into_chain_spec()
 }
 
 /// Chain for running a cluster of nodes locally.
 ///
-/// If `runtime` is given, it is used as the genesis runtime. Similar to [dev] but uses proper PoW
-/// consensus.
-pub fn local_devnet() -> ChainSpec {
+/// Similar to [dev] but uses proper PoW consensus.
+pub fn local_devnet(genesis_state: GenesisBuilder) -> ChainSpec {
     ChainParams {
         id: String::from("local-devnet"),
         chain_type: ChainType::Development,
@@ -76,6 +87,8 @@ pub fn local_devnet() -> ChainSpec {
         runtime: LATEST_RUNTIME_WASM.to_owned(),
         balances: dev_balances(),
         sudo_key: account_id("Alice"),
+        treasury_reward_share: Permill::zero(),
+        genesis_state,
     }
     .into_chain_spec()
 }
@@ -100,6 +113,10 @@ struct ChainParams {
     runtime: Vec<u8>,
     balances: Vec<(AccountId, Balance)>,
     sudo_key: AccountId,
+    /// Share of the block reward that is credited to the treasury instead of the block author.
+    treasury_reward_share: Permill,
+    /// Additional genesis balances and registry state.
+    genesis_state: GenesisBuilder,
 }
 
 impl ChainParams {
@@ -110,9 +127,14 @@ impl ChainParams {
             boot_nodes,
             pow_alg,
             runtime,
-            balances,
+            mut balances,
             sudo_key,
+            treasury_reward_share,
+            genesis_state,
         } = self;
+        balances.extend(genesis_state.balances());
+        let registry_config = genesis_state.registry_config(treasury_reward_share);
+        let vesting_config = genesis_state.vesting_config();
         let make_genesis_config = move || genesis::GenesisConfig {
             system: Some(genesis::SystemConfig {
                 code: runtime.clone(),
@@ -122,6 +144,8 @@ impl ChainParams {
                 balances: balances.clone(),
             }),
             pallet_sudo: Some(genesis::SudoConfig { key: sudo_key }),
+            pallet_vesting: vesting_config.clone(),
+            registry: registry_config.clone(),
         };
         GenericChainSpec::from_genesis(
             &id,
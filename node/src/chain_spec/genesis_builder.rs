@@ -0,0 +1,550 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Provides [GenesisBuilder] to declare the initial registry state of a chain in a file.
+use radicle_registry_runtime::{
+    genesis, registry::genesis::genesis_org_account_id, AccountId, Balance, BlockNumber, Bytes128,
+    Id, ProjectDomain, ProjectName,
+};
+use serde::{Deserialize, Serialize};
+use sp_runtime::Permill;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Initial balances, vesting schedules, users, orgs, projects, retired ids and projects, and
+/// reservations of a chain.
+///
+/// The state is read from a JSON or TOML file with [GenesisBuilder::from_file]:
+///
+/// ```toml
+/// [[balances]]
+/// account = "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+/// balance = 1000000
+///
+/// [[vesting]]
+/// account = "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+/// start = 0
+/// length = 100000
+/// liquid = 200000
+///
+/// [[users]]
+/// id = "alice"
+/// account = "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+///
+/// [[orgs]]
+/// id = "monadic"
+/// members = ["alice"]
+/// balance = 1000
+/// frozen = false
+///
+/// [[projects]]
+/// name = "radicle"
+/// domain = { Org = "monadic" }
+///
+/// retired_ids = ["bob"]
+///
+/// [[reserved_ids]]
+/// id = "carol"
+/// holder = "5FA9nQDVg267DEd8m1ZypXLBnvN7SFxYwV7ndqSYGiN9TTpu"
+///
+/// [[retired_projects]]
+/// name = "registry"
+/// domain = { User = "alice" }
+///
+/// [[reserved_project_names]]
+/// name = "upstream"
+/// domain = { Org = "monadic" }
+/// ```
+///
+/// Accounts are given as SS58 addresses. Org accounts are derived from the org id with
+/// [genesis_org_account_id] and funded with the given org balance. Project metadata defaults to
+/// empty. Users and orgs may declare optional `metadata` like projects.
+///
+/// A vesting entry locks the genesis balance of the account except for `liquid`. The locked
+/// amount is released linearly over `length` blocks starting at block `start`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisBuilder {
+    #[serde(default)]
+    pub balances: Vec<GenesisBalance>,
+    #[serde(default)]
+    pub vesting: Vec<GenesisVesting>,
+    #[serde(default)]
+    pub users: Vec<GenesisUser>,
+    #[serde(default)]
+    pub orgs: Vec<GenesisOrg>,
+    #[serde(default)]
+    pub projects: Vec<GenesisProject>,
+    /// Org and user ids that were unregistered and cannot be claimed again.
+    #[serde(default)]
+    pub retired_ids: Vec<Id>,
+    #[serde(default)]
+    pub reserved_ids: Vec<GenesisReservedId>,
+    /// Projects that were unregistered and cannot be registered again.
+    #[serde(default)]
+    pub retired_projects: Vec<GenesisProjectId>,
+    /// Project names that can only be registered in the given domain.
+    #[serde(default)]
+    pub reserved_project_names: Vec<GenesisProjectId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisBalance {
+    pub account: AccountId,
+    pub balance: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisVesting {
+    pub account: AccountId,
+    /// Block at which the locked balance starts to be released.
+    pub start: BlockNumber,
+    /// Number of blocks over which the locked balance is released.
+    pub length: BlockNumber,
+    /// Part of the genesis balance that is not locked.
+    #[serde(default)]
+    pub liquid: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisUser {
+    pub id: Id,
+    pub account: AccountId,
+    pub metadata: Option<Bytes128>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisOrg {
+    pub id: Id,
+    pub members: Vec<Id>,
+    /// Initial balance of the org account.
+    #[serde(default)]
+    pub balance: Balance,
+    pub metadata: Option<Bytes128>,
+    #[serde(default)]
+    pub frozen: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisProject {
+    pub name: ProjectName,
+    pub domain: ProjectDomain,
+    pub metadata: Option<Bytes128>,
+}
+
+/// An org or user id that can only be claimed by the account `holder`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisReservedId {
+    pub id: Id,
+    pub holder: AccountId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisProjectId {
+    pub name: ProjectName,
+    pub domain: ProjectDomain,
+}
+
+impl GenesisBuilder {
+    /// Read the genesis state from a file. Files with a `.toml` extension are parsed as TOML, all
+    /// other files as JSON.
+    ///
+    /// Fails if the file cannot be parsed or the declared state is inconsistent. See
+    /// [GenesisBuilder::validate].
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read genesis state {}: {}", path.display(), err))?;
+        let builder: GenesisBuilder = if path.extension() == Some("toml".as_ref()) {
+            toml::from_str(&content).map_err(|err| err.to_string())
+        } else {
+            serde_json::from_str(&content).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("Invalid genesis state {}: {}", path.display(), err))?;
+        builder.validate()?;
+        Ok(builder)
+    }
+
+    /// Check that the declared state can be built into the genesis storage.
+    ///
+    /// All org and user ids including retired and reserved ids must be distinct and every account
+    /// may be associated with at most one user. Org members must be declared users and projects
+    /// must belong to a declared org or user. Project ids including retired project ids must be
+    /// unique. Project names may be reserved at most once and for a declared org or user. Vesting
+    /// schedules must be declared for accounts with a declared balance and at most once per
+    /// account.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut vesting_accounts = BTreeSet::new();
+        for vesting in &self.vesting {
+            if !vesting_accounts.insert(vesting.account) {
+                return Err(format!(
+                    "Vesting schedule for account {} is declared twice",
+                    vesting.account
+                ));
+            }
+            if vesting.length == 0 {
+                return Err(format!(
+                    "Vesting schedule for account {} has zero length",
+                    vesting.account
+                ));
+            }
+            if !self
+                .balances
+                .iter()
+                .any(|balance| balance.account == vesting.account)
+            {
+                return Err(format!(
+                    "Vesting account {} has no declared balance",
+                    vesting.account
+                ));
+            }
+        }
+
+        let mut ids = BTreeSet::new();
+        let mut user_accounts = BTreeSet::new();
+        for user in &self.users {
+            if !ids.insert(&user.id) {
+                return Err(format!("Id {} is declared twice", user.id));
+            }
+            if !user_accounts.insert(user.account) {
+                return Err(format!(
+                    "Account of user {} is associated with another user",
+                    user.id
+                ));
+            }
+        }
+
+        let user_ids = ids.clone();
+        for org in &self.orgs {
+            if !ids.insert(&org.id) {
+                return Err(format!("Id {} is declared twice", org.id));
+            }
+            if org.members.is_empty() {
+                return Err(format!("Org {} has no members", org.id));
+            }
+            if let Some(member) = org.members.iter().find(|id| !user_ids.contains(id)) {
+                return Err(format!("Member {} of org {} is not a user", member, org.id));
+            }
+        }
+
+        for id in self
+            .retired_ids
+            .iter()
+            .chain(self.reserved_ids.iter().map(|reserved| &reserved.id))
+        {
+            if !ids.insert(id) {
+                return Err(format!("Id {} is declared twice", id));
+            }
+        }
+
+        let domain_exists = |domain: &ProjectDomain| match domain {
+            ProjectDomain::Org(org_id) => self.orgs.iter().any(|org| &org.id == org_id),
+            ProjectDomain::User(user_id) => user_ids.contains(user_id),
+        };
+
+        let mut project_ids = BTreeSet::new();
+        for project in &self.projects {
+            if !domain_exists(&project.domain) {
+                return Err(format!(
+                    "Domain {:?} of project {} does not exist",
+                    project.domain, project.name
+                ));
+            }
+            if !project_ids.insert((&project.name, &project.domain)) {
+                return Err(format!(
+                    "Project {}.{:?} is declared twice",
+                    project.name, project.domain
+                ));
+            }
+        }
+        for project in &self.retired_projects {
+            if !project_ids.insert((&project.name, &project.domain)) {
+                return Err(format!(
+                    "Project {}.{:?} is declared twice",
+                    project.name, project.domain
+                ));
+            }
+        }
+
+        let mut reserved_project_names = BTreeSet::new();
+        for reservation in &self.reserved_project_names {
+            if !domain_exists(&reservation.domain) {
+                return Err(format!(
+                    "Domain {:?} of project name reservation {} does not exist",
+                    reservation.domain, reservation.name
+                ));
+            }
+            if !reserved_project_names.insert(&reservation.name) {
+                return Err(format!(
+                    "Project name {} is reserved twice",
+                    reservation.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Balances of the declared accounts and org accounts.
+    pub fn balances(&self) -> Vec<(AccountId, Balance)> {
+        let org_balances = self
+            .orgs
+            .iter()
+            .filter(|org| org.balance > 0)
+            .map(|org| (genesis_org_account_id(&org.id), org.balance));
+        self.balances
+            .iter()
+            .map(|balance| (balance.account, balance.balance))
+            .chain(org_balances)
+            .collect()
+    }
+
+    /// Vesting genesis config with the declared vesting schedules. Returns `None` if no schedule
+    /// is declared.
+    pub fn vesting_config(&self) -> Option<genesis::VestingConfig> {
+        if self.vesting.is_empty() {
+            return None;
+        }
+        Some(genesis::VestingConfig {
+            vesting: self
+                .vesting
+                .iter()
+                .map(|vesting| {
+                    (
+                        vesting.account,
+                        vesting.start,
+                        vesting.length,
+                        vesting.liquid,
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Registry genesis config with the declared users, orgs, projects, retired ids and projects,
+    /// and reservations.
+    ///
+    /// Returns `None` if no registry state is declared and `treasury_reward_share` is zero so that
+    /// the genesis state of existing chains stays unchanged.
+    pub fn registry_config(
+        &self,
+        treasury_reward_share: Permill,
+    ) -> Option<genesis::RegistryConfig> {
+        if self.users.is_empty()
+            && self.orgs.is_empty()
+            && self.projects.is_empty()
+            && self.retired_ids.is_empty()
+            && self.reserved_ids.is_empty()
+            && self.retired_projects.is_empty()
+            && self.reserved_project_names.is_empty()
+            && treasury_reward_share == Permill::zero()
+        {
+            return None;
+        }
+        Some(genesis::RegistryConfig {
+            treasury_reward_share,
+            users: self
+                .users
+                .iter()
+                .map(|user| (user.id.clone(), user.account, user.metadata.clone()))
+                .collect(),
+            orgs: self
+                .orgs
+                .iter()
+                .map(|org| {
+                    (
+                        org.id.clone(),
+                        org.members.clone(),
+                        org.metadata.clone(),
+                        org.frozen,
+                    )
+                })
+                .collect(),
+            projects: self
+                .projects
+                .iter()
+                .map(|project| {
+                    let metadata = project.metadata.clone().unwrap_or_else(|| {
+                        Bytes128::from_vec(Vec::new()).expect("empty metadata is valid")
+                    });
+                    (project.name.clone(), project.domain.clone(), metadata)
+                })
+                .collect(),
+            retired_ids: self.retired_ids.clone(),
+            reserved_ids: self
+                .reserved_ids
+                .iter()
+                .map(|reserved| (reserved.id.clone(), reserved.holder))
+                .collect(),
+            retired_projects: self
+                .retired_projects
+                .iter()
+                .map(|project| (project.name.clone(), project.domain.clone()))
+                .collect(),
+            reserved_project_names: self
+                .reserved_project_names
+                .iter()
+                .map(|reservation| (reservation.name.clone(), reservation.domain.clone()))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sp_core::crypto::{Pair as _, Ss58Codec as _};
+    use std::convert::TryFrom;
+
+    fn alice() -> AccountId {
+        sp_core::ed25519::Pair::from_string("//Alice", None)
+            .unwrap()
+            .public()
+    }
+
+    #[test]
+    fn parse_toml() {
+        let toml = format!(
+            r#"
+            [[balances]]
+            account = "{account}"
+            balance = 1000000
+
+            [[vesting]]
+            account = "{account}"
+            start = 10
+            length = 1000
+            liquid = 200000
+
+            [[users]]
+            id = "alice"
+            account = "{account}"
+
+            [[orgs]]
+            id = "monadic"
+            members = ["alice"]
+            balance = 1000
+
+            [[projects]]
+            name = "radicle"
+            domain = {{ Org = "monadic" }}
+
+            [[reserved_ids]]
+            id = "carol"
+            holder = "{account}"
+
+            [[reserved_project_names]]
+            name = "upstream"
+            domain = {{ Org = "monadic" }}
+            "#,
+            account = alice().to_ss58check()
+        );
+        let builder: GenesisBuilder = toml::from_str(&toml).unwrap();
+        builder.validate().unwrap();
+
+        let monadic = Id::try_from("monadic").unwrap();
+        assert_eq!(
+            builder.balances(),
+            vec![
+                (alice(), 1_000_000),
+                (genesis_org_account_id(&monadic), 1000)
+            ]
+        );
+        let registry_config = builder.registry_config(Permill::zero()).unwrap();
+        assert_eq!(
+            registry_config.orgs,
+            vec![(
+                monadic.clone(),
+                vec![Id::try_from("alice").unwrap()],
+                None,
+                false
+            )]
+        );
+        assert_eq!(registry_config.projects.len(), 1);
+        assert_eq!(
+            registry_config.reserved_ids,
+            vec![(Id::try_from("carol").unwrap(), alice())]
+        );
+        assert_eq!(
+            registry_config.reserved_project_names,
+            vec![(
+                ProjectName::try_from("upstream").unwrap(),
+                ProjectDomain::Org(monadic)
+            )]
+        );
+        assert_eq!(
+            builder.vesting_config().unwrap().vesting,
+            vec![(alice(), 10, 1000, 200_000)]
+        );
+    }
+
+    #[test]
+    fn validate_vesting_without_balance() {
+        let builder = GenesisBuilder {
+            vesting: vec![GenesisVesting {
+                account: alice(),
+                start: 0,
+                length: 100,
+                liquid: 0,
+            }],
+            ..Default::default()
+        };
+        assert!(builder.validate().is_err());
+    }
+
+    #[test]
+    fn validate_unknown_member() {
+        let builder = GenesisBuilder {
+            orgs: vec![GenesisOrg {
+                id: Id::try_from("monadic").unwrap(),
+                members: vec![Id::try_from("alice").unwrap()],
+                balance: 0,
+                metadata: None,
+                frozen: false,
+            }],
+            ..Default::default()
+        };
+        assert!(builder.validate().is_err());
+    }
+
+    #[test]
+    fn validate_retired_id_in_use() {
+        let builder = GenesisBuilder {
+            users: vec![GenesisUser {
+                id: Id::try_from("alice").unwrap(),
+                account: alice(),
+                metadata: None,
+            }],
+            retired_ids: vec![Id::try_from("alice").unwrap()],
+            ..Default::default()
+        };
+        assert_eq!(
+            builder.validate(),
+            Err("Id alice is declared twice".to_string())
+        );
+    }
+
+    #[test]
+    fn empty_registry_config() {
+        assert!(GenesisBuilder::default()
+            .registry_config(Permill::zero())
+            .is_none());
+    }
+}
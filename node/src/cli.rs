@@ -15,19 +15,43 @@
 
 //! Provides [Cli] struct that represents the command line arguments.
 use radicle_registry_runtime::AccountId;
-use sc_cli::{RunCmd, Subcommand, SubstrateCli};
+use sc_cli::{RunCmd, SubstrateCli};
 use sc_network::config::MultiaddrWithPeerId;
 use sc_service::{ChainSpec, Configuration};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use structopt::StructOpt;
 
+use crate::limits::LimitsOptions;
+use crate::logger::LogFormat;
+use crate::metrics::PushGatewayConfig;
 use crate::service;
+use crate::state_export::{ExportStateCmd, ImportStateCmd};
 
 lazy_static::lazy_static! {
     static ref DEFAULT_CHAIN: &'static str = option_env!("DEFAULT_CHAIN").unwrap_or("dev");
 }
 
+/// Subcommands of the node in addition to running it.
+#[derive(Debug, StructOpt)]
+pub enum Subcommand {
+    #[structopt(flatten)]
+    Base(sc_cli::Subcommand),
+
+    /// Run the runtime benchmarks. Only available if the node is built with the
+    /// `runtime-benchmarks` feature.
+    #[cfg(feature = "runtime-benchmarks")]
+    #[structopt(name = "benchmark")]
+    Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+    /// Export the registry state of the chain at a block to a JSON file.
+    ExportState(ExportStateCmd),
+
+    /// Create the chain spec of a dev chain that starts with a state exported by `export-state`.
+    ImportState(ImportStateCmd),
+}
+
 /// Full node for the Radicle Registry network
 #[derive(Debug, StructOpt)]
 pub struct Cli {
@@ -87,6 +111,31 @@ pub struct Cli {
     #[structopt(long)]
     prometheus_external: bool,
 
+    /// Push metrics to the Prometheus push gateway at the given URL, e.g.
+    /// `http://127.0.0.1:9091`.
+    ///
+    /// Useful for nodes that cannot be scraped by Prometheus, for example because they are
+    /// behind a NAT. The pull endpoint stays available.
+    #[structopt(long, value_name = "URL")]
+    prometheus_push_gateway: Option<String>,
+
+    /// Number of seconds between two pushes to the Prometheus push gateway.
+    #[structopt(long, value_name = "SECONDS", default_value = "15")]
+    prometheus_push_interval: u64,
+
+    /// Label attached to all metrics pushed to the Prometheus push gateway, given as
+    /// `KEY=VALUE`. May be given multiple times.
+    ///
+    /// If no `instance` label is given, the value of `--name` is used as the instance label.
+    #[structopt(
+        long = "prometheus-push-label",
+        value_name = "KEY=VALUE",
+        number_of_values = 1,
+        requires = "prometheus-push-gateway",
+        parse(try_from_str = parse_label)
+    )]
+    prometheus_push_labels: Vec<(String, String)>,
+
     /// Human-readable name for this node to use for telemetry
     #[structopt(long, value_name = "NAME")]
     name: Option<String>,
@@ -99,9 +148,29 @@ pub struct Cli {
     #[structopt(long, conflicts_with = "chain")]
     spec: Option<PathBuf>,
 
-    /// Run the dev chain with an in-memory database and mining
+    /// Run the dev chain with an in-memory database and author blocks instantly
     #[structopt(long, conflicts_with = "chain")]
     dev: bool,
+
+    /// JSON or TOML file with balances, users, orgs, and projects to add to the genesis state of
+    /// the `dev`, `local-devnet`, or `devnet` chain.
+    ///
+    /// Use it with the `build-spec` subcommand to create the chain spec of a new network.
+    #[structopt(long, value_name = "FILE", conflicts_with = "spec")]
+    genesis_state: Option<PathBuf>,
+
+    #[structopt(flatten)]
+    limits: LimitsOptions,
+
+    /// Format of the log output. With `json` every log line is a JSON object with the keys
+    /// `timestamp`, `level`, `target`, `message`, and `fields`.
+    #[structopt(
+        long,
+        value_name = "FORMAT",
+        default_value = "text",
+        possible_values = &["text", "json"]
+    )]
+    log_format: LogFormat,
 }
 
 impl SubstrateCli for Cli {
@@ -138,10 +207,17 @@ impl SubstrateCli for Cli {
         if let Some(spec_path) = &self.spec {
             crate::chain_spec::from_spec_file(spec_path.clone())
         } else {
+            let genesis_state = match &self.genesis_state {
+                Some(path) => crate::chain_spec::GenesisBuilder::from_file(path)?,
+                None => Default::default(),
+            };
             match id {
-                "dev" => Ok(crate::chain_spec::dev()),
-                "local-devnet" => Ok(crate::chain_spec::local_devnet()),
-                "devnet" => Ok(crate::chain_spec::devnet()),
+                "dev" => Ok(crate::chain_spec::dev(genesis_state)),
+                "local-devnet" => Ok(crate::chain_spec::local_devnet(genesis_state)),
+                "devnet" => Ok(crate::chain_spec::devnet(genesis_state)),
+                "ffnet" if self.genesis_state.is_some() => {
+                    Err("--genesis-state cannot be used with the ffnet chain".to_string())
+                }
                 "ffnet" => Ok(crate::chain_spec::ffnet()),
                 other => Err(format!("Invalid chain {}", other)),
             }
@@ -152,9 +228,9 @@ impl SubstrateCli for Cli {
 
 impl Cli {
     pub fn run(&self) -> sc_cli::Result<()> {
-        crate::logger::init();
+        crate::logger::init(self.log_format);
         match &self.subcommand {
-            Some(subcommand) => {
+            Some(Subcommand::Base(subcommand)) => {
                 let result = self
                     .create_runner(subcommand)?
                     .run_subcommand(subcommand, |config| {
@@ -166,6 +242,14 @@ impl Cli {
                 let _ = std::io::stdout().flush();
                 result
             }
+            #[cfg(feature = "runtime-benchmarks")]
+            Some(Subcommand::Benchmark(cmd)) => self
+                .create_runner(cmd)?
+                .sync_run(|config| cmd.run::<crate::blockchain::Block, service::Executor>(config)),
+            Some(Subcommand::ExportState(cmd)) => self
+                .create_runner(cmd)?
+                .sync_run(|config| cmd.run(self.adjust_config(config))),
+            Some(Subcommand::ImportState(cmd)) => cmd.run(),
             None => self.create_runner(&self.create_run_cmd())?.run_node(
                 |_config| {
                     // This should never be called since it is not accesible via the command line.
@@ -173,9 +257,21 @@ impl Cli {
                     // We leave this call here so that the type checker can properly infer the type
                     // of this closure.
                     #[allow(unreachable_code)]
-                    service::new_full(self.adjust_config(_config), self.block_author())
+                    service::new_full(
+                        self.adjust_config(_config),
+                        self.block_author(),
+                        self.push_gateway_config(),
+                        self.limits.limits(),
+                    )
+                },
+                |config| {
+                    service::new_full(
+                        self.adjust_config(config),
+                        self.block_author(),
+                        self.push_gateway_config(),
+                        self.limits.limits(),
+                    )
                 },
-                |config| service::new_full(self.adjust_config(config), self.block_author()),
                 radicle_registry_runtime::VERSION,
             ),
         }
@@ -193,6 +289,25 @@ impl Cli {
         }
     }
 
+    fn push_gateway_config(&self) -> Option<PushGatewayConfig> {
+        let url = self.prometheus_push_gateway.clone()?;
+        let mut labels = self
+            .prometheus_push_labels
+            .iter()
+            .cloned()
+            .collect::<std::collections::HashMap<_, _>>();
+        if let Some(name) = &self.name {
+            labels
+                .entry(String::from("instance"))
+                .or_insert_with(|| name.clone());
+        }
+        Some(PushGatewayConfig {
+            url,
+            interval: Duration::from_secs(self.prometheus_push_interval),
+            labels,
+        })
+    }
+
     fn create_run_cmd(&self) -> RunCmd {
         // This does not panic if there are no required arguments which we statically know.
         let mut run_cmd = RunCmd::from_iter_safe(vec![] as Vec<String>).unwrap();
@@ -252,10 +367,19 @@ impl Cli {
         if self.unsafe_rpc_external {
             config.rpc_cors = None;
         }
+
+        config.transaction_pool = self.limits.limits().pool_options();
         config
     }
 }
 
+fn parse_label(data: &str) -> Result<(String, String), String> {
+    match data.splitn(2, '=').collect::<Vec<_>>().as_slice() {
+        [key, value] if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("Invalid label \"{}\", expected KEY=VALUE", data)),
+    }
+}
+
 fn parse_ss58_account_id(data: &str) -> Result<AccountId, String> {
     sp_core::crypto::Ss58Codec::from_ss58check(data).map_err(|err| format!("{:?}", err))
 }
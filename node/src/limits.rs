@@ -0,0 +1,172 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool and block building limits that are configured on the command line.
+//!
+//! The active [Limits] are served by the [NODE_LIMITS_RPC_METHOD] RPC method.
+
+use serde::Serialize;
+use sp_runtime::Percent;
+use structopt::StructOpt;
+
+use radicle_registry_runtime::{AvailableBlockRatio, MaximumBlockLength, MaximumBlockWeight};
+
+/// Name of the RPC method that returns the [Limits] of the node.
+pub const NODE_LIMITS_RPC_METHOD: &str = "registry_nodeLimits";
+
+/// Command line options to configure [Limits]. The values are validated when they are parsed.
+#[derive(Debug, Clone, StructOpt)]
+pub struct LimitsOptions {
+    /// Maximum number of transactions in the ready queue of the transaction pool. The queue of
+    /// transactions that are not ready yet holds up to a tenth of this number.
+    #[structopt(long, value_name = "COUNT", default_value = "8192", parse(try_from_str = parse_positive))]
+    pool_limit: usize,
+
+    /// Maximum size of all transactions in the ready queue of the transaction pool in kilobytes.
+    /// The queue of transactions that are not ready yet holds up to a tenth of this size.
+    #[structopt(long, value_name = "KB", default_value = "20480", parse(try_from_str = parse_positive))]
+    pool_kbytes: usize,
+
+    /// Maximum number of transactions of a single account in the transaction pool. There is no
+    /// limit if the option is not given.
+    #[structopt(long, value_name = "COUNT", parse(try_from_str = parse_positive))]
+    pool_account_limit: Option<u32>,
+
+    /// Percentage of the block weight available to transactions that the node fills when it
+    /// authors a block.
+    #[structopt(long, value_name = "PERCENT", default_value = "100", parse(try_from_str = parse_percent))]
+    block_weight_target: u8,
+
+    /// Percentage of the block length available to transactions that the node fills when it
+    /// authors a block.
+    #[structopt(long, value_name = "PERCENT", default_value = "100", parse(try_from_str = parse_percent))]
+    block_length_target: u8,
+}
+
+impl LimitsOptions {
+    /// Compute the limits from the options. Block targets are relative to the weight and length
+    /// available to normal transactions in the runtime.
+    pub fn limits(&self) -> Limits {
+        let future_count = std::cmp::max(self.pool_limit / 10, 1);
+        let future_kbytes = std::cmp::max(self.pool_kbytes / 10, 1);
+        let max_block_weight = AvailableBlockRatio::get() * MaximumBlockWeight::get();
+        let max_block_length = AvailableBlockRatio::get() * MaximumBlockLength::get();
+        Limits {
+            pool_ready_count: self.pool_limit,
+            pool_ready_bytes: self.pool_kbytes * 1024,
+            pool_future_count: future_count,
+            pool_future_bytes: future_kbytes * 1024,
+            pool_account_limit: self.pool_account_limit,
+            block_weight_target: Percent::from_percent(self.block_weight_target) * max_block_weight,
+            block_length_target: Percent::from_percent(self.block_length_target) * max_block_length,
+        }
+    }
+}
+
+/// Limits of the transaction pool and of the blocks authored by the node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Limits {
+    /// Maximum number of transactions in the ready queue of the transaction pool.
+    pub pool_ready_count: usize,
+
+    /// Maximum size of all transactions in the ready queue of the transaction pool in bytes.
+    pub pool_ready_bytes: usize,
+
+    /// Maximum number of transactions in the future queue of the transaction pool.
+    pub pool_future_count: usize,
+
+    /// Maximum size of all transactions in the future queue of the transaction pool in bytes.
+    pub pool_future_bytes: usize,
+
+    /// Maximum number of transactions of a single account in the transaction pool.
+    pub pool_account_limit: Option<u32>,
+
+    /// Total weight of the transactions the node includes in a block it authors.
+    pub block_weight_target: u64,
+
+    /// Total encoded length of the transactions the node includes in a block it authors.
+    pub block_length_target: u32,
+}
+
+impl Limits {
+    /// Options for the transaction pool that enforce the pool limits.
+    pub fn pool_options(&self) -> sc_transaction_pool::txpool::Options {
+        sc_transaction_pool::txpool::Options {
+            ready: sc_transaction_pool::txpool::base_pool::Limit {
+                count: self.pool_ready_count,
+                total_bytes: self.pool_ready_bytes,
+            },
+            future: sc_transaction_pool::txpool::base_pool::Limit {
+                count: self.pool_future_count,
+                total_bytes: self.pool_future_bytes,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Add the [NODE_LIMITS_RPC_METHOD] method to `io`.
+    pub fn extend_rpc(self, io: &mut jsonrpc_core::IoHandler<sc_rpc::Metadata>) {
+        io.add_method(
+            NODE_LIMITS_RPC_METHOD,
+            move |_params: jsonrpc_core::Params| {
+                Ok(serde_json::to_value(self).expect("Limits are serializable"))
+            },
+        );
+    }
+}
+
+fn parse_positive<T: std::str::FromStr + Default + PartialOrd>(data: &str) -> Result<T, String> {
+    match data.parse::<T>() {
+        Ok(value) if value > T::default() => Ok(value),
+        _ => Err(format!("\"{}\" is not a positive number", data)),
+    }
+}
+
+fn parse_percent(data: &str) -> Result<u8, String> {
+    match data.parse::<u8>() {
+        Ok(value) if value > 0 && value <= 100 => Ok(value),
+        _ => Err(format!(
+            "\"{}\" is not a percentage between 1 and 100",
+            data
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_limits() {
+        let limits = LimitsOptions::from_iter(&["limits"]).limits();
+        assert_eq!(limits.pool_ready_count, 8192);
+        assert_eq!(limits.pool_future_count, 819);
+        assert_eq!(limits.pool_account_limit, None);
+        assert_eq!(
+            u64::from(limits.block_length_target),
+            u64::from(AvailableBlockRatio::get() * MaximumBlockLength::get())
+        );
+    }
+
+    #[test]
+    fn invalid_limits() {
+        assert!(LimitsOptions::from_iter_safe(&["limits", "--pool-limit", "0"]).is_err());
+        assert!(LimitsOptions::from_iter_safe(&["limits", "--pool-account-limit", "0"]).is_err());
+        assert!(
+            LimitsOptions::from_iter_safe(&["limits", "--block-weight-target", "101"]).is_err()
+        );
+    }
+}
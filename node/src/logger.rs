@@ -14,16 +14,64 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 //! Provides [init] to initialize our custom logger.
+//!
+//! With [LogFormat::Json] every record is printed as a JSON object on a single line. Use
+//! [with_fields] to attach structured fields to a record.
 use env_logger::fmt::Color;
+use std::cell::RefCell;
 use std::io::Write as _;
+use std::str::FromStr;
+
+/// Output format of the logger. See [init].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogFormat {
+    /// Human readable lines with colors.
+    Text,
+    /// One JSON object per line with the keys `timestamp`, `level`, `target`, `message`, and
+    /// `fields`.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!("Invalid log format {}", other)),
+        }
+    }
+}
+
+thread_local! {
+    /// Fields attached to the records logged by [with_fields].
+    static FIELDS: RefCell<Option<serde_json::Value>> = RefCell::new(None);
+}
 
 /// Initializes [env_logger] using the `RUST_LOG` environment variables and our custom formatter.
-pub fn init() {
+pub fn init(format: LogFormat) {
     let env = env_logger::Env::new().default_filter_or("info");
-    env_logger::Builder::from_env(env)
-        .format(format_record)
-        .target(env_logger::Target::Stdout)
-        .init();
+    let mut builder = env_logger::Builder::from_env(env);
+    match format {
+        LogFormat::Text => builder.format(format_record),
+        LogFormat::Json => builder.format(format_record_json),
+    };
+    builder.target(env_logger::Target::Stdout).init();
+}
+
+/// Attach the JSON object `fields` to all records logged by `f`.
+///
+/// ```ignore
+/// logger::with_fields(json!({ "blockNumber": 1 }), || log::info!("Imported block #1"));
+/// ```
+///
+/// The fields are only printed with [LogFormat::Json].
+pub fn with_fields<T>(fields: serde_json::Value, f: impl FnOnce() -> T) -> T {
+    FIELDS.with(|current| current.replace(Some(fields)));
+    let result = f();
+    FIELDS.with(|current| current.replace(None));
+    result
 }
 
 fn format_record(
@@ -52,3 +100,25 @@ fn format_record(
         msg = record.args()
     )
 }
+
+fn format_record_json(
+    formatter: &mut env_logger::fmt::Formatter,
+    record: &log::Record,
+) -> std::io::Result<()> {
+    let time = time::OffsetDateTime::now_local();
+    let timestamp = format!(
+        "{}.{:03}{}",
+        time.format("%Y-%m-%dT%H:%M:%S"),
+        time.millisecond(),
+        time.format("%z")
+    );
+    let fields = FIELDS.with(|current| current.borrow().clone());
+    let line = serde_json::json!({
+        "timestamp": timestamp,
+        "level": record.level().to_string(),
+        "target": record.target(),
+        "message": record.args().to_string(),
+        "fields": fields.unwrap_or_else(|| serde_json::json!({})),
+    });
+    writeln!(formatter, "{}", line)
+}
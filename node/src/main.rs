@@ -20,10 +20,14 @@
 mod blockchain;
 mod chain_spec;
 mod cli;
+mod limits;
 mod logger;
 mod metrics;
+mod pool;
 mod pow;
+mod registry_rpc;
 mod service;
+mod state_export;
 
 use crate::cli::Cli;
 use sc_cli::SubstrateCli;
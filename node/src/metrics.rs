@@ -14,21 +14,50 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use futures::StreamExt;
+use parity_scale_codec::{Decode as _, Encode};
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::future::Future;
+use std::time::Duration;
 
-use sc_client_api::{AuxStore, BlockBackend, BlockImportNotification, BlockchainEvents};
+use frame_support::{dispatch::GetCallName as _, storage::StoragePrefixedMap};
+use radicle_registry_runtime::{store, Call, UncheckedExtrinsic};
+use sc_client_api::{
+    AuxStore, BlockBackend, BlockImportNotification, BlockchainEvents, StorageProvider,
+};
 use sc_service::{AbstractService, Error};
+use sp_core::storage::StorageKey;
 use sp_runtime::{generic::BlockId, traits::Block as _};
+use sp_transaction_pool::{InPoolTransaction as _, TransactionPool as _};
 use substrate_prometheus_endpoint::prometheus::core::Atomic;
-use substrate_prometheus_endpoint::{Gauge, Registry, U64};
+use substrate_prometheus_endpoint::{Gauge, GaugeVec, Opts, Registry, U64};
 
 use crate::pow::Difficulty;
 
-pub fn register_metrics<S>(service: &S) -> Result<(), Error>
+/// Job name used when pushing metrics to a push gateway.
+const PUSH_GATEWAY_JOB: &str = "radicle_registry_node";
+
+/// Configuration for pushing metrics to a Prometheus push gateway.
+#[derive(Clone, Debug)]
+pub struct PushGatewayConfig {
+    /// URL of the push gateway, e.g. `http://127.0.0.1:9091`.
+    pub url: String,
+    /// Time between two pushes.
+    pub interval: Duration,
+    /// Grouping labels that identify this node, e.g. `instance`.
+    pub labels: HashMap<String, String>,
+}
+
+pub fn register_metrics<S>(
+    service: &S,
+    opt_push_gateway: Option<PushGatewayConfig>,
+) -> Result<(), Error>
 where
     S: AbstractService,
-    S::Client: BlockchainEvents<S::Block> + BlockBackend<S::Block> + AuxStore,
+    S::Client: BlockchainEvents<S::Block>
+        + BlockBackend<S::Block>
+        + AuxStore
+        + StorageProvider<S::Block, S::Backend>,
 {
     let registry = match service.prometheus_registry() {
         Some(registry) => registry,
@@ -38,17 +67,49 @@ where
         }
     };
     register_best_block_metrics(service, &registry)?;
+    register_transaction_pool_metrics(service, &registry)?;
+    if let Some(push_gateway) = opt_push_gateway {
+        spawn_push_gateway_thread(registry, push_gateway)?;
+    }
+    Ok(())
+}
+
+/// Periodically push all metrics from `registry` to the push gateway.
+///
+/// Pushing is blocking so we run it on a dedicated thread instead of the service executor.
+fn spawn_push_gateway_thread(registry: Registry, config: PushGatewayConfig) -> Result<(), Error> {
+    log::info!("Pushing metrics to Prometheus push gateway {}", config.url);
+    std::thread::Builder::new()
+        .name("prometheus-push".into())
+        .spawn(move || loop {
+            let result = prometheus::push_metrics(
+                PUSH_GATEWAY_JOB,
+                config.labels.clone(),
+                &config.url,
+                registry.gather(),
+                None,
+            );
+            if let Err(error) = result {
+                log::warn!("Failed to push metrics to {}: {}", config.url, error);
+            }
+            std::thread::sleep(config.interval);
+        })
+        .map_err(|e| format!("failed to spawn prometheus push thread: {}", e))?;
     Ok(())
 }
 
 fn register_best_block_metrics<S>(service: &S, registry: &Registry) -> Result<(), Error>
 where
     S: AbstractService,
-    S::Client: BlockchainEvents<S::Block> + BlockBackend<S::Block> + AuxStore,
+    S::Client: BlockchainEvents<S::Block>
+        + BlockBackend<S::Block>
+        + AuxStore
+        + StorageProvider<S::Block, S::Backend>,
 {
     let update_difficulty_gauge = create_difficulty_gauge_updater(service, registry)?;
     let update_block_size_gauges = create_block_size_gauges_updater(service, registry)?;
     let update_reorganization_gauges = create_reorganization_gauges_updater(registry)?;
+    let update_registry_gauges = create_registry_gauges_updater(service, registry)?;
     let task = service
         .client()
         .import_notification_stream()
@@ -57,6 +118,7 @@ where
                 update_difficulty_gauge(&info);
                 update_block_size_gauges(&info);
                 update_reorganization_gauges(&info);
+                update_registry_gauges(&info);
             }
             futures::future::ready(())
         });
@@ -146,6 +208,125 @@ fn create_reorganization_gauges_updater<S: AbstractService>(
     Ok(updater)
 }
 
+/// Count the registered orgs, users and projects in the state of the best block.
+fn create_registry_gauges_updater<S>(
+    service: &S,
+    registry: &Registry,
+) -> Result<impl Fn(&BlockImportNotification<S::Block>), Error>
+where
+    S: AbstractService,
+    S::Client: StorageProvider<S::Block, S::Backend>,
+{
+    let orgs_gauge = register_gauge::<U64>(
+        &registry,
+        "registry_orgs",
+        "Number of orgs registered in the best block of the chain",
+    )?;
+    let users_gauge = register_gauge::<U64>(
+        &registry,
+        "registry_users",
+        "Number of users registered in the best block of the chain",
+    )?;
+    let projects_gauge = register_gauge::<U64>(
+        &registry,
+        "registry_projects",
+        "Number of projects registered in the best block of the chain",
+    )?;
+    let client = service.client();
+    let updater = move |info: &BlockImportNotification<S::Block>| {
+        let block_id = BlockId::hash(info.hash);
+        let count_keys = |prefix: [u8; 32]| {
+            client
+                .storage_keys(&block_id, &StorageKey(prefix.to_vec()))
+                .map(|keys| keys.len() as u64)
+        };
+        if let Ok(orgs) = count_keys(store::Orgs1::final_prefix()) {
+            orgs_gauge.set(orgs);
+        }
+        if let Ok(users) = count_keys(store::Users1::final_prefix()) {
+            users_gauge.set(users);
+        }
+        if let Ok(projects) = count_keys(store::Projects1::final_prefix()) {
+            projects_gauge.set(projects);
+        }
+    };
+    Ok(updater)
+}
+
+/// Update the transaction pool gauges whenever a transaction is added to the pool or a block is
+/// imported.
+fn register_transaction_pool_metrics<S>(service: &S, registry: &Registry) -> Result<(), Error>
+where
+    S: AbstractService,
+    S::Client: BlockchainEvents<S::Block>,
+{
+    let ready_gauge = register_gauge::<U64>(
+        &registry,
+        "transaction_pool_ready",
+        "Number of transactions in the pool that can be included in the next block",
+    )?;
+    let future_gauge = register_gauge::<U64>(
+        &registry,
+        "transaction_pool_future",
+        "Number of transactions in the pool that wait for transactions with lower nonces",
+    )?;
+    let registry_calls_gauge = register_gauge_vec::<U64>(
+        &registry,
+        "transaction_pool_ready_registry_calls",
+        "Number of ready transactions in the pool by registry call",
+        &["call"],
+    )?;
+    let pool = service.transaction_pool();
+    let update_gauges = move || {
+        let status = pool.status();
+        ready_gauge.set(status.ready as u64);
+        future_gauge.set(status.future as u64);
+        registry_calls_gauge.reset();
+        for tx in pool.ready() {
+            registry_calls_gauge
+                .with_label_values(&[registry_call_name(tx.data())])
+                .inc();
+        }
+    };
+    let task = futures::stream::select(
+        service
+            .transaction_pool()
+            .import_notification_stream()
+            .map(|_| ()),
+        service.client().import_notification_stream().map(|_| ()),
+    )
+    .for_each(move |()| {
+        update_gauges();
+        futures::future::ready(())
+    });
+    spawn_metric_task(service, "transaction_pool", task);
+    Ok(())
+}
+
+/// Name of the registry call of an encoded extrinsic, e.g. `register_org`. Returns `"other"` for
+/// extrinsics of other modules.
+fn registry_call_name(extrinsic: &impl Encode) -> &'static str {
+    match UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..]) {
+        Ok(UncheckedExtrinsic {
+            function: Call::Registry(call),
+            ..
+        }) => call.get_call_name(),
+        _ => "other",
+    }
+}
+
+fn register_gauge_vec<P: Atomic + 'static>(
+    registry: &Registry,
+    gauge_name: &str,
+    gauge_help: &str,
+    labels: &[&str],
+) -> Result<GaugeVec<P>, Error> {
+    let gauge_vec = GaugeVec::new(Opts::new(gauge_name, gauge_help), labels)
+        .map_err(|e| format!("failed to create metric gauge '{}': {}", gauge_name, e))?;
+    substrate_prometheus_endpoint::register(gauge_vec, &registry)
+        .map_err(|e| format!("failed to register metric gauge '{}': {}", gauge_name, e).into())
+}
+
 fn register_gauge<P: Atomic + 'static>(
     registry: &Registry,
     gauge_name: &str,
@@ -0,0 +1,291 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool extensions that enforce the [Limits] configured on the command line.
+//!
+//! [AccountLimitedChainApi] enforces [Limits::pool_account_limit] when transactions are
+//! validated. [BlockTargetPool] enforces the block weight and length targets for the blocks
+//! authored by the node.
+
+use futures::{future, FutureExt as _};
+use parity_scale_codec::{Compact, Decode, Encode as _};
+use sc_client_api::StorageProvider as _;
+use sc_transaction_pool::{error::Error, txpool::ChainApi, FullChainApi};
+use sp_core::storage::StorageKey;
+use sp_runtime::{
+    generic::BlockId,
+    transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+    },
+    OpaqueExtrinsic,
+};
+use sp_transaction_pool::{
+    ImportNotificationStream, InPoolTransaction, PoolFuture, PoolStatus, TransactionFor,
+    TransactionPool, TransactionStatusStreamFor, TxHash,
+};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use frame_support::{storage::generator::StorageMap, weights::GetDispatchInfo as _};
+use radicle_registry_runtime::{
+    state::AccountTransactionIndex, store, AccountId, BlockNumber, RuntimeApi, UncheckedExtrinsic,
+};
+
+use crate::blockchain::{Block, Hash};
+use crate::limits::Limits;
+use crate::service::Executor;
+
+type FullClient = sc_service::TFullClient<Block, RuntimeApi, Executor>;
+
+/// Code of the [InvalidTransaction::Custom] error for transactions that exceed
+/// [Limits::pool_account_limit].
+pub const ACCOUNT_LIMIT_EXCEEDED: u8 = 1;
+
+/// [ChainApi] that rejects transactions of an account that already has
+/// [Limits::pool_account_limit] transactions in the pool. All other calls are delegated to
+/// [FullChainApi].
+///
+/// Transactions are applied in the order of their nonces. A transaction whose nonce exceeds the
+/// nonce of its author in the state by `n` can only be valid if the `n` transactions before it
+/// are in the pool, too.
+pub struct AccountLimitedChainApi {
+    inner: FullChainApi<FullClient, Block>,
+    client: Arc<FullClient>,
+    account_limit: Option<u32>,
+}
+
+impl AccountLimitedChainApi {
+    /// Create a [ChainApi] that enforces `account_limit`. If `account_limit` is `None` it
+    /// behaves like [FullChainApi].
+    pub fn new(client: Arc<FullClient>, account_limit: Option<u32>) -> Self {
+        AccountLimitedChainApi {
+            inner: FullChainApi::new(client.clone()),
+            client,
+            account_limit,
+        }
+    }
+
+    /// Return true if the transaction exceeds the account limit at the given block.
+    ///
+    /// Returns false for transactions that cannot be decoded or that are unsigned. They are
+    /// rejected or accepted by the runtime validation.
+    fn exceeds_account_limit(
+        &self,
+        at: &BlockId<Block>,
+        uxt: &OpaqueExtrinsic,
+    ) -> Result<bool, Error> {
+        let account_limit = match self.account_limit {
+            Some(account_limit) => account_limit,
+            None => return Ok(false),
+        };
+        let xt = match UncheckedExtrinsic::decode(&mut &uxt.encode()[..]) {
+            Ok(xt) => xt,
+            Err(_) => return Ok(false),
+        };
+        let (author, _signature, extra) = match xt.signature {
+            Some(signature) => signature,
+            None => return Ok(false),
+        };
+        // The nonce field of `CheckNonce` is private. It is encoded as a compact integer.
+        let Compact(nonce) = Compact::<AccountTransactionIndex>::decode(&mut &extra.3.encode()[..])
+            .expect("CheckNonce is encoded as a compact nonce");
+        let account_nonce = self.account_nonce(at, author)?;
+        Ok(nonce >= account_nonce.saturating_add(account_limit))
+    }
+
+    fn account_nonce(
+        &self,
+        at: &BlockId<Block>,
+        account_id: AccountId,
+    ) -> Result<AccountTransactionIndex, Error> {
+        let key = StorageKey(store::Account::storage_map_final_key(account_id));
+        let account_info = match self.client.storage(at, &key).map_err(Error::Blockchain)? {
+            Some(data) => Some(Decode::decode(&mut &data.0[..]).map_err(|error| {
+                Error::Blockchain(sp_blockchain::Error::Msg(format!(
+                    "Failed to decode account info: {}",
+                    error
+                )))
+            })?),
+            None => None,
+        };
+        Ok(store::Account::from_optional_value_to_query(account_info).nonce)
+    }
+}
+
+impl ChainApi for AccountLimitedChainApi {
+    type Block = Block;
+    type Hash = Hash;
+    type Error = Error;
+    type ValidationFuture =
+        Pin<Box<dyn future::Future<Output = Result<TransactionValidity, Error>> + Send>>;
+    type BodyFuture = <FullChainApi<FullClient, Block> as ChainApi>::BodyFuture;
+
+    fn validate_transaction(
+        &self,
+        at: &BlockId<Block>,
+        source: TransactionSource,
+        uxt: OpaqueExtrinsic,
+    ) -> Self::ValidationFuture {
+        match self.exceeds_account_limit(at, &uxt) {
+            Ok(false) => self.inner.validate_transaction(at, source, uxt).boxed(),
+            Ok(true) => future::ready(Ok(Err(TransactionValidityError::Invalid(
+                InvalidTransaction::Custom(ACCOUNT_LIMIT_EXCEEDED),
+            ))))
+            .boxed(),
+            Err(error) => future::ready(Err(error)).boxed(),
+        }
+    }
+
+    fn block_id_to_number(&self, at: &BlockId<Block>) -> Result<Option<BlockNumber>, Error> {
+        self.inner.block_id_to_number(at)
+    }
+
+    fn block_id_to_hash(&self, at: &BlockId<Block>) -> Result<Option<Hash>, Error> {
+        self.inner.block_id_to_hash(at)
+    }
+
+    fn hash_and_length(&self, uxt: &OpaqueExtrinsic) -> (Hash, usize) {
+        self.inner.hash_and_length(uxt)
+    }
+
+    fn block_body(&self, at: &BlockId<Block>) -> Self::BodyFuture {
+        self.inner.block_body(at)
+    }
+}
+
+/// [TransactionPool] used for authoring blocks that only provides as many ready transactions as
+/// fit into the [Limits::block_weight_target] and [Limits::block_length_target]. All other calls
+/// are delegated to the wrapped pool.
+pub struct BlockTargetPool<Pool> {
+    inner: Arc<Pool>,
+    block_weight_target: u64,
+    block_length_target: u32,
+}
+
+impl<Pool> BlockTargetPool<Pool> {
+    pub fn new(inner: Arc<Pool>, limits: &Limits) -> Self {
+        BlockTargetPool {
+            inner,
+            block_weight_target: limits.block_weight_target,
+            block_length_target: limits.block_length_target,
+        }
+    }
+}
+
+/// Weight and encoded length of a transaction. Transactions that cannot be decoded have zero
+/// weight. They are rejected by the runtime when the block is built.
+fn weight_and_length(uxt: &OpaqueExtrinsic) -> (u64, u32) {
+    let encoded = uxt.encode();
+    let weight = UncheckedExtrinsic::decode(&mut &encoded[..])
+        .map(|xt| xt.get_dispatch_info().weight)
+        .unwrap_or(0);
+    (weight, encoded.len() as u32)
+}
+
+impl<Pool> TransactionPool for BlockTargetPool<Pool>
+where
+    Pool: TransactionPool<Block = Block>,
+{
+    type Block = Block;
+    type Hash = Pool::Hash;
+    type InPoolTransaction = Pool::InPoolTransaction;
+    type Error = Pool::Error;
+
+    fn submit_at(
+        &self,
+        at: &BlockId<Block>,
+        source: TransactionSource,
+        xts: Vec<TransactionFor<Self>>,
+    ) -> PoolFuture<Vec<Result<TxHash<Self>, Self::Error>>, Self::Error> {
+        self.inner.submit_at(at, source, xts)
+    }
+
+    fn submit_one(
+        &self,
+        at: &BlockId<Block>,
+        source: TransactionSource,
+        xt: TransactionFor<Self>,
+    ) -> PoolFuture<TxHash<Self>, Self::Error> {
+        self.inner.submit_one(at, source, xt)
+    }
+
+    fn submit_and_watch(
+        &self,
+        at: &BlockId<Block>,
+        source: TransactionSource,
+        xt: TransactionFor<Self>,
+    ) -> PoolFuture<Box<TransactionStatusStreamFor<Self>>, Self::Error> {
+        self.inner.submit_and_watch(at, source, xt)
+    }
+
+    /// Ready transactions in the order of the wrapped pool until the next transaction would
+    /// exceed the block weight or length target.
+    fn ready_at(
+        &self,
+        at: BlockNumber,
+    ) -> Pin<
+        Box<
+            dyn future::Future<
+                    Output = Box<dyn Iterator<Item = Arc<Self::InPoolTransaction>> + Send>,
+                > + Send,
+        >,
+    > {
+        let block_weight_target = self.block_weight_target;
+        let block_length_target = self.block_length_target;
+        self.inner
+            .ready_at(at)
+            .map(move |ready| {
+                let mut block_weight: u64 = 0;
+                let mut block_length: u32 = 0;
+                let ready = ready.take_while(move |tx| {
+                    let (weight, length) = weight_and_length(tx.data());
+                    block_weight = block_weight.saturating_add(weight);
+                    block_length = block_length.saturating_add(length);
+                    block_weight <= block_weight_target && block_length <= block_length_target
+                });
+                Box::new(ready) as Box<dyn Iterator<Item = _> + Send>
+            })
+            .boxed()
+    }
+
+    fn ready(&self) -> Box<dyn Iterator<Item = Arc<Self::InPoolTransaction>>> {
+        self.inner.ready()
+    }
+
+    fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
+        self.inner.remove_invalid(hashes)
+    }
+
+    fn status(&self) -> PoolStatus {
+        self.inner.status()
+    }
+
+    fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
+        self.inner.import_notification_stream()
+    }
+
+    fn on_broadcasted(&self, propagations: HashMap<TxHash<Self>, Vec<String>>) {
+        self.inner.on_broadcasted(propagations)
+    }
+
+    fn hash_of(&self, xt: &TransactionFor<Self>) -> TxHash<Self> {
+        self.inner.hash_of(xt)
+    }
+
+    fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
+        self.inner.ready_transaction(hash)
+    }
+}
@@ -26,7 +26,7 @@
 
 use crate::blockchain::{Block, Hash, Header};
 use crate::pow::{harmonic_mean::HarmonicMean, Difficulty};
-use radicle_registry_runtime::timestamp_in_digest;
+use radicle_registry_runtime::{timestamp_in_digest, TARGET_BLOCK_TIME_MS};
 use sc_client_api::{blockchain, AuxStore};
 use sc_consensus_pow::{Error, PowAlgorithm, PowAux};
 use sp_api::ProvideRuntimeApi;
@@ -45,7 +45,6 @@ const INITIAL_DIFFICULTY: u64 = 1_000_000;
 const ADJUST_DIFFICULTY_DAMPING: u32 = 3;
 const ADJUST_DIFFICULTY_CLAMPING: u32 = 2;
 const ADJUST_DIFFICULTY_WINDOW_SIZE: u64 = 12;
-const TARGET_BLOCK_TIME_MS: u64 = 60_000;
 const TARGET_WINDOW_TIME_MS: u64 = ADJUST_DIFFICULTY_WINDOW_SIZE * TARGET_BLOCK_TIME_MS;
 
 /// An implementation of the Blake3 PoW algorithm.
@@ -21,6 +21,9 @@ use std::convert::{TryFrom, TryInto};
 pub enum Config {
     Dummy,
     Blake3,
+    /// No PoW. The miner authors a block as soon as a transaction enters the transaction pool and
+    /// all blocks are accepted. Only suitable for a single local development node.
+    InstantSeal,
 }
 
 impl Config {
@@ -0,0 +1,164 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! `registry_*` RPC methods that serve the registry state through the
+//! [radicle_registry_runtime::registry_api::RegistryApi] runtime API.
+//!
+//! All methods read the state of the best block and take positional parameters. Ids and project
+//! names are given as strings and project domains as `{"Org": id}` or `{"User": id}`. Methods
+//! return `null` if the requested entity does not exist.
+//!
+//! [ID_STATUS_RPC_METHOD] returns the status of an org or user id as one of `"available"`,
+//! `"taken"`, `"retired"` or `"reserved"`.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+
+use radicle_registry_runtime::{
+    registry_api::RegistryApi, state, AccountId, Bytes128, Id, ProjectDomain, ProjectName,
+};
+
+use crate::blockchain::Block;
+
+/// Name of the RPC method that returns the [radicle_registry_runtime::IdStatus] of an id.
+pub const ID_STATUS_RPC_METHOD: &str = "registry_idAvailable";
+
+/// Add the registry RPC methods to `io`.
+pub fn extend_rpc<C>(io: &mut jsonrpc_core::IoHandler<sc_rpc::Metadata>, client: Arc<C>)
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: RegistryApi<Block>,
+{
+    add_query(io, &client, "registry_getOrg", |api, at, (id,): (Id,)| {
+        Ok(api.get_org(at, id)?.map(Org::from))
+    });
+    add_query(io, &client, "registry_getUser", |api, at, (id,): (Id,)| {
+        Ok(api.get_user(at, id)?.map(User::from))
+    });
+    add_query(
+        io,
+        &client,
+        "registry_getProject",
+        |api, at, (name, domain): (ProjectName, ProjectDomain)| {
+            Ok(api.get_project(at, (name, domain))?.map(Project::from))
+        },
+    );
+    add_query(
+        io,
+        &client,
+        "registry_listOrgMembers",
+        |api, at, (id,): (Id,)| api.list_org_members(at, id),
+    );
+    add_query(
+        io,
+        &client,
+        ID_STATUS_RPC_METHOD,
+        |api, at, (id,): (Id,)| api.id_status(at, id),
+    );
+}
+
+/// Add the RPC method `name` that parses its parameters as `Params` and calls `query` with the
+/// runtime API at the best block.
+fn add_query<C, Params, Output>(
+    io: &mut jsonrpc_core::IoHandler<sc_rpc::Metadata>,
+    client: &Arc<C>,
+    name: &str,
+    query: impl Fn(&C::Api, &BlockId<Block>, Params) -> Result<Output, sp_api::ApiErrorFor<C, Block>>
+        + Send
+        + Sync
+        + 'static,
+) where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    Params: serde::de::DeserializeOwned,
+    Output: Serialize,
+{
+    let client = client.clone();
+    io.add_method(name, move |params: jsonrpc_core::Params| {
+        let params = params.parse::<Params>()?;
+        let at = BlockId::hash(client.info().best_hash);
+        let output =
+            query(&*client.runtime_api(), &at, params).map_err(|error| jsonrpc_core::Error {
+                code: jsonrpc_core::ErrorCode::InternalError,
+                message: "Runtime API call failed".to_string(),
+                data: Some(format!("{:?}", error).into()),
+            })?;
+        Ok(serde_json::to_value(output).expect("Registry state is serializable"))
+    });
+}
+
+/// JSON representation of [state::Orgs1Data].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Org {
+    account_id: AccountId,
+    members: Vec<Id>,
+    projects: Vec<ProjectName>,
+    registered_at: Option<u32>,
+    metadata: Option<Bytes128>,
+}
+
+impl From<state::Orgs1Data> for Org {
+    fn from(org: state::Orgs1Data) -> Self {
+        Org {
+            account_id: org.account_id(),
+            members: org.members().clone(),
+            projects: org.projects().clone(),
+            registered_at: org.registered_at(),
+            metadata: org.metadata().cloned(),
+        }
+    }
+}
+
+/// JSON representation of [state::Users1Data].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    account_id: AccountId,
+    projects: Vec<ProjectName>,
+    registered_at: Option<u32>,
+    metadata: Option<Bytes128>,
+}
+
+impl From<state::Users1Data> for User {
+    fn from(user: state::Users1Data) -> Self {
+        User {
+            account_id: user.account_id(),
+            projects: user.projects().clone(),
+            registered_at: user.registered_at(),
+            metadata: user.metadata().cloned(),
+        }
+    }
+}
+
+/// JSON representation of [state::Projects1Data].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Project {
+    metadata: Bytes128,
+    registered_at: Option<u32>,
+}
+
+impl From<state::Projects1Data> for Project {
+    fn from(project: state::Projects1Data) -> Self {
+        Project {
+            metadata: project.metadata().clone(),
+            registered_at: project.registered_at(),
+        }
+    }
+}
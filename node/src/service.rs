@@ -18,35 +18,54 @@
 //! This module is based on `service` module from the Substrate node template.
 
 use futures::StreamExt;
+use serde_json::json;
 use std::convert::TryFrom;
 use std::time::Duration;
 
 use sc_client_api::client::BlockchainEvents as _;
 use sc_consensus::LongestChain;
+use sc_consensus_manual_seal::rpc::EngineCommand;
 use sc_executor::native_executor_instance;
 use sc_service::{AbstractService, Configuration, Error};
 use sp_inherents::InherentDataProviders;
 
 use radicle_registry_runtime::{registry::AuthoringInherentData, AccountId, RuntimeApi};
 
-use crate::blockchain::Block;
-use crate::metrics::register_metrics;
+use crate::blockchain::{Block, Hash};
+use crate::limits::Limits;
+use crate::logger;
+use crate::metrics::{register_metrics, PushGatewayConfig};
+use crate::pool::{AccountLimitedChainApi, BlockTargetPool};
 use crate::pow::{blake3_pow::Blake3Pow, config::Config, dummy_pow::DummyPow};
+use crate::registry_rpc;
 
+#[cfg(not(feature = "runtime-benchmarks"))]
 native_executor_instance!(
         pub Executor,
         radicle_registry_runtime::api::dispatch,
         radicle_registry_runtime::native_version,
 );
 
+#[cfg(feature = "runtime-benchmarks")]
+native_executor_instance!(
+        pub Executor,
+        radicle_registry_runtime::api::dispatch,
+        radicle_registry_runtime::native_version,
+        frame_benchmarking::benchmarking::HostFunctions,
+);
+
 /// Starts a `ServiceBuilder` for a full service.
+///
+/// The transaction pool rejects transactions of accounts that already have
+/// `$pool_account_limit` transactions in the pool. See [AccountLimitedChainApi].
 macro_rules! new_full_start {
-    ($config:expr, $inherent_data_providers: expr) => {{
+    ($config:expr, $inherent_data_providers: expr, $pool_account_limit: expr) => {{
         let mut import_setup = None;
         let builder = sc_service::ServiceBuilder::new_full::<Block, RuntimeApi, Executor>($config)?
             .with_select_chain(|_config, backend| Ok(LongestChain::new(backend.clone())))?
             .with_transaction_pool(|builder| {
-                let pool_api = sc_transaction_pool::FullChainApi::new(builder.client().clone());
+                let pool_api =
+                    AccountLimitedChainApi::new(builder.client().clone(), $pool_account_limit);
                 Ok(sc_transaction_pool::BasicPool::new(
                     builder.config().transaction_pool.clone(),
                     std::sync::Arc::new(pool_api),
@@ -108,6 +127,23 @@ macro_rules! node_import_queue {
                 Blake3Pow::new($client.clone()),
                 $spawner
             ),
+            Config::InstantSeal => {
+                // The PoW block import registers the timestamp inherent. We need to do it
+                // ourselves for instant sealing.
+                if !$inherent_data_providers.has_provider(&sp_timestamp::INHERENT_IDENTIFIER) {
+                    $inherent_data_providers
+                        .register_provider(sp_timestamp::InherentDataProvider)
+                        .map_err(|error| Error::Other(format!("{:?}", error)))?;
+                }
+                let import_queue = sc_consensus_manual_seal::import_queue(
+                    Box::new($client.clone()),
+                    $spawner,
+                    None,
+                );
+                let block_import =
+                    Box::new($client.clone()) as sp_consensus::import_queue::BoxBlockImport<_, _>;
+                (block_import, import_queue)
+            }
         }
     }};
 }
@@ -140,10 +176,14 @@ macro_rules! node_import_queue_for_pow_alg {
 
 /// Builds a new service for a full client.
 ///
-/// Starts a miner if `opt_block_author` was provided.
+/// Starts a miner if `opt_block_author` was provided. Pushes metrics to a Prometheus push gateway
+/// if `opt_push_gateway` was provided. The pool limits of `limits` must already be applied to
+/// `config`.
 pub fn new_full(
     config: Configuration,
     opt_block_author: Option<AccountId>,
+    opt_push_gateway: Option<PushGatewayConfig>,
+    limits: Limits,
 ) -> Result<impl AbstractService, Error> {
     log::info!(
         "Native runtime version: spec={} impl={}",
@@ -153,23 +193,50 @@ pub fn new_full(
 
     let pow_alg = Config::try_from(&config)?;
     let inherent_data_providers = InherentDataProviders::new();
-    let (builder, import_setup) = new_full_start!(config, inherent_data_providers.clone());
+    let (builder, import_setup) = new_full_start!(
+        config,
+        inherent_data_providers.clone(),
+        limits.pool_account_limit
+    );
     let block_import = import_setup.expect("No import setup set for miner");
 
+    // Commands sent by the manual seal RPC. See [rpc_extensions].
+    let (engine_command_sink, engine_commands) = futures::channel::mpsc::channel(1024);
+    let builder = builder.with_rpc_extensions(|builder| {
+        let mut io = rpc_extensions(&pow_alg, engine_command_sink, limits);
+        registry_rpc::extend_rpc(&mut io, builder.client().clone());
+        Ok(io)
+    })?;
+
     let service = builder.build_full()?;
-    register_metrics(&service)?;
+    register_metrics(&service, opt_push_gateway)?;
 
-    if let Some(block_author) = opt_block_author {
-        let client = service.client();
-        service.spawn_essential_task_handle().spawn(
-            "mined-block-notifier",
-            client.import_notification_stream().for_each(|info| {
-                if info.origin == sp_consensus::BlockOrigin::Own {
-                    log::info!("Imported own block #{} ({})", info.header.number, info.hash)
-                }
+    service.spawn_essential_task_handle().spawn(
+        "block-import-logger",
+        service
+            .client()
+            .import_notification_stream()
+            .for_each(|info| {
+                let fields = json!({
+                    "event": "blockImported",
+                    "blockHash": info.hash,
+                    "blockNumber": info.header.number,
+                    "origin": format!("{:?}", info.origin),
+                    "isNewBest": info.is_new_best,
+                });
+                logger::with_fields(fields, || {
+                    if info.origin == sp_consensus::BlockOrigin::Own {
+                        log::info!("Imported own block #{} ({})", info.header.number, info.hash)
+                    } else {
+                        log::debug!("Imported block #{} ({})", info.header.number, info.hash)
+                    }
+                });
                 futures::future::ready(())
             }),
-        );
+    );
+
+    if let Some(block_author) = opt_block_author {
+        let client = service.client();
 
         let authoring_inherent_data = AuthoringInherentData { block_author };
 
@@ -180,11 +247,12 @@ pub fn new_full(
 
         let proposer = sc_basic_authorship::ProposerFactory::new(
             service.client(),
-            service.transaction_pool(),
+            std::sync::Arc::new(BlockTargetPool::new(service.transaction_pool(), &limits)),
             service.prometheus_registry().as_ref(),
         );
 
-        log::info!("Starting block miner");
+        let fields = json!({ "event": "minerStarted", "blockAuthor": block_author });
+        logger::with_fields(fields, || log::info!("Starting block miner"));
 
         match pow_alg {
             Config::Dummy => start_mine!(
@@ -201,18 +269,70 @@ pub fn new_full(
                 inherent_data_providers,
                 Blake3Pow::new(client)
             ),
+            Config::InstantSeal => {
+                let select_chain = service
+                    .select_chain()
+                    .expect("Select chain is set up by new_full_start");
+                let pool = service.transaction_pool().pool().clone();
+                // Seal a block for every transaction that is imported into the pool.
+                let instant_seal_commands =
+                    pool.validated_pool().import_notification_stream().map(|_| {
+                        EngineCommand::SealNewBlock {
+                            create_empty: false,
+                            finalize: false,
+                            parent_hash: None,
+                            sender: None,
+                        }
+                    });
+                service.spawn_essential_task_handle().spawn(
+                    "instant-seal",
+                    sc_consensus_manual_seal::run_manual_seal(
+                        block_import,
+                        proposer,
+                        client,
+                        pool,
+                        futures::stream::select(instant_seal_commands, engine_commands),
+                        select_chain,
+                        inherent_data_providers,
+                    ),
+                );
+            }
         }
     } else {
-        log::info!("Mining is disabled");
+        let fields = json!({ "event": "minerDisabled" });
+        logger::with_fields(fields, || log::info!("Mining is disabled"));
     }
 
     Ok(service)
 }
 
+/// RPC extensions of the node that do not depend on the client. See [registry_rpc] for the
+/// methods that serve the registry state.
+///
+/// Provides the [crate::limits::NODE_LIMITS_RPC_METHOD] method that returns `limits`.
+///
+/// For [Config::InstantSeal] chains this also provides the `engine_createBlock` and
+/// `engine_finalizeBlock` methods. The methods send their commands to `engine_command_sink`.
+/// Blocks are only created and finalized if the node authors blocks.
+fn rpc_extensions(
+    pow_alg: &Config,
+    engine_command_sink: futures::channel::mpsc::Sender<EngineCommand<Hash>>,
+    limits: Limits,
+) -> jsonrpc_core::IoHandler<sc_rpc::Metadata> {
+    use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApi as _};
+
+    let mut io = jsonrpc_core::IoHandler::default();
+    limits.extend_rpc(&mut io);
+    if let Config::InstantSeal = pow_alg {
+        io.extend_with(ManualSeal::new(engine_command_sink).to_delegate());
+    }
+    io
+}
+
 /// Build a new service to be used for one-shot commands.
 pub fn new_for_command(
     config: Configuration,
 ) -> Result<impl sc_service::ServiceBuilderCommand<Block = Block>, Error> {
     let inherent_data_providers = InherentDataProviders::new();
-    Ok(new_full_start!(config, inherent_data_providers).0)
+    Ok(new_full_start!(config, inherent_data_providers, None).0)
 }
@@ -0,0 +1,355 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Provides the `export-state` and `import-state` subcommands that copy the registry state of a
+//! chain into the genesis state of a new dev chain.
+//!
+//! `export-state` reads all users, orgs, projects, account balances, retired ids and projects, and
+//! reservations at a block and writes them as a versioned [StateExport] JSON file. `import-state`
+//! turns such a file into the chain spec of a dev chain that starts with the exported state.
+//!
+//! Balances of org accounts are exported as the balance of the org because org accounts of the
+//! new chain are derived from the org id. Reserved balances, vesting schedules, the registration
+//! block of entities, pending project transfers, and treasury proposals are not exported.
+
+use parity_scale_codec::Decode;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+use frame_support::storage::StoragePrefixedMap;
+use radicle_registry_runtime::{
+    registry::decode_blake_two128_concat_key, store, AccountId, Balance, BlockNumber, IdStatus,
+    RuntimeApi,
+};
+use sc_cli::{CliConfiguration, PruningParams, SharedParams};
+use sc_client_api::StorageProvider as _;
+use sc_service::{ChainSpec as _, Configuration};
+use sp_blockchain::HeaderBackend as _;
+use sp_core::storage::StorageKey;
+use sp_runtime::generic::BlockId;
+
+use crate::blockchain::{Block, Hash};
+use crate::chain_spec::{
+    self, GenesisBalance, GenesisBuilder, GenesisOrg, GenesisProject, GenesisProjectId,
+    GenesisReservedId, GenesisUser,
+};
+use crate::service::Executor;
+
+type FullClient = sc_service::TFullClient<Block, RuntimeApi, Executor>;
+
+/// Version of the [StateExport] file format. Files with a different version are rejected by
+/// [StateExport::from_file].
+pub const STATE_EXPORT_VERSION: u32 = 2;
+
+/// Registry state of a chain at a block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct StateExport {
+    pub version: u32,
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    pub state: GenesisBuilder,
+}
+
+impl StateExport {
+    /// Read a state export written by `export-state`.
+    ///
+    /// Fails if the file cannot be parsed, has an unsupported version, or the exported state is
+    /// inconsistent. See [GenesisBuilder::validate].
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read state export {}: {}", path.display(), err))?;
+        Self::from_json(&content)
+            .map_err(|err| format!("Invalid state export {}: {}", path.display(), err))
+    }
+
+    fn from_json(content: &str) -> Result<Self, String> {
+        let version = serde_json::from_str::<serde_json::Value>(content)
+            .map_err(|err| err.to_string())?
+            .get("version")
+            .and_then(|version| version.as_u64());
+        if version != Some(STATE_EXPORT_VERSION as u64) {
+            return Err(format!(
+                "Unsupported version {:?}, expected {}",
+                version, STATE_EXPORT_VERSION
+            ));
+        }
+        let export: StateExport = serde_json::from_str(content).map_err(|err| err.to_string())?;
+        export.state.validate()?;
+        Ok(export)
+    }
+}
+
+/// Export the registry state of the chain at a block to a JSON file
+#[derive(Debug, StructOpt)]
+pub struct ExportStateCmd {
+    /// Number of the block to export the state of. Defaults to the best block.
+    #[structopt(long, value_name = "NUMBER")]
+    block: Option<BlockNumber>,
+
+    /// File to write the state to. Defaults to stdout.
+    #[structopt(long, short, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    #[structopt(flatten)]
+    shared_params: SharedParams,
+
+    #[structopt(flatten)]
+    pruning_params: PruningParams,
+}
+
+impl ExportStateCmd {
+    pub fn run(&self, config: Configuration) -> sc_cli::Result<()> {
+        let client = sc_service::new_full_client::<Block, RuntimeApi, Executor>(&config)?;
+        let export = export_state(&client, self.block)?;
+        let json = serde_json::to_string_pretty(&export).map_err(|err| err.to_string())?;
+        write_output(self.output.as_deref(), &json)
+    }
+}
+
+impl CliConfiguration for ExportStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+}
+
+/// Create the chain spec of a dev chain that starts with the state exported by `export-state`
+#[derive(Debug, StructOpt)]
+pub struct ImportStateCmd {
+    /// State file written by `export-state`.
+    #[structopt(value_name = "FILE")]
+    input: PathBuf,
+
+    /// File to write the chain spec to. Defaults to stdout.
+    ///
+    /// Run the dev chain with `--dev --spec <FILE>`.
+    #[structopt(long, short, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+impl ImportStateCmd {
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let export = StateExport::from_file(&self.input)?;
+        log::info!(
+            "Importing state of block #{} ({})",
+            export.block_number,
+            export.block_hash
+        );
+        let json = chain_spec::dev(export.state).as_json(false)?;
+        write_output(self.output.as_deref(), &json)
+    }
+}
+
+fn write_output(output: Option<&Path>, content: &str) -> sc_cli::Result<()> {
+    match output {
+        Some(path) => std::fs::write(path, content)?,
+        None => println!("{}", content),
+    }
+    Ok(())
+}
+
+/// Read the registry state at the block with the given number or at the best block.
+fn export_state(
+    client: &FullClient,
+    block_number: Option<BlockNumber>,
+) -> Result<StateExport, String> {
+    let (block_number, block_hash) = match block_number {
+        Some(number) => {
+            let hash = client
+                .hash(number)
+                .map_err(|err| err.to_string())?
+                .ok_or_else(|| format!("Block #{} does not exist", number))?;
+            (number, hash)
+        }
+        None => {
+            let info = client.info();
+            (info.best_number, info.best_hash)
+        }
+    };
+    let block_id = BlockId::Hash(block_hash);
+
+    let mut balances = map_entries::<store::Account, AccountId, _>(client, &block_id)?
+        .into_iter()
+        .map(|(account, info)| (account, info.data.free))
+        .collect::<BTreeMap<AccountId, Balance>>();
+
+    let users = map_entries::<store::Users1, _, _>(client, &block_id)?
+        .into_iter()
+        .map(|(id, user)| GenesisUser {
+            id,
+            account: user.account_id(),
+            metadata: user.metadata().cloned(),
+        })
+        .collect();
+
+    let orgs = map_entries::<store::Orgs1, _, _>(client, &block_id)?
+        .into_iter()
+        .map(|(id, org)| GenesisOrg {
+            id,
+            members: org.members().clone(),
+            balance: balances.remove(&org.account_id()).unwrap_or(0),
+            metadata: org.metadata().cloned(),
+            frozen: org.frozen(),
+        })
+        .collect();
+
+    let projects = map_entries::<store::Projects1, _, _>(client, &block_id)?
+        .into_iter()
+        .map(|((name, domain), project)| GenesisProject {
+            name,
+            domain,
+            metadata: Some(project.metadata().clone()),
+        })
+        .collect();
+
+    let retired_ids = map_entries::<store::IdStatuses1, _, IdStatus>(client, &block_id)?
+        .into_iter()
+        .filter(|(_, status)| *status == IdStatus::Retired)
+        .map(|(id, _)| id)
+        .collect();
+
+    let reserved_ids = map_entries::<store::ReservedIds1, _, _>(client, &block_id)?
+        .into_iter()
+        .map(|(id, holder)| GenesisReservedId { id, holder })
+        .collect();
+
+    let retired_projects = map_entries::<store::RetiredProjects1, _, ()>(client, &block_id)?
+        .into_iter()
+        .map(|((name, domain), ())| GenesisProjectId { name, domain })
+        .collect();
+
+    let reserved_project_names =
+        map_entries::<store::ReservedProjectNames1, _, _>(client, &block_id)?
+            .into_iter()
+            .map(|(name, domain)| GenesisProjectId { name, domain })
+            .collect();
+
+    let state = GenesisBuilder {
+        balances: balances
+            .into_iter()
+            .filter(|(_, balance)| *balance > 0)
+            .map(|(account, balance)| GenesisBalance { account, balance })
+            .collect(),
+        vesting: Vec::new(),
+        users,
+        orgs,
+        projects,
+        retired_ids,
+        reserved_ids,
+        retired_projects,
+        reserved_project_names,
+    };
+    state.validate()?;
+    Ok(StateExport {
+        version: STATE_EXPORT_VERSION,
+        block_number,
+        block_hash,
+        state,
+    })
+}
+
+/// All entries of the `Blake2_128Concat` storage map `S` at the given block.
+fn map_entries<S, Key, Value>(
+    client: &FullClient,
+    block_id: &BlockId<Block>,
+) -> Result<Vec<(Key, Value)>, String>
+where
+    S: StoragePrefixedMap<Value>,
+    Key: Decode,
+    Value: Decode,
+{
+    client
+        .storage_pairs(block_id, &StorageKey(S::final_prefix().to_vec()))
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .map(|(key, value)| {
+            let key = decode_blake_two128_concat_key::<Key>(&key.0);
+            let value = Value::decode(&mut &value.0[..]);
+            key.and_then(|key| Ok((key, value?)))
+                .map_err(|err| format!("Failed to decode storage entry: {}", err))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn export() -> StateExport {
+        let account = sp_core::ed25519::Public::from_raw([1; 32]);
+        let user_id = radicle_registry_runtime::Id::try_from("alice").unwrap();
+        StateExport {
+            version: STATE_EXPORT_VERSION,
+            block_number: 42,
+            block_hash: Hash::repeat_byte(7),
+            state: GenesisBuilder {
+                balances: vec![GenesisBalance {
+                    account,
+                    balance: 1000,
+                }],
+                users: vec![GenesisUser {
+                    id: user_id.clone(),
+                    account,
+                    metadata: None,
+                }],
+                orgs: vec![GenesisOrg {
+                    id: radicle_registry_runtime::Id::try_from("monadic").unwrap(),
+                    members: vec![user_id],
+                    balance: 10,
+                    metadata: Some(radicle_registry_runtime::Bytes128::from_vec(vec![1]).unwrap()),
+                    frozen: true,
+                }],
+                retired_ids: vec![radicle_registry_runtime::Id::try_from("bob").unwrap()],
+                reserved_ids: vec![GenesisReservedId {
+                    id: radicle_registry_runtime::Id::try_from("carol").unwrap(),
+                    holder: account,
+                }],
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let json = serde_json::to_string(&export()).unwrap();
+        assert_eq!(StateExport::from_json(&json), Ok(export()));
+    }
+
+    #[test]
+    fn reject_unsupported_version() {
+        let mut value = serde_json::to_value(&export()).unwrap();
+        value["version"] = serde_json::json!(STATE_EXPORT_VERSION + 1);
+        let result = StateExport::from_json(&value.to_string());
+        assert!(result.unwrap_err().starts_with("Unsupported version"));
+    }
+
+    #[test]
+    fn reject_invalid_state() {
+        let mut export = export();
+        export.state.users.clear();
+        let json = serde_json::to_string(&export).unwrap();
+        assert_eq!(
+            StateExport::from_json(&json),
+            Err("Member alice of org monadic is not a user".to_string())
+        );
+    }
+}
@@ -15,6 +15,7 @@
 
 use radicle_registry_client::*;
 use radicle_registry_test_utils::*;
+use sp_runtime::traits::Header as _;
 
 /// Assert that a known account is recognized as existent on chain
 #[async_std::test]
@@ -32,10 +33,94 @@ async fn account_exists() {
 #[async_std::test]
 async fn random_account_does_not_exist() {
     let (client, _) = Client::new_emulator();
-    let random_account = ed25519::Pair::generate().0.public();
+    let random_account = random_key_pair().public();
 
     assert!(
         !client.account_exists(&random_account).await.unwrap(),
         "Account was expected to be on chain"
     );
 }
+
+/// Assert that the account statements of both parties contain a transfer and that the sender
+/// statement contains the fee.
+#[async_std::test]
+async fn account_statement_contains_transfer() {
+    let (client, _) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+
+    let from_block = client.block_header_best_chain().await.unwrap().number + 1;
+    transfer(&client, &donator, recipient, 1000).await;
+    let transfer_block = client.block_header_best_chain().await.unwrap();
+
+    let donator_kinds = client
+        .account_statement(&donator.public(), from_block, transfer_block.number)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        donator_kinds,
+        vec![
+            AccountStatementEntryKind::FeePaid { amount: 1 },
+            AccountStatementEntryKind::TransferOut {
+                to: recipient,
+                amount: 1000
+            },
+        ]
+    );
+
+    let recipient_entries = client
+        .account_statement(&recipient, from_block, transfer_block.number)
+        .await
+        .unwrap();
+    assert_eq!(
+        recipient_entries,
+        vec![AccountStatementEntry {
+            block_number: transfer_block.number,
+            block_hash: transfer_block.hash(),
+            kind: AccountStatementEntryKind::TransferIn {
+                from: donator.public(),
+                amount: 1000
+            },
+        }]
+    );
+
+    let later_entries = client
+        .account_statement(&recipient, transfer_block.number + 1, u32::MAX)
+        .await
+        .unwrap();
+    assert!(later_entries.is_empty());
+}
+
+/// Assert that [ClientT::account_info] reports the free balance of an account without locks or
+/// vesting schedule.
+#[async_std::test]
+async fn account_info() {
+    let (client, _) = Client::new_emulator();
+    let account_id = key_pair_with_funds(&client).await.public();
+
+    let account_info = client.account_info(&account_id).await.unwrap();
+    assert_eq!(
+        account_info,
+        AccountInfo {
+            free: client.free_balance(&account_id).await.unwrap(),
+            reserved: 0,
+            locked: 0,
+            vesting: None,
+        }
+    );
+}
+
+#[test]
+fn vesting_schedule_locked_at() {
+    let vesting = VestingSchedule {
+        locked: 1000,
+        per_block: 10,
+        starting_block: 5,
+    };
+    assert_eq!(vesting.locked_at(0), 1000);
+    assert_eq!(vesting.locked_at(15), 900);
+    assert_eq!(vesting.locked_at(500), 0);
+}
@@ -0,0 +1,78 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test creating blocks on demand and waiting for blocks.
+use radicle_registry_client::*;
+use sp_runtime::traits::Header as _;
+use std::time::Duration;
+
+/// Assert that an empty block can be created and finalized on demand.
+#[async_std::test]
+async fn dev_create_and_finalize_block() {
+    let (client, _) = Client::new_emulator();
+    let tip = client.block_header_best_chain().await.unwrap();
+
+    let block_hash = client.dev_create_block().await.unwrap();
+    let new_tip = client.block_header_best_chain().await.unwrap();
+    assert_eq!(new_tip.hash(), block_hash);
+    assert_eq!(new_tip.number, tip.number + 1);
+    assert_eq!(new_tip.parent_hash, tip.hash());
+
+    client.dev_finalize_block(block_hash).await.unwrap();
+}
+
+/// Assert that creating a block on an ancestor of the tip replaces the blocks after the ancestor.
+#[async_std::test]
+async fn dev_create_block_at() {
+    let (client, _) = Client::new_emulator();
+    let fork_point = client.dev_create_block().await.unwrap();
+    let retracted = client.dev_create_block().await.unwrap();
+
+    let block_hash = client.dev_create_block_at(fork_point).await.unwrap();
+    assert_ne!(block_hash, retracted);
+    let new_tip = client.block_header_best_chain().await.unwrap();
+    assert_eq!(new_tip.hash(), block_hash);
+    assert_eq!(new_tip.parent_hash, fork_point);
+
+    match client.dev_create_block_at(retracted).await {
+        Err(Error::BlockMissing { block_hash }) => assert_eq!(block_hash, retracted),
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+/// Assert that [Client::wait_for_block] resolves once the block is created and times out
+/// otherwise.
+#[async_std::test]
+async fn wait_for_block() {
+    let (client, _) = Client::new_emulator();
+    let target = client.block_header_best_chain().await.unwrap().number + 2;
+
+    let waiter = {
+        let client = client.clone();
+        async_std::task::spawn(async move {
+            client.wait_for_block(target, Duration::from_secs(10)).await
+        })
+    };
+    client.dev_create_block().await.unwrap();
+    let block_hash = client.dev_create_block().await.unwrap();
+    let header = waiter.await.unwrap();
+    assert_eq!(header.number, target);
+    assert_eq!(header.hash(), block_hash);
+
+    match client.wait_for_blocks(1, Duration::from_millis(10)).await {
+        Err(Error::BlockWaitTimeout { block_number }) => assert_eq!(block_number, target + 1),
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
@@ -14,7 +14,6 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use radicle_registry_client::*;
-use radicle_registry_runtime::registry::BLOCK_REWARD;
 use radicle_registry_test_utils::*;
 use sp_runtime::Permill;
 
@@ -22,9 +21,10 @@ use sp_runtime::Permill;
 #[async_std::test]
 async fn block_rewards_credited() {
     let (client, _) = Client::new_emulator();
+    let block_reward = client.chain_constants().await.unwrap().block_reward;
 
     let alice = key_pair_with_funds(&client).await;
-    let bob = ed25519::Pair::generate().0.public();
+    let bob = random_key_pair().public();
     let author_balance = client.free_balance(&EMULATOR_BLOCK_AUTHOR).await.unwrap();
 
     let fee = 3000;
@@ -41,5 +41,5 @@ async fn block_rewards_credited() {
 
     let rewards = client.free_balance(&EMULATOR_BLOCK_AUTHOR).await.unwrap() - author_balance;
     let fee_reward = Permill::from_percent(99) * fee;
-    assert_eq!(rewards, fee_reward + BLOCK_REWARD);
+    assert_eq!(rewards, fee_reward + block_reward);
 }
@@ -0,0 +1,69 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test [Client::subscribe_registry_events] and [Client::subscribe_finalized_blocks].
+use futures::TryStreamExt as _;
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+use sp_runtime::traits::Header as _;
+
+/// Assert that the registry events of new blocks are emitted by
+/// [Client::subscribe_registry_events].
+#[async_std::test]
+async fn subscribe_registry_events() {
+    let (client, _) = Client::new_emulator();
+    let block_reward = client.chain_constants().await.unwrap().block_reward;
+    let donator = key_pair_with_funds(&client).await;
+    let mut registry_events = client.subscribe_registry_events().await.unwrap();
+
+    transfer(&client, &donator, random_key_pair().public(), 1000).await;
+
+    let fee_paid = registry_events.try_next().await.unwrap().unwrap();
+    assert_eq!(fee_paid, event::Registry::FeePaid(donator.public(), 1));
+    let reward = registry_events.try_next().await.unwrap().unwrap();
+    assert_eq!(
+        reward,
+        event::Registry::BlockRewardCredited(EMULATOR_BLOCK_AUTHOR, block_reward)
+    );
+}
+
+/// Assert that [Client::subscribe_finalized_blocks] emits the headers of new blocks in order
+/// and that their events can be fetched with [Client::block_event_records].
+#[async_std::test]
+async fn subscribe_finalized_blocks() {
+    let (client, _) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let tip = client.block_header_best_chain().await.unwrap();
+    let mut finalized_blocks = client.subscribe_finalized_blocks().await.unwrap();
+
+    let tx_included = submit_ok_with_fee(
+        &client,
+        &donator,
+        message::Transfer {
+            recipient: random_key_pair().public(),
+            amount: 1000,
+        },
+        1,
+    )
+    .await;
+
+    let header = finalized_blocks.try_next().await.unwrap().unwrap();
+    assert_eq!(header.number, tip.number + 1);
+    assert_eq!(header.hash(), tx_included.block);
+
+    let events = client.block_event_records(header.hash()).await.unwrap();
+    assert!(events.iter().any(|record| event::registry_event(record)
+        == Some(&event::Registry::FeePaid(donator.public(), 1))));
+}
@@ -0,0 +1,426 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime tests implemented with [MemoryClient].
+//!
+//! High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+//!
+//! The tests in this module check for every message that the transaction fee is debited from the
+//! right payer and that no other balances change except for the intended transfers.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Balance changes a transaction is expected to cause besides the fee payment.
+struct Expected {
+    /// Account that pays the transaction fee.
+    payer: AccountId,
+
+    /// Whether the author is charged the registration fee.
+    registration_fee: bool,
+
+    /// Funds moved by the transaction as `(from, to, amount)`.
+    transfers: Vec<(AccountId, AccountId, Balance)>,
+}
+
+impl Expected {
+    fn paid_by(payer: AccountId) -> Self {
+        Expected {
+            payer,
+            registration_fee: false,
+            transfers: Vec::new(),
+        }
+    }
+
+    fn with_registration_fee(mut self) -> Self {
+        self.registration_fee = true;
+        self
+    }
+
+    fn with_transfer(mut self, from: AccountId, to: AccountId, amount: Balance) -> Self {
+        self.transfers.push((from, to, amount));
+        self
+    }
+}
+
+/// Submit `message` and assert that the balance of every account in `accounts`, the author, the
+/// payer, the transfer parties and the block author changed exactly as described by `expected`.
+///
+/// Also asserts that [ClientT::estimate_fee] agrees on the payer and the registration fee.
+async fn assert_fee_accounting<Message_: Message + Clone>(
+    client: &Client,
+    accounts: &[AccountId],
+    author: &ed25519::Pair,
+    message: Message_,
+    expected: Expected,
+) -> TransactionIncluded {
+    let constants = client.chain_constants().await.unwrap();
+    let estimate = client
+        .estimate_fee(&author.public(), message.clone())
+        .await
+        .unwrap();
+    assert_eq!(estimate.payer, expected.payer, "Unexpected estimated payer");
+    assert_eq!(
+        estimate.registration_fee > 0,
+        expected.registration_fee,
+        "Unexpected estimated registration fee"
+    );
+
+    let mut tracked = accounts.to_vec();
+    tracked.extend(&[author.public(), expected.payer, EMULATOR_BLOCK_AUTHOR]);
+    for (from, to, _) in &expected.transfers {
+        tracked.extend(&[*from, *to]);
+    }
+    tracked.sort();
+    tracked.dedup();
+
+    let balances_before = balances(client, &tracked).await;
+    let fee = random_balance();
+    let tx_included = submit_ok_with_fee(client, author, message, fee).await;
+    let balances_after = balances(client, &tracked).await;
+
+    for ((account, before), after) in tracked.iter().zip(balances_before).zip(balances_after) {
+        let mut change: i128 = 0;
+        if *account == expected.payer {
+            change -= fee as i128;
+        }
+        if *account == author.public() && expected.registration_fee {
+            change -= constants.registration_fee as i128;
+        }
+        if *account == EMULATOR_BLOCK_AUTHOR {
+            let fee_reward = fee - constants.fee_burn_share * fee;
+            change += (constants.block_reward + fee_reward) as i128;
+        }
+        for (from, to, amount) in &expected.transfers {
+            if account == from {
+                change -= *amount as i128;
+            }
+            if account == to {
+                change += *amount as i128;
+            }
+        }
+        assert_eq!(
+            after as i128 - before as i128,
+            change,
+            "Unexpected balance change of account {}",
+            account
+        );
+    }
+
+    tx_included
+}
+
+async fn balances(client: &Client, accounts: &[AccountId]) -> Vec<Balance> {
+    let mut balances = Vec::new();
+    for account in accounts {
+        balances.push(client.free_balance(account).await.unwrap());
+    }
+    balances
+}
+
+async fn org_account(client: &Client, org_id: Id) -> AccountId {
+    client.get_org(org_id).await.unwrap().unwrap().account_id()
+}
+
+#[async_std::test]
+async fn user_and_org_registration() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let accounts = [treasury_account(), root_key_pair().public()];
+
+    let register_user = random_register_user_message();
+    let user_id = register_user.user_id.clone();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &author,
+        register_user,
+        Expected::paid_by(author.public()).with_registration_fee(),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let register_org = random_register_org_message();
+    let org_id = register_org.org_id.clone();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &author,
+        register_org,
+        Expected::paid_by(author.public()).with_registration_fee(),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &author,
+        message::UnregisterOrg { org_id },
+        Expected::paid_by(author.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &author,
+        message::UnregisterUser { user_id },
+        Expected::paid_by(author.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+/// Org transactions authored by a member are paid by the org, those authored by anybody else are
+/// paid by the author.
+#[async_std::test]
+async fn org_transactions() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .user("carol")
+        .org("monadic", &["alice"])
+        .org("dissolved", &["alice"])
+        .apply(&client)
+        .await;
+    let alice = &world.user("alice").key_pair;
+    let bob = &world.user("bob").key_pair;
+    let org_id = world.org_id("monadic");
+    let org_account = org_account(&client, org_id.clone()).await;
+    let dissolved_org_account = org_account(&client, world.org_id("dissolved")).await;
+    let accounts = [
+        world.user("carol").key_pair.public(),
+        org_account,
+        dissolved_org_account,
+        treasury_account(),
+    ];
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        alice,
+        message::RegisterMember {
+            org_id: org_id.clone(),
+            user_id: world.user("carol").id.clone(),
+        },
+        Expected::paid_by(org_account),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let register_project = random_register_project_message(&ProjectDomain::Org(org_id.clone()));
+    let project_name = register_project.project_name.clone();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        alice,
+        register_project,
+        Expected::paid_by(org_account),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        alice,
+        message::UnregisterProject {
+            project_name,
+            project_domain: ProjectDomain::Org(org_id.clone()),
+        },
+        Expected::paid_by(org_account),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        alice,
+        message::TransferFromOrg {
+            org_id: org_id.clone(),
+            recipient: bob.public(),
+            amount: 100,
+            allow_death: false,
+        },
+        Expected::paid_by(org_account).with_transfer(org_account, bob.public(), 100),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    // Bob is not a member of the org and pays for the failing transactions.
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        bob,
+        random_register_project_message(&ProjectDomain::Org(org_id.clone())),
+        Expected::paid_by(bob.public()),
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        bob,
+        message::TransferFromOrg {
+            org_id,
+            recipient: bob.public(),
+            amount: 100,
+            allow_death: false,
+        },
+        Expected::paid_by(bob.public()),
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+
+    let dissolved_org_funds = client.free_balance(&dissolved_org_account).await.unwrap();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        alice,
+        message::DissolveOrg {
+            org_id: world.org_id("dissolved"),
+            recipient: bob.public(),
+        },
+        Expected::paid_by(alice.public()).with_transfer(
+            dissolved_org_account,
+            bob.public(),
+            dissolved_org_funds,
+        ),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn user_project_transactions() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let accounts = [treasury_account()];
+
+    let register_project = random_register_project_message(&ProjectDomain::User(alice.id.clone()));
+    let project_name = register_project.project_name.clone();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &alice.key_pair,
+        register_project,
+        Expected::paid_by(alice.key_pair.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &alice.key_pair,
+        message::TransferProjectOwnership {
+            project_name: project_name.clone(),
+            user_id: alice.id.clone(),
+            recipient: bob.id.clone(),
+        },
+        Expected::paid_by(alice.key_pair.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &bob.key_pair,
+        message::AcceptProjectOwnership {
+            project_name: project_name.clone(),
+            user_id: alice.id.clone(),
+        },
+        Expected::paid_by(bob.key_pair.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &bob.key_pair,
+        message::UnregisterProject {
+            project_name,
+            project_domain: ProjectDomain::User(bob.id.clone()),
+        },
+        Expected::paid_by(bob.key_pair.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn transfer_and_treasury_transactions() {
+    let (client, _) = Client::new_emulator();
+    let alice = key_pair_with_funds(&client).await;
+    let bob = key_pair_with_funds(&client).await;
+    let root = root_key_pair();
+    let accounts = [treasury_account()];
+
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &alice,
+        message::Transfer {
+            recipient: bob.public(),
+            amount: 1000,
+        },
+        Expected::paid_by(alice.public()).with_transfer(alice.public(), bob.public(), 1000),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    transfer(&client, &root, treasury_account(), 10_000).await;
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &alice,
+        message::ProposeTreasurySpend {
+            recipient: bob.public(),
+            amount: 500,
+        },
+        Expected::paid_by(alice.public()),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let proposal_id = *client
+        .list_treasury_proposals()
+        .await
+        .unwrap()
+        .last()
+        .unwrap();
+    let tx_included = assert_fee_accounting(
+        &client,
+        &accounts,
+        &root,
+        message::ApproveTreasurySpend { proposal_id },
+        Expected::paid_by(root.public()).with_transfer(treasury_account(), bob.public(), 500),
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
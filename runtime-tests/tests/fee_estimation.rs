@@ -0,0 +1,78 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern [ClientT::estimate_fee].
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Test that the estimate for registering a user includes the registration fee and matches the
+/// funds the author is actually charged.
+#[async_std::test]
+async fn estimate_user_registration() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let message = random_register_user_message();
+    let constants = client.chain_constants().await.unwrap();
+
+    let estimate = client
+        .estimate_fee(&author.public(), message.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        estimate,
+        FeeEstimate {
+            payer: author.public(),
+            tx_fee: constants.minimum_tx_fee,
+            registration_fee: constants.registration_fee,
+            existential_deposit: constants.existential_deposit,
+        }
+    );
+
+    let initial_balance = client.free_balance(&author.public()).await.unwrap();
+    let tx_included = submit_ok_with_fee(&client, &author, message, estimate.tx_fee).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_balance - estimate.tx_fee - estimate.registration_fee
+    );
+}
+
+/// Test that org transactions are estimated to be paid by the org only if the author is a member.
+#[async_std::test]
+async fn estimate_org_payer() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let org_id = world.org_id("monadic");
+    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let message = random_register_project_message(&ProjectDomain::Org(org_id));
+
+    let alice = world.user("alice").key_pair.public();
+    let estimate = client.estimate_fee(&alice, message.clone()).await.unwrap();
+    assert_eq!(estimate.payer, org.account_id());
+    assert_eq!(estimate.registration_fee, 0);
+
+    let bob = world.user("bob").key_pair.public();
+    let estimate = client.estimate_fee(&bob, message).await.unwrap();
+    assert_eq!(estimate.payer, bob);
+}
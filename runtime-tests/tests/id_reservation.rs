@@ -0,0 +1,190 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime tests implemented with [MemoryClient].
+//!
+//! High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+//!
+//! The tests in this module concern the reservation of org and user ids.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Reserve a random id for `holder` and return it.
+async fn reserve_random_id(client: &Client, holder: AccountId) -> Id {
+    let id = random_id();
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ReserveId {
+            id: id.clone(),
+            holder,
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::IdReserved(id.clone(), holder)));
+    id
+}
+
+#[async_std::test]
+async fn reserve_id() {
+    let (client, _) = Client::new_emulator();
+    let holder = random_key_pair().public();
+
+    let id = reserve_random_id(&client, holder).await;
+
+    let reservations = client.list_id_reservations().await.unwrap();
+    assert!(reservations.contains(&(id.clone(), holder)));
+    assert_eq!(client.get_id_status(&id).await.unwrap(), IdStatus::Reserved);
+}
+
+#[async_std::test]
+async fn reserve_id_requires_sudo() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let id = random_id();
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::ReserveId {
+            id: id.clone(),
+            holder: author.public(),
+        },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+    assert_eq!(
+        client.get_id_status(&id).await.unwrap(),
+        IdStatus::Available
+    );
+}
+
+#[async_std::test]
+async fn reserve_taken_id() {
+    let (client, _) = Client::new_emulator();
+    let (_, user_id) = key_pair_with_associated_user(&client).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ReserveId {
+            id: user_id,
+            holder: random_key_pair().public(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::IdAlreadyTaken.into())
+    );
+}
+
+/// Test that only the holder of a reservation can register a user with the reserved id and that
+/// the reservation is removed on registration.
+#[async_std::test]
+async fn register_user_with_reserved_id() {
+    let (client, _) = Client::new_emulator();
+    let holder = key_pair_with_funds(&client).await;
+    let other = key_pair_with_funds(&client).await;
+    let id = reserve_random_id(&client, holder.public()).await;
+    let register_user = message::RegisterUser {
+        user_id: id.clone(),
+        idempotency_key: None,
+    };
+
+    let tx_included = submit_ok(&client, &other, register_user.clone()).await;
+    assert_eq!(tx_included.result, Err(RegistryError::IdReserved.into()));
+    assert!(client.get_user(id.clone()).await.unwrap().is_none());
+
+    let tx_included = submit_ok(&client, &holder, register_user).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(client.get_user(id.clone()).await.unwrap().is_some());
+    assert!(!client
+        .list_id_reservations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|(reserved_id, _)| *reserved_id == id));
+}
+
+#[async_std::test]
+async fn register_org_with_reserved_id() {
+    let (client, _) = Client::new_emulator();
+    let (holder, _) = key_pair_with_associated_user(&client).await;
+    let (other, _) = key_pair_with_associated_user(&client).await;
+    let id = reserve_random_id(&client, holder.public()).await;
+    let register_org = message::RegisterOrg {
+        org_id: id.clone(),
+        idempotency_key: None,
+    };
+
+    let tx_included = submit_ok(&client, &other, register_org.clone()).await;
+    assert_eq!(tx_included.result, Err(RegistryError::IdReserved.into()));
+    assert!(client.get_org(id.clone()).await.unwrap().is_none());
+
+    let tx_included = submit_ok(&client, &holder, register_org).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(client.get_org(id).await.unwrap().is_some());
+}
+
+#[async_std::test]
+async fn cancel_id_reservation() {
+    let (client, _) = Client::new_emulator();
+    let other = key_pair_with_funds(&client).await;
+    let id = reserve_random_id(&client, random_key_pair().public()).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::CancelIdReservation { id: id.clone() },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(
+        client.get_id_status(&id).await.unwrap(),
+        IdStatus::Available
+    );
+
+    let tx_included = submit_ok(
+        &client,
+        &other,
+        message::RegisterUser {
+            user_id: id,
+            idempotency_key: None,
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn cancel_inexistent_id_reservation() {
+    let (client, _) = Client::new_emulator();
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::CancelIdReservation { id: random_id() },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentIdReservation.into())
+    );
+}
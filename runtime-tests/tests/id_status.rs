@@ -70,6 +70,28 @@ async fn test_retired_by_org() {
     assert_eq!(status, IdStatus::Retired);
 }
 
+/// Test that an Id is Retired once an org is dissolved
+#[async_std::test]
+async fn test_retired_by_dissolved_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::DissolveOrg {
+            org_id: org_id.clone(),
+            recipient: random_key_pair().public(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let status = client.get_id_status(&org_id).await.unwrap();
+    assert_eq!(status, IdStatus::Retired);
+}
+
 /// Test that an Id is Retired once unregistered by a user
 #[async_std::test]
 async fn test_retired_by_user() {
@@ -87,3 +109,36 @@ async fn test_retired_by_user() {
     let status = client.get_id_status(&user_id).await.unwrap();
     assert_eq!(status, IdStatus::Retired);
 }
+
+/// Test that [ClientT::id_available] reports the same status as [ClientT::get_id_status].
+#[async_std::test]
+async fn test_id_available() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    assert_eq!(
+        client.id_available(&user_id).await.unwrap(),
+        IdStatus::Taken
+    );
+    assert_eq!(client.id_available(&org_id).await.unwrap(), IdStatus::Taken);
+    assert_eq!(
+        client.id_available(&random_id()).await.unwrap(),
+        IdStatus::Available
+    );
+}
+
+/// Test that [ClientT::id_available] of a pinned client reports the status at the pinned block.
+#[async_std::test]
+async fn test_id_available_at_block() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let pinned = client.at_best_finalized().await.unwrap();
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    assert_eq!(client.id_available(&org_id).await.unwrap(), IdStatus::Taken);
+    assert_eq!(
+        pinned.id_available(&org_id).await.unwrap(),
+        IdStatus::Available
+    );
+}
@@ -0,0 +1,166 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern transferring user ids to other accounts and handing over org
+/// memberships.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Verify that a user keeps its id and projects when it is associated with another account and
+/// that only the new account controls the user.
+#[async_std::test]
+async fn transfer_user_id() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let new_key_pair = key_pair_with_funds(&client).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        message::TransferUserId {
+            new_account_id: new_key_pair.public(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert_eq!(user.account_id(), new_key_pair.public());
+    assert_eq!(user.projects(), &vec![world.project_id("radicle").0]);
+
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        message::TransferUserId {
+            new_account_id: alice.key_pair.public(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::AuthorHasNoAssociatedUser.into())
+    );
+
+    let tx_included = submit_ok(
+        &client,
+        &new_key_pair,
+        message::UpdateUser {
+            user_id: alice.id.clone(),
+            metadata: Bytes128::random(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+/// Verify that a user id cannot be transferred to an account that is associated with another
+/// user.
+#[async_std::test]
+async fn transfer_user_id_to_associated_account() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        message::TransferUserId {
+            new_account_id: bob.key_pair.public(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::UserAccountAssociated.into())
+    );
+
+    let user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert_eq!(user.account_id(), alice.key_pair.public());
+}
+
+/// Verify that a member can hand over their membership in an org to another user.
+#[async_std::test]
+async fn transfer_org_membership() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let org_id = world.org_id("monadic");
+
+    let transfer = message::TransferOrgMembership {
+        org_id: org_id.clone(),
+        recipient: bob.id.clone(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, transfer.clone()).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    assert_eq!(org.members(), &vec![bob.id.clone()]);
+
+    let tx_included = submit_ok(&client, &alice.key_pair, transfer).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+}
+
+/// Verify that a membership cannot be handed over to a user that is already a member.
+#[async_std::test]
+async fn transfer_org_membership_to_member() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice", "bob"])
+        .apply(&client)
+        .await;
+    let org_id = world.org_id("monadic");
+
+    let tx_included = submit_ok(
+        &client,
+        &world.user("alice").key_pair,
+        message::TransferOrgMembership {
+            org_id: org_id.clone(),
+            recipient: world.user("bob").id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::AlreadyAMember.into())
+    );
+
+    let org = client.get_org(org_id).await.unwrap().unwrap();
+    assert_eq!(org.members().len(), 2);
+}
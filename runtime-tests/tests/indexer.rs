@@ -0,0 +1,97 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test the [indexer] with [indexer::JsonLinesSink].
+
+use std::path::PathBuf;
+
+use radicle_registry_client::{indexer::*, *};
+use radicle_registry_test_utils::*;
+use sp_runtime::traits::Header as _;
+
+fn temp_path() -> PathBuf {
+    std::env::temp_dir().join(format!("registry-indexer-{}.jsonl", rand::random::<u64>()))
+}
+
+fn read_blocks(path: &PathBuf) -> Vec<serde_json::Value> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[async_std::test]
+async fn index_from_genesis() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let best_number = client.block_header_best_chain().await.unwrap().number;
+
+    let path = temp_path();
+    let mut indexer = Indexer::new(client.clone(), JsonLinesSink::open(&path).unwrap());
+    assert_eq!(indexer.sync().await.unwrap(), best_number + 1);
+    assert_eq!(indexer.sync().await.unwrap(), 0);
+
+    let blocks = read_blocks(&path);
+    assert_eq!(blocks.len() as u32, best_number + 1);
+    for (number, block) in blocks.iter().enumerate() {
+        assert_eq!(block["number"], number);
+    }
+    let org_change = blocks
+        .iter()
+        .flat_map(|block| block["stateChanges"].as_array().unwrap().clone())
+        .find(|change| change["org"] == serde_json::json!(org_id))
+        .unwrap();
+    assert_eq!(
+        org_change["state"]["members"],
+        serde_json::json!(client.get_org(org_id).await.unwrap().unwrap().members())
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+/// Assert that blocks that are not on the finalized chain are replaced when the indexer syncs.
+#[async_std::test]
+async fn replace_retracted_blocks() {
+    let (client, emulator) = Client::new_emulator();
+    emulator.add_blocks(3);
+
+    let path = temp_path();
+    let mut indexer = Indexer::new(client.clone(), JsonLinesSink::open(&path).unwrap());
+    indexer.sync().await.unwrap();
+    drop(indexer);
+
+    // Replace blocks 2 and 3 with a fork.
+    let mut lines = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    lines.truncate(2);
+    for number in 2..4 {
+        lines.push(serde_json::json!({ "number": number, "hash": H256::zero() }).to_string());
+    }
+    std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+    let mut indexer = Indexer::new(client.clone(), JsonLinesSink::open(&path).unwrap());
+    assert_eq!(indexer.sync().await.unwrap(), 2);
+
+    let blocks = read_blocks(&path);
+    assert_eq!(blocks.len(), 4);
+    let best_hash = client.block_header_best_chain().await.unwrap().hash();
+    assert_eq!(blocks[3]["hash"], serde_json::json!(best_hash));
+    assert_eq!(blocks[3]["parentHash"], blocks[2]["hash"]);
+    std::fs::remove_file(path).unwrap();
+}
@@ -24,27 +24,23 @@ use radicle_registry_test_utils::*;
 #[async_std::test]
 async fn register_member() {
     let (client, _) = Client::new_emulator();
-    let (author, author_id) = key_pair_with_associated_user(&client).await;
-    let (_, member_user_id) = key_pair_with_associated_user(&client).await;
-
-    let register_org = random_register_org_message();
-    submit_ok(&client, &author, register_org.clone()).await;
-
-    // The org needs funds to submit transactions.
-    let org = client
-        .get_org(register_org.org_id.clone())
-        .await
-        .unwrap()
-        .unwrap();
-    let initial_balance = 1000;
-    transfer(&client, &author, org.account_id(), initial_balance).await;
+    let world = Scenario::new()
+        .user("author")
+        .user("member")
+        .org("org", &["author"])
+        .apply(&client)
+        .await;
+    let author = &world.user("author").key_pair;
+    let author_id = world.user("author").id.clone();
+    let member_user_id = world.user("member").id.clone();
+    let initial_balance = ORG_FUNDS;
 
     let random_fee = random_balance();
     let message = message::RegisterMember {
-        org_id: register_org.org_id.clone(),
+        org_id: world.org_id("org"),
         user_id: member_user_id,
     };
-    let tx_included = submit_ok_with_fee(&client, &author, message.clone(), random_fee).await;
+    let tx_included = submit_ok_with_fee(&client, author, message.clone(), random_fee).await;
     assert_eq!(tx_included.result, Ok(()));
 
     // Fetch the org again
@@ -105,6 +101,7 @@ async fn register_member_with_bad_actor() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        idempotency_key: None,
     };
     submit_ok(&client, &good_actor, register_org.clone()).await;
 
@@ -149,6 +146,7 @@ async fn register_duplicate_member() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        idempotency_key: None,
     };
     submit_ok(&client, &author, register_org.clone()).await;
 
@@ -199,6 +197,7 @@ async fn register_nonexistent_user() {
     let org_id = random_id();
     let register_org = message::RegisterOrg {
         org_id: org_id.clone(),
+        idempotency_key: None,
     };
     submit_ok(&client, &author, register_org.clone()).await;
 
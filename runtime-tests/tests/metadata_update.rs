@@ -0,0 +1,129 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern updating the metadata of orgs and users.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Verify that any member of an org can set the metadata of the org.
+#[async_std::test]
+async fn update_org() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice", "bob"])
+        .apply(&client)
+        .await;
+    let org_id = world.org_id("monadic");
+    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    assert_eq!(org.metadata(), None);
+
+    let metadata = Bytes128::random();
+    let tx_included = submit_ok(
+        &client,
+        &world.user("bob").key_pair,
+        message::UpdateOrg {
+            org_id: org_id.clone(),
+            metadata: metadata.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let updated_org = client.get_org(org_id).await.unwrap().unwrap();
+    assert_eq!(updated_org.metadata(), Some(&metadata));
+    assert_eq!(updated_org.members(), org.members());
+    assert_eq!(updated_org.registered_at(), org.registered_at());
+}
+
+/// Verify that the metadata of an org cannot be set by a user that is not a member.
+#[async_std::test]
+async fn update_org_by_non_member() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("eve")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let org_id = world.org_id("monadic");
+
+    let tx_included = submit_ok(
+        &client,
+        &world.user("eve").key_pair,
+        message::UpdateOrg {
+            org_id: org_id.clone(),
+            metadata: Bytes128::random(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+
+    let org = client.get_org(org_id).await.unwrap().unwrap();
+    assert_eq!(org.metadata(), None);
+}
+
+/// Verify that a user can set their own metadata and that other users cannot.
+#[async_std::test]
+async fn update_user() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("eve")
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+
+    let tx_included = submit_ok(
+        &client,
+        &world.user("eve").key_pair,
+        message::UpdateUser {
+            user_id: alice.id.clone(),
+            metadata: Bytes128::random(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+    let user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert_eq!(user.metadata(), None);
+
+    let metadata = Bytes128::random();
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        message::UpdateUser {
+            user_id: alice.id.clone(),
+            metadata: metadata.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let updated_user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert_eq!(updated_user.metadata(), Some(&metadata));
+    assert_eq!(updated_user.projects(), user.projects());
+    assert_eq!(updated_user.registered_at(), user.registered_at());
+}
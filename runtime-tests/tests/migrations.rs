@@ -0,0 +1,77 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test the storage migrations of the registry by bringing the emulator storage into the format of
+//! an older runtime and enacting a runtime upgrade.
+use frame_support::storage::{StorageMap as _, StorageValue as _};
+use radicle_registry_client::*;
+use radicle_registry_runtime::registry::{migrations, store};
+use radicle_registry_test_utils::*;
+
+#[async_std::test]
+async fn migrate_from_initial_storage_version() {
+    let (client, emulator) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    // Remove all state that did not exist in the initial storage version.
+    emulator.execute_with(|| {
+        store::UsersByAccountId1::remove(author.public());
+        store::IdStatuses1::remove(&user_id);
+        store::IdStatuses1::remove(&org_id);
+        store::PalletStorageVersion::kill();
+        assert_eq!(
+            migrations::storage_version(),
+            migrations::INITIAL_STORAGE_VERSION
+        );
+    });
+    assert_eq!(
+        client.get_user_by_account(&author.public()).await.unwrap(),
+        None
+    );
+
+    emulator.upgrade_runtime();
+
+    let (indexed_user_id, _) = client
+        .get_user_by_account(&author.public())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(indexed_user_id, user_id);
+    assert_eq!(
+        client.get_id_status(&user_id).await.unwrap(),
+        IdStatus::Taken
+    );
+    assert_eq!(
+        client.get_id_status(&org_id).await.unwrap(),
+        IdStatus::Taken
+    );
+
+    let history = client.migration_history().await.unwrap();
+    assert_eq!(
+        history.last().map(|migration| migration.to_version()),
+        Some(migrations::STORAGE_VERSION)
+    );
+    emulator.execute_with(|| {
+        assert_eq!(
+            store::PalletStorageVersion::get(),
+            Some(migrations::STORAGE_VERSION)
+        )
+    });
+
+    // Running the migrations again does not change the history.
+    emulator.upgrade_runtime();
+    assert_eq!(client.migration_history().await.unwrap(), history);
+}
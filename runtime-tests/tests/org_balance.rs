@@ -0,0 +1,81 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test [Client::watch_org_treasury].
+use futures::TryStreamExt as _;
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that the org balance is watched and that alerts are emitted when the balance crosses
+/// the low water mark.
+#[async_std::test]
+async fn watch_org_treasury() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let org_id = world.org_id("monadic");
+    let low_water_mark = ORG_FUNDS + 500;
+    let mut updates = client
+        .watch_org_treasury(org_id.clone(), low_water_mark)
+        .await
+        .unwrap();
+
+    let update = updates.try_next().await.unwrap().unwrap();
+    assert_eq!(update.best_balance, ORG_FUNDS);
+    assert_eq!(update.alert, Some(OrgBalanceAlert::Low));
+
+    let funded = client
+        .fund_org(&alice.key_pair, org_id.clone(), 1000, random_balance())
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(funded.result, Ok(()));
+    let update = updates.try_next().await.unwrap().unwrap();
+    assert_eq!(update.best_balance, ORG_FUNDS + 1000);
+    assert_eq!(update.finalized_balance, ORG_FUNDS + 1000);
+    assert_eq!(update.alert, Some(OrgBalanceAlert::Recovered));
+
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        message::TransferFromOrg {
+            org_id,
+            recipient: random_key_pair().public(),
+            amount: 600,
+            allow_death: false,
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    let update = updates.try_next().await.unwrap().unwrap();
+    assert_eq!(update.alert, Some(OrgBalanceAlert::Low));
+}
+
+/// Assert that watching an org that does not exist fails.
+#[async_std::test]
+async fn watch_org_treasury_missing_org() {
+    let (client, _) = Client::new_emulator();
+    let org_id = random_id();
+    match client.watch_org_treasury(org_id.clone(), 0).await {
+        Err(Error::OrgMissing { org_id: missing }) => assert_eq!(missing, org_id),
+        _ => panic!("Expected Error::OrgMissing"),
+    }
+}
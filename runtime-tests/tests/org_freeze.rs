@@ -0,0 +1,193 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime tests implemented with [MemoryClient].
+//!
+//! High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+//!
+//! The tests in this module concern freezing and unfreezing orgs.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+fn transfer_from_org(org_id: &Id) -> message::TransferFromOrg {
+    message::TransferFromOrg {
+        org_id: org_id.clone(),
+        recipient: random_key_pair().public(),
+        amount: 10,
+        allow_death: false,
+    }
+}
+
+/// Verify that funds cannot be transferred from a frozen org and no projects can be registered
+/// under it until the org is unfrozen.
+#[async_std::test]
+async fn freeze_and_unfreeze_org() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let org_id = world.org_id("monadic");
+    let org_domain = ProjectDomain::Org(org_id.clone());
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::FreezeOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::OrgFrozen(org_id.clone())));
+    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    assert!(org.frozen());
+
+    let tx_included = submit_ok(&client, &alice.key_pair, transfer_from_org(&org_id)).await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgFrozen.into()));
+    let register_project = random_register_project_message(&org_domain);
+    let tx_included = submit_ok(&client, &alice.key_pair, register_project).await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgFrozen.into()));
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::UnfreezeOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::OrgUnfrozen(org_id.clone())));
+
+    let tx_included = submit_ok(&client, &alice.key_pair, transfer_from_org(&org_id)).await;
+    assert_eq!(tx_included.result, Ok(()));
+    let register_project = random_register_project_message(&org_domain);
+    let tx_included = submit_ok(&client, &alice.key_pair, register_project).await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn freeze_org_requires_sudo() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let org_id = world.org_id("monadic");
+
+    let tx_included = submit_ok(
+        &client,
+        &world.user("alice").key_pair,
+        message::FreezeOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+    let org = client.get_org(org_id).await.unwrap().unwrap();
+    assert!(!org.frozen());
+}
+
+#[async_std::test]
+async fn unfreeze_org_not_frozen() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::UnfreezeOrg {
+            org_id: world.org_id("monadic"),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgNotFrozen.into()));
+}
+
+/// Verify that a frozen org cannot be dissolved and that its members cannot move projects out of
+/// it.
+#[async_std::test]
+async fn frozen_org_keeps_funds_and_projects() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .project("radicle", Domain::Org("monadic"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let org_id = world.org_id("monadic");
+    let (project_name, org_domain) = world.project_id("radicle");
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::FreezeOrg {
+            org_id: org_id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let move_project = message::TransferProject {
+        project_name: project_name.clone(),
+        from: org_domain.clone(),
+        to: ProjectDomain::User(alice.id.clone()),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, move_project).await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgFrozen.into()));
+
+    let rename_project = message::RenameProject {
+        old_name: project_name.clone(),
+        new_name: random_project_name(),
+        domain: org_domain.clone(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, rename_project).await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgFrozen.into()));
+
+    let unregister_project = message::UnregisterProject {
+        project_name: project_name.clone(),
+        project_domain: org_domain,
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, unregister_project).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+    let balance = client.free_balance(&org.account_id()).await.unwrap();
+    let dissolve = message::DissolveOrg {
+        org_id: org_id.clone(),
+        recipient: alice.key_pair.public(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, dissolve).await;
+    assert_eq!(tx_included.result, Err(RegistryError::OrgFrozen.into()));
+    assert!(client.get_org(org_id).await.unwrap().is_some());
+    assert_eq!(
+        client.free_balance(&org.account_id()).await.unwrap(),
+        balance
+    );
+}
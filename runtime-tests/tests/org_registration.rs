@@ -24,6 +24,7 @@ use radicle_registry_test_utils::*;
 #[async_std::test]
 async fn register_org() {
     let (client, _) = Client::new_emulator();
+    let registration_fee = client.registration_fee().await.unwrap();
     let (author, user_id) = key_pair_with_associated_user(&client).await;
 
     let initial_balance = client.free_balance(&author.public()).await.unwrap();
@@ -45,10 +46,17 @@ async fn register_org() {
         .unwrap();
     assert_eq!(org.members(), &vec![user_id]);
     assert!(org.projects().is_empty());
+    let block_number = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap()
+        .number;
+    assert_eq!(org.registered_at(), Some(block_number));
 
     assert_eq!(
         client.free_balance(&author.public()).await.unwrap(),
-        initial_balance - random_fee - REGISTRATION_FEE,
+        initial_balance - random_fee - registration_fee,
         "The tx fee was not charged properly."
     );
 }
@@ -58,14 +66,15 @@ async fn register_org() {
 #[async_std::test]
 async fn register_user_with_insufficient_funds_for_registration_fee() {
     let (client, _) = Client::new_emulator();
+    let registration_fee = client.registration_fee().await.unwrap();
 
     let random_fee = random_balance();
     // Two times tx_fee AND registration fee, first for registering the associated
     // user and then for registering the org.
-    let total_required_funds = 2 * (random_fee + REGISTRATION_FEE);
+    let total_required_funds = 2 * (random_fee + registration_fee);
 
     let author = {
-        let key_pair = ed25519::Pair::generate().0;
+        let key_pair = random_key_pair();
         transfer(
             &client,
             &root_key_pair(),
@@ -101,7 +110,7 @@ async fn register_user_with_insufficient_funds_for_registration_fee() {
     // actually register the org.
     assert_eq!(
         client.free_balance(&author.public()).await.unwrap(),
-        initial_balance - random_fee - REGISTRATION_FEE - random_fee,
+        initial_balance - random_fee - registration_fee - random_fee,
         "The tx fee was not charged properly."
     );
 }
@@ -152,6 +161,58 @@ async fn register_with_id_taken_by_org() {
     );
 }
 
+/// Test that resubmitting an org registration with the same idempotency key fails with a
+/// dedicated error and does not charge the registration fee.
+#[async_std::test]
+async fn register_twice_with_idempotency_key() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+
+    let register_org_message = message::RegisterOrg {
+        idempotency_key: Some(random_idempotency_key()),
+        ..random_register_org_message()
+    };
+    let tx_included_once = submit_ok(&client, &author, register_org_message.clone()).await;
+    assert_eq!(tx_included_once.result, Ok(()));
+
+    let initial_balance = client.free_balance(&author.public()).await.unwrap();
+    let random_fee = random_balance();
+    let tx_included_twice =
+        submit_ok_with_fee(&client, &author, register_org_message, random_fee).await;
+    assert_eq!(
+        tx_included_twice.result,
+        Err(RegistryError::IdempotencyKeyUsed.into())
+    );
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_balance - random_fee,
+        "The registration fee must not be charged."
+    );
+}
+
+/// Test that an idempotency key used by one account can be used by another account.
+#[async_std::test]
+async fn register_with_idempotency_key_of_other_account() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (other, _) = key_pair_with_associated_user(&client).await;
+    let idempotency_key = Some(random_idempotency_key());
+
+    let register_org_message = message::RegisterOrg {
+        idempotency_key,
+        ..random_register_org_message()
+    };
+    let tx_included = submit_ok(&client, &author, register_org_message).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let register_org_message = message::RegisterOrg {
+        idempotency_key,
+        ..random_register_org_message()
+    };
+    let tx_included = submit_ok(&client, &other, register_org_message).await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
 /// Test that an org can not be registered with an id already taken by a user.
 #[async_std::test]
 async fn register_with_taken_user_id() {
@@ -161,11 +222,15 @@ async fn register_with_taken_user_id() {
 
     let register_user_message = message::RegisterUser {
         user_id: id.clone(),
+        idempotency_key: None,
     };
     let tx_included_user = submit_ok(&client, &author, register_user_message.clone()).await;
     assert_eq!(tx_included_user.result, Ok(()));
 
-    let register_org_message = message::RegisterOrg { org_id: id };
+    let register_org_message = message::RegisterOrg {
+        org_id: id,
+        idempotency_key: None,
+    };
     let tx_included_org = submit_ok(&client, &author, register_org_message.clone()).await;
     assert_eq!(
         tx_included_org.result,
@@ -208,7 +273,10 @@ async fn register_with_id_of_unregistered_user() {
     assert_eq!(tx_unregister_applied.result, Ok(()));
 
     // Try to register an Org with the unregistered user id
-    let register_org_message = message::RegisterOrg { org_id: user_id };
+    let register_org_message = message::RegisterOrg {
+        org_id: user_id,
+        idempotency_key: None,
+    };
     let tx_included = submit_ok(&client, &author, register_org_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
 }
@@ -329,6 +397,159 @@ async fn unregister_org_with_projects() {
     );
 }
 
+#[async_std::test]
+async fn dissolve_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let recipient = random_key_pair().public();
+    let org_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let initial_balance = client.free_balance(&author.public()).await.unwrap();
+
+    let random_fee = random_balance();
+    let tx_included = submit_ok_with_fee(
+        &client,
+        &author,
+        message::DissolveOrg {
+            org_id: org_id.clone(),
+            recipient,
+        },
+        random_fee,
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert!(
+        !org_exists(&client, org_id.clone()).await,
+        "The org was not expected to exist"
+    );
+    assert_eq!(client.free_balance(&org.account_id()).await.unwrap(), 0);
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), org_balance);
+    assert_eq!(
+        client.free_balance(&author.public()).await.unwrap(),
+        initial_balance - random_fee,
+        "The tx fee was not charged properly."
+    );
+}
+
+#[async_std::test]
+async fn dissolve_org_bad_actor() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let org_balance = client.free_balance(&org.account_id()).await.unwrap();
+
+    let (bad_actor, _) = key_pair_with_associated_user(&client).await;
+    let tx_included = submit_ok(
+        &client,
+        &bad_actor,
+        message::DissolveOrg {
+            org_id: org_id.clone(),
+            recipient: bad_actor.public(),
+        },
+    )
+    .await;
+
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::UnregisterableOrg.into())
+    );
+    assert!(
+        org_exists(&client, org_id.clone()).await,
+        "Org not found in orgs list"
+    );
+    assert_eq!(
+        client.free_balance(&org.account_id()).await.unwrap(),
+        org_balance
+    );
+}
+
+#[async_std::test]
+async fn dissolve_org_with_projects() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    create_project(&client, &author, &ProjectDomain::Org(org_id.clone())).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::DissolveOrg {
+            org_id: org_id.clone(),
+            recipient: author.public(),
+        },
+    )
+    .await;
+
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::UnregisterableOrg.into())
+    );
+    assert!(
+        org_exists(&client, org_id.clone()).await,
+        "Org not found in orgs list"
+    );
+}
+
+/// Verify that `list_orgs` returns the org ids in ascending order regardless of the order in
+/// which the orgs were registered.
+#[async_std::test]
+async fn list_orgs_sorted() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+
+    let mut org_ids = Vec::new();
+    for _ in 0..5 {
+        let (org_id, _) = register_random_org(&client, &author).await;
+        org_ids.push(org_id);
+    }
+    org_ids.sort();
+
+    assert_eq!(client.list_orgs().await.unwrap(), org_ids);
+}
+
+/// Verify that paging through `list_orgs_paged` yields every org exactly once.
+#[async_std::test]
+async fn list_orgs_paged() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    for _ in 0..5 {
+        register_random_org(&client, &author).await;
+    }
+
+    let mut org_ids = Vec::new();
+    let mut start_key = None;
+    loop {
+        let page = client.list_orgs_paged(start_key, 2).await.unwrap();
+        assert!(page.items.len() <= 2);
+        org_ids.extend(page.items);
+        match page.next_key {
+            Some(next_key) => start_key = Some(next_key),
+            None => break,
+        }
+    }
+    org_ids.sort();
+
+    assert_eq!(org_ids, client.list_orgs().await.unwrap());
+}
+
+/// Verify that `list_orgs_with_data` returns every org with the same data as `get_org`.
+#[async_std::test]
+async fn list_orgs_with_data() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    for _ in 0..3 {
+        register_random_org(&client, &author).await;
+    }
+
+    let orgs = client.list_orgs_with_data().await.unwrap();
+    let org_ids = orgs.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>();
+    assert_eq!(org_ids, client.list_orgs().await.unwrap());
+    for (org_id, org) in orgs {
+        assert_eq!(client.get_org(org_id).await.unwrap(), Some(org));
+    }
+}
+
 async fn org_exists(client: &Client, org_id: Id) -> bool {
     client
         .list_orgs()
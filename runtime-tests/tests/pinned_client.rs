@@ -0,0 +1,39 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test reading state at a block with [Client::at].
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that a client pinned to a block reads state at that block while the unpinned client
+/// reads the latest state.
+#[async_std::test]
+async fn pinned_client_reads_state_at_block() {
+    let (client, _) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    transfer(&client, &donator, recipient, 1000).await;
+
+    let pinned = client.at_best_finalized().await.unwrap();
+    let users_before = pinned.list_users().await.unwrap();
+    transfer(&client, &donator, recipient, 500).await;
+    let user_id = associate_key_pair_with_random_user(&client, &donator).await;
+
+    assert_eq!(pinned.free_balance(&recipient).await.unwrap(), 1000);
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 1500);
+    assert_eq!(pinned.list_users().await.unwrap(), users_before);
+    assert!(pinned.get_user(user_id.clone()).await.unwrap().is_none());
+    assert!(client.get_user(user_id).await.unwrap().is_some());
+}
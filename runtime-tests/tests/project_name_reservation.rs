@@ -0,0 +1,182 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime tests implemented with [MemoryClient].
+//!
+//! High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+//!
+//! The tests in this module concern the reservation of project names.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Reserve a random project name for `domain` and return it.
+async fn reserve_random_project_name(client: &Client, domain: &ProjectDomain) -> ProjectName {
+    let project_name = random_project_name();
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ReserveProjectName {
+            project_name: project_name.clone(),
+            domain: domain.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::ProjectNameReserved(
+            project_name.clone(),
+            domain.clone()
+        )));
+    project_name
+}
+
+#[async_std::test]
+async fn register_project_with_reserved_name() {
+    let (client, _) = Client::new_emulator();
+    let (holder, _) = key_pair_with_associated_user(&client).await;
+    let (holder_org_id, _) = register_random_org(&client, &holder).await;
+    let holder_domain = ProjectDomain::Org(holder_org_id);
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let project_name = reserve_random_project_name(&client, &holder_domain).await;
+
+    let reservations = client.list_project_name_reservations().await.unwrap();
+    assert!(reservations.contains(&(project_name.clone(), holder_domain.clone())));
+
+    let message = message::RegisterProject {
+        project_name: project_name.clone(),
+        ..random_register_project_message(&ProjectDomain::Org(org_id.clone()))
+    };
+    let tx_included = submit_ok(&client, &author, message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::ProjectNameReserved.into())
+    );
+    assert!(client
+        .get_project(project_name.clone(), ProjectDomain::Org(org_id))
+        .await
+        .unwrap()
+        .is_none());
+
+    let message = message::RegisterProject {
+        project_name: project_name.clone(),
+        ..random_register_project_message(&holder_domain)
+    };
+    let tx_included = submit_ok(&client, &holder, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+}
+
+#[async_std::test]
+async fn rename_project_to_reserved_name() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let user_id = associate_key_pair_with_random_user(&client, &author).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let domain = ProjectDomain::User(user_id);
+    let (old_name, _) = create_project(&client, &author, &domain).await;
+    let new_name = reserve_random_project_name(&client, &ProjectDomain::Org(org_id)).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::RenameProject {
+            old_name: old_name.clone(),
+            new_name,
+            domain: domain.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::ProjectNameReserved.into())
+    );
+    assert!(client
+        .get_project(old_name, domain)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[async_std::test]
+async fn cancel_project_name_reservation() {
+    let (client, _) = Client::new_emulator();
+    let (holder, _) = key_pair_with_associated_user(&client).await;
+    let (holder_org_id, _) = register_random_org(&client, &holder).await;
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let project_name =
+        reserve_random_project_name(&client, &ProjectDomain::Org(holder_org_id)).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::CancelProjectNameReservation {
+            project_name: project_name.clone(),
+        },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(!client
+        .list_project_name_reservations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|(name, _)| *name == project_name));
+
+    let message = message::RegisterProject {
+        project_name: project_name.clone(),
+        ..random_register_project_message(&ProjectDomain::Org(org_id))
+    };
+    let tx_included = submit_ok(&client, &author, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::CancelProjectNameReservation { project_name },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentProjectNameReservation.into())
+    );
+}
+
+#[async_std::test]
+async fn reserve_project_name_requires_sudo() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+    let project_name = random_project_name();
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::ReserveProjectName {
+            project_name: project_name.clone(),
+            domain: ProjectDomain::Org(org_id),
+        },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+    assert!(!client
+        .list_project_name_reservations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|(name, _)| *name == project_name));
+}
@@ -53,6 +53,13 @@ async fn register_project() {
             .unwrap()
             .unwrap();
         assert_eq!(project.metadata().clone(), message.metadata.clone());
+        let block_number = client
+            .block_header(tx_included.block)
+            .await
+            .unwrap()
+            .unwrap()
+            .number;
+        assert_eq!(project.registered_at(), Some(block_number));
 
         let has_project = client
             .list_projects()
@@ -82,6 +89,30 @@ async fn register_project() {
     }
 }
 
+/// Verify that `list_projects_for` returns the projects of an org or user ordered by name.
+#[async_std::test]
+async fn list_projects_for_domain() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let mut expected_projects = Vec::new();
+        for _ in 0..3 {
+            expected_projects.push(create_project(&client, &author, &domain).await);
+        }
+        expected_projects.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+
+        let projects = client.list_projects_for(domain.clone()).await.unwrap();
+        assert_eq!(projects, expected_projects);
+    }
+
+    let projects = client
+        .list_projects_for(ProjectDomain::Org(random_id()))
+        .await
+        .unwrap();
+    assert!(projects.is_empty());
+}
+
 // Verify that a project can not be registered under a domain that does not exist.
 #[async_std::test]
 async fn register_project_under_inexistent_domain() {
@@ -119,7 +150,7 @@ async fn re_register_project_same_domain_entity() {
             &client,
             &author,
             message::RegisterProject {
-                metadata: Bytes128::random(),
+                metadata: random_bytes128(),
                 ..message.clone()
             },
         )
@@ -0,0 +1,146 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern renaming projects.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Verify that a renamed org project is stored under the new name, that the project list of the
+/// org is updated and that the previous name is retired.
+#[async_std::test]
+async fn rename_project() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice", "bob"])
+        .project("radicle", Domain::Org("monadic"))
+        .apply(&client)
+        .await;
+    let (old_name, domain) = world.project_id("radicle");
+    let new_name = random_project_name();
+    let project = client
+        .get_project(old_name.clone(), domain.clone())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let message = message::RenameProject {
+        old_name: old_name.clone(),
+        new_name: new_name.clone(),
+        domain: domain.clone(),
+    };
+    let tx_included = submit_ok(&client, &world.user("bob").key_pair, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::ProjectRenamed(
+            old_name.clone(),
+            new_name.clone(),
+            domain.clone()
+        )));
+
+    assert_eq!(
+        client
+            .get_project(new_name.clone(), domain.clone())
+            .await
+            .unwrap(),
+        Some(project)
+    );
+    assert_eq!(
+        client
+            .get_project(old_name.clone(), domain.clone())
+            .await
+            .unwrap(),
+        None
+    );
+    let org = client
+        .get_org(world.org_id("monadic"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(org.projects(), &vec![new_name]);
+
+    let mut register_message = random_register_project_message(&domain);
+    register_message.project_name = old_name;
+    let tx_included = submit_ok(&client, &world.user("alice").key_pair, register_message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::ProjectIdRetired.into())
+    );
+}
+
+/// Verify that a project cannot be renamed to the name of another project in the same domain.
+#[async_std::test]
+async fn rename_project_duplicate() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .project("radicle", Domain::User("alice"))
+        .project("registry", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let (old_name, domain) = world.project_id("radicle");
+    let (new_name, _) = world.project_id("registry");
+
+    let message = message::RenameProject {
+        old_name: old_name.clone(),
+        new_name,
+        domain: domain.clone(),
+    };
+    let tx_included = submit_ok(&client, &world.user("alice").key_pair, message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::DuplicateProjectId.into())
+    );
+    assert!(client
+        .get_project(old_name, domain)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+/// Verify that only the user of a user domain can rename its projects.
+#[async_std::test]
+async fn rename_project_unauthorized() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let (old_name, domain) = world.project_id("radicle");
+
+    let message = message::RenameProject {
+        old_name: old_name.clone(),
+        new_name: random_project_name(),
+        domain: domain.clone(),
+    };
+    let tx_included = submit_ok(&client, &world.user("bob").key_pair, message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+    assert!(client
+        .get_project(old_name, domain)
+        .await
+        .unwrap()
+        .is_some());
+}
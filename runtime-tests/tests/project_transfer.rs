@@ -0,0 +1,333 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern transferring projects between users and orgs.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+fn transfer_message(project_id: &ProjectId, recipient: &Id) -> message::TransferProjectOwnership {
+    let (project_name, project_domain) = project_id.clone();
+    let user_id = match project_domain {
+        ProjectDomain::User(user_id) => user_id,
+        ProjectDomain::Org(_) => panic!("Project must be registered under a user"),
+    };
+    message::TransferProjectOwnership {
+        project_name,
+        user_id,
+        recipient: recipient.clone(),
+    }
+}
+
+/// Verify that a project is moved to the recipient once the recipient accepts the transfer.
+#[async_std::test]
+async fn transfer_project_ownership() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let (project_name, old_domain) = world.project_id("radicle");
+    let project = client
+        .get_project(project_name.clone(), old_domain.clone())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let offer = transfer_message(&world.project_id("radicle"), &bob.id);
+    let tx_included = submit_ok(&client, &alice.key_pair, offer).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(
+        client
+            .get_pending_project_transfer(project_name.clone(), alice.id.clone())
+            .await
+            .unwrap(),
+        Some(bob.id.clone())
+    );
+
+    let accept = message::AcceptProjectOwnership {
+        project_name: project_name.clone(),
+        user_id: alice.id.clone(),
+    };
+    let tx_included = submit_ok(&client, &bob.key_pair, accept).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let new_domain = ProjectDomain::User(bob.id.clone());
+    assert_eq!(
+        client
+            .get_project(project_name.clone(), new_domain)
+            .await
+            .unwrap(),
+        Some(project)
+    );
+    assert_eq!(
+        client
+            .get_project(project_name.clone(), old_domain)
+            .await
+            .unwrap(),
+        None
+    );
+    let alice_user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert!(alice_user.projects().is_empty());
+    let bob_user = client.get_user(bob.id.clone()).await.unwrap().unwrap();
+    assert_eq!(bob_user.projects(), &vec![project_name.clone()]);
+    assert_eq!(
+        client
+            .get_pending_project_transfer(project_name, alice.id.clone())
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// Verify that only the owner can offer a project and only the recipient can accept it.
+#[async_std::test]
+async fn transfer_project_ownership_bad_actor() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .user("eve")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let eve = world.user("eve");
+    let project_id = world.project_id("radicle");
+
+    let tx_included = submit_ok(
+        &client,
+        &eve.key_pair,
+        transfer_message(&project_id, &eve.id),
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+
+    submit_ok(
+        &client,
+        &alice.key_pair,
+        transfer_message(&project_id, &bob.id),
+    )
+    .await;
+    let accept = message::AcceptProjectOwnership {
+        project_name: project_id.0.clone(),
+        user_id: alice.id.clone(),
+    };
+    let tx_included = submit_ok(&client, &eve.key_pair, accept).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+    let project = client
+        .get_project(project_id.0.clone(), project_id.1.clone())
+        .await
+        .unwrap();
+    assert!(project.is_some());
+}
+
+/// Verify that accepting a transfer fails if no transfer is pending.
+#[async_std::test]
+async fn accept_project_ownership_without_transfer() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let (project_name, _) = world.project_id("radicle");
+
+    let accept = message::AcceptProjectOwnership {
+        project_name,
+        user_id: world.user("alice").id.clone(),
+    };
+    let tx_included = submit_ok(&client, &world.user("bob").key_pair, accept).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentProjectTransfer.into())
+    );
+}
+
+/// Verify that a project cannot be offered to a user that already has a project with the same
+/// name.
+#[async_std::test]
+async fn transfer_project_ownership_duplicate() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let (project_name, _) = world.project_id("radicle");
+
+    let mut register_message =
+        random_register_project_message(&ProjectDomain::User(bob.id.clone()));
+    register_message.project_name = project_name.clone();
+    submit_ok(&client, &bob.key_pair, register_message).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &alice.key_pair,
+        transfer_message(&world.project_id("radicle"), &bob.id),
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::DuplicateProjectId.into())
+    );
+}
+
+/// Verify that a project is moved from a user to an org of which the user is a member.
+#[async_std::test]
+async fn transfer_project_user_to_org() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let org_id = world.org_id("monadic");
+    let (project_name, from) = world.project_id("radicle");
+    let to = ProjectDomain::Org(org_id.clone());
+    let project = client
+        .get_project(project_name.clone(), from.clone())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let message = message::TransferProject {
+        project_name: project_name.clone(),
+        from: from.clone(),
+        to: to.clone(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included.events.contains(&event::Registry::ProjectMoved(
+        project_name.clone(),
+        from.clone(),
+        to.clone()
+    )));
+
+    assert_eq!(
+        client.get_project(project_name.clone(), to).await.unwrap(),
+        Some(project)
+    );
+    assert_eq!(
+        client
+            .get_project(project_name.clone(), from)
+            .await
+            .unwrap(),
+        None
+    );
+    let user = client.get_user(alice.id.clone()).await.unwrap().unwrap();
+    assert!(user.projects().is_empty());
+    let org = client.get_org(org_id).await.unwrap().unwrap();
+    assert_eq!(org.projects(), &vec![project_name]);
+}
+
+/// Verify that a project is moved between two orgs that share a member and that the previous
+/// project id is retired.
+#[async_std::test]
+async fn transfer_project_between_orgs() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .org("monadic", &["alice"])
+        .org("radicle", &["alice"])
+        .project("registry", Domain::Org("monadic"))
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let (project_name, from) = world.project_id("registry");
+    let to = ProjectDomain::Org(world.org_id("radicle"));
+
+    let message = message::TransferProject {
+        project_name: project_name.clone(),
+        from: from.clone(),
+        to: to.clone(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    let monadic = client
+        .get_org(world.org_id("monadic"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(monadic.projects().is_empty());
+    let radicle = client
+        .get_org(world.org_id("radicle"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(radicle.projects(), &vec![project_name.clone()]);
+
+    let mut register_message = random_register_project_message(&from);
+    register_message.project_name = project_name;
+    let tx_included = submit_ok(&client, &alice.key_pair, register_message).await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::ProjectIdRetired.into())
+    );
+}
+
+/// Verify that the author must be authorized in both the current and the new domain.
+#[async_std::test]
+async fn transfer_project_unauthorized() {
+    let (client, _) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["bob"])
+        .project("radicle", Domain::User("alice"))
+        .apply(&client)
+        .await;
+    let (project_name, from) = world.project_id("radicle");
+    let to = ProjectDomain::Org(world.org_id("monadic"));
+
+    for author in &["alice", "bob"] {
+        let message = message::TransferProject {
+            project_name: project_name.clone(),
+            from: from.clone(),
+            to: to.clone(),
+        };
+        let tx_included = submit_ok(&client, &world.user(author).key_pair, message).await;
+        assert_eq!(
+            tx_included.result,
+            Err(RegistryError::InsufficientSenderPermissions.into())
+        );
+    }
+    assert!(client
+        .get_project(project_name, from)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,124 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+/// Runtime tests implemented with [MemoryClient].
+///
+/// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+///
+/// The tests in this module concern project unregistration.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Verify that a project can be unregistered under a user and an org and that it cannot be
+/// registered again.
+#[async_std::test]
+async fn unregister_project() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let register_message = random_register_project_message(&domain);
+        submit_ok(&client, &author, register_message.clone()).await;
+
+        let tx_included = submit_ok(
+            &client,
+            &author,
+            message::UnregisterProject {
+                project_name: register_message.project_name.clone(),
+                project_domain: domain.clone(),
+            },
+        )
+        .await;
+        assert_eq!(tx_included.result, Ok(()));
+
+        let project = client
+            .get_project(register_message.project_name.clone(), domain.clone())
+            .await
+            .unwrap();
+        assert!(project.is_none());
+
+        let projects = match &domain {
+            ProjectDomain::Org(org_id) => {
+                let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
+                org.projects().clone()
+            }
+            ProjectDomain::User(user_id) => {
+                let user = client.get_user(user_id.clone()).await.unwrap().unwrap();
+                user.projects().clone()
+            }
+        };
+        assert!(projects.is_empty());
+
+        let re_registration = submit_ok(&client, &author, register_message).await;
+        assert_eq!(
+            re_registration.result,
+            Err(RegistryError::ProjectIdRetired.into())
+        );
+    }
+}
+
+/// Verify that a project cannot be unregistered by an author that does not own the project.
+#[async_std::test]
+async fn unregister_project_bad_actor() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let (bad_actor, _) = key_pair_with_associated_user(&client).await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let (project_name, _) = create_project(&client, &author, &domain).await;
+
+        let tx_included = submit_ok(
+            &client,
+            &bad_actor,
+            message::UnregisterProject {
+                project_name: project_name.clone(),
+                project_domain: domain.clone(),
+            },
+        )
+        .await;
+        assert_eq!(
+            tx_included.result,
+            Err(RegistryError::InsufficientSenderPermissions.into())
+        );
+
+        let project = client
+            .get_project(project_name, domain.clone())
+            .await
+            .unwrap();
+        assert!(project.is_some());
+    }
+}
+
+#[async_std::test]
+async fn unregister_inexistent_project() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+
+    for domain in generate_project_domains(&client, &author).await {
+        let tx_included = submit_ok(
+            &client,
+            &author,
+            message::UnregisterProject {
+                project_name: random_project_name(),
+                project_domain: domain,
+            },
+        )
+        .await;
+        assert_eq!(
+            tx_included.result,
+            Err(RegistryError::InexistentProjectId.into())
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test the effect of reorgs created with [EmulatorControl::reorg].
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+use sp_runtime::traits::Header as _;
+
+/// Assert that a transfer is reverted when its block is removed by a reorg.
+#[async_std::test]
+async fn reorg_reverts_transfer() {
+    let (client, emulator) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    let mut notifications = emulator.block_notifications();
+
+    transfer(&client, &donator, recipient, 1000).await;
+    let transfer_block = client.block_header_best_chain().await.unwrap();
+    emulator.reorg(1, 2);
+
+    assert!(!client.account_exists(&recipient).await.unwrap());
+    assert_eq!(
+        client.block_header_best_chain().await.unwrap().number,
+        transfer_block.number + 1
+    );
+
+    let mut received = Vec::new();
+    while let Ok(Some(notification)) = notifications.try_next() {
+        received.push(notification);
+    }
+    assert_eq!(received.len(), 4);
+    assert_eq!(
+        received[0],
+        BlockNotification::Imported(transfer_block.clone())
+    );
+    assert_eq!(
+        received[1],
+        BlockNotification::Retracted(transfer_block.clone())
+    );
+    match &received[2] {
+        BlockNotification::Imported(header) => {
+            assert_eq!(header.number, transfer_block.number);
+            assert_ne!(header.hash(), transfer_block.hash());
+        }
+        other => panic!("Unexpected notification {:?}", other),
+    }
+}
+
+/// Assert that a reorg of the emulator reverts a transaction that was reported as finalized.
+#[async_std::test]
+async fn reorg_reverts_finalized_transfer() {
+    let (client, emulator) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+
+    let tx_included = submit_ok(
+        &client,
+        &donator,
+        message::Transfer {
+            recipient,
+            amount: 1000,
+        },
+    )
+    .await;
+    let finalized_hash = tx_included.wait_finalized().await.unwrap().block;
+    let finalized_block = client.block_header(finalized_hash).await.unwrap().unwrap();
+    emulator.reorg(1, 1);
+
+    let finalized = client.at_best_finalized().await.unwrap();
+    assert!(!finalized.account_exists(&recipient).await.unwrap());
+    let new_tip = client.block_header_best_chain().await.unwrap();
+    assert_eq!(new_tip.number, finalized_block.number);
+    assert_ne!(new_tip.hash(), finalized_block.hash());
+}
@@ -0,0 +1,74 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test [ClientT::subscribe_org] and [ClientT::subscribe_project].
+use futures::TryStreamExt as _;
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that changes of an org are emitted and that a reorg that removes a change emits the
+/// previous state again.
+#[async_std::test]
+async fn subscribe_org() {
+    let (client, emulator) = Client::new_emulator();
+    let world = Scenario::new()
+        .user("alice")
+        .user("bob")
+        .org("monadic", &["alice"])
+        .apply(&client)
+        .await;
+    let alice = world.user("alice");
+    let bob = world.user("bob");
+    let org_id = world.org_id("monadic");
+    let mut orgs = client.subscribe_org(org_id.clone()).await.unwrap();
+
+    let org = orgs.try_next().await.unwrap().unwrap().unwrap();
+    assert_eq!(org.members(), &vec![alice.id.clone()]);
+
+    let message = message::RegisterMember {
+        org_id,
+        user_id: bob.id.clone(),
+    };
+    let tx_included = submit_ok(&client, &alice.key_pair, message).await;
+    assert_eq!(tx_included.result, Ok(()));
+    let org = orgs.try_next().await.unwrap().unwrap().unwrap();
+    assert_eq!(org.members(), &vec![alice.id.clone(), bob.id.clone()]);
+
+    emulator.reorg(1, 1);
+    let org = orgs.try_next().await.unwrap().unwrap().unwrap();
+    assert_eq!(org.members(), &vec![alice.id.clone()]);
+}
+
+/// Assert that `None` is emitted for a project that does not exist and the project state once it
+/// is registered.
+#[async_std::test]
+async fn subscribe_project() {
+    let (client, _) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let domain = ProjectDomain::User(user_id);
+    let message = random_register_project_message(&domain);
+    let mut projects = client
+        .subscribe_project(message.project_name.clone(), domain)
+        .await
+        .unwrap();
+
+    assert_eq!(projects.try_next().await.unwrap(), Some(None));
+
+    let tx_included = submit_ok(&client, &author, message.clone()).await;
+    assert_eq!(tx_included.result, Ok(()));
+    let project = projects.try_next().await.unwrap().unwrap().unwrap();
+    assert_eq!(project.metadata(), &message.metadata);
+}
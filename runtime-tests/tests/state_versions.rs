@@ -0,0 +1,85 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test that the client reads orgs, users and projects that are stored in an older version of
+//! [state::Orgs1Data], [state::Users1Data] and [state::Projects1Data].
+use frame_support::storage::StorageMap as _;
+use futures::TryStreamExt as _;
+use radicle_registry_client::*;
+use radicle_registry_runtime::registry::store;
+use radicle_registry_test_utils::*;
+
+#[async_std::test]
+async fn read_oldest_state_versions() {
+    let (client, emulator) = Client::new_emulator();
+    let (author, user_id) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let project_domain = ProjectDomain::Org(org_id.clone());
+    let (project_name, project) = create_project(&client, &author, &project_domain).await;
+    let project_id = (project_name.clone(), project_domain.clone());
+
+    let org_v1 = state::Orgs1Data::V1(state::OrgV1 {
+        account_id: org.account_id(),
+        members: org.members().clone(),
+        projects: org.projects().clone(),
+    });
+    let user_v1 = state::Users1Data::V1(state::UserV1 {
+        account_id: author.public(),
+        projects: Vec::new(),
+    });
+    let project_v1 = state::Projects1Data::V1(state::ProjectV1 {
+        metadata: project.metadata().clone(),
+    });
+    emulator.execute_with(|| {
+        store::Orgs1::insert(&org_id, org_v1.clone());
+        store::Users1::insert(&user_id, user_v1.clone());
+        store::Projects1::insert(&project_id, project_v1.clone());
+    });
+
+    assert_eq!(client.get_org(org_id).await.unwrap(), Some(org_v1));
+    assert_eq!(client.get_user(user_id).await.unwrap(), Some(user_v1));
+    assert_eq!(
+        client
+            .get_project(project_name, project_domain)
+            .await
+            .unwrap(),
+        Some(project_v1)
+    );
+}
+
+/// Test that [ClientT::get_org], [ClientT::get_org_with_proof] and [ClientT::subscribe_org] decode
+/// an org stored in an older version in the same way.
+#[async_std::test]
+async fn org_reads_agree_on_oldest_state_version() {
+    let (client, emulator) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+
+    let org_v1 = state::Orgs1Data::V1(state::OrgV1 {
+        account_id: org.account_id(),
+        members: org.members().clone(),
+        projects: org.projects().clone(),
+    });
+    emulator.execute_with(|| store::Orgs1::insert(&org_id, org_v1.clone()));
+
+    assert_eq!(
+        client.get_org(org_id.clone()).await.unwrap(),
+        Some(org_v1.clone())
+    );
+    let read = client.get_org_with_proof(org_id.clone()).await.unwrap();
+    assert_eq!(read.value, Some(org_v1.clone()));
+    let mut orgs = client.subscribe_org(org_id).await.unwrap();
+    assert_eq!(orgs.try_next().await.unwrap(), Some(Some(org_v1)));
+}
@@ -0,0 +1,71 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test verifiable reads with [ClientT::get_org_with_proof] and similar methods.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that the proof of an org read verifies against the block header and that a tampered
+/// value or a wrong header is rejected.
+#[async_std::test]
+async fn org_with_proof() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, _) = register_random_org(&client, &author).await;
+
+    let read = client.get_org_with_proof(org_id.clone()).await.unwrap();
+    assert_eq!(read.value, client.get_org(org_id).await.unwrap());
+    assert!(read.value.is_some());
+    let header = client.block_header(read.block_hash).await.unwrap().unwrap();
+    read.verify(&header).unwrap();
+
+    let tampered = StorageReadProof {
+        value: None,
+        ..read.clone()
+    };
+    match tampered.verify(&header) {
+        Err(Error::StorageProofVerification { block_hash, .. }) => {
+            assert_eq!(block_hash, read.block_hash)
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    let parent = client
+        .block_header(header.parent_hash)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(read.verify(&parent).is_err());
+}
+
+/// Assert that the absence of a project can be proven.
+#[async_std::test]
+async fn missing_project_with_proof() {
+    let (client, _) = Client::new_emulator();
+    let (_, user_id) = key_pair_with_associated_user(&client).await;
+
+    let read = client
+        .get_project_with_proof(random_project_name(), ProjectDomain::User(user_id.clone()))
+        .await
+        .unwrap();
+    assert_eq!(read.value, None);
+    let header = client.block_header(read.block_hash).await.unwrap().unwrap();
+    read.verify(&header).unwrap();
+
+    let user_read = client.get_user_with_proof(user_id).await.unwrap();
+    assert!(user_read.value.is_some());
+    user_read.verify(&header).unwrap();
+}
@@ -0,0 +1,26 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test queries of the sudo module.
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that the sudo key of the emulator is the account of [root_key_pair].
+#[async_std::test]
+async fn sudo_key() {
+    let (client, _) = Client::new_emulator();
+    let sudo_key = client.sudo_key().await.unwrap();
+    assert_eq!(sudo_key, Some(root_key_pair().public()));
+}
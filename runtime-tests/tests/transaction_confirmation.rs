@@ -0,0 +1,60 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test waiting for transactions to be confirmed according to a [ConfirmationPolicy].
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Assert that a transaction is confirmed once its block has enough descendants.
+#[async_std::test]
+async fn confirmed_after_depth() {
+    let (client, emulator) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let policy = ConfirmationPolicy { depth: 2 };
+
+    let tx_included = submit_ok(&client, &author, random_register_org_message()).await;
+    emulator.add_blocks(1);
+    let confirmed = client.wait_for_confirmation(&tx_included, policy);
+    futures::pin_mut!(confirmed);
+    assert!(futures::poll!(&mut confirmed).is_pending());
+
+    emulator.add_blocks(1);
+    confirmed.await.unwrap();
+}
+
+/// Assert that waiting for the confirmation fails if the including block is removed from the best
+/// chain by a reorg.
+#[async_std::test]
+async fn retracted_by_reorg() {
+    let (client, emulator) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let policy = ConfirmationPolicy { depth: 3 };
+
+    let tx_included = submit_ok(&client, &author, random_register_org_message()).await;
+    emulator.add_blocks(1);
+    emulator.reorg(2, 4);
+
+    match client.wait_for_confirmation(&tx_included, policy).await {
+        Err(Error::TransactionRetracted {
+            tx_hash,
+            block_hash,
+        }) => {
+            assert_eq!(tx_hash, tx_included.tx_hash);
+            assert_eq!(block_hash, tx_included.block);
+        }
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
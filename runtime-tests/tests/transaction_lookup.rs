@@ -0,0 +1,96 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test looking up included transactions with [ClientT::get_transaction] and
+//! [ClientT::block_body].
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+#[async_std::test]
+async fn get_transaction() {
+    let (client, emulator) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let message = message::Transfer {
+        recipient: random_key_pair().public(),
+        amount: 1000,
+    };
+    let fee = random_balance();
+    let tx_included = submit_ok_with_fee(&client, &author, message.clone(), fee).await;
+    emulator.add_blocks(3);
+
+    for block_hash_hint in vec![Some(tx_included.block), None, Some(H256::zero())] {
+        let info = client
+            .get_transaction(tx_included.tx_hash, block_hash_hint)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.block, tx_included.block);
+        assert_eq!(info.author, author.public());
+        assert_eq!(info.fee, fee);
+        assert_eq!(info.message::<message::Transfer>(), Some(message.clone()));
+        assert_eq!(info.message::<message::RegisterOrg>(), None);
+        assert_eq!(info.result, Ok(()));
+        assert!(!info.events.is_empty());
+    }
+}
+
+#[async_std::test]
+async fn get_failed_transaction() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let message = message::RegisterMember {
+        org_id: random_id(),
+        user_id: random_id(),
+    };
+    let tx_included = submit_ok(&client, &author, message).await;
+
+    let info = client
+        .get_transaction(tx_included.tx_hash, Some(tx_included.block))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.result, tx_included.result);
+    assert!(info.result.is_err());
+}
+
+#[async_std::test]
+async fn get_unknown_transaction() {
+    let (client, _) = Client::new_emulator();
+    let info = client.get_transaction(H256::zero(), None).await.unwrap();
+    assert!(info.is_none());
+}
+
+#[async_std::test]
+async fn block_body() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let message = message::Transfer {
+        recipient: random_key_pair().public(),
+        amount: 1000,
+    };
+    let tx_included = submit_ok(&client, &author, message.clone()).await;
+
+    let transactions = client.block_body(tx_included.block).await.unwrap().unwrap();
+    assert_eq!(transactions.len(), 1);
+    let transaction = &transactions[0];
+    assert_eq!(transaction.tx_hash, tx_included.tx_hash);
+    assert_eq!(transaction.author, author.public());
+    assert_eq!(transaction.message::<message::Transfer>(), Some(message));
+    assert_eq!(transaction.call_name(), "Registry.transfer");
+    assert_eq!(transaction.result, Ok(()));
+
+    assert!(client.block_body(H256::zero()).await.unwrap().is_none());
+}
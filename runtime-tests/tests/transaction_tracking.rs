@@ -0,0 +1,114 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Test tracking transactions with [Client::track_transaction].
+
+use futures::StreamExt as _;
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+use sp_runtime::traits::Header as _;
+
+/// Create a signed transfer from `author` to a random recipient.
+async fn transfer_transaction(
+    client: &Client,
+    author: &ed25519::Pair,
+) -> Transaction<message::Transfer> {
+    Transaction::new_signed(
+        author,
+        message::Transfer {
+            recipient: random_key_pair().public(),
+            amount: 1000,
+        },
+        TransactionExtra {
+            nonce: client.account_nonce(&author.public()).await.unwrap(),
+            genesis_hash: client.genesis_hash(),
+            fee: random_balance(),
+            runtime_transaction_version: client
+                .runtime_version()
+                .await
+                .unwrap()
+                .transaction_version,
+        },
+    )
+    .await
+    .unwrap()
+}
+
+/// Assert that a tracked transaction is finalized once a block is added on top of its block.
+#[async_std::test]
+async fn track_until_finalized() {
+    let (client, emulator) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let transaction = transfer_transaction(&client, &author).await;
+
+    let mut statuses = client.track_transaction(transaction).await.unwrap();
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Pending
+    );
+    let block_hash = client.block_header_best_chain().await.unwrap().hash();
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::InBlock(block_hash)
+    );
+
+    emulator.add_blocks(1);
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Finalized(block_hash)
+    );
+    assert!(statuses.next().await.is_none());
+}
+
+/// Assert that a tracked transaction is submitted again when its block is removed by a reorg.
+#[async_std::test]
+async fn resubmit_after_reorg() {
+    let (client, emulator) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let transaction = transfer_transaction(&client, &author).await;
+
+    let mut statuses = client.track_transaction(transaction).await.unwrap();
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Pending
+    );
+    let retracted_block_hash = client.block_header_best_chain().await.unwrap().hash();
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::InBlock(retracted_block_hash)
+    );
+
+    emulator.reorg(1, 1);
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Retracted(retracted_block_hash)
+    );
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Pending
+    );
+    let block_hash = client.block_header_best_chain().await.unwrap().hash();
+    assert_ne!(block_hash, retracted_block_hash);
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::InBlock(block_hash)
+    );
+
+    emulator.add_blocks(1);
+    assert_eq!(
+        statuses.next().await.unwrap().unwrap(),
+        TrackedTransactionStatus::Finalized(block_hash)
+    );
+}
@@ -18,6 +18,7 @@
 /// High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
 ///
 /// The tests in this module concern transferring funds.
+use futures::TryStreamExt as _;
 use radicle_registry_client::*;
 use radicle_registry_test_utils::*;
 
@@ -46,7 +47,7 @@ async fn transfer_fail() {
 async fn transfer_any_amount() {
     let (client, _) = Client::new_emulator();
     let donator = key_pair_with_funds(&client).await;
-    let receipient = ed25519::Pair::generate().0.public();
+    let receipient = random_key_pair().public();
 
     for amount in (1..10000).step_by(500) {
         let tx_included = submit_ok(
@@ -74,7 +75,7 @@ async fn org_account_transfer() {
     let (client, _) = Client::new_emulator();
     let (author, _) = key_pair_with_associated_user(&client).await;
 
-    let bob = ed25519::Pair::generate().0.public();
+    let bob = random_key_pair().public();
     let (org_id, org) = register_random_org(&client, &author).await;
 
     let org_inigial_balance = client.free_balance(&org.account_id()).await.unwrap();
@@ -113,6 +114,7 @@ async fn org_account_transfer() {
             org_id,
             recipient: bob,
             amount: org_transfer_amount,
+            allow_death: false,
         },
         org_transfer_fee,
     )
@@ -124,6 +126,40 @@ async fn org_account_transfer() {
     );
 }
 
+/// Test that a transfer from an org account deposits an event with the remaining org balance.
+#[async_std::test]
+async fn org_account_transfer_event() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let recipient = random_key_pair().public();
+    let mut registry_events = client.subscribe_registry_events().await.unwrap();
+
+    submit_ok(
+        &client,
+        &author,
+        message::TransferFromOrg {
+            org_id: org_id.clone(),
+            recipient,
+            amount: 100,
+            allow_death: false,
+        },
+    )
+    .await;
+
+    let remaining_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let org_transferred = loop {
+        match registry_events.try_next().await.unwrap().unwrap() {
+            event @ event::Registry::OrgTransferred(..) => break event,
+            _ => continue,
+        }
+    };
+    assert_eq!(
+        org_transferred,
+        event::Registry::OrgTransferred(org_id, recipient, 100, remaining_balance)
+    );
+}
+
 #[async_std::test]
 /// Test that a transfer from an org account fails if the sender is not an org member.
 async fn org_account_transfer_non_member() {
@@ -133,7 +169,7 @@ async fn org_account_transfer_non_member() {
 
     let initial_balance = client.free_balance(&org.account_id()).await.unwrap();
 
-    let bad_actor = ed25519::Pair::generate().0;
+    let bad_actor = random_key_pair();
     // The bad actor needs funds to submit transactions.
     transfer(&client, &author, bad_actor.public(), 1000).await;
 
@@ -145,6 +181,7 @@ async fn org_account_transfer_non_member() {
             org_id,
             recipient: bad_actor.public(),
             amount: 1000,
+            allow_death: false,
         },
         random_fee,
     )
@@ -160,3 +197,214 @@ async fn org_account_transfer_non_member() {
         "The tx fee was not charged properly."
     );
 }
+
+/// Test that a transfer from an org account fails with [RegistryError::InsufficientOrgBalance]
+/// if it would drain the org account and `allow_death` is not set.
+#[async_std::test]
+async fn org_account_transfer_keep_alive() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let recipient = random_key_pair().public();
+
+    let initial_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let random_fee = random_balance();
+    let tx_included = submit_ok_with_fee(
+        &client,
+        &author,
+        message::TransferFromOrg {
+            org_id,
+            recipient,
+            amount: initial_balance - random_fee,
+            allow_death: false,
+        },
+        random_fee,
+    )
+    .await;
+
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientOrgBalance.into())
+    );
+    assert_eq!(
+        client.free_balance(&org.account_id()).await.unwrap(),
+        initial_balance - random_fee,
+    );
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 0);
+}
+
+/// Test that a transfer from an org account that exceeds its balance fails with
+/// [RegistryError::InsufficientOrgBalance] even if `allow_death` is set.
+#[async_std::test]
+async fn org_account_transfer_exceeding_balance() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+
+    let initial_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::TransferFromOrg {
+            org_id,
+            recipient: author.public(),
+            amount: initial_balance + 1,
+            allow_death: true,
+        },
+    )
+    .await;
+
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientOrgBalance.into())
+    );
+}
+
+/// Test that an org member can drain the org account when `allow_death` is set.
+#[async_std::test]
+async fn org_account_transfer_allow_death() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+    let recipient = random_key_pair().public();
+
+    let initial_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let random_fee = random_balance();
+    let amount = initial_balance - random_fee;
+    let tx_included = submit_ok_with_fee(
+        &client,
+        &author,
+        message::TransferFromOrg {
+            org_id,
+            recipient,
+            amount,
+            allow_death: true,
+        },
+        random_fee,
+    )
+    .await;
+
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(client.free_balance(&org.account_id()).await.unwrap(), 0);
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), amount);
+}
+
+/// Fund an org with [ClientT::fund_org] and check that the org account received the amount.
+#[async_std::test]
+async fn fund_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let (org_id, org) = register_random_org(&client, &author).await;
+
+    let initial_org_balance = client.free_balance(&org.account_id()).await.unwrap();
+    let amount = random_balance();
+    let fee = client.minimum_tx_fee().await.unwrap();
+    let tx_included = client
+        .fund_org(&author, org_id, amount, fee)
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert_eq!(
+        client.free_balance(&org.account_id()).await.unwrap(),
+        initial_org_balance + amount
+    );
+}
+
+/// Funding an org that does not exist fails without submitting a transaction.
+#[async_std::test]
+async fn fund_inexistent_org() {
+    let (client, _) = Client::new_emulator();
+    let (author, _) = key_pair_with_associated_user(&client).await;
+    let org_id = random_id();
+
+    let initial_nonce = client.account_nonce(&author.public()).await.unwrap();
+    let fee = client.minimum_tx_fee().await.unwrap();
+    match client.fund_org(&author, org_id.clone(), 10, fee).await {
+        Err(Error::OrgMissing { org_id: missing }) => assert_eq!(missing, org_id),
+        Err(error) => panic!("Unexpected error {}", error),
+        Ok(_) => panic!("Funding an inexistent org must fail"),
+    }
+    assert_eq!(
+        client.account_nonce(&author.public()).await.unwrap(),
+        initial_nonce
+    );
+}
+
+/// Assert that an account backed by an Sr25519 key pair can sign transactions.
+#[async_std::test]
+async fn sr25519_account_transfer() {
+    let (client, _) = Client::new_emulator();
+    let donator = key_pair_with_funds(&client).await;
+    let (author, _) = sr25519::Pair::generate();
+    let author_account_id = author.account_id();
+    transfer(&client, &donator, author_account_id, 1000).await;
+
+    let recipient = random_key_pair().public();
+    let fee = client.minimum_tx_fee().await.unwrap();
+    let tx_included = client
+        .sign_and_submit_message(
+            &author,
+            message::Transfer {
+                recipient,
+                amount: 100,
+            },
+            fee,
+        )
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 100);
+    assert_eq!(
+        client.free_balance(&author_account_id).await.unwrap(),
+        1000 - 100 - fee
+    );
+}
+
+/// Assert that an encoded transfer can be submitted with [ClientT::submit_raw] and that invalid
+/// bytes are rejected.
+#[async_std::test]
+async fn submit_raw_transfer() {
+    let (client, _) = Client::new_emulator();
+    let alice = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    let transaction = Transaction::new_signed(
+        &alice,
+        message::Transfer {
+            recipient,
+            amount: 1000,
+        },
+        TransactionExtra {
+            nonce: client.account_nonce(&alice.public()).await.unwrap(),
+            genesis_hash: client.genesis_hash(),
+            fee: random_balance(),
+            runtime_transaction_version: client
+                .runtime_version()
+                .await
+                .unwrap()
+                .transaction_version,
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx_included = client
+        .submit_raw(transaction.encode())
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(tx_included.result, Ok(()));
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 1000);
+
+    match client.submit_raw(vec![1, 2, 3]).await {
+        Err(Error::TransactionDecoding { .. }) => (),
+        Err(error) => panic!("Unexpected error {:?}", error),
+        Ok(_) => panic!("Invalid extrinsic was submitted"),
+    }
+}
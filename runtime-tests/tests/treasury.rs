@@ -0,0 +1,224 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime tests implemented with [MemoryClient].
+//!
+//! High-level runtime tests that only use [MemoryClient] and treat the runtime as a black box.
+//!
+//! The tests in this module concern treasury spend proposals.
+
+use radicle_registry_client::*;
+use radicle_registry_test_utils::*;
+
+/// Propose a treasury spend of `amount` and return the id of the proposal.
+async fn propose_spend(
+    client: &Client,
+    author: &ed25519::Pair,
+    recipient: AccountId,
+    amount: Balance,
+) -> TreasuryProposalId {
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::ProposeTreasurySpend { recipient, amount },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    *client
+        .list_treasury_proposals()
+        .await
+        .unwrap()
+        .last()
+        .unwrap()
+}
+
+#[async_std::test]
+async fn approve_treasury_spend() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    transfer(&client, &root_key_pair(), treasury_account(), 10_000).await;
+
+    let proposal_id = propose_spend(&client, &author, recipient, 1000).await;
+    let proposal = client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(proposal.proposer(), author.public());
+    assert_eq!(proposal.recipient(), recipient);
+    assert_eq!(proposal.amount(), 1000);
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ApproveTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+
+    assert_eq!(client.free_balance(&recipient).await.unwrap(), 1000);
+    assert_eq!(client.treasury_balance().await.unwrap(), 9000);
+    assert!(client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[async_std::test]
+async fn approve_treasury_spend_requires_sudo() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    transfer(&client, &root_key_pair(), treasury_account(), 10_000).await;
+    let proposal_id = propose_spend(&client, &author, recipient, 1000).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::ApproveTreasurySpend { proposal_id },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+
+    assert!(!client.account_exists(&recipient).await.unwrap());
+    assert!(client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[async_std::test]
+async fn approve_inexistent_treasury_proposal() {
+    let (client, _) = Client::new_emulator();
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ApproveTreasurySpend { proposal_id: 1234 },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentTreasuryProposal.into())
+    );
+}
+
+#[async_std::test]
+async fn approve_treasury_spend_insufficient_balance() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    let treasury_balance = client.treasury_balance().await.unwrap();
+    let proposal_id = propose_spend(&client, &author, recipient, treasury_balance + 1).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::ApproveTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientTreasuryBalance.into())
+    );
+    assert!(client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[async_std::test]
+async fn reject_treasury_spend() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    transfer(&client, &root_key_pair(), treasury_account(), 10_000).await;
+    let proposal_id = propose_spend(&client, &author, recipient, 1000).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::RejectTreasurySpend { proposal_id },
+    )
+    .await;
+    assert!(tx_included.result.is_err());
+
+    let tx_included = submit_ok(
+        &client,
+        &root_key_pair(),
+        message::RejectTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(tx_included
+        .events
+        .contains(&event::Registry::TreasurySpendRejected(proposal_id)));
+
+    assert!(!client.account_exists(&recipient).await.unwrap());
+    assert_eq!(client.treasury_balance().await.unwrap(), 10_000);
+    assert!(client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[async_std::test]
+async fn cancel_treasury_spend() {
+    let (client, _) = Client::new_emulator();
+    let author = key_pair_with_funds(&client).await;
+    let other = key_pair_with_funds(&client).await;
+    let recipient = random_key_pair().public();
+    let proposal_id = propose_spend(&client, &author, recipient, 1000).await;
+
+    let tx_included = submit_ok(
+        &client,
+        &other,
+        message::CancelTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InsufficientSenderPermissions.into())
+    );
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::CancelTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(tx_included.result, Ok(()));
+    assert!(client
+        .get_treasury_proposal(proposal_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let tx_included = submit_ok(
+        &client,
+        &author,
+        message::CancelTreasurySpend { proposal_id },
+    )
+    .await;
+    assert_eq!(
+        tx_included.result,
+        Err(RegistryError::InexistentTreasuryProposal.into())
+    );
+}
@@ -24,6 +24,7 @@ use radicle_registry_test_utils::*;
 #[async_std::test]
 async fn register_user() {
     let (client, _) = Client::new_emulator();
+    let registration_fee = client.registration_fee().await.unwrap();
     let alice = key_pair_with_funds(&client).await;
     let initial_balance = client.free_balance(&alice.public()).await.unwrap();
 
@@ -40,7 +41,7 @@ async fn register_user() {
 
     assert_eq!(
         client.free_balance(&alice.public()).await.unwrap(),
-        initial_balance - random_fee - REGISTRATION_FEE,
+        initial_balance - random_fee - registration_fee,
         "The tx fee was not charged properly."
     );
 
@@ -50,6 +51,13 @@ async fn register_user() {
         .unwrap()
         .unwrap();
     assert!(user.projects().is_empty());
+    let block_number = client
+        .block_header(tx_included.block)
+        .await
+        .unwrap()
+        .unwrap()
+        .number;
+    assert_eq!(user.registered_at(), Some(block_number));
 }
 
 /// Verify that it fails to register a user if the author has insufficient funds to
@@ -57,12 +65,13 @@ async fn register_user() {
 #[async_std::test]
 async fn register_user_with_insufficient_funds_for_registration_fee() {
     let (client, _) = Client::new_emulator();
+    let registration_fee = client.registration_fee().await.unwrap();
 
     let random_fee = random_balance();
-    let total_required_funds = random_fee + REGISTRATION_FEE;
+    let total_required_funds = random_fee + registration_fee;
 
     let author = {
-        let key_pair = ed25519::Pair::generate().0;
+        let key_pair = random_key_pair();
         transfer(
             &client,
             &root_key_pair(),
@@ -120,12 +129,18 @@ async fn register_with_id_taken_by_org() {
     let (author_x, _) = key_pair_with_associated_user(&client).await;
     let id = random_id();
 
-    let register_org_message = message::RegisterOrg { org_id: id.clone() };
+    let register_org_message = message::RegisterOrg {
+        org_id: id.clone(),
+        idempotency_key: None,
+    };
     let tx_included_org = submit_ok(&client, &author_x, register_org_message.clone()).await;
     assert_eq!(tx_included_org.result, Ok(()));
 
     let author_y = key_pair_with_funds(&client).await;
-    let register_user_message = message::RegisterUser { user_id: id };
+    let register_user_message = message::RegisterUser {
+        user_id: id,
+        idempotency_key: None,
+    };
     let tx_included_user = submit_ok(&client, &author_y, register_user_message.clone()).await;
     assert_eq!(
         tx_included_user.result,
@@ -167,6 +182,7 @@ async fn register_with_id_of_unregistered_user() {
     // Try to re-register User with the unregistered id
     let register_user_message = message::RegisterUser {
         user_id: user_id.clone(),
+        idempotency_key: None,
     };
     let tx_included = submit_ok(&client, &author, register_user_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
@@ -199,6 +215,7 @@ async fn register_with_id_of_unregistered_org() {
     // Try to register a user with the unregistered org id
     let register_user_message = message::RegisterUser {
         user_id: register_org_message.org_id.clone(),
+        idempotency_key: None,
     };
     let tx_included = submit_ok(&client, &author, register_user_message.clone()).await;
     assert_eq!(tx_included.result, Err(RegistryError::IdRetired.into()));
@@ -217,6 +234,12 @@ async fn unregister_user() {
         user_exists(&client, register_user_message.user_id.clone()).await,
         "User not found in users list"
     );
+    let (user_id, _) = client
+        .get_user_by_account(&alice.public())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user_id, register_user_message.user_id);
 
     // Unregistration.
     let initial_balance = client.free_balance(&alice.public()).await.unwrap();
@@ -232,6 +255,11 @@ async fn unregister_user() {
         !user_exists(&client, register_user_message.user_id.clone()).await,
         "The user was not expected to exist"
     );
+    assert!(client
+        .get_user_by_account(&alice.public())
+        .await
+        .unwrap()
+        .is_none());
     assert_eq!(
         client.free_balance(&alice.public()).await.unwrap(),
         initial_balance - random_fee,
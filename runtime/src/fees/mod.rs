@@ -17,16 +17,20 @@
 
 use crate::{AccountId, Balance, Call};
 
-use frame_support::dispatch::DispatchInfo;
+use frame_support::{dispatch::DispatchInfo, weights::Weight};
 use parity_scale_codec::{Decode, Encode};
 use sp_runtime::traits::SignedExtension;
 use sp_runtime::transaction_validity::{
-    InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    InvalidTransaction, TransactionLongevity, TransactionPriority, TransactionValidity,
+    TransactionValidityError, ValidTransaction,
 };
+use sp_runtime::Permill;
 
 mod payment;
 
-pub use payment::{pay_registration_fee, pay_tx_fee};
+pub use payment::{
+    charges_registration_fee, fee_burn, pay_registration_fee, pay_tx_fee, paying_org,
+};
 
 /// The minimum acceptable tx fee
 pub const MINIMUM_TX_FEE: Balance = 1;
@@ -34,9 +38,24 @@ pub const MINIMUM_TX_FEE: Balance = 1;
 /// The registration fee
 pub const REGISTRATION_FEE: Balance = 10;
 
+/// Share of every transaction fee that is burned. The rest is credited to the block author.
+pub const FEE_BURN_SHARE: Permill = Permill::from_percent(1);
+
+/// Number of blocks a valid transaction stays in the transaction pool before it is revalidated.
+pub const TRANSACTION_LONGEVITY: TransactionLongevity = 1024;
+
+/// Factor applied to the fee per unit of weight to obtain the [priority] of a transaction. It is
+/// large enough to distinguish fees of transactions with the weights of the registry
+/// dispatchables.
+const PRIORITY_PER_FEE_PER_WEIGHT: u128 = 1_000_000_000_000;
+
 /// Pay the transaction fee indicated by the author.
 /// The fee should be higher or equal to [MINIMUM_TX_FEE].
-/// The higher the fee, the higher the priority of a transaction.
+/// The higher the fee per weight of the call, the higher the [priority] of a transaction.
+///
+/// The `provides` and `requires` tags that order the transactions of an author by nonce are set by
+/// [frame_system::CheckNonce]. A transaction whose nonce is ahead of the account nonce stays in
+/// the pool until the transactions with the missing nonces arrive.
 #[derive(Debug, Encode, Decode, Clone, Eq, PartialEq)]
 pub struct PayTxFee {
     pub fee: Balance,
@@ -58,7 +77,7 @@ impl SignedExtension for PayTxFee {
         &self,
         author: &Self::AccountId,
         call: &Self::Call,
-        _info: &DispatchInfo,
+        info: &DispatchInfo,
         _len: usize,
     ) -> TransactionValidity {
         let error = TransactionValidityError::Invalid(InvalidTransaction::Payment);
@@ -67,8 +86,38 @@ impl SignedExtension for PayTxFee {
         }
         pay_tx_fee(author, self.fee, call).map_err(|_| error)?;
 
-        let mut valid_tx = ValidTransaction::default();
-        valid_tx.priority = self.fee as u64;
-        Ok(valid_tx)
+        Ok(ValidTransaction {
+            priority: priority(self.fee, info.weight),
+            longevity: TRANSACTION_LONGEVITY,
+            ..Default::default()
+        })
+    }
+}
+
+/// Priority of a transaction that pays `fee` for a call with the given weight.
+///
+/// The priority is proportional to the fee per unit of weight so that a heavy transaction with a
+/// low fee does not outrank light transactions with higher fees.
+pub fn priority(fee: Balance, weight: Weight) -> TransactionPriority {
+    let priority = fee.saturating_mul(PRIORITY_PER_FEE_PER_WEIGHT) / u128::from(weight.max(1));
+    priority.min(u128::from(TransactionPriority::max_value())) as TransactionPriority
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn priority_by_fee_per_weight() {
+        let light = crate::registry::weights::transfer();
+        let heavy = crate::registry::weights::register_org();
+        assert!(priority(10, light) > priority(10, heavy));
+        assert!(priority(10, light) > priority(15, heavy));
+        assert!(priority(2 * 10, 2 * light) == priority(10, light));
+        assert!(priority(MINIMUM_TX_FEE, heavy) > 0);
+        assert_eq!(
+            priority(Balance::max_value(), 0),
+            TransactionPriority::max_value()
+        );
     }
 }
@@ -21,13 +21,9 @@ use frame_support::storage::{StorageMap as _, StorageValue as _};
 use frame_support::traits::{
     Currency, ExistenceRequirement, Imbalance, WithdrawReason, WithdrawReasons,
 };
-use sp_runtime::Permill;
 
 type NegativeImbalance = <crate::runtime::Balances as Currency<AccountId>>::NegativeImbalance;
 
-/// Share of a transaction fee that is burned rather than credited to the block author.
-const BURN_SHARE: Permill = Permill::from_percent(1);
-
 pub fn pay_tx_fee(author: &AccountId, fee: Balance, call: &Call) -> Result<(), DispatchError> {
     let payer = payer_account(*author, call);
     let withdrawn_fee = withdraw(
@@ -35,7 +31,8 @@ pub fn pay_tx_fee(author: &AccountId, fee: Balance, call: &Call) -> Result<(), D
         &payer,
         WithdrawReason::TransactionPayment | WithdrawReason::Tip,
     )?;
-    let (burn, reward) = withdrawn_fee.split(BURN_SHARE * fee);
+    deposit_fee_paid_event(payer, fee);
+    let (burn, reward) = withdrawn_fee.split(fee_burn(fee));
     drop(burn);
 
     // The block author is only available when this function is run as part of the block execution.
@@ -48,12 +45,23 @@ pub fn pay_tx_fee(author: &AccountId, fee: Balance, call: &Call) -> Result<(), D
     Ok(())
 }
 
+/// Part of `fee` that is burned according to [super::FEE_BURN_SHARE].
+pub fn fee_burn(fee: Balance) -> Balance {
+    super::FEE_BURN_SHARE * fee
+}
+
 pub fn pay_registration_fee(author: &AccountId) -> Result<(), RegistryError> {
     let _burnt = withdraw(super::REGISTRATION_FEE, author, WithdrawReason::Fee.into())
         .map_err(|_| RegistryError::FailedRegistrationFeePayment)?;
+    deposit_fee_paid_event(*author, super::REGISTRATION_FEE);
     Ok(())
 }
 
+fn deposit_fee_paid_event(payer: AccountId, fee: Balance) {
+    let event = crate::registry::Event::FeePaid(payer, fee);
+    frame_system::Module::<crate::Runtime>::deposit_event(crate::Event::from(event));
+}
+
 fn withdraw(
     fee: Balance,
     payer: &AccountId,
@@ -71,22 +79,54 @@ fn withdraw(
 /// Authorize calls that involve another paying entity than the tx author.
 /// The tx author pays for all unauthorized calls.
 fn payer_account(author: AccountId, call: &Call) -> AccountId {
+    match paying_org(call) {
+        Some(org_id) => org_payer_account(author, org_id),
+        None => author,
+    }
+}
+
+/// Return the org whose account pays the transaction fee for `call` if the author is a member of
+/// the org. Returns `None` for calls that are always paid by the author.
+pub fn paying_org(call: &Call) -> Option<&Id> {
     match call {
         Call::Registry(registry_call) => match registry_call {
             // Transactions payed by the org
             call::Registry::register_project(m) => match &m.project_domain {
-                ProjectDomain::Org(org_id) => org_payer_account(author, org_id),
-                ProjectDomain::User(_user_id) => author,
+                ProjectDomain::Org(org_id) => Some(org_id),
+                ProjectDomain::User(_user_id) => None,
             },
-            call::Registry::transfer_from_org(m) => org_payer_account(author, &m.org_id),
-            call::Registry::register_member(m) => org_payer_account(author, &m.org_id),
+            call::Registry::unregister_project(m) => match &m.project_domain {
+                ProjectDomain::Org(org_id) => Some(org_id),
+                ProjectDomain::User(_user_id) => None,
+            },
+            call::Registry::transfer_from_org(m) => Some(&m.org_id),
+            call::Registry::register_member(m) => Some(&m.org_id),
+            call::Registry::update_org(m) => Some(&m.org_id),
+            call::Registry::transfer_org_membership(m) => Some(&m.org_id),
 
             // Transactions paid by the author
             call::Registry::register_org(_)
             | call::Registry::unregister_org(_)
+            | call::Registry::dissolve_org(_)
             | call::Registry::transfer(_)
             | call::Registry::register_user(_)
-            | call::Registry::unregister_user(_) => author,
+            | call::Registry::unregister_user(_)
+            | call::Registry::propose_treasury_spend(_)
+            | call::Registry::approve_treasury_spend(_)
+            | call::Registry::reject_treasury_spend(_)
+            | call::Registry::cancel_treasury_spend(_)
+            | call::Registry::reserve_id(_)
+            | call::Registry::cancel_id_reservation(_)
+            | call::Registry::reserve_project_name(_)
+            | call::Registry::cancel_project_name_reservation(_)
+            | call::Registry::freeze_org(_)
+            | call::Registry::unfreeze_org(_)
+            | call::Registry::transfer_project_ownership(_)
+            | call::Registry::accept_project_ownership(_)
+            | call::Registry::transfer_project(_)
+            | call::Registry::rename_project(_)
+            | call::Registry::update_user(_)
+            | call::Registry::transfer_user_id(_) => None,
 
             // Inherents
             call::Registry::set_block_author(_) => {
@@ -97,17 +137,27 @@ fn payer_account(author: AccountId, call: &Call) -> AccountId {
                 unreachable!("__PhantomItem should never be used.")
             }
         },
-        _ => author,
+        _ => None,
     }
 }
 
+/// Return true if the author pays the [super::REGISTRATION_FEE] when `call` is applied.
+pub fn charges_registration_fee(call: &Call) -> bool {
+    matches!(
+        call,
+        Call::Registry(call::Registry::register_org(_))
+            | Call::Registry(call::Registry::register_user(_))
+    )
+}
+
 /// Find which account should pay for an org-related call.
 /// When the User associated with `author` is a member of the org
 /// identified by `org_id`, return that org's account, otherwise the author's.
+/// The author always pays for calls concerning a frozen org.
 fn org_payer_account(author: AccountId, org_id: &Id) -> AccountId {
     match store::Orgs1::get(org_id) {
         Some(org) => {
-            if org_has_member_with_account(&org, author) {
+            if org_has_member_with_account(&org, author) && !org.frozen() {
                 org.account_id()
             } else {
                 author
@@ -120,24 +170,15 @@ fn org_payer_account(author: AccountId, org_id: &Id) -> AccountId {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{genesis::GenesisConfig, runtime::Balances};
+    use crate::{registry::genesis::new_test_ext, runtime::Balances};
 
     use core::convert::TryFrom;
     use frame_support::traits::Currency;
     use sp_core::{crypto::Pair, ed25519};
-    use sp_runtime::BuildStorage;
 
     #[test]
     fn test_pay_tx_fee() {
-        let genesis_config = GenesisConfig {
-            pallet_balances: None,
-            pallet_sudo: None,
-            system: None,
-        };
-
-        let mut test_ext = sp_io::TestExternalities::new(genesis_config.build_storage().unwrap());
-
-        test_ext.execute_with(move || {
+        new_test_ext().execute_with(move || {
             let block_author = ed25519::Pair::from_string("//Bob", None).unwrap().public();
             store::BlockAuthor::put(block_author);
 
@@ -148,6 +189,7 @@ mod test {
 
             let call = call::Registry::register_user(message::RegisterUser {
                 user_id: Id::try_from("alice").unwrap(),
+                idempotency_key: None,
             })
             .into();
             let fee = 1000;
@@ -160,4 +202,57 @@ mod test {
             assert_eq!(tx_author_balance, 2000)
         });
     }
+
+    #[test]
+    fn fee_burn_reduces_issuance() {
+        new_test_ext().execute_with(move || {
+            let block_author = ed25519::Pair::from_string("//Bob", None).unwrap().public();
+            store::BlockAuthor::put(block_author);
+
+            let tx_author = ed25519::Pair::from_string("//Alice", None)
+                .unwrap()
+                .public();
+            let _imbalance = Balances::deposit_creating(&tx_author, 30_000);
+            let issuance = Balances::total_issuance();
+
+            let call = call::Registry::transfer(message::Transfer {
+                recipient: block_author,
+                amount: 1,
+            })
+            .into();
+            let fee = 12_345;
+            pay_tx_fee(&tx_author, fee, &call).unwrap();
+
+            let burn = fee_burn(fee);
+            assert_eq!(burn, 123);
+            assert_eq!(Balances::total_issuance(), issuance - burn);
+            assert_eq!(Balances::free_balance(&block_author), fee - burn);
+        });
+    }
+
+    #[test]
+    fn frozen_org_does_not_pay_fees() {
+        new_test_ext().execute_with(move || {
+            let tx_author = ed25519::Pair::from_string("//Alice", None)
+                .unwrap()
+                .public();
+            let org_account = ed25519::Pair::from_string("//Org", None).unwrap().public();
+            let user_id = Id::try_from("alice").unwrap();
+            let org_id = Id::try_from("monadic").unwrap();
+            store::Users1::insert(&user_id, state::Users1Data::new(tx_author, Vec::new(), 0));
+            store::UsersByAccountId1::insert(tx_author, user_id.clone());
+            let org = state::Orgs1Data::new(org_account, vec![user_id], Vec::new(), 0);
+            store::Orgs1::insert(&org_id, org.clone());
+
+            let call = call::Registry::update_org(message::UpdateOrg {
+                org_id: org_id.clone(),
+                metadata: Bytes128::random(),
+            })
+            .into();
+            assert_eq!(payer_account(tx_author, &call), org_account);
+
+            store::Orgs1::insert(&org_id, org.set_frozen(true));
+            assert_eq!(payer_account(tx_author, &call), tx_author);
+        });
+    }
 }
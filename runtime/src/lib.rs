@@ -31,7 +31,6 @@ std::compile_error!("Features \"std\" and \"no-std\" cannot be enabled simultane
 
 extern crate alloc;
 
-use sp_core::ed25519;
 use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::{create_runtime_str, generic};
 pub use sp_version::RuntimeVersion;
@@ -39,10 +38,13 @@ pub use sp_version::RuntimeVersion;
 pub use radicle_registry_core::*;
 pub use runtime::api as runtime_api;
 pub use runtime::api::{api, RuntimeApi};
-pub use runtime::{Call, Event, Origin, Runtime};
+pub use runtime::{
+    AvailableBlockRatio, Call, Event, MaximumBlockLength, MaximumBlockWeight, Origin, Runtime,
+};
 
 pub mod fees;
 pub mod registry;
+pub mod registry_api;
 mod runtime;
 pub mod timestamp_in_digest;
 
@@ -51,9 +53,6 @@ pub use registry::DecodeKey;
 /// An index to a block.
 pub type BlockNumber = u32;
 
-/// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
-pub type Signature = ed25519::Signature;
-
 /// A hash of some data used by the chain.
 ///
 /// Same as  [sp_runtime::traits::Hash::Output] for [Hashing].
@@ -81,7 +80,11 @@ pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, Call, Signa
 /// A timestamp: milliseconds since the unix epoch.
 type Moment = u64;
 
-pub const SPEC_VERSION: u32 = 19;
+/// Target time between two blocks in milliseconds. The node adjusts the PoW difficulty to
+/// approach this block time.
+pub const TARGET_BLOCK_TIME_MS: u64 = 60_000;
+
+pub const SPEC_VERSION: u32 = 57;
 
 /// This runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -121,6 +124,7 @@ pub fn native_version() -> sp_version::NativeVersion {
 pub mod store {
     pub use crate::registry::store::*;
     pub type Account = frame_system::Account<crate::Runtime>;
+    pub type Vesting = pallet_vesting::Vesting<crate::Runtime>;
     #[doc(inline)]
     pub use crate::registry::DecodeKey;
 }
@@ -129,6 +133,10 @@ pub mod event {
     pub use crate::runtime::Event;
     pub type Record = frame_system::EventRecord<crate::runtime::Event, crate::Hash>;
     pub type System = frame_system::Event<crate::Runtime>;
+    pub type Registry = crate::registry::Event;
+    pub type Balances = pallet_balances::Event<crate::Runtime>;
+    pub type Sudo = pallet_sudo::Event<crate::Runtime>;
+    pub type Vesting = pallet_vesting::Event<crate::Runtime>;
 
     /// Return the index of the transaction in the block that dispatched the event.
     ///
@@ -146,9 +154,12 @@ pub mod call {
     pub type Registry = crate::registry::Call<crate::Runtime>;
     pub type System = frame_system::Call<crate::Runtime>;
     pub type Sudo = pallet_sudo::Call<crate::Runtime>;
+    pub type Vesting = pallet_vesting::Call<crate::Runtime>;
 }
 
 #[cfg(feature = "std")]
 pub mod genesis {
-    pub use crate::runtime::{BalancesConfig, GenesisConfig, SudoConfig, SystemConfig};
+    pub use crate::runtime::{
+        BalancesConfig, GenesisConfig, RegistryConfig, SudoConfig, SystemConfig, VestingConfig,
+    };
 }
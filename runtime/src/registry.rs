@@ -17,20 +17,29 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use frame_support::{
-    decl_module, decl_storage,
+    decl_event, decl_module, decl_storage,
     dispatch::DispatchResult,
     storage::{IterableStorageMap, StorageMap, StorageValue as _},
     traits::{Currency, ExistenceRequirement, Randomness as _},
-    weights::Pays,
+    weights::{Pays, Weight},
 };
-use frame_system::{ensure_none, ensure_signed};
+use frame_system::{ensure_none, ensure_root, ensure_signed};
 use sp_core::crypto::UncheckedFrom;
+use sp_runtime::Permill;
 
 use radicle_registry_core::*;
 
-use crate::{fees, AccountId, Hash};
+use crate::{fees, AccountId, BlockNumber, Hash};
 
+pub mod genesis;
+pub mod idempotency;
 mod inherents;
+pub mod migrations;
+pub mod rewards;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 
 pub use inherents::AuthoringInherentData;
 
@@ -57,11 +66,116 @@ where
     <Self as frame_system::Trait>::OnKilledAccount:
         frame_support::traits::OnKilledAccount<Self::AccountId>,
 {
+    type Event: From<Event> + Into<<Self as frame_system::Trait>::Event>;
 }
 
 /// Funds that are credited to the block author for every block.
 pub const BLOCK_REWARD: Balance = rad_to_balance(20);
 
+decl_event!(
+    /// Events deposited by the registry module.
+    ///
+    /// The SCALE encoding of an event starts with the index of its variant. Clients and indexers
+    /// rely on these indices so new variants must only be appended and existing variants must
+    /// never be removed or reordered. See the `event_indices` test.
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Event {
+        /// An org was dissolved with [message::DissolveOrg]. Contains the org id, the recipient
+        /// account and the amount that was transferred from the org account to the recipient.
+        OrgDissolved(Id, AccountId, Balance),
+
+        /// A storage migration was executed on runtime upgrade. Contains the storage version
+        /// before and after the migration and the number of migrated storage items.
+        MigrationExecuted(u32, u32, u32),
+
+        /// A block reward was credited. Contains the account and the amount. Deposited for the
+        /// block author and, if the reward is split, for the [rewards::treasury_account].
+        BlockRewardCredited(AccountId, Balance),
+
+        /// A treasury spend was proposed with [message::ProposeTreasurySpend]. Contains the
+        /// proposal id, the recipient and the amount.
+        TreasurySpendProposed(TreasuryProposalId, AccountId, Balance),
+
+        /// A treasury spend proposal was approved with [message::ApproveTreasurySpend] and paid
+        /// out. Contains the proposal id, the recipient and the amount.
+        TreasurySpendApproved(TreasuryProposalId, AccountId, Balance),
+
+        /// A fee was paid. Contains the paying account and the amount. Deposited for transaction
+        /// fees and registration fees.
+        FeePaid(AccountId, Balance),
+
+        /// A project was unregistered with [message::UnregisterProject]. Contains the project
+        /// name and domain.
+        ProjectUnregistered(ProjectName, ProjectDomain),
+
+        /// Funds were transferred from an org account with [message::TransferFromOrg]. Contains
+        /// the org id, the recipient, the amount and the free balance of the org account after
+        /// the transfer.
+        OrgTransferred(Id, AccountId, Balance, Balance),
+
+        /// An org or user id was reserved with [message::ReserveId]. Contains the id and the
+        /// holder of the reservation.
+        IdReserved(Id, AccountId),
+
+        /// The reservation of an org or user id was cancelled with
+        /// [message::CancelIdReservation]. Contains the id.
+        IdReservationCancelled(Id),
+
+        /// A user offered a project to another user with [message::TransferProjectOwnership].
+        /// Contains the project name, the current owner and the recipient.
+        ProjectTransferProposed(ProjectName, Id, Id),
+
+        /// A project transfer was accepted with [message::AcceptProjectOwnership]. Contains the
+        /// project name, the previous owner and the new owner.
+        ProjectTransferred(ProjectName, Id, Id),
+
+        /// The metadata of an org was set with [message::UpdateOrg]. Contains the org id.
+        OrgUpdated(Id),
+
+        /// The metadata of a user was set with [message::UpdateUser]. Contains the user id.
+        UserUpdated(Id),
+
+        /// A user was associated with another account with [message::TransferUserId]. Contains
+        /// the user id, the previous account and the new account.
+        UserIdTransferred(Id, AccountId, AccountId),
+
+        /// A member of an org handed over their membership with
+        /// [message::TransferOrgMembership]. Contains the org id, the previous member and the new
+        /// member.
+        OrgMembershipTransferred(Id, Id, Id),
+
+        /// A project was moved to another domain with [message::TransferProject]. Contains the
+        /// project name, the previous domain and the new domain.
+        ProjectMoved(ProjectName, ProjectDomain, ProjectDomain),
+
+        /// A project was renamed with [message::RenameProject]. Contains the previous name, the
+        /// new name and the domain of the project.
+        ProjectRenamed(ProjectName, ProjectName, ProjectDomain),
+
+        /// An org was frozen with [message::FreezeOrg].
+        OrgFrozen(Id),
+
+        /// An org was unfrozen with [message::UnfreezeOrg].
+        OrgUnfrozen(Id),
+
+        /// A treasury spend proposal was rejected with [message::RejectTreasurySpend]. Contains
+        /// the proposal id.
+        TreasurySpendRejected(TreasuryProposalId),
+
+        /// A treasury spend proposal was withdrawn by its proposer with
+        /// [message::CancelTreasurySpend]. Contains the proposal id.
+        TreasurySpendCancelled(TreasuryProposalId),
+
+        /// A project name was reserved with [message::ReserveProjectName]. Contains the project
+        /// name and the domain it is reserved for.
+        ProjectNameReserved(ProjectName, ProjectDomain),
+
+        /// The reservation of a project name was cancelled with
+        /// [message::CancelProjectNameReservation]. Contains the project name.
+        ProjectNameReservationCancelled(ProjectName),
+    }
+);
+
 pub mod store {
     use super::*;
 
@@ -84,6 +198,21 @@ pub mod store {
             // can be extracted from the key.
             pub RetiredIds1: map hasher(blake2_128_concat) Id => ();
 
+            // Org and user ids reserved with [message::ReserveId], mapped to the account that
+            // holds the reservation.
+            // We use the blake2_128_concat hasher so that the Id can be extracted from the key.
+            pub ReservedIds1: map hasher(blake2_128_concat) Id => Option<AccountId>;
+
+            // Project names reserved for an org or user with [message::ReserveProjectName].
+            // Projects with a reserved name can only be added to the reserved domain.
+            // We use the blake2_128_concat hasher so that the ProjectName can be extracted from
+            // the key.
+            pub ReservedProjectNames1: map hasher(blake2_128_concat) ProjectName => Option<ProjectDomain>;
+
+            // The status of all org and user ids that are not [IdStatus::Available].
+            // We use the blake2_128_concat hasher so that the Id can be extracted from the key.
+            pub IdStatuses1: map hasher(blake2_128_concat) Id => IdStatus;
+
             // The storage for Orgs, indexed by Id.
             // We use the blake2_128_concat hasher so that the Id
             // can be extracted from the key.
@@ -93,14 +222,88 @@ pub mod store {
             // We use the blake2_128_concat hasher so that the Id can be extraced from the key.
             pub Users1: map hasher(blake2_128_concat) Id => Option<state::Users1Data>;
 
+            // Index of [Users1] by the account associated with the user.
+            // Populated for users registered before the index existed by [migrations].
+            pub UsersByAccountId1: map hasher(blake2_128_concat) AccountId => Option<Id>;
+
+            // Number of orgs in [Orgs1] the user is a member of. Lets us check whether a user is
+            // a member of any org without iterating over all orgs.
+            // Populated for orgs registered before the index existed by [migrations].
+            pub OrgMemberships1: map hasher(blake2_128_concat) Id => u32;
+
             // We use the blake2_128_concat hasher so that the ProjectId can be extracted from the
             // key.
             pub Projects1: map hasher(blake2_128_concat) ProjectId => Option<state::Projects1Data>;
+
+            // The below map indexes all unregistered project ids. They cannot be registered
+            // again.
+            // We use the blake2_128_concat hasher so that the ProjectId can be extracted from the
+            // key.
+            pub RetiredProjects1: map hasher(blake2_128_concat) ProjectId => ();
+
+            // Pending transfers of user projects offered with [message::TransferProjectOwnership],
+            // mapped to the recipient user.
+            // We use the blake2_128_concat hasher so that the ProjectId can be extracted from the
+            // key.
+            pub PendingProjectTransfers1: map hasher(blake2_128_concat) ProjectId => Option<Id>;
+
+            // Share of [BLOCK_REWARD] that is credited to [rewards::treasury_account] instead of
+            // the block author. Configured in the chain spec and zero by default.
+            pub TreasuryRewardShare get(fn treasury_reward_share) config(): Permill;
+
+            // Id for the next treasury spend proposal.
+            pub NextTreasuryProposalId: TreasuryProposalId;
+
+            // Open treasury spend proposals.
+            // We use the blake2_128_concat hasher so that the TreasuryProposalId can be extracted
+            // from the key.
+            pub TreasuryProposals1: map hasher(blake2_128_concat) TreasuryProposalId => Option<state::TreasuryProposals1Data>;
+
+            // Idempotency keys of applied registrations and their authors mapped to the block they
+            // were applied in. See [idempotency].
+            pub IdempotencyKeys1: map hasher(blake2_128_concat) (AccountId, IdempotencyKey) => Option<BlockNumber>;
+
+            // Idempotency keys and their authors recorded in a block. Used to prune
+            // [IdempotencyKeys1].
+            pub IdempotencyKeysByBlock1: map hasher(twox_64_concat) BlockNumber => Vec<(AccountId, IdempotencyKey)>;
+
+            // History of all storage migrations executed on runtime upgrades in the order of
+            // execution. See [migrations].
+            pub MigrationHistory1: Vec<state::MigrationHistory1Data>;
+
+            // Version of the registry storage layout. Written by [migrations] and at genesis.
+            // Chains that never stored the version derive it from [MigrationHistory1]. See
+            // [migrations::storage_version].
+            pub PalletStorageVersion: Option<u32>;
+
+            // Migrations executed by the runtime upgrade at the beginning of the current block.
+            // Taken in `on_initialize` to deposit events and not persisted.
+            pub ExecutedMigrations: Vec<state::MigrationHistory1Data>;
+        }
+        add_extra_genesis {
+            // Users that exist at genesis given by their id, associated account and metadata.
+            config(users): Vec<(Id, AccountId, Option<Bytes128>)>;
+            // Orgs that exist at genesis given by their id, members, metadata and whether they
+            // are frozen. See [genesis::genesis_org_account_id] for the org accounts.
+            config(orgs): Vec<(Id, Vec<Id>, Option<Bytes128>, bool)>;
+            // Projects that exist at genesis given by their name, domain and metadata.
+            config(projects): Vec<(ProjectName, ProjectDomain, Bytes128)>;
+            // Org and user ids that are retired at genesis.
+            config(retired_ids): Vec<Id>;
+            // Org and user ids that are reserved at genesis given by the id and the holder of the
+            // reservation.
+            config(reserved_ids): Vec<(Id, AccountId)>;
+            // Project ids that are retired at genesis.
+            config(retired_projects): Vec<ProjectId>;
+            // Project names that are reserved at genesis given by the name and the domain it is
+            // reserved for.
+            config(reserved_project_names): Vec<(ProjectName, ProjectDomain)>;
+            build(|config: &GenesisConfig| super::genesis::build(config));
         }
     }
 }
 
-pub use store::Store;
+pub use store::{GenesisConfig, Store};
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where
@@ -112,14 +315,30 @@ decl_module! {
         <T as frame_system::Trait>::OnKilledAccount:
             frame_support::traits::OnKilledAccount<AccountId>
     {
-        #[weight = (0, Pays::No)]
+        /// Fee charged for registering an org or a user. See [fees::REGISTRATION_FEE].
+        const RegistrationFee: Balance = fees::REGISTRATION_FEE;
+
+        /// Minimum fee a transaction must pay. See [fees::MINIMUM_TX_FEE].
+        const MinimumTxFee: Balance = fees::MINIMUM_TX_FEE;
+
+        /// Share of every transaction fee that is burned. See [fees::FEE_BURN_SHARE].
+        const FeeBurnShare: Permill = fees::FEE_BURN_SHARE;
+
+        /// Funds credited for every block. See [BLOCK_REWARD].
+        const BlockReward: Balance = BLOCK_REWARD;
+
+        /// Target time between two blocks in milliseconds. See [crate::TARGET_BLOCK_TIME_MS].
+        const TargetBlockTime: u64 = crate::TARGET_BLOCK_TIME_MS;
+
+        fn deposit_event() = default;
+
+        #[weight = (weights::register_project(), Pays::No)]
         pub fn register_project(origin, message: message::RegisterProject) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            idempotency::ensure_unused(sender, &message.idempotency_key)?;
 
             let project_id = (message.project_name.clone(), message.project_domain.clone());
-            if store::Projects1::get(project_id.clone()).is_some() {
-                return Err(RegistryError::DuplicateProjectId.into());
-            };
+            ensure_project_id_is_available(&project_id)?;
 
             match &message.project_domain {
                 ProjectDomain::Org(org_id) => {
@@ -127,6 +346,9 @@ decl_module! {
                     if !org_has_member_with_account(&org, sender) {
                         return Err(RegistryError::InsufficientSenderPermissions.into());
                     }
+                    if org.frozen() {
+                        return Err(RegistryError::OrgFrozen.into());
+                    }
                     store::Orgs1::insert(org_id, org.add_project(message.project_name.clone()));
                 },
                 ProjectDomain::User(user_id) => {
@@ -139,13 +361,15 @@ decl_module! {
             };
 
             let new_project = state::Projects1Data::new(
-                message.metadata
+                message.metadata,
+                frame_system::Module::<crate::Runtime>::block_number(),
             );
             store::Projects1::insert(project_id, new_project);
+            idempotency::record(sender, message.idempotency_key);
             Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::register_member(), Pays::No)]
         pub fn register_member(origin, message: message::RegisterMember) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
@@ -162,16 +386,18 @@ decl_module! {
                 return Err(RegistryError::AlreadyAMember.into());
             }
 
-            let org_with_member = org.add_member(message.user_id.clone());
+            add_org_memberships(&[message.user_id.clone()]);
+            let org_with_member = org.add_member(message.user_id);
             store::Orgs1::insert(message.org_id, org_with_member);
             Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::register_org(), Pays::No)]
         pub fn register_org(origin, message: message::RegisterOrg) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            idempotency::ensure_unused(sender, &message.idempotency_key)?;
 
-            ensure_id_is_available(&message.org_id)?;
+            ensure_id_is_available(&message.org_id, sender)?;
             let user_id = get_user_id_with_account(sender).ok_or(RegistryError::AuthorHasNoAssociatedUser)?;
             fees::pay_registration_fee(&sender)?;
             let random_account_id = AccountId::unchecked_from(
@@ -179,26 +405,32 @@ decl_module! {
                     b"org-account-id",
                 )
             );
-            let new_org = state::Orgs1Data::new(random_account_id, vec![user_id],  Vec::new());
+            add_org_memberships(&[user_id.clone()]);
+            let new_org = state::Orgs1Data::new(
+                random_account_id,
+                vec![user_id],
+                Vec::new(),
+                frame_system::Module::<crate::Runtime>::block_number(),
+            );
             store::Orgs1::insert(message.org_id.clone(), new_org);
+            store::ReservedIds1::remove(&message.org_id);
+            store::IdStatuses1::insert(&message.org_id, IdStatus::Taken);
             store::RetiredIds1::insert(message.org_id, ());
+            idempotency::record(sender, message.idempotency_key);
             Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::unregister_org(), Pays::No)]
         pub fn unregister_org(origin, message: message::UnregisterOrg) -> DispatchResult {
-            fn can_be_unregistered(org: state::Orgs1Data, sender: AccountId) -> bool {
-                org.projects().is_empty() && get_user_id_with_account(sender)
-                    .map(|user_id| org.members() == &[user_id]).unwrap_or(false)
-            }
-
             let sender = ensure_signed(origin)?;
 
             match store::Orgs1::get(message.org_id.clone()) {
                 None => Err(RegistryError::InexistentOrg.into()),
                 Some(org) => {
-                    if can_be_unregistered(org, sender) {
-                        store::Orgs1::remove(message.org_id);
+                    if org_can_be_unregistered(&org, sender) {
+                        remove_org_memberships(org.members());
+                        store::Orgs1::remove(&message.org_id);
+                        store::IdStatuses1::insert(message.org_id, IdStatus::Retired);
                         Ok(())
                     }
                     else {
@@ -208,11 +440,42 @@ decl_module! {
             }
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::dissolve_org(), Pays::No)]
+        pub fn dissolve_org(origin, message: message::DissolveOrg) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let org = store::Orgs1::get(message.org_id.clone())
+                .ok_or(RegistryError::InexistentOrg)?;
+
+            if !org_can_be_unregistered(&org, sender) {
+                return Err(RegistryError::UnregisterableOrg.into());
+            }
+            if org.frozen() {
+                return Err(RegistryError::OrgFrozen.into());
+            }
+
+            let amount = crate::runtime::Balances::free_balance(&org.account_id());
+            if amount > 0 {
+                <crate::runtime::Balances as Currency<_>>::transfer(
+                    &org.account_id(),
+                    &message.recipient,
+                    amount,
+                    ExistenceRequirement::AllowDeath
+                )?;
+            }
+
+            remove_org_memberships(org.members());
+            store::Orgs1::remove(message.org_id.clone());
+            store::IdStatuses1::insert(&message.org_id, IdStatus::Retired);
+            Self::deposit_event(Event::OrgDissolved(message.org_id, message.recipient, amount));
+            Ok(())
+        }
+
+        #[weight = (weights::register_user(), Pays::No)]
         pub fn register_user(origin, message: message::RegisterUser) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            idempotency::ensure_unused(sender, &message.idempotency_key)?;
 
-            ensure_id_is_available(&message.user_id)?;
+            ensure_id_is_available(&message.user_id, sender)?;
 
             if get_user_with_account(sender).is_some() {
                 return Err(RegistryError::UserAccountAssociated.into())
@@ -222,13 +485,18 @@ decl_module! {
             let new_user = state::Users1Data::new(
                 sender,
                 Vec::new(),
+                frame_system::Module::<crate::Runtime>::block_number(),
             );
             store::Users1::insert(message.user_id.clone(), new_user);
+            store::UsersByAccountId1::insert(sender, message.user_id.clone());
+            store::ReservedIds1::remove(&message.user_id);
+            store::IdStatuses1::insert(&message.user_id, IdStatus::Taken);
             store::RetiredIds1::insert(message.user_id, ());
+            idempotency::record(sender, message.idempotency_key);
             Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::unregister_user(), Pays::No)]
         pub fn unregister_user(origin, message: message::UnregisterUser) -> DispatchResult {
 
             let sender = ensure_signed(origin)?;
@@ -237,34 +505,53 @@ decl_module! {
             if message.user_id != user_id {
                 return Err(RegistryError::InsufficientSenderPermissions.into());
             }
-            if !user.projects().is_empty() || find_org(|org| org.members().contains(&user_id)).is_some() {
+            if !user.projects().is_empty() || store::OrgMemberships1::get(&user_id) > 0 {
                 return Err(RegistryError::UnregisterableUser.into());
             }
 
-            store::Users1::remove(user_id);
+            store::Users1::remove(&user_id);
+            store::UsersByAccountId1::remove(sender);
+            store::IdStatuses1::insert(user_id, IdStatus::Retired);
             Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::transfer_from_org(), Pays::No)]
         pub fn transfer_from_org(origin, message: message::TransferFromOrg) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let org = store::Orgs1::get(message.org_id)
                 .ok_or(RegistryError::InexistentOrg)?;
 
-            if org_has_member_with_account(&org, sender) {
-                <crate::runtime::Balances as Currency<_>>::transfer(
-                    &org.account_id(),
-                    &message.recipient,
-                    message.amount,
-                    ExistenceRequirement::KeepAlive
-                )
+            if !org_has_member_with_account(&org, sender) {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
             }
-            else {
-                Err(RegistryError::InsufficientSenderPermissions.into())
+            if org.frozen() {
+                return Err(RegistryError::OrgFrozen.into());
             }
+
+            let existence_requirement = if message.allow_death {
+                ExistenceRequirement::AllowDeath
+            } else {
+                ExistenceRequirement::KeepAlive
+            };
+            ensure_org_can_transfer(&org, message.amount, existence_requirement)?;
+
+            <crate::runtime::Balances as Currency<_>>::transfer(
+                &org.account_id(),
+                &message.recipient,
+                message.amount,
+                existence_requirement
+            )?;
+            let remaining_balance = crate::runtime::Balances::free_balance(&org.account_id());
+            Self::deposit_event(Event::OrgTransferred(
+                message.org_id,
+                message.recipient,
+                message.amount,
+                remaining_balance,
+            ));
+            Ok(())
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::transfer(), Pays::No)]
         pub fn transfer(origin, message: message::Transfer) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
@@ -276,7 +563,349 @@ decl_module! {
             )
         }
 
-        #[weight = (0, Pays::No)]
+        #[weight = (weights::propose_treasury_spend(), Pays::No)]
+        pub fn propose_treasury_spend(origin, message: message::ProposeTreasurySpend) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proposal_id = store::NextTreasuryProposalId::get();
+            let next_proposal_id = proposal_id
+                .checked_add(1)
+                .ok_or(RegistryError::TreasuryProposalIdsExhausted)?;
+            store::NextTreasuryProposalId::put(next_proposal_id);
+            let proposal = state::TreasuryProposals1Data::new(sender, message.recipient, message.amount);
+            store::TreasuryProposals1::insert(proposal_id, proposal);
+            Self::deposit_event(Event::TreasurySpendProposed(proposal_id, message.recipient, message.amount));
+            Ok(())
+        }
+
+        #[weight = (weights::approve_treasury_spend(), Pays::No)]
+        pub fn approve_treasury_spend(origin, message: message::ApproveTreasurySpend) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let proposal = store::TreasuryProposals1::get(message.proposal_id)
+                .ok_or(RegistryError::InexistentTreasuryProposal)?;
+            let treasury_account = rewards::treasury_account();
+            let remaining = crate::runtime::Balances::free_balance(&treasury_account)
+                .checked_sub(proposal.amount());
+            match remaining {
+                Some(remaining) if remaining >= crate::runtime::Balances::minimum_balance() => (),
+                _ => return Err(RegistryError::InsufficientTreasuryBalance.into()),
+            }
+
+            <crate::runtime::Balances as Currency<_>>::transfer(
+                &treasury_account,
+                &proposal.recipient(),
+                proposal.amount(),
+                ExistenceRequirement::KeepAlive,
+            )?;
+            store::TreasuryProposals1::remove(message.proposal_id);
+            Self::deposit_event(Event::TreasurySpendApproved(message.proposal_id, proposal.recipient(), proposal.amount()));
+            Ok(())
+        }
+
+        #[weight = (weights::reject_treasury_spend(), Pays::No)]
+        pub fn reject_treasury_spend(origin, message: message::RejectTreasurySpend) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if !store::TreasuryProposals1::contains_key(message.proposal_id) {
+                return Err(RegistryError::InexistentTreasuryProposal.into());
+            }
+            store::TreasuryProposals1::remove(message.proposal_id);
+            Self::deposit_event(Event::TreasurySpendRejected(message.proposal_id));
+            Ok(())
+        }
+
+        #[weight = (weights::cancel_treasury_spend(), Pays::No)]
+        pub fn cancel_treasury_spend(origin, message: message::CancelTreasurySpend) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let proposal = store::TreasuryProposals1::get(message.proposal_id)
+                .ok_or(RegistryError::InexistentTreasuryProposal)?;
+            if proposal.proposer() != sender {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+            store::TreasuryProposals1::remove(message.proposal_id);
+            Self::deposit_event(Event::TreasurySpendCancelled(message.proposal_id));
+            Ok(())
+        }
+
+        #[weight = (weights::unregister_project(), Pays::No)]
+        pub fn unregister_project(origin, message: message::UnregisterProject) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.project_name.clone(), message.project_domain.clone());
+            if !store::Projects1::contains_key(project_id.clone()) {
+                return Err(RegistryError::InexistentProjectId.into());
+            }
+
+            match &message.project_domain {
+                ProjectDomain::Org(org_id) => {
+                    let org = store::Orgs1::get(org_id).ok_or(RegistryError::InexistentOrg)?;
+                    if !org_has_member_with_account(&org, sender) {
+                        return Err(RegistryError::InsufficientSenderPermissions.into());
+                    }
+                    store::Orgs1::insert(org_id, org.remove_project(&message.project_name));
+                },
+                ProjectDomain::User(user_id) => {
+                    let user = store::Users1::get(user_id).ok_or(RegistryError::InexistentUser)?;
+                    if user.account_id() != sender {
+                        return Err(RegistryError::InsufficientSenderPermissions.into());
+                    }
+                    store::Users1::insert(user_id, user.remove_project(&message.project_name));
+                },
+            };
+
+            store::Projects1::remove(project_id.clone());
+            store::PendingProjectTransfers1::remove(project_id.clone());
+            store::RetiredProjects1::insert(project_id, ());
+            Self::deposit_event(Event::ProjectUnregistered(message.project_name, message.project_domain));
+            Ok(())
+        }
+
+        #[weight = (weights::reserve_id(), Pays::No)]
+        pub fn reserve_id(origin, message: message::ReserveId) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure_id_is_unclaimed(&message.id)?;
+
+            store::ReservedIds1::insert(message.id.clone(), message.holder);
+            store::IdStatuses1::insert(&message.id, IdStatus::Reserved);
+            Self::deposit_event(Event::IdReserved(message.id, message.holder));
+            Ok(())
+        }
+
+        #[weight = (weights::cancel_id_reservation(), Pays::No)]
+        pub fn cancel_id_reservation(origin, message: message::CancelIdReservation) -> DispatchResult {
+            ensure_root(origin)?;
+            if !store::ReservedIds1::contains_key(&message.id) {
+                return Err(RegistryError::InexistentIdReservation.into());
+            }
+
+            store::ReservedIds1::remove(&message.id);
+            store::IdStatuses1::remove(&message.id);
+            Self::deposit_event(Event::IdReservationCancelled(message.id));
+            Ok(())
+        }
+
+        #[weight = (weights::reserve_project_name(), Pays::No)]
+        pub fn reserve_project_name(origin, message: message::ReserveProjectName) -> DispatchResult {
+            ensure_root(origin)?;
+            let domain_exists = match &message.domain {
+                ProjectDomain::Org(org_id) => store::Orgs1::contains_key(org_id),
+                ProjectDomain::User(user_id) => store::Users1::contains_key(user_id),
+            };
+            if !domain_exists {
+                return Err(match message.domain {
+                    ProjectDomain::Org(_) => RegistryError::InexistentOrg,
+                    ProjectDomain::User(_) => RegistryError::InexistentUser,
+                }.into());
+            }
+
+            store::ReservedProjectNames1::insert(&message.project_name, &message.domain);
+            Self::deposit_event(Event::ProjectNameReserved(message.project_name, message.domain));
+            Ok(())
+        }
+
+        #[weight = (weights::cancel_project_name_reservation(), Pays::No)]
+        pub fn cancel_project_name_reservation(origin, message: message::CancelProjectNameReservation) -> DispatchResult {
+            ensure_root(origin)?;
+            if !store::ReservedProjectNames1::contains_key(&message.project_name) {
+                return Err(RegistryError::InexistentProjectNameReservation.into());
+            }
+
+            store::ReservedProjectNames1::remove(&message.project_name);
+            Self::deposit_event(Event::ProjectNameReservationCancelled(message.project_name));
+            Ok(())
+        }
+
+        #[weight = (weights::freeze_org(), Pays::No)]
+        pub fn freeze_org(origin, message: message::FreezeOrg) -> DispatchResult {
+            ensure_root(origin)?;
+            let org = store::Orgs1::get(&message.org_id).ok_or(RegistryError::InexistentOrg)?;
+            if org.frozen() {
+                return Err(RegistryError::OrgFrozen.into());
+            }
+
+            store::Orgs1::insert(&message.org_id, org.set_frozen(true));
+            Self::deposit_event(Event::OrgFrozen(message.org_id));
+            Ok(())
+        }
+
+        #[weight = (weights::unfreeze_org(), Pays::No)]
+        pub fn unfreeze_org(origin, message: message::UnfreezeOrg) -> DispatchResult {
+            ensure_root(origin)?;
+            let org = store::Orgs1::get(&message.org_id).ok_or(RegistryError::InexistentOrg)?;
+            if !org.frozen() {
+                return Err(RegistryError::OrgNotFrozen.into());
+            }
+
+            store::Orgs1::insert(&message.org_id, org.set_frozen(false));
+            Self::deposit_event(Event::OrgUnfrozen(message.org_id));
+            Ok(())
+        }
+
+        #[weight = (weights::transfer_project_ownership(), Pays::No)]
+        pub fn transfer_project_ownership(origin, message: message::TransferProjectOwnership) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.project_name.clone(), ProjectDomain::User(message.user_id.clone()));
+            if !store::Projects1::contains_key(project_id.clone()) {
+                return Err(RegistryError::InexistentProjectId.into());
+            }
+            let user = store::Users1::get(&message.user_id).ok_or(RegistryError::InexistentUser)?;
+            if user.account_id() != sender {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+            if !store::Users1::contains_key(&message.recipient) {
+                return Err(RegistryError::InexistentUser.into());
+            }
+            ensure_project_id_is_available(&(message.project_name.clone(), ProjectDomain::User(message.recipient.clone())))?;
+
+            store::PendingProjectTransfers1::insert(project_id, message.recipient.clone());
+            Self::deposit_event(Event::ProjectTransferProposed(message.project_name, message.user_id, message.recipient));
+            Ok(())
+        }
+
+        #[weight = (weights::accept_project_ownership(), Pays::No)]
+        pub fn accept_project_ownership(origin, message: message::AcceptProjectOwnership) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.project_name.clone(), ProjectDomain::User(message.user_id.clone()));
+            let recipient_id = store::PendingProjectTransfers1::get(project_id.clone())
+                .ok_or(RegistryError::InexistentProjectTransfer)?;
+            let recipient = store::Users1::get(&recipient_id).ok_or(RegistryError::InexistentUser)?;
+            if recipient.account_id() != sender {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+            let new_project_id = (message.project_name.clone(), ProjectDomain::User(recipient_id.clone()));
+            ensure_project_id_is_available(&new_project_id)?;
+
+            let project = store::Projects1::get(project_id.clone()).ok_or(RegistryError::InexistentProjectId)?;
+            let owner = store::Users1::get(&message.user_id).ok_or(RegistryError::InexistentUser)?;
+            store::Users1::insert(&message.user_id, owner.remove_project(&message.project_name));
+            store::Users1::insert(&recipient_id, recipient.add_project(message.project_name.clone()));
+
+            store::Projects1::remove(project_id.clone());
+            store::PendingProjectTransfers1::remove(project_id.clone());
+            store::RetiredProjects1::insert(project_id, ());
+            store::Projects1::insert(new_project_id, project);
+            Self::deposit_event(Event::ProjectTransferred(message.project_name, message.user_id, recipient_id));
+            Ok(())
+        }
+
+        #[weight = (weights::update_org(), Pays::No)]
+        pub fn update_org(origin, message: message::UpdateOrg) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let org = store::Orgs1::get(&message.org_id).ok_or(RegistryError::InexistentOrg)?;
+            if !org_has_member_with_account(&org, sender) {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+
+            store::Orgs1::insert(&message.org_id, org.set_metadata(message.metadata));
+            Self::deposit_event(Event::OrgUpdated(message.org_id));
+            Ok(())
+        }
+
+        #[weight = (weights::update_user(), Pays::No)]
+        pub fn update_user(origin, message: message::UpdateUser) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let user = store::Users1::get(&message.user_id).ok_or(RegistryError::InexistentUser)?;
+            if user.account_id() != sender {
+                return Err(RegistryError::InsufficientSenderPermissions.into());
+            }
+
+            store::Users1::insert(&message.user_id, user.set_metadata(message.metadata));
+            Self::deposit_event(Event::UserUpdated(message.user_id));
+            Ok(())
+        }
+
+        #[weight = (weights::transfer_user_id(), Pays::No)]
+        pub fn transfer_user_id(origin, message: message::TransferUserId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let (user_id, user) = get_user_with_account(sender).ok_or(RegistryError::AuthorHasNoAssociatedUser)?;
+            if store::UsersByAccountId1::contains_key(message.new_account_id) {
+                return Err(RegistryError::UserAccountAssociated.into());
+            }
+
+            store::Users1::insert(&user_id, user.set_account_id(message.new_account_id));
+            store::UsersByAccountId1::remove(sender);
+            store::UsersByAccountId1::insert(message.new_account_id, user_id.clone());
+            Self::deposit_event(Event::UserIdTransferred(user_id, sender, message.new_account_id));
+            Ok(())
+        }
+
+        #[weight = (weights::transfer_org_membership(), Pays::No)]
+        pub fn transfer_org_membership(origin, message: message::TransferOrgMembership) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let org = store::Orgs1::get(&message.org_id).ok_or(RegistryError::InexistentOrg)?;
+            let user_id = match get_user_id_with_account(sender) {
+                Some(user_id) if org.members().contains(&user_id) => user_id,
+                _ => return Err(RegistryError::InsufficientSenderPermissions.into()),
+            };
+            if !store::Users1::contains_key(&message.recipient) {
+                return Err(RegistryError::InexistentUser.into());
+            }
+            if org.members().contains(&message.recipient) {
+                return Err(RegistryError::AlreadyAMember.into());
+            }
+
+            remove_org_memberships(&[user_id.clone()]);
+            add_org_memberships(&[message.recipient.clone()]);
+            store::Orgs1::insert(&message.org_id, org.replace_member(&user_id, message.recipient.clone()));
+            Self::deposit_event(Event::OrgMembershipTransferred(message.org_id, user_id, message.recipient));
+            Ok(())
+        }
+
+        #[weight = (weights::transfer_project(), Pays::No)]
+        pub fn transfer_project(origin, message: message::TransferProject) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.project_name.clone(), message.from.clone());
+            let project = store::Projects1::get(project_id.clone()).ok_or(RegistryError::InexistentProjectId)?;
+            ensure_authorized_in_domain(&message.from, sender)?;
+            ensure_authorized_in_domain(&message.to, sender)?;
+            ensure_domain_not_frozen(&message.from)?;
+            ensure_domain_not_frozen(&message.to)?;
+            let new_project_id = (message.project_name.clone(), message.to.clone());
+            ensure_project_id_is_available(&new_project_id)?;
+
+            remove_project_from_domain(&message.from, &message.project_name);
+            add_project_to_domain(&message.to, message.project_name.clone());
+
+            store::Projects1::remove(project_id.clone());
+            store::PendingProjectTransfers1::remove(project_id.clone());
+            store::RetiredProjects1::insert(project_id, ());
+            store::Projects1::insert(new_project_id, project);
+            Self::deposit_event(Event::ProjectMoved(message.project_name, message.from, message.to));
+            Ok(())
+        }
+
+        #[weight = (weights::rename_project(), Pays::No)]
+        pub fn rename_project(origin, message: message::RenameProject) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            let project_id = (message.old_name.clone(), message.domain.clone());
+            let project = store::Projects1::get(project_id.clone()).ok_or(RegistryError::InexistentProjectId)?;
+            ensure_authorized_in_domain(&message.domain, sender)?;
+            ensure_domain_not_frozen(&message.domain)?;
+            let new_project_id = (message.new_name.clone(), message.domain.clone());
+            ensure_project_id_is_available(&new_project_id)?;
+
+            remove_project_from_domain(&message.domain, &message.old_name);
+            add_project_to_domain(&message.domain, message.new_name.clone());
+
+            store::Projects1::remove(project_id.clone());
+            store::PendingProjectTransfers1::remove(project_id.clone());
+            store::RetiredProjects1::insert(project_id, ());
+            store::Projects1::insert(new_project_id, project);
+            Self::deposit_event(Event::ProjectRenamed(message.old_name, message.new_name, message.domain));
+            Ok(())
+        }
+
+        #[weight = (weights::set_block_author(), Pays::No)]
         fn set_block_author(origin, author: AccountId) -> DispatchResult {
             assert!(ensure_none(origin).is_ok(), "set_block_author call is only valid as an inherent");
             assert!(store::BlockAuthor::get().is_none(), "set_block_author can only be called once");
@@ -284,22 +913,153 @@ decl_module! {
             Ok(())
         }
 
+        fn on_runtime_upgrade() -> Weight {
+            migrations::on_runtime_upgrade()
+        }
+
+        fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+            let migration_events = migrations::deposit_migration_events();
+            let pruned_keys = idempotency::prune(frame_system::Module::<crate::Runtime>::block_number());
+            weights::on_initialize(migration_events, pruned_keys)
+        }
+
         fn on_finalize() {
             let block_author = store::BlockAuthor::take().expect("Block author must be set by an extrinsic");
-            let imbalance = crate::runtime::Balances::deposit_creating(&block_author, BLOCK_REWARD);
-            drop(imbalance);
+            rewards::credit_block_reward(block_author);
         }
 
     }
 }
 
-fn ensure_id_is_available(id: &Id) -> Result<(), RegistryError> {
-    if store::Users1::contains_key(id) || store::Orgs1::contains_key(id) {
-        Err(RegistryError::IdAlreadyTaken)
-    } else if store::RetiredIds1::contains_key(id) {
-        Err(RegistryError::IdRetired)
+/// Check that `id` can be registered by `sender`. The id must be unclaimed and, if it is reserved,
+/// `sender` must be the holder of the reservation.
+fn ensure_id_is_available(id: &Id, sender: AccountId) -> Result<(), RegistryError> {
+    ensure_id_is_unclaimed(id)?;
+    match store::ReservedIds1::get(id) {
+        Some(holder) if holder != sender => Err(RegistryError::IdReserved),
+        _ => Ok(()),
+    }
+}
+
+fn ensure_id_is_unclaimed(id: &Id) -> Result<(), RegistryError> {
+    match store::IdStatuses1::get(id) {
+        IdStatus::Taken => Err(RegistryError::IdAlreadyTaken),
+        IdStatus::Retired => Err(RegistryError::IdRetired),
+        IdStatus::Available | IdStatus::Reserved => Ok(()),
+    }
+}
+
+/// Check that a project with the given id can be registered. The id must neither be taken nor
+/// retired and its name must not be reserved for another domain.
+fn ensure_project_id_is_available(project_id: &ProjectId) -> Result<(), RegistryError> {
+    let (project_name, domain) = project_id;
+    if store::Projects1::contains_key(project_id) {
+        Err(RegistryError::DuplicateProjectId)
+    } else if store::RetiredProjects1::contains_key(project_id) {
+        Err(RegistryError::ProjectIdRetired)
     } else {
+        match store::ReservedProjectNames1::get(project_name) {
+            Some(reserved_domain) if reserved_domain != *domain => {
+                Err(RegistryError::ProjectNameReserved)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Check that `sender` may manage the projects of `domain`. For an org the user associated with
+/// `sender` must be a member of the org. For a user `sender` must be the account of the user.
+fn ensure_authorized_in_domain(
+    domain: &ProjectDomain,
+    sender: AccountId,
+) -> Result<(), RegistryError> {
+    let authorized = match domain {
+        ProjectDomain::Org(org_id) => {
+            let org = store::Orgs1::get(org_id).ok_or(RegistryError::InexistentOrg)?;
+            org_has_member_with_account(&org, sender)
+        }
+        ProjectDomain::User(user_id) => {
+            let user = store::Users1::get(user_id).ok_or(RegistryError::InexistentUser)?;
+            user.account_id() == sender
+        }
+    };
+    if authorized {
         Ok(())
+    } else {
+        Err(RegistryError::InsufficientSenderPermissions)
+    }
+}
+
+/// Check that `domain` is not an org that is frozen with [message::FreezeOrg].
+fn ensure_domain_not_frozen(domain: &ProjectDomain) -> Result<(), RegistryError> {
+    match domain {
+        ProjectDomain::Org(org_id)
+            if store::Orgs1::get(org_id).map_or(false, |org| org.frozen()) =>
+        {
+            Err(RegistryError::OrgFrozen)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Remove `project_name` from the projects of the org or user of `domain` if it exists.
+fn remove_project_from_domain(domain: &ProjectDomain, project_name: &ProjectName) {
+    match domain {
+        ProjectDomain::Org(org_id) => {
+            if let Some(org) = store::Orgs1::get(org_id) {
+                store::Orgs1::insert(org_id, org.remove_project(project_name));
+            }
+        }
+        ProjectDomain::User(user_id) => {
+            if let Some(user) = store::Users1::get(user_id) {
+                store::Users1::insert(user_id, user.remove_project(project_name));
+            }
+        }
+    }
+}
+
+/// Add `project_name` to the projects of the org or user of `domain` if it exists.
+fn add_project_to_domain(domain: &ProjectDomain, project_name: ProjectName) {
+    match domain {
+        ProjectDomain::Org(org_id) => {
+            if let Some(org) = store::Orgs1::get(org_id) {
+                store::Orgs1::insert(org_id, org.add_project(project_name));
+            }
+        }
+        ProjectDomain::User(user_id) => {
+            if let Some(user) = store::Users1::get(user_id) {
+                store::Users1::insert(user_id, user.add_project(project_name));
+            }
+        }
+    }
+}
+
+/// Check whether the org has no projects and the user associated with `sender` is its only
+/// member.
+fn org_can_be_unregistered(org: &state::Orgs1Data, sender: AccountId) -> bool {
+    org.projects().is_empty()
+        && get_user_id_with_account(sender)
+            .map(|user_id| org.members() == &[user_id])
+            .unwrap_or(false)
+}
+
+/// Check that the org account can afford to transfer `amount`. With
+/// [ExistenceRequirement::KeepAlive] the remaining balance must not fall below the existential
+/// deposit.
+fn ensure_org_can_transfer(
+    org: &state::Orgs1Data,
+    amount: Balance,
+    existence_requirement: ExistenceRequirement,
+) -> Result<(), RegistryError> {
+    type Balances = crate::runtime::Balances;
+    let free_balance = Balances::free_balance(&org.account_id());
+    let minimum_remaining = match existence_requirement {
+        ExistenceRequirement::KeepAlive => Balances::minimum_balance(),
+        ExistenceRequirement::AllowDeath => 0,
+    };
+    match free_balance.checked_sub(amount) {
+        Some(remaining) if remaining >= minimum_remaining => Ok(()),
+        _ => Err(RegistryError::InsufficientOrgBalance),
     }
 }
 
@@ -307,11 +1067,29 @@ fn get_user_id_with_account(account_id: AccountId) -> Option<Id> {
     get_user_with_account(account_id).map(|(id, _)| id)
 }
 
-// TODO(xla): This is a naive first version of the check to see if an account is
-// already associated to a user. While fine for small dataset this needs to be reworked
-// in the future.
+/// Return the user associated with the given account. Uses the [store::UsersByAccountId1] index.
 pub fn get_user_with_account(account_id: AccountId) -> Option<(Id, state::Users1Data)> {
-    store::Users1::iter().find(|(_, user)| user.account_id() == account_id)
+    let user_id = store::UsersByAccountId1::get(account_id)?;
+    store::Users1::get(&user_id).map(|user| (user_id, user))
+}
+
+/// Count the membership of every user in `members` in [store::OrgMemberships1]. Called whenever
+/// users become members of an org.
+pub fn add_org_memberships(members: &[Id]) {
+    for member in members {
+        store::OrgMemberships1::mutate(member, |count| *count += 1);
+    }
+}
+
+/// Remove the memberships counted by [add_org_memberships]. Called whenever users stop being
+/// members of an org.
+fn remove_org_memberships(members: &[Id]) {
+    for member in members {
+        match store::OrgMemberships1::get(member) {
+            0 | 1 => store::OrgMemberships1::remove(member),
+            count => store::OrgMemberships1::insert(member, count - 1),
+        }
+    }
 }
 
 pub fn find_org(predicate: impl Fn(&state::Orgs1Data) -> bool) -> Option<state::Orgs1Data> {
@@ -367,6 +1145,30 @@ impl DecodeKey for store::Users1 {
     }
 }
 
+impl DecodeKey for store::TreasuryProposals1 {
+    type Key = TreasuryProposalId;
+
+    fn decode_key(key: &[u8]) -> Result<TreasuryProposalId, parity_scale_codec::Error> {
+        decode_blake_two128_concat_key(key)
+    }
+}
+
+impl DecodeKey for store::ReservedIds1 {
+    type Key = Id;
+
+    fn decode_key(key: &[u8]) -> Result<Id, parity_scale_codec::Error> {
+        decode_blake_two128_concat_key(key)
+    }
+}
+
+impl DecodeKey for store::ReservedProjectNames1 {
+    type Key = ProjectName;
+
+    fn decode_key(key: &[u8]) -> Result<ProjectName, parity_scale_codec::Error> {
+        decode_blake_two128_concat_key(key)
+    }
+}
+
 /// Decode a blake_two128_concat hashed key to the inferred type K.
 ///
 /// The key consists of the concatenation of the module prefix hash (16 bytes),
@@ -385,6 +1187,7 @@ pub fn decode_blake_two128_concat_key<K: parity_scale_codec::Decode>(
 mod test {
     use core::convert::TryFrom;
     use frame_support::storage::generator::StorageMap;
+    use parity_scale_codec::Encode as _;
 
     use super::*;
 
@@ -419,4 +1222,79 @@ mod test {
         let decoded_key = store::Users1::decode_key(&hashed_key).unwrap();
         assert_eq!(decoded_key, user_id);
     }
+
+    /// Test that store::TreasuryProposals1::decode_key after
+    /// store::TreasuryProposals1::storage_map_final_key is identical to the original id.
+    #[test]
+    fn treasury_proposals_decode_key_identity() {
+        let proposal_id: TreasuryProposalId = 42;
+        let hashed_key = store::TreasuryProposals1::storage_map_final_key(proposal_id);
+        let decoded_key = store::TreasuryProposals1::decode_key(&hashed_key).unwrap();
+        assert_eq!(decoded_key, proposal_id);
+    }
+
+    /// Test that the variant indices of [Event] do not change. Append new variants to the list.
+    #[test]
+    fn event_indices() {
+        let id = Id::try_from("monadic").unwrap();
+        let project_name = ProjectName::try_from("radicle".to_string()).unwrap();
+        let account_id = AccountId::from_raw([1; 32]);
+        let events = vec![
+            Event::OrgDissolved(id.clone(), account_id, 1),
+            Event::MigrationExecuted(1, 2, 1),
+            Event::BlockRewardCredited(account_id, 1),
+            Event::TreasurySpendProposed(1, account_id, 1),
+            Event::TreasurySpendApproved(1, account_id, 1),
+            Event::FeePaid(account_id, 1),
+            Event::ProjectUnregistered(project_name.clone(), ProjectDomain::Org(id.clone())),
+            Event::OrgTransferred(id.clone(), account_id, 1, 1),
+            Event::IdReserved(id.clone(), account_id),
+            Event::IdReservationCancelled(id.clone()),
+            Event::ProjectTransferProposed(project_name.clone(), id.clone(), id.clone()),
+            Event::ProjectTransferred(project_name.clone(), id.clone(), id.clone()),
+            Event::OrgUpdated(id.clone()),
+            Event::UserUpdated(id.clone()),
+            Event::UserIdTransferred(id.clone(), account_id, account_id),
+            Event::OrgMembershipTransferred(id.clone(), id.clone(), id.clone()),
+            Event::ProjectMoved(
+                project_name.clone(),
+                ProjectDomain::Org(id.clone()),
+                ProjectDomain::User(id.clone()),
+            ),
+            Event::ProjectRenamed(
+                project_name.clone(),
+                project_name.clone(),
+                ProjectDomain::User(id.clone()),
+            ),
+            Event::OrgFrozen(id.clone()),
+            Event::OrgUnfrozen(id.clone()),
+            Event::TreasurySpendRejected(1),
+            Event::TreasurySpendCancelled(1),
+            Event::ProjectNameReserved(project_name.clone(), ProjectDomain::Org(id)),
+            Event::ProjectNameReservationCancelled(project_name),
+        ];
+        for (index, event) in events.iter().enumerate() {
+            assert_eq!(usize::from(event.encode()[0]), index, "{:?}", event);
+        }
+    }
+
+    /// Test that store::ReservedIds1::decode_key after store::ReservedIds1::storage_map_final_key
+    /// is identical to the original id.
+    #[test]
+    fn reserved_ids_decode_key_identity() {
+        let id = Id::try_from("monadic").unwrap();
+        let hashed_key = store::ReservedIds1::storage_map_final_key(id.clone());
+        let decoded_key = store::ReservedIds1::decode_key(&hashed_key).unwrap();
+        assert_eq!(decoded_key, id);
+    }
+
+    /// Test that store::ReservedProjectNames1::decode_key after
+    /// store::ReservedProjectNames1::storage_map_final_key is identical to the original name.
+    #[test]
+    fn reserved_project_names_decode_key_identity() {
+        let project_name = ProjectName::try_from("radicle".to_string()).unwrap();
+        let hashed_key = store::ReservedProjectNames1::storage_map_final_key(project_name.clone());
+        let decoded_key = store::ReservedProjectNames1::decode_key(&hashed_key).unwrap();
+        assert_eq!(decoded_key, project_name);
+    }
 }
@@ -0,0 +1,505 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of the registry dispatchables. See [super::weights] for how they are used.
+//!
+//! The registry [super::Trait] fixes most of the system types, so the benchmarks run against the
+//! concrete [Runtime]. The `benchmarks!` macro requires a `Trait` and a `Module` to implement
+//! [frame_benchmarking::Benchmarking] for. We provide minimal stand-ins, like the Substrate
+//! benchmarking crates for pallets that cannot be benchmarked generically do.
+
+use alloc::{format, vec};
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{storage::StorageMap as _, traits::Currency as _};
+use frame_system::RawOrigin;
+
+use radicle_registry_core::*;
+
+use super::{rewards, store};
+use crate::{AccountId, Runtime};
+
+type Registry = super::Module<Runtime>;
+type Balances = crate::runtime::Balances;
+
+/// Only implemented for [Runtime].
+pub trait Trait: frame_system::Trait {}
+
+impl Trait for Runtime {}
+
+/// Benchmarks of the registry module. Use `add_benchmark!` with `Module<Runtime>`.
+pub struct Module<T: Trait>(PhantomData<T>);
+
+const SEED: u32 = 0;
+
+/// Upper bound for the number of members and projects of an org.
+const MAX_ORG_ENTRIES: u32 = 100;
+
+/// Funds of every account created by the benchmarks.
+const FUNDS: Balance = rad_to_balance(1_000);
+
+fn funded_account(name: &'static str, index: u32) -> AccountId {
+    let account_id = account(name, index, SEED);
+    Balances::make_free_balance_be(&account_id, FUNDS);
+    account_id
+}
+
+fn bench_id(prefix: &str, index: u32) -> Id {
+    Id::try_from(format!("{}{}", prefix, index)).expect("benchmark ids are valid")
+}
+
+fn bench_project_name(index: u32) -> ProjectName {
+    ProjectName::try_from(format!("project{}", index)).expect("benchmark project names are valid")
+}
+
+/// Register a user with a funded account. The user id is derived from `name` and `index`.
+fn setup_user(name: &'static str, index: u32) -> Result<(AccountId, Id), &'static str> {
+    let account_id = funded_account(name, index);
+    let user_id = bench_id(name, index);
+    Registry::register_user(
+        RawOrigin::Signed(account_id).into(),
+        message::RegisterUser {
+            user_id: user_id.clone(),
+            idempotency_key: None,
+        },
+    )?;
+    Ok((account_id, user_id))
+}
+
+/// Register an org with `founder` as the only member and fund the org account.
+fn setup_org(founder: AccountId, index: u32) -> Result<(Id, state::Orgs1Data), &'static str> {
+    let org_id = bench_id("org", index);
+    Registry::register_org(
+        RawOrigin::Signed(founder).into(),
+        message::RegisterOrg {
+            org_id: org_id.clone(),
+            idempotency_key: None,
+        },
+    )?;
+    let org = store::Orgs1::get(&org_id).ok_or("org not registered")?;
+    Balances::make_free_balance_be(&org.account_id(), FUNDS);
+    Ok((org_id, org))
+}
+
+/// Register a project in the domain of the user `user_id` owned by `owner`.
+fn setup_user_project(owner: AccountId, user_id: &Id) -> Result<ProjectName, &'static str> {
+    let project_name = bench_project_name(0);
+    Registry::register_project(
+        RawOrigin::Signed(owner).into(),
+        message::RegisterProject {
+            project_name: project_name.clone(),
+            project_domain: ProjectDomain::User(user_id.clone()),
+            metadata: Bytes128::try_from(vec![0; 128]).expect("metadata fits"),
+            idempotency_key: None,
+        },
+    )?;
+    Ok(project_name)
+}
+
+fn setup_project(author: AccountId, org_id: &Id, index: u32) -> Result<ProjectName, &'static str> {
+    let project_name = bench_project_name(index);
+    Registry::register_project(
+        RawOrigin::Signed(author).into(),
+        message::RegisterProject {
+            project_name: project_name.clone(),
+            project_domain: ProjectDomain::Org(org_id.clone()),
+            metadata: Bytes128::try_from(vec![0; 128]).expect("metadata fits"),
+            idempotency_key: None,
+        },
+    )?;
+    Ok(project_name)
+}
+
+benchmarks! {
+    _ { }
+
+    register_project {
+        let p in 0 .. MAX_ORG_ENTRIES => ();
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        for i in 0 .. p {
+            setup_project(caller, &org_id, i)?;
+        }
+        let project_name = bench_project_name(p);
+        let message = message::RegisterProject {
+            project_name: project_name.clone(),
+            project_domain: ProjectDomain::Org(org_id.clone()),
+            metadata: Bytes128::try_from(vec![0; 128]).expect("metadata fits"),
+            idempotency_key: Some(IdempotencyKey::repeat_byte(1)),
+        };
+    }: { Registry::register_project(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Projects1::contains_key((project_name, ProjectDomain::Org(org_id))));
+    }
+
+    rename_project {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let old_name = setup_user_project(caller, &user_id)?;
+        let new_name = bench_project_name(1);
+        let message = message::RenameProject {
+            old_name,
+            new_name: new_name.clone(),
+            domain: ProjectDomain::User(user_id.clone()),
+        };
+    }: { Registry::rename_project(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Projects1::contains_key((new_name, ProjectDomain::User(user_id))));
+    }
+
+    register_member {
+        let m in 1 .. MAX_ORG_ENTRIES => ();
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        for i in 1 .. m {
+            let (_, user_id) = setup_user("member", i)?;
+            Registry::register_member(
+                RawOrigin::Signed(caller).into(),
+                message::RegisterMember { org_id: org_id.clone(), user_id },
+            )?;
+        }
+        let (_, user_id) = setup_user("member", m)?;
+        let message = message::RegisterMember { org_id: org_id.clone(), user_id: user_id.clone() };
+    }: { Registry::register_member(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        let org = store::Orgs1::get(org_id).ok_or("org missing")?;
+        assert!(org.members().contains(&user_id));
+    }
+
+    register_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let org_id = bench_id("org", 0);
+        let message = message::RegisterOrg {
+            org_id: org_id.clone(),
+            idempotency_key: Some(IdempotencyKey::repeat_byte(1)),
+        };
+    }: { Registry::register_org(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Orgs1::contains_key(org_id));
+    }
+
+    unregister_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let message = message::UnregisterOrg { org_id: org_id.clone() };
+    }: { Registry::unregister_org(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(!store::Orgs1::contains_key(org_id));
+    }
+
+    dissolve_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let recipient: AccountId = account("recipient", 0, SEED);
+        let message = message::DissolveOrg { org_id: org_id.clone(), recipient };
+    }: { Registry::dissolve_org(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(!store::Orgs1::contains_key(org_id));
+        assert_eq!(Balances::free_balance(&recipient), FUNDS);
+    }
+
+    register_user {
+        let caller = funded_account("caller", 0);
+        let user_id = bench_id("caller", 0);
+        let message = message::RegisterUser {
+            user_id: user_id.clone(),
+            idempotency_key: Some(IdempotencyKey::repeat_byte(1)),
+        };
+    }: { Registry::register_user(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Users1::contains_key(user_id));
+    }
+
+    unregister_user {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let message = message::UnregisterUser { user_id: user_id.clone() };
+    }: { Registry::unregister_user(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(!store::Users1::contains_key(user_id));
+    }
+
+    transfer_from_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let recipient: AccountId = account("recipient", 0, SEED);
+        let message = message::TransferFromOrg {
+            org_id,
+            recipient,
+            amount: FUNDS / 2,
+            allow_death: false,
+        };
+    }: { Registry::transfer_from_org(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert_eq!(Balances::free_balance(&recipient), FUNDS / 2);
+    }
+
+    transfer {
+        let caller = funded_account("caller", 0);
+        let recipient: AccountId = account("recipient", 0, SEED);
+        let message = message::Transfer { recipient, amount: FUNDS / 2 };
+    }: { Registry::transfer(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert_eq!(Balances::free_balance(&recipient), FUNDS / 2);
+    }
+
+    propose_treasury_spend {
+        let caller = funded_account("caller", 0);
+        let recipient: AccountId = account("recipient", 0, SEED);
+        let message = message::ProposeTreasurySpend { recipient, amount: FUNDS };
+    }: { Registry::propose_treasury_spend(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::TreasuryProposals1::contains_key(0));
+    }
+
+    approve_treasury_spend {
+        let caller = funded_account("caller", 0);
+        let recipient: AccountId = account("recipient", 0, SEED);
+        Balances::make_free_balance_be(&rewards::treasury_account(), 2 * FUNDS);
+        Registry::propose_treasury_spend(
+            RawOrigin::Signed(caller).into(),
+            message::ProposeTreasurySpend { recipient, amount: FUNDS },
+        )?;
+        let message = message::ApproveTreasurySpend { proposal_id: 0 };
+    }: { Registry::approve_treasury_spend(RawOrigin::Root.into(), message)? }
+    verify {
+        assert_eq!(Balances::free_balance(&recipient), FUNDS);
+    }
+
+    reject_treasury_spend {
+        let caller = funded_account("caller", 0);
+        let recipient: AccountId = account("recipient", 0, SEED);
+        Registry::propose_treasury_spend(
+            RawOrigin::Signed(caller).into(),
+            message::ProposeTreasurySpend { recipient, amount: FUNDS },
+        )?;
+        let message = message::RejectTreasurySpend { proposal_id: 0 };
+    }: { Registry::reject_treasury_spend(RawOrigin::Root.into(), message)? }
+    verify {
+        assert!(!store::TreasuryProposals1::contains_key(0));
+    }
+
+    cancel_treasury_spend {
+        let caller = funded_account("caller", 0);
+        let recipient: AccountId = account("recipient", 0, SEED);
+        Registry::propose_treasury_spend(
+            RawOrigin::Signed(caller).into(),
+            message::ProposeTreasurySpend { recipient, amount: FUNDS },
+        )?;
+        let message = message::CancelTreasurySpend { proposal_id: 0 };
+    }: { Registry::cancel_treasury_spend(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(!store::TreasuryProposals1::contains_key(0));
+    }
+
+    unregister_project {
+        let p in 1 .. MAX_ORG_ENTRIES => ();
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        for i in 0 .. p {
+            setup_project(caller, &org_id, i)?;
+        }
+        let message = message::UnregisterProject {
+            project_name: bench_project_name(0),
+            project_domain: ProjectDomain::Org(org_id.clone()),
+        };
+    }: { Registry::unregister_project(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::RetiredProjects1::contains_key((bench_project_name(0), ProjectDomain::Org(org_id))));
+    }
+
+    reserve_id {
+        let id = bench_id("reserved", 0);
+        let holder: AccountId = account("holder", 0, SEED);
+        let message = message::ReserveId { id: id.clone(), holder };
+    }: { Registry::reserve_id(RawOrigin::Root.into(), message)? }
+    verify {
+        assert_eq!(store::ReservedIds1::get(id), Some(holder));
+    }
+
+    cancel_id_reservation {
+        let id = bench_id("reserved", 0);
+        let holder: AccountId = account("holder", 0, SEED);
+        Registry::reserve_id(
+            RawOrigin::Root.into(),
+            message::ReserveId { id: id.clone(), holder },
+        )?;
+        let message = message::CancelIdReservation { id: id.clone() };
+    }: { Registry::cancel_id_reservation(RawOrigin::Root.into(), message)? }
+    verify {
+        assert!(!store::ReservedIds1::contains_key(id));
+    }
+
+    reserve_project_name {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let project_name = bench_project_name(0);
+        let domain = ProjectDomain::Org(org_id);
+        let message = message::ReserveProjectName {
+            project_name: project_name.clone(),
+            domain: domain.clone(),
+        };
+    }: { Registry::reserve_project_name(RawOrigin::Root.into(), message)? }
+    verify {
+        assert_eq!(store::ReservedProjectNames1::get(project_name), Some(domain));
+    }
+
+    cancel_project_name_reservation {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let project_name = bench_project_name(0);
+        Registry::reserve_project_name(
+            RawOrigin::Root.into(),
+            message::ReserveProjectName {
+                project_name: project_name.clone(),
+                domain: ProjectDomain::Org(org_id),
+            },
+        )?;
+        let message = message::CancelProjectNameReservation { project_name: project_name.clone() };
+    }: { Registry::cancel_project_name_reservation(RawOrigin::Root.into(), message)? }
+    verify {
+        assert!(!store::ReservedProjectNames1::contains_key(project_name));
+    }
+
+    freeze_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let message = message::FreezeOrg { org_id: org_id.clone() };
+    }: { Registry::freeze_org(RawOrigin::Root.into(), message)? }
+    verify {
+        assert!(store::Orgs1::get(org_id).ok_or("org not registered")?.frozen());
+    }
+
+    unfreeze_org {
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        Registry::freeze_org(
+            RawOrigin::Root.into(),
+            message::FreezeOrg { org_id: org_id.clone() },
+        )?;
+        let message = message::UnfreezeOrg { org_id: org_id.clone() };
+    }: { Registry::unfreeze_org(RawOrigin::Root.into(), message)? }
+    verify {
+        assert!(!store::Orgs1::get(org_id).ok_or("org not registered")?.frozen());
+    }
+
+    transfer_project_ownership {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let (_, recipient) = setup_user("recipient", 0)?;
+        let project_name = setup_user_project(caller, &user_id)?;
+        let project_id = (project_name.clone(), ProjectDomain::User(user_id.clone()));
+        let message = message::TransferProjectOwnership {
+            project_name,
+            user_id,
+            recipient: recipient.clone(),
+        };
+    }: { Registry::transfer_project_ownership(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert_eq!(store::PendingProjectTransfers1::get(project_id), Some(recipient));
+    }
+
+    accept_project_ownership {
+        let (owner, user_id) = setup_user("owner", 0)?;
+        let (caller, recipient) = setup_user("caller", 0)?;
+        let project_name = setup_user_project(owner, &user_id)?;
+        Registry::transfer_project_ownership(
+            RawOrigin::Signed(owner).into(),
+            message::TransferProjectOwnership {
+                project_name: project_name.clone(),
+                user_id: user_id.clone(),
+                recipient: recipient.clone(),
+            },
+        )?;
+        let message = message::AcceptProjectOwnership {
+            project_name: project_name.clone(),
+            user_id,
+        };
+    }: { Registry::accept_project_ownership(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Projects1::contains_key((project_name, ProjectDomain::User(recipient))));
+    }
+
+    transfer_project {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        let project_name = setup_user_project(caller, &user_id)?;
+        let message = message::TransferProject {
+            project_name: project_name.clone(),
+            from: ProjectDomain::User(user_id),
+            to: ProjectDomain::Org(org_id.clone()),
+        };
+    }: { Registry::transfer_project(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert!(store::Projects1::contains_key((project_name, ProjectDomain::Org(org_id))));
+    }
+
+    update_org {
+        let m in 1 .. MAX_ORG_ENTRIES => ();
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        for i in 1 .. m {
+            let (_, user_id) = setup_user("member", i)?;
+            Registry::register_member(
+                RawOrigin::Signed(caller).into(),
+                message::RegisterMember { org_id: org_id.clone(), user_id },
+            )?;
+        }
+        let metadata = Bytes128::try_from(vec![0; 128]).expect("metadata fits");
+        let message = message::UpdateOrg { org_id: org_id.clone(), metadata: metadata.clone() };
+    }: { Registry::update_org(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        let org = store::Orgs1::get(org_id).ok_or("org missing")?;
+        assert_eq!(org.metadata(), Some(&metadata));
+    }
+
+    update_user {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let metadata = Bytes128::try_from(vec![0; 128]).expect("metadata fits");
+        let message = message::UpdateUser { user_id: user_id.clone(), metadata: metadata.clone() };
+    }: { Registry::update_user(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        let user = store::Users1::get(user_id).ok_or("user missing")?;
+        assert_eq!(user.metadata(), Some(&metadata));
+    }
+
+    transfer_user_id {
+        let (caller, user_id) = setup_user("caller", 0)?;
+        let new_account_id = funded_account("new", 0);
+        let message = message::TransferUserId { new_account_id };
+    }: { Registry::transfer_user_id(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        assert_eq!(store::UsersByAccountId1::get(new_account_id), Some(user_id));
+        assert!(!store::UsersByAccountId1::contains_key(caller));
+    }
+
+    transfer_org_membership {
+        let m in 1 .. MAX_ORG_ENTRIES => ();
+        let (caller, _) = setup_user("caller", 0)?;
+        let (org_id, _) = setup_org(caller, 0)?;
+        for i in 1 .. m {
+            let (_, user_id) = setup_user("member", i)?;
+            Registry::register_member(
+                RawOrigin::Signed(caller).into(),
+                message::RegisterMember { org_id: org_id.clone(), user_id },
+            )?;
+        }
+        let (_, recipient) = setup_user("recipient", 0)?;
+        let message = message::TransferOrgMembership { org_id: org_id.clone(), recipient: recipient.clone() };
+    }: { Registry::transfer_org_membership(RawOrigin::Signed(caller).into(), message)? }
+    verify {
+        let org = store::Orgs1::get(org_id).ok_or("org missing")?;
+        assert!(org.members().contains(&recipient));
+    }
+}
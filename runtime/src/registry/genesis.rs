@@ -0,0 +1,278 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Registry state declared in the genesis config of a chain.
+//!
+//! Orgs, users and projects in the [store::GenesisConfig] are inserted into the storage as if
+//! they had been registered in the genesis block. Retired ids and projects and reservations are
+//! inserted as if they had been retired or reserved in the genesis block. Building the genesis
+//! storage panics if the declared state is inconsistent, for example if an org member is not a
+//! declared user.
+
+use alloc::vec::Vec;
+use frame_support::storage::{StorageMap as _, StorageValue as _};
+use parity_scale_codec::Encode as _;
+use sp_core::crypto::UncheckedFrom;
+
+use radicle_registry_core::*;
+
+use super::{migrations, store};
+use crate::{AccountId, Hash};
+
+/// Account of an org declared in the genesis config.
+///
+/// Orgs registered with [message::RegisterOrg] get a random account. Genesis orgs get an account
+/// derived from the org id so that it is known before the chain starts and can be funded in the
+/// genesis config.
+pub fn genesis_org_account_id(org_id: &Id) -> AccountId {
+    let hash = sp_io::hashing::blake2_256(&(b"genesis-org-account-id", org_id).encode());
+    AccountId::unchecked_from(Hash::from(hash))
+}
+
+/// Insert the genesis users, orgs, projects, retired entities and reservations into the storage.
+pub fn build(config: &store::GenesisConfig) {
+    // The genesis state is built in the latest storage layout.
+    store::PalletStorageVersion::put(migrations::STORAGE_VERSION);
+
+    for (user_id, account_id, metadata) in &config.users {
+        assert_id_unclaimed(user_id);
+        assert!(
+            !store::UsersByAccountId1::contains_key(account_id),
+            "Genesis user {} uses an account that is associated with another user",
+            user_id
+        );
+        let mut user = state::Users1Data::new(*account_id, Vec::new(), 0);
+        if let Some(metadata) = metadata {
+            user = user.set_metadata(metadata.clone());
+        }
+        store::Users1::insert(user_id, user);
+        store::UsersByAccountId1::insert(account_id, user_id);
+        store::RetiredIds1::insert(user_id, ());
+        store::IdStatuses1::insert(user_id, IdStatus::Taken);
+    }
+
+    for (org_id, members, metadata, frozen) in &config.orgs {
+        assert_id_unclaimed(org_id);
+        assert!(!members.is_empty(), "Genesis org {} has no members", org_id);
+        for member in members {
+            assert!(
+                store::Users1::contains_key(member),
+                "Member {} of genesis org {} is not a user",
+                member,
+                org_id
+            );
+        }
+        super::add_org_memberships(members);
+        let mut org = state::Orgs1Data::new(
+            genesis_org_account_id(org_id),
+            members.clone(),
+            Vec::new(),
+            0,
+        )
+        .set_frozen(*frozen);
+        if let Some(metadata) = metadata {
+            org = org.set_metadata(metadata.clone());
+        }
+        store::Orgs1::insert(org_id, org);
+        store::RetiredIds1::insert(org_id, ());
+        store::IdStatuses1::insert(org_id, IdStatus::Taken);
+    }
+
+    for (project_name, project_domain, metadata) in &config.projects {
+        let project_id = (project_name.clone(), project_domain.clone());
+        assert!(
+            !store::Projects1::contains_key(&project_id),
+            "Genesis project {}.{:?} is declared twice",
+            project_name,
+            project_domain
+        );
+        match project_domain {
+            ProjectDomain::Org(org_id) => {
+                let org = store::Orgs1::get(org_id)
+                    .unwrap_or_else(|| panic!("Genesis project org {} does not exist", org_id));
+                store::Orgs1::insert(org_id, org.add_project(project_name.clone()));
+            }
+            ProjectDomain::User(user_id) => {
+                let user = store::Users1::get(user_id)
+                    .unwrap_or_else(|| panic!("Genesis project user {} does not exist", user_id));
+                store::Users1::insert(user_id, user.add_project(project_name.clone()));
+            }
+        }
+        store::Projects1::insert(project_id, state::Projects1Data::new(metadata.clone(), 0));
+    }
+
+    for id in &config.retired_ids {
+        assert_id_unclaimed(id);
+        store::RetiredIds1::insert(id, ());
+        store::IdStatuses1::insert(id, IdStatus::Retired);
+    }
+
+    for (id, holder) in &config.reserved_ids {
+        assert_id_unclaimed(id);
+        assert!(
+            !store::ReservedIds1::contains_key(id),
+            "Genesis id reservation {} is declared twice",
+            id
+        );
+        store::ReservedIds1::insert(id, holder);
+        store::IdStatuses1::insert(id, IdStatus::Reserved);
+    }
+
+    for project_id in &config.retired_projects {
+        assert!(
+            !store::Projects1::contains_key(project_id)
+                && !store::RetiredProjects1::contains_key(project_id),
+            "Genesis retired project {}.{:?} is declared twice",
+            project_id.0,
+            project_id.1
+        );
+        store::RetiredProjects1::insert(project_id, ());
+    }
+
+    for (project_name, project_domain) in &config.reserved_project_names {
+        assert!(
+            !store::ReservedProjectNames1::contains_key(project_name),
+            "Genesis project name reservation {} is declared twice",
+            project_name
+        );
+        store::ReservedProjectNames1::insert(project_name, project_domain);
+    }
+}
+
+/// Test externalities with an empty genesis storage.
+#[cfg(test)]
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+    use sp_runtime::BuildStorage as _;
+
+    let genesis_config = crate::genesis::GenesisConfig {
+        pallet_balances: None,
+        pallet_sudo: None,
+        pallet_vesting: None,
+        registry: None,
+        system: None,
+    };
+    sp_io::TestExternalities::new(genesis_config.build_storage().unwrap())
+}
+
+fn assert_id_unclaimed(id: &Id) {
+    assert!(
+        !store::RetiredIds1::contains_key(id),
+        "Genesis id {} is declared twice",
+        id
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::genesis::{GenesisConfig, RegistryConfig};
+    use core::convert::TryFrom;
+    use sp_runtime::BuildStorage;
+
+    fn genesis_config(registry: RegistryConfig) -> GenesisConfig {
+        GenesisConfig {
+            pallet_balances: None,
+            pallet_sudo: None,
+            pallet_vesting: None,
+            registry: Some(registry),
+            system: None,
+        }
+    }
+
+    #[test]
+    fn build_genesis_state() {
+        let alice = Id::try_from("alice").unwrap();
+        let alice_account = sp_core::ed25519::Public::from_raw([1; 32]);
+        let monadic = Id::try_from("monadic").unwrap();
+        let radicle = ProjectName::try_from("radicle").unwrap();
+        let metadata = Bytes128::from_vec(vec![1, 2, 3]).unwrap();
+        let retired = Id::try_from("retired").unwrap();
+        let reserved = Id::try_from("reserved").unwrap();
+        let retired_project = ProjectName::try_from("retired").unwrap();
+        let config = genesis_config(RegistryConfig {
+            treasury_reward_share: Default::default(),
+            users: vec![(alice.clone(), alice_account, Some(metadata.clone()))],
+            orgs: vec![(
+                monadic.clone(),
+                vec![alice.clone()],
+                Some(metadata.clone()),
+                true,
+            )],
+            projects: vec![(
+                radicle.clone(),
+                ProjectDomain::Org(monadic.clone()),
+                metadata.clone(),
+            )],
+            retired_ids: vec![retired.clone()],
+            reserved_ids: vec![(reserved.clone(), alice_account)],
+            retired_projects: vec![(retired_project.clone(), ProjectDomain::User(alice.clone()))],
+            reserved_project_names: vec![(
+                retired_project.clone(),
+                ProjectDomain::Org(monadic.clone()),
+            )],
+        });
+
+        sp_io::TestExternalities::new(config.build_storage().unwrap()).execute_with(|| {
+            let user = store::Users1::get(&alice).unwrap();
+            assert_eq!(user.account_id(), alice_account);
+            assert_eq!(user.metadata(), Some(&metadata));
+            assert_eq!(
+                store::UsersByAccountId1::get(alice_account),
+                Some(alice.clone())
+            );
+
+            let org = store::Orgs1::get(&monadic).unwrap();
+            assert_eq!(org.account_id(), genesis_org_account_id(&monadic));
+            assert_eq!(org.members(), &vec![alice.clone()]);
+            assert_eq!(org.projects(), &vec![radicle.clone()]);
+            assert_eq!(org.metadata(), Some(&metadata));
+            assert!(org.frozen());
+
+            let project =
+                store::Projects1::get((radicle, ProjectDomain::Org(monadic.clone()))).unwrap();
+            assert_eq!(project.metadata(), &metadata);
+            assert!(store::RetiredIds1::contains_key(&alice));
+
+            assert_eq!(store::IdStatuses1::get(&retired), IdStatus::Retired);
+            assert_eq!(store::IdStatuses1::get(&reserved), IdStatus::Reserved);
+            assert_eq!(store::ReservedIds1::get(&reserved), Some(alice_account));
+            assert!(store::RetiredProjects1::contains_key((
+                retired_project.clone(),
+                ProjectDomain::User(alice)
+            )));
+            assert_eq!(
+                store::ReservedProjectNames1::get(&retired_project),
+                Some(ProjectDomain::Org(monadic))
+            );
+            assert_eq!(migrations::storage_version(), migrations::STORAGE_VERSION);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a user")]
+    fn build_genesis_state_unknown_member() {
+        let config = genesis_config(RegistryConfig {
+            treasury_reward_share: Default::default(),
+            orgs: vec![(
+                Id::try_from("monadic").unwrap(),
+                vec![Id::try_from("alice").unwrap()],
+                None,
+                false,
+            )],
+            ..Default::default()
+        });
+        sp_io::TestExternalities::new(config.build_storage().unwrap());
+    }
+}
@@ -0,0 +1,119 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Idempotency keys of registration messages.
+//!
+//! The key of a successful registration is stored together with the author for
+//! [IDEMPOTENCY_KEY_LIFETIME] blocks. A registration by the same author with a key that is still
+//! stored fails with [RegistryError::IdempotencyKeyUsed] before any registration fee is charged.
+//!
+//! Keys are scoped to the author so that nobody can make the registration of another account fail
+//! by submitting a registration with the same key first.
+
+use alloc::vec::Vec;
+use frame_support::storage::StorageMap as _;
+
+use radicle_registry_core::{IdempotencyKey, RegistryError, IDEMPOTENCY_KEY_LIFETIME};
+
+use super::store;
+use crate::{AccountId, BlockNumber};
+
+/// Fail with [RegistryError::IdempotencyKeyUsed] if `key` was recorded for `author` and not pruned
+/// yet.
+pub fn ensure_unused(author: AccountId, key: &Option<IdempotencyKey>) -> Result<(), RegistryError> {
+    match key {
+        Some(key) if store::IdempotencyKeys1::contains_key((author, *key)) => {
+            Err(RegistryError::IdempotencyKeyUsed)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Record the key of a successful registration by `author` in the current block.
+pub fn record(author: AccountId, key: Option<IdempotencyKey>) {
+    if let Some(key) = key {
+        let block_number = frame_system::Module::<crate::Runtime>::block_number();
+        store::IdempotencyKeys1::insert((author, key), block_number);
+        store::IdempotencyKeysByBlock1::mutate(block_number, |keys| keys.push((author, key)));
+    }
+}
+
+/// Remove the keys that were recorded [IDEMPOTENCY_KEY_LIFETIME] blocks before `block_number` and
+/// return the number of removed keys.
+///
+/// Called from `on_initialize`.
+pub fn prune(block_number: BlockNumber) -> u32 {
+    match block_number.checked_sub(IDEMPOTENCY_KEY_LIFETIME) {
+        Some(expired_block) => {
+            let keys: Vec<(AccountId, IdempotencyKey)> =
+                store::IdempotencyKeysByBlock1::take(expired_block);
+            for key in &keys {
+                store::IdempotencyKeys1::remove(key);
+            }
+            keys.len() as u32
+        }
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{registry::genesis::new_test_ext, Runtime};
+
+    #[test]
+    fn keys_expire() {
+        new_test_ext().execute_with(|| {
+            let author = AccountId::from_raw([1; 32]);
+            let key = IdempotencyKey::repeat_byte(1);
+            assert_eq!(ensure_unused(author, &None), Ok(()));
+            assert_eq!(ensure_unused(author, &Some(key)), Ok(()));
+
+            frame_system::Module::<Runtime>::set_block_number(1);
+            record(author, Some(key));
+            assert_eq!(
+                ensure_unused(author, &Some(key)),
+                Err(RegistryError::IdempotencyKeyUsed)
+            );
+
+            assert_eq!(prune(IDEMPOTENCY_KEY_LIFETIME), 0);
+            assert_eq!(
+                ensure_unused(author, &Some(key)),
+                Err(RegistryError::IdempotencyKeyUsed)
+            );
+
+            assert_eq!(prune(IDEMPOTENCY_KEY_LIFETIME + 1), 1);
+            assert_eq!(ensure_unused(author, &Some(key)), Ok(()));
+            assert!(!store::IdempotencyKeysByBlock1::contains_key(1));
+        });
+    }
+
+    #[test]
+    fn keys_are_scoped_to_author() {
+        new_test_ext().execute_with(|| {
+            let author = AccountId::from_raw([1; 32]);
+            let other = AccountId::from_raw([2; 32]);
+            let key = IdempotencyKey::repeat_byte(1);
+
+            frame_system::Module::<Runtime>::set_block_number(1);
+            record(author, Some(key));
+            assert_eq!(
+                ensure_unused(author, &Some(key)),
+                Err(RegistryError::IdempotencyKeyUsed)
+            );
+            assert_eq!(ensure_unused(other, &Some(key)), Ok(()));
+        });
+    }
+}
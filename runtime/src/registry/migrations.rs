@@ -0,0 +1,367 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the registry module that run when a new runtime is enacted.
+//!
+//! A migration is added to [MIGRATIONS] together with the storage changes that it migrates to.
+//! Every executed migration is recorded in [store::MigrationHistory1] and announced with an
+//! [Event::MigrationExecuted] event. The storage version of the registry module is stored in
+//! [store::PalletStorageVersion]. Chains that ran migrations before the version was stored derive
+//! it from the last recorded migration.
+//!
+//! Migrations can be tested with the client emulator by bringing the storage into the old format
+//! and enacting a runtime upgrade.
+
+use alloc::vec::Vec;
+use frame_support::{
+    storage::{IterableStorageMap as _, StorageMap as _, StorageValue as _},
+    traits::Get as _,
+    weights::Weight,
+};
+
+use radicle_registry_core::{state, IdStatus};
+
+use super::{store, Event};
+
+/// Storage version of a chain that has never run a migration.
+pub const INITIAL_STORAGE_VERSION: u32 = 1;
+
+/// Storage version after all [MIGRATIONS] have run. New chains start with this version.
+pub const STORAGE_VERSION: u32 = INITIAL_STORAGE_VERSION + MIGRATIONS.len() as u32;
+
+/// A step that migrates the registry storage from `from_version` to `from_version + 1`.
+pub struct Migration {
+    pub from_version: u32,
+
+    /// Migrate the storage and return the number of migrated storage items and the storage
+    /// accesses of the migration.
+    pub migrate: fn() -> MigrationOutcome,
+}
+
+/// Result of running the `migrate` function of a [Migration].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MigrationOutcome {
+    /// Number of migrated storage items. Recorded in [store::MigrationHistory1].
+    pub items_migrated: u32,
+    /// Number of storage reads.
+    pub reads: Weight,
+    /// Number of storage writes.
+    pub writes: Weight,
+}
+
+/// All migrations ordered by `from_version`.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from_version: 1,
+        migrate: index_users_by_account,
+    },
+    Migration {
+        from_version: 2,
+        migrate: populate_id_statuses,
+    },
+    Migration {
+        from_version: 3,
+        migrate: index_org_memberships,
+    },
+];
+
+/// Populate [store::UsersByAccountId1] with all users registered before the index was introduced.
+fn index_users_by_account() -> MigrationOutcome {
+    let mut outcome = MigrationOutcome::default();
+    for (user_id, user) in store::Users1::iter() {
+        store::UsersByAccountId1::insert(user.account_id(), user_id);
+        outcome.items_migrated += 1;
+        outcome.reads += 1;
+        outcome.writes += 1;
+    }
+    outcome
+}
+
+/// Populate [store::IdStatuses1] with the status of all ids claimed or reserved before the
+/// statuses were stored.
+///
+/// Ids in [store::RetiredIds1] were claimed at some point and are retired unless they are still
+/// taken by a user or an org.
+fn populate_id_statuses() -> MigrationOutcome {
+    let mut outcome = MigrationOutcome::default();
+    for (id, _) in store::ReservedIds1::iter() {
+        store::IdStatuses1::insert(id, IdStatus::Reserved);
+        outcome.items_migrated += 1;
+        outcome.reads += 1;
+        outcome.writes += 1;
+    }
+    for (id, ()) in store::RetiredIds1::iter() {
+        store::IdStatuses1::insert(id, IdStatus::Retired);
+        outcome.items_migrated += 1;
+        outcome.reads += 1;
+        outcome.writes += 1;
+    }
+    for (id, _) in store::Users1::iter() {
+        store::IdStatuses1::insert(id, IdStatus::Taken);
+        outcome.reads += 1;
+        outcome.writes += 1;
+    }
+    for (id, _) in store::Orgs1::iter() {
+        store::IdStatuses1::insert(id, IdStatus::Taken);
+        outcome.reads += 1;
+        outcome.writes += 1;
+    }
+    outcome
+}
+
+/// Populate [store::OrgMemberships1] with the members of all orgs registered before the index was
+/// introduced.
+fn index_org_memberships() -> MigrationOutcome {
+    let mut outcome = MigrationOutcome::default();
+    for (_, org) in store::Orgs1::iter() {
+        super::add_org_memberships(org.members());
+        let members = org.members().len() as Weight;
+        outcome.items_migrated += 1;
+        outcome.reads += 1 + members;
+        outcome.writes += members;
+    }
+    outcome
+}
+
+/// Run all migrations that have not been executed yet and return their weight.
+///
+/// Called from `on_runtime_upgrade`.
+pub fn on_runtime_upgrade() -> Weight {
+    run_migrations(MIGRATIONS)
+}
+
+/// Deposit [Event::MigrationExecuted] for all migrations executed by [on_runtime_upgrade] and
+/// return the number of deposited events.
+///
+/// Called from `on_initialize`. We cannot deposit the events in `on_runtime_upgrade` because the
+/// system module clears all events when it initializes the block, which happens after
+/// `on_runtime_upgrade`.
+pub fn deposit_migration_events() -> u32 {
+    let executed_migrations = store::ExecutedMigrations::take();
+    for migration in &executed_migrations {
+        let event = Event::MigrationExecuted(
+            migration.from_version(),
+            migration.to_version(),
+            migration.items_migrated(),
+        );
+        frame_system::Module::<crate::Runtime>::deposit_event(crate::Event::from(event));
+    }
+    executed_migrations.len() as u32
+}
+
+/// Storage version of the registry module on chain.
+pub fn storage_version() -> u32 {
+    store::PalletStorageVersion::get().unwrap_or_else(|| {
+        store::MigrationHistory1::get()
+            .last()
+            .map(|migration| migration.to_version())
+            .unwrap_or(INITIAL_STORAGE_VERSION)
+    })
+}
+
+/// Run the migrations that start at the current storage version and return the weight of the
+/// storage reads and writes of the migrations and of recording them.
+fn run_migrations(migrations: &[Migration]) -> Weight {
+    let mut reads: Weight = 0;
+    let mut writes: Weight = 0;
+    let mut executed = Vec::new();
+    for migration in migrations {
+        // `storage_version` reads the stored version and at most the migration history.
+        reads += 2;
+        if migration.from_version != storage_version() {
+            continue;
+        }
+        let outcome = (migration.migrate)();
+        let record = state::MigrationHistory1Data::new(
+            migration.from_version,
+            migration.from_version + 1,
+            outcome.items_migrated,
+        );
+        store::MigrationHistory1::mutate(|history| history.push(record.clone()));
+        store::PalletStorageVersion::put(migration.from_version + 1);
+        executed.push(record);
+        reads += outcome.reads + 1;
+        writes += outcome.writes + 2;
+    }
+    if !executed.is_empty() {
+        store::ExecutedMigrations::put(executed);
+        writes += 1;
+    }
+    <crate::Runtime as frame_system::Trait>::DbWeight::get().reads_writes(reads, writes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{event, registry::genesis::new_test_ext, Runtime};
+    use core::convert::TryFrom;
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from_version: 1,
+            migrate: || MigrationOutcome {
+                items_migrated: 3,
+                reads: 3,
+                writes: 3,
+            },
+        },
+        Migration {
+            from_version: 2,
+            migrate: || MigrationOutcome {
+                items_migrated: 5,
+                reads: 5,
+                writes: 10,
+            },
+        },
+    ];
+
+    #[test]
+    fn migrations_run_once() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(storage_version(), INITIAL_STORAGE_VERSION);
+
+            let weight = run_migrations(TEST_MIGRATIONS);
+            let db_weight = <Runtime as frame_system::Trait>::DbWeight::get();
+            assert_eq!(
+                weight,
+                db_weight.reads_writes(2 * 2 + 3 + 5 + 2, 3 + 10 + 2 * 2 + 1)
+            );
+            let history = vec![
+                state::MigrationHistory1Data::new(1, 2, 3),
+                state::MigrationHistory1Data::new(2, 3, 5),
+            ];
+            assert_eq!(store::MigrationHistory1::get(), history);
+            assert_eq!(storage_version(), 3);
+
+            let weight = run_migrations(TEST_MIGRATIONS);
+            assert_eq!(weight, db_weight.reads(2 * 2));
+            assert_eq!(store::MigrationHistory1::get(), history);
+        });
+    }
+
+    #[test]
+    fn storage_version_from_history() {
+        new_test_ext().execute_with(|| {
+            store::MigrationHistory1::put(vec![state::MigrationHistory1Data::new(1, 2, 0)]);
+            assert_eq!(storage_version(), 2);
+
+            run_migrations(TEST_MIGRATIONS);
+            assert_eq!(store::PalletStorageVersion::get(), Some(3));
+            assert_eq!(
+                store::MigrationHistory1::get(),
+                vec![
+                    state::MigrationHistory1Data::new(1, 2, 0),
+                    state::MigrationHistory1Data::new(2, 3, 5),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn index_users_by_account_migration() {
+        new_test_ext().execute_with(|| {
+            let account_id = sp_core::ed25519::Public::from_raw([1; 32]);
+            let user_id = radicle_registry_core::Id::try_from("alice").unwrap();
+            store::Users1::insert(
+                user_id.clone(),
+                state::Users1Data::new(account_id, Vec::new(), 0),
+            );
+
+            run_migrations(&MIGRATIONS[..1]);
+            assert_eq!(store::UsersByAccountId1::get(account_id), Some(user_id));
+            assert_eq!(
+                store::MigrationHistory1::get(),
+                vec![state::MigrationHistory1Data::new(1, 2, 1)]
+            );
+        });
+    }
+
+    #[test]
+    fn populate_id_statuses_migration() {
+        new_test_ext().execute_with(|| {
+            let account_id = sp_core::ed25519::Public::from_raw([1; 32]);
+            let taken = radicle_registry_core::Id::try_from("alice").unwrap();
+            let retired = radicle_registry_core::Id::try_from("bob").unwrap();
+            let reserved = radicle_registry_core::Id::try_from("carol").unwrap();
+            store::Users1::insert(&taken, state::Users1Data::new(account_id, Vec::new(), 0));
+            store::RetiredIds1::insert(&taken, ());
+            store::RetiredIds1::insert(&retired, ());
+            store::ReservedIds1::insert(&reserved, account_id);
+
+            populate_id_statuses();
+            assert_eq!(store::IdStatuses1::get(taken), IdStatus::Taken);
+            assert_eq!(store::IdStatuses1::get(retired), IdStatus::Retired);
+            assert_eq!(store::IdStatuses1::get(reserved), IdStatus::Reserved);
+            assert_eq!(
+                store::IdStatuses1::get(radicle_registry_core::Id::try_from("dave").unwrap()),
+                IdStatus::Available
+            );
+        });
+    }
+
+    #[test]
+    fn index_org_memberships_migration() {
+        new_test_ext().execute_with(|| {
+            let alice = radicle_registry_core::Id::try_from("alice").unwrap();
+            let bob = radicle_registry_core::Id::try_from("bob").unwrap();
+            for (org_id, members) in &[
+                ("org1", vec![alice.clone()]),
+                ("org2", vec![alice.clone(), bob.clone()]),
+            ] {
+                store::Orgs1::insert(
+                    radicle_registry_core::Id::try_from(*org_id).unwrap(),
+                    state::Orgs1Data::new(
+                        sp_core::ed25519::Public::from_raw([1; 32]),
+                        members.clone(),
+                        Vec::new(),
+                        0,
+                    ),
+                );
+            }
+
+            assert_eq!(
+                index_org_memberships(),
+                MigrationOutcome {
+                    items_migrated: 2,
+                    reads: 5,
+                    writes: 3,
+                }
+            );
+            assert_eq!(store::OrgMemberships1::get(alice), 2);
+            assert_eq!(store::OrgMemberships1::get(bob), 1);
+        });
+    }
+
+    #[test]
+    fn migration_events() {
+        new_test_ext().execute_with(|| {
+            run_migrations(&TEST_MIGRATIONS[..1]);
+            frame_system::Module::<Runtime>::set_block_number(1);
+            assert_eq!(deposit_migration_events(), 1);
+
+            let events = frame_system::Module::<Runtime>::events()
+                .into_iter()
+                .map(|record| record.event)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                events,
+                vec![crate::Event::from(event::Registry::MigrationExecuted(
+                    1, 2, 3
+                ))]
+            );
+            assert!(store::ExecutedMigrations::get().is_empty());
+        });
+    }
+}
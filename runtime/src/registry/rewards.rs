@@ -0,0 +1,120 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Crediting of block rewards.
+//!
+//! Every block [BLOCK_REWARD] is credited to the block author. If [store::TreasuryRewardShare] is
+//! configured, that share of the reward is credited to the [treasury_account] instead.
+
+use frame_support::{storage::StorageValue as _, traits::Currency as _};
+use sp_runtime::{traits::AccountIdConversion as _, ModuleId};
+
+use radicle_registry_core::{AccountId, Balance};
+
+use super::{store, Event, BLOCK_REWARD};
+
+/// Identifier from which the [treasury_account] is derived.
+const TREASURY_MODULE_ID: ModuleId = ModuleId(*b"rad/trsy");
+
+/// Account that receives the treasury share of block rewards.
+pub fn treasury_account() -> AccountId {
+    TREASURY_MODULE_ID.into_account()
+}
+
+/// Credit the block reward to the block author and the treasury according to
+/// [store::TreasuryRewardShare] and deposit [Event::BlockRewardCredited] events.
+///
+/// Called from `on_finalize`.
+pub fn credit_block_reward(block_author: AccountId) {
+    let treasury_reward = store::TreasuryRewardShare::get() * BLOCK_REWARD;
+    credit(block_author, BLOCK_REWARD - treasury_reward);
+    if treasury_reward > 0 {
+        credit(treasury_account(), treasury_reward);
+    }
+}
+
+fn credit(account_id: AccountId, amount: Balance) {
+    let imbalance = crate::runtime::Balances::deposit_creating(&account_id, amount);
+    drop(imbalance);
+    let event = Event::BlockRewardCredited(account_id, amount);
+    frame_system::Module::<crate::Runtime>::deposit_event(crate::Event::from(event));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{event, registry::genesis, Runtime};
+    use sp_runtime::Permill;
+
+    type Balances = crate::runtime::Balances;
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut test_ext = genesis::new_test_ext();
+        test_ext.execute_with(|| frame_system::Module::<Runtime>::set_block_number(1));
+        test_ext
+    }
+
+    fn events() -> Vec<crate::Event> {
+        frame_system::Module::<Runtime>::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect()
+    }
+
+    #[test]
+    fn full_reward_to_block_author() {
+        new_test_ext().execute_with(|| {
+            let block_author = AccountId::from_raw([1u8; 32]);
+            credit_block_reward(block_author);
+
+            assert_eq!(Balances::free_balance(&block_author), BLOCK_REWARD);
+            assert_eq!(Balances::free_balance(&treasury_account()), 0);
+            assert_eq!(
+                events(),
+                vec![crate::Event::from(event::Registry::BlockRewardCredited(
+                    block_author,
+                    BLOCK_REWARD
+                ))]
+            );
+        });
+    }
+
+    #[test]
+    fn reward_split_with_treasury() {
+        new_test_ext().execute_with(|| {
+            store::TreasuryRewardShare::put(Permill::from_percent(10));
+            let block_author = AccountId::from_raw([1u8; 32]);
+            credit_block_reward(block_author);
+
+            let treasury_reward = BLOCK_REWARD / 10;
+            let author_reward = BLOCK_REWARD - treasury_reward;
+            assert_eq!(Balances::free_balance(&block_author), author_reward);
+            assert_eq!(Balances::free_balance(&treasury_account()), treasury_reward);
+            assert_eq!(
+                events(),
+                vec![
+                    crate::Event::from(event::Registry::BlockRewardCredited(
+                        block_author,
+                        author_reward
+                    )),
+                    crate::Event::from(event::Registry::BlockRewardCredited(
+                        treasury_account(),
+                        treasury_reward
+                    )),
+                ]
+            );
+        });
+    }
+}
@@ -0,0 +1,162 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights of the registry dispatchables.
+//!
+//! Every weight consists of an execution part and a database part. The database part follows the
+//! storage reads and writes of the dispatchable and is priced with the [RocksDbWeight] configured
+//! for the runtime.
+//!
+//! The execution parts are placeholders that have not been measured. They must be replaced with
+//! the results of the benchmarks in `benchmarking.rs` on reference hardware before the weights
+//! can protect a chain against expensive blocks. See `DEVELOPING.md` for how to run the
+//! benchmarks. Rerun them and update the values here when the implementation of a dispatchable
+//! changes.
+//!
+//! [RocksDbWeight]: frame_support::weights::constants::RocksDbWeight
+
+use frame_support::{traits::Get, weights::Weight};
+
+/// Weight of `reads` storage reads and `writes` storage writes.
+fn db(reads: Weight, writes: Weight) -> Weight {
+    <crate::Runtime as frame_system::Trait>::DbWeight::get().reads_writes(reads, writes)
+}
+
+pub fn register_project() -> Weight {
+    45_000_000 + db(7, 4)
+}
+
+pub fn register_member() -> Weight {
+    30_000_000 + db(5, 2)
+}
+
+pub fn register_org() -> Weight {
+    60_000_000 + db(9, 9)
+}
+
+pub fn unregister_org() -> Weight {
+    25_000_000 + db(3, 3)
+}
+
+pub fn dissolve_org() -> Weight {
+    55_000_000 + db(6, 6)
+}
+
+pub fn register_user() -> Weight {
+    50_000_000 + db(6, 8)
+}
+
+pub fn unregister_user() -> Weight {
+    30_000_000 + db(3, 3)
+}
+
+pub fn transfer_from_org() -> Weight {
+    55_000_000 + db(5, 3)
+}
+
+pub fn transfer() -> Weight {
+    40_000_000 + db(2, 2)
+}
+
+pub fn propose_treasury_spend() -> Weight {
+    25_000_000 + db(1, 3)
+}
+
+pub fn approve_treasury_spend() -> Weight {
+    50_000_000 + db(3, 4)
+}
+
+pub fn reject_treasury_spend() -> Weight {
+    15_000_000 + db(1, 1)
+}
+
+pub fn cancel_treasury_spend() -> Weight {
+    20_000_000 + db(1, 1)
+}
+
+pub fn unregister_project() -> Weight {
+    35_000_000 + db(4, 5)
+}
+
+pub fn reserve_id() -> Weight {
+    20_000_000 + db(1, 3)
+}
+
+pub fn cancel_id_reservation() -> Weight {
+    15_000_000 + db(1, 3)
+}
+
+pub fn reserve_project_name() -> Weight {
+    20_000_000 + db(1, 1)
+}
+
+pub fn cancel_project_name_reservation() -> Weight {
+    15_000_000 + db(1, 1)
+}
+
+pub fn transfer_project_ownership() -> Weight {
+    30_000_000 + db(5, 1)
+}
+
+pub fn accept_project_ownership() -> Weight {
+    45_000_000 + db(6, 6)
+}
+
+pub fn update_org() -> Weight {
+    25_000_000 + db(2, 1)
+}
+
+pub fn update_user() -> Weight {
+    15_000_000 + db(1, 1)
+}
+
+pub fn transfer_user_id() -> Weight {
+    25_000_000 + db(3, 3)
+}
+
+pub fn transfer_org_membership() -> Weight {
+    30_000_000 + db(5, 3)
+}
+
+pub fn transfer_project() -> Weight {
+    45_000_000 + db(7, 7)
+}
+
+pub fn rename_project() -> Weight {
+    40_000_000 + db(6, 5)
+}
+
+pub fn freeze_org() -> Weight {
+    20_000_000 + db(1, 1)
+}
+
+pub fn unfreeze_org() -> Weight {
+    20_000_000 + db(1, 1)
+}
+
+pub fn set_block_author() -> Weight {
+    5_000_000 + db(1, 1)
+}
+
+/// Weight of `on_initialize` that deposited `migration_events` events for executed migrations and
+/// pruned `pruned_keys` idempotency keys.
+///
+/// Taking the executed migrations and the keys of the expired block reads and removes one item
+/// each. Every event is added to the events of the system module and every pruned key is removed
+/// from the idempotency keys.
+pub fn on_initialize(migration_events: u32, pruned_keys: u32) -> Weight {
+    let items = Weight::from(migration_events) + Weight::from(pruned_keys);
+    db(2 + items, 2 + items)
+}
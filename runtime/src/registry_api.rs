@@ -0,0 +1,44 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API that gives node RPC methods read access to the registry state.
+//!
+//! The API is implemented for [crate::Runtime] in the `runtime::api` module. The node exposes the
+//! functions as `registry_*` JSON-RPC methods so that clients do not need to know how the
+//! storage keys of the registry are derived.
+
+use alloc::vec::Vec;
+use radicle_registry_core::{state, Id, IdStatus, ProjectId};
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the registry state.
+    pub trait RegistryApi {
+        /// Return the org with the given id if it is registered.
+        fn get_org(id: Id) -> Option<state::Orgs1Data>;
+
+        /// Return the user with the given id if it is registered.
+        fn get_user(id: Id) -> Option<state::Users1Data>;
+
+        /// Return the project with the given id if it is registered.
+        fn get_project(project_id: ProjectId) -> Option<state::Projects1Data>;
+
+        /// Return the members of the org with the given id or `None` if the org is not
+        /// registered.
+        fn list_org_members(id: Id) -> Option<Vec<Id>>;
+
+        /// Return whether the org or user id is available, taken, retired or reserved.
+        fn id_status(id: Id) -> IdStatus;
+    }
+}
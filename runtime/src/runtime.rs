@@ -14,7 +14,10 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use alloc::{boxed::Box, vec::Vec};
-use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use frame_support::{
+    construct_runtime, parameter_types,
+    weights::{constants::RocksDbWeight, Weight},
+};
 use frame_system as system;
 use radicle_registry_core::{state::AccountTransactionIndex, Balance};
 use sp_runtime::{traits::Block as BlockT, Perbill};
@@ -84,7 +87,7 @@ impl frame_system::Trait for Runtime {
     type AvailableBlockRatio = AvailableBlockRatio;
 
     /// The weight of database operations that the runtime can invoke.
-    type DbWeight = ();
+    type DbWeight = RocksDbWeight;
 
     /// The base weight of executing a block, independent of the transactions in the block.
     type BlockExecutionWeight = ();
@@ -156,7 +159,21 @@ impl pallet_sudo::Trait for Runtime {
     type Call = Call;
 }
 
-impl registry::Trait for Runtime {}
+parameter_types! {
+    /// The minimum amount that can be transferred with a vesting schedule.
+    pub const MinVestedTransfer: Balance = 1_000;
+}
+
+impl pallet_vesting::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type BlockNumberToBalance = sp_runtime::traits::ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+}
+
+impl registry::Trait for Runtime {
+    type Event = Event;
+}
 
 construct_runtime!(
         pub enum Runtime where
@@ -169,6 +186,7 @@ construct_runtime!(
                 RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Module, Call, Storage},
                 Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
                 Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
-                Registry: registry::{Module, Call, Storage, Inherent},
+                Registry: registry::{Module, Call, Storage, Event, Config, Inherent},
+                Vesting: pallet_vesting::{Module, Call, Storage, Event<T>, Config<T>},
         }
 );
@@ -15,7 +15,7 @@
 
 //! Implements Substrate runtime APIs and provide a function based interface for the runtime APIs.
 use alloc::vec::Vec;
-use frame_support::{ensure, fail, traits::Randomness};
+use frame_support::{ensure, fail, storage::StorageMap as _, traits::Randomness};
 use sp_core::OpaqueMetadata;
 use sp_runtime::traits::Block as BlockT;
 use sp_runtime::{
@@ -24,6 +24,8 @@ use sp_runtime::{
 };
 use sp_version::RuntimeVersion;
 
+use radicle_registry_core::{state, Id, IdStatus, ProjectId};
+
 use super::{
     registry, AllModules, Block, Call, Header, InherentDataExt, RandomnessCollectiveFlip, Runtime,
     UncheckedExtrinsic, VERSION,
@@ -84,7 +86,9 @@ fn validate_extrinsic_call(xt: &UncheckedExtrinsic) -> Result<(), InvalidTransac
         Call::RandomnessCollectiveFlip(_) => fail!(FOBIDDEN_CALL_ERROR),
 
         // Allowed calls for signed extrinsics.
-        Call::Registry(_) | Call::Sudo(_) => ensure!(xt.signature.is_some(), UNSGINED_CALL_ERROR),
+        Call::Registry(_) | Call::Sudo(_) | Call::Vesting(_) => {
+            ensure!(xt.signature.is_some(), UNSGINED_CALL_ERROR)
+        }
     }
 
     Ok(())
@@ -165,9 +169,62 @@ sp_api::impl_runtime_apis! {
         }
     }
 
+    impl crate::registry_api::RegistryApi<Block> for Runtime {
+        fn get_org(id: Id) -> Option<state::Orgs1Data> {
+            registry::store::Orgs1::get(id)
+        }
+
+        fn get_user(id: Id) -> Option<state::Users1Data> {
+            registry::store::Users1::get(id)
+        }
+
+        fn get_project(project_id: ProjectId) -> Option<state::Projects1Data> {
+            registry::store::Projects1::get(project_id)
+        }
+
+        fn list_org_members(id: Id) -> Option<Vec<Id>> {
+            registry::store::Orgs1::get(id).map(|org| org.members().clone())
+        }
+
+        fn id_status(id: Id) -> IdStatus {
+            registry::store::IdStatuses1::get(id)
+        }
+    }
+
     impl sp_consensus_pow::TimestampApi<Block, u64> for Runtime {
         fn timestamp() -> u64 {
             pallet_timestamp::Module::<Runtime>::get()
         }
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{add_benchmark, BenchmarkBatch, Benchmarking};
+            type Registry = registry::benchmarking::Module<Runtime>;
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (
+                &pallet,
+                &benchmark,
+                &lowest_range_values,
+                &highest_range_values,
+                &steps,
+                repeat,
+            );
+            add_benchmark!(params, batches, b"registry", Registry);
+
+            if batches.is_empty() {
+                return Err("Benchmark not found for this pallet.".into());
+            }
+            Ok(batches)
+        }
+    }
 }
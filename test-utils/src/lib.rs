@@ -14,6 +14,8 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 //! Miscellaneous helpers used throughout Registry tests.
+//!
+//! All random values are generated with the seeded generator from the [rng] module.
 
 use std::convert::TryFrom;
 
@@ -23,6 +25,11 @@ use rand::Rng;
 use radicle_registry_client::*;
 use radicle_registry_core::state;
 
+pub mod rng;
+pub mod scenario;
+pub use rng::with_rng;
+pub use scenario::*;
+
 /// Submit a transaction and wait for it to be successfully applied.
 ///
 /// Panics if submission errors.
@@ -33,7 +40,7 @@ pub async fn submit_ok_with_fee<Message_: Message>(
     fee: Balance,
 ) -> TransactionIncluded {
     client
-        .sign_and_submit_message(&author, message, fee)
+        .sign_and_submit_message(author, message, fee)
         .await
         .unwrap()
         .await
@@ -70,12 +77,12 @@ pub async fn create_project(
 }
 
 pub fn random_id() -> Id {
-    let size = rand::thread_rng().gen_range(1, 33);
+    let size = with_rng(|rng| rng.gen_range(1, 33));
     Id::try_from(random_alnum_string(size).to_lowercase()).unwrap()
 }
 
 pub fn random_project_name() -> ProjectName {
-    let size = rand::thread_rng().gen_range(1, 33);
+    let size = with_rng(|rng| rng.gen_range(1, 33));
     ProjectName::try_from(random_alnum_string(size).to_lowercase()).unwrap()
 }
 
@@ -83,6 +90,7 @@ pub fn random_project_name() -> ProjectName {
 pub fn random_register_org_message() -> message::RegisterOrg {
     message::RegisterOrg {
         org_id: random_id(),
+        idempotency_key: None,
     }
 }
 
@@ -91,7 +99,8 @@ pub fn random_register_project_message(domain: &ProjectDomain) -> message::Regis
     message::RegisterProject {
         project_name: random_project_name(),
         project_domain: domain.clone(),
-        metadata: Bytes128::random(),
+        metadata: random_bytes128(),
+        idempotency_key: None,
     }
 }
 
@@ -99,6 +108,7 @@ pub fn random_register_project_message(domain: &ProjectDomain) -> message::Regis
 pub fn random_register_user_message() -> message::RegisterUser {
     message::RegisterUser {
         user_id: random_id(),
+        idempotency_key: None,
     }
 }
 
@@ -106,9 +116,25 @@ pub fn root_key_pair() -> ed25519::Pair {
     ed25519::Pair::from_string("//Alice", None).unwrap()
 }
 
+/// Generate a random key pair.
+pub fn random_key_pair() -> ed25519::Pair {
+    let seed = with_rng(|rng| rng.gen::<[u8; 32]>());
+    ed25519::Pair::from_seed(&seed)
+}
+
+/// Generate a random [Bytes128] with the maximum length.
+pub fn random_bytes128() -> Bytes128 {
+    let bytes = with_rng(|rng| (0..128).map(|_| rng.gen()).collect());
+    Bytes128::from_vec(bytes).unwrap()
+}
+
+pub fn random_idempotency_key() -> IdempotencyKey {
+    IdempotencyKey::from(with_rng(|rng| rng.gen::<[u8; 32]>()))
+}
+
 /// Generate a random a key pair and equip the account with some funds.
 pub async fn key_pair_with_funds(client: &Client) -> ed25519::Pair {
-    let key_pair = ed25519::Pair::generate().0;
+    let key_pair = random_key_pair();
 
     transfer(&client, &root_key_pair(), key_pair.public(), 100_000).await;
 
@@ -129,6 +155,7 @@ pub async fn associate_key_pair_with_random_user(client: &Client, key_pair: &ed2
     let user_id = random_id();
     let register_user_message = message::RegisterUser {
         user_id: user_id.clone(),
+        idempotency_key: None,
     };
     let tx_applied = submit_ok(&client, &key_pair, register_user_message).await;
     assert_eq!(tx_applied.result, Ok(()));
@@ -137,10 +164,11 @@ pub async fn associate_key_pair_with_random_user(client: &Client, key_pair: &ed2
 }
 
 pub fn random_alnum_string(size: usize) -> String {
-    rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(size)
-        .collect::<String>()
+    with_rng(|rng| {
+        rng.sample_iter(&Alphanumeric)
+            .take(size)
+            .collect::<String>()
+    })
 }
 
 /// Check if the user with the given id exists in the chain state.
@@ -154,7 +182,7 @@ pub async fn user_exists(client: &Client, user_id: Id) -> bool {
 }
 
 pub fn random_balance() -> Balance {
-    rand::thread_rng().gen_range(20, 100)
+    with_rng(|rng| rng.gen_range(20, 100))
 }
 
 pub async fn transfer(
@@ -200,7 +228,7 @@ pub async fn register_random_org(
     submit_ok(&client, author, register_org).await;
 
     let org = client.get_org(org_id.clone()).await.unwrap().unwrap();
-    transfer(&client, &author, org.account_id(), 1000).await;
+    transfer(&client, &author, org.account_id(), ORG_FUNDS).await;
 
     (org_id, org)
 }
@@ -0,0 +1,68 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Seeded random number generator for all random test values.
+//!
+//! Every test thread uses its own [StdRng]. The seed of a thread is derived from the `TEST_SEED`
+//! environment variable, or a random seed if the variable is not set, and the name of the thread.
+//! The test harness names the thread of every test after the test, so tests that run in parallel
+//! generate different values while every test generates the same values for the same `TEST_SEED`.
+//! The seeds are printed when a thread first uses the generator. The test harness only shows the
+//! output of failed tests, so a failure can be reproduced by running the failed test with
+//! `TEST_SEED` set to the printed value.
+//!
+//! Values are only reproducible if the test generates them in the same order.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash as _, Hasher as _};
+
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+
+/// Environment variable that provides the seed.
+pub const TEST_SEED_VAR: &str = "TEST_SEED";
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(new_rng());
+}
+
+/// Call `f` with the random number generator of the current thread.
+pub fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+fn new_rng() -> StdRng {
+    let test_seed = match std::env::var(TEST_SEED_VAR) {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be an unsigned integer", TEST_SEED_VAR)),
+        Err(_) => rand::thread_rng().gen(),
+    };
+    let thread = std::thread::current();
+    let thread_name = thread.name().unwrap_or_default();
+    let seed = thread_seed(test_seed, thread_name);
+    println!(
+        "Random values are generated with {}={} (seed {} for thread {:?})",
+        TEST_SEED_VAR, test_seed, seed, thread_name
+    );
+    StdRng::seed_from_u64(seed)
+}
+
+/// Derive the seed of the thread with `thread_name` from `test_seed`.
+fn thread_seed(test_seed: u64, thread_name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    thread_name.hash(&mut hasher);
+    test_seed ^ hasher.finish()
+}
@@ -0,0 +1,213 @@
+// Radicle Registry
+// Copyright (C) 2019 Monadic GmbH <radicle@monadic.xyz>
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License version 3 as
+// published by the Free Software Foundation.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Declarative setup of multi-actor test scenarios.
+//!
+//! A [Scenario] describes users, orgs and projects by alias. [Scenario::apply] submits all the
+//! transactions required to bring them into existence in order and returns a [World] that maps
+//! the aliases to the actual key pairs and ids on chain.
+//!
+//! ```no_run
+//! # use radicle_registry_client::*;
+//! # use radicle_registry_test_utils::*;
+//! # async fn example(client: Client) {
+//! let world = Scenario::new()
+//!     .user("alice")
+//!     .user("bob")
+//!     .org("monadic", &["alice", "bob"])
+//!     .project("radicle", Domain::Org("monadic"))
+//!     .apply(&client)
+//!     .await;
+//! let alice = world.user("alice");
+//! let org_id = world.org_id("monadic");
+//! # }
+//! ```
+//!
+//! All ids and project names are random so that scenarios can be applied repeatedly to the same
+//! chain.
+
+use std::collections::HashMap;
+
+use radicle_registry_client::*;
+
+use crate::*;
+
+/// The amount every org account is funded with after registration.
+pub const ORG_FUNDS: Balance = 1000;
+
+/// Owner of a project in a [Scenario], referenced by alias.
+#[derive(Clone, Copy, Debug)]
+pub enum Domain<'a> {
+    Org(&'a str),
+    User(&'a str),
+}
+
+#[derive(Clone, Debug)]
+enum Step {
+    User {
+        alias: String,
+    },
+    Org {
+        alias: String,
+        members: Vec<String>,
+    },
+    Project {
+        alias: String,
+        domain: (DomainKind, String),
+    },
+}
+
+#[derive(Clone, Copy, Debug)]
+enum DomainKind {
+    Org,
+    User,
+}
+
+/// Builder for a set of users, orgs and projects. See the [module documentation](self).
+#[derive(Clone, Debug, Default)]
+pub struct Scenario {
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a user with a freshly generated and funded key pair.
+    pub fn user(mut self, alias: &str) -> Self {
+        self.steps.push(Step::User {
+            alias: alias.to_string(),
+        });
+        self
+    }
+
+    /// Add an org with the given members. The first member registers the org and then registers
+    /// the remaining members. The org account is funded with [ORG_FUNDS].
+    ///
+    /// Panics on [Scenario::apply] if `members` is empty or references an unknown user.
+    pub fn org(mut self, alias: &str, members: &[&str]) -> Self {
+        self.steps.push(Step::Org {
+            alias: alias.to_string(),
+            members: members.iter().map(|member| member.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Add a project registered in the given domain. Org projects are registered by the first
+    /// member of the org.
+    ///
+    /// Panics on [Scenario::apply] if `domain` references an unknown user or org.
+    pub fn project(mut self, alias: &str, domain: Domain) -> Self {
+        let domain = match domain {
+            Domain::Org(org) => (DomainKind::Org, org.to_string()),
+            Domain::User(user) => (DomainKind::User, user.to_string()),
+        };
+        self.steps.push(Step::Project {
+            alias: alias.to_string(),
+            domain,
+        });
+        self
+    }
+
+    /// Submit all transactions required to set up the scenario.
+    ///
+    /// Panics if any of the transactions fails.
+    pub async fn apply(self, client: &Client) -> World {
+        let mut world = World::default();
+        for step in self.steps {
+            match step {
+                Step::User { alias } => {
+                    let (key_pair, id) = key_pair_with_associated_user(client).await;
+                    world.users.insert(alias, ScenarioUser { key_pair, id });
+                }
+                Step::Org { alias, members } => {
+                    let (founder, others) = members
+                        .split_first()
+                        .unwrap_or_else(|| panic!("Org \"{}\" has no members", alias));
+                    let founder = world.user(founder).clone();
+                    let (org_id, _) = register_random_org(client, &founder.key_pair).await;
+                    for member in others {
+                        let message = message::RegisterMember {
+                            org_id: org_id.clone(),
+                            user_id: world.user(member).id.clone(),
+                        };
+                        let tx_included = submit_ok(client, &founder.key_pair, message).await;
+                        assert_eq!(tx_included.result, Ok(()));
+                    }
+                    world.orgs.insert(alias, (org_id, founder));
+                }
+                Step::Project { alias, domain } => {
+                    let (author, project_domain) = match domain {
+                        (DomainKind::Org, org) => {
+                            let (org_id, founder) = world.org(&org);
+                            (founder.key_pair.clone(), ProjectDomain::Org(org_id.clone()))
+                        }
+                        (DomainKind::User, user) => {
+                            let user = world.user(&user);
+                            (user.key_pair.clone(), ProjectDomain::User(user.id.clone()))
+                        }
+                    };
+                    let (project_name, _) = create_project(client, &author, &project_domain).await;
+                    world.projects.insert(alias, (project_name, project_domain));
+                }
+            }
+        }
+        world
+    }
+}
+
+/// A user created by a [Scenario].
+#[derive(Clone)]
+pub struct ScenarioUser {
+    /// Key pair associated with the user. The account is funded.
+    pub key_pair: ed25519::Pair,
+    pub id: Id,
+}
+
+/// Result of [Scenario::apply]. Resolves the aliases used in the scenario.
+///
+/// All accessors panic if the alias is unknown.
+#[derive(Default)]
+pub struct World {
+    users: HashMap<String, ScenarioUser>,
+    orgs: HashMap<String, (Id, ScenarioUser)>,
+    projects: HashMap<String, ProjectId>,
+}
+
+impl World {
+    pub fn user(&self, alias: &str) -> &ScenarioUser {
+        self.users
+            .get(alias)
+            .unwrap_or_else(|| panic!("Unknown user \"{}\"", alias))
+    }
+
+    pub fn org_id(&self, alias: &str) -> Id {
+        self.org(alias).0.clone()
+    }
+
+    pub fn project_id(&self, alias: &str) -> ProjectId {
+        self.projects
+            .get(alias)
+            .unwrap_or_else(|| panic!("Unknown project \"{}\"", alias))
+            .clone()
+    }
+
+    fn org(&self, alias: &str) -> &(Id, ScenarioUser) {
+        self.orgs
+            .get(alias)
+            .unwrap_or_else(|| panic!("Unknown org \"{}\"", alias))
+    }
+}